@@ -22,13 +22,22 @@
 # - UnicodeData.txt
 # - auxiliary/GraphemeBreakProperty.txt
 # - emoji/emoji-data.txt
-# - emoji/emoji-test.txt (for tests only)
+# - emoji/emoji-test.txt
 # - emoji/emoji-variation-sequences.txt
 # - extracted/DerivedCombiningClass.txt
 # - extracted/DerivedGeneralCategory.txt
 # - extracted/DerivedJoiningGroup.txt
 # - extracted/DerivedJoiningType.txt
 #
+# It also uses the following files from glibc's `localedata` directory,
+# for `compat::glibc`:
+#
+# - charmaps/UTF-8
+# - locales/i18n_ctype
+#
+# For `compat::python_wcwidth`, it uses the tables of the Python `wcwidth` package,
+# which must be installed at `PYTHON_WCWIDTH_VERSION`.
+#
 # Since this should not require frequent updates, we just store this
 # out-of-line and check the generated module into git.
 
@@ -40,12 +49,65 @@ import re
 import sys
 import urllib.request
 from collections import defaultdict
-from itertools import batched
+from itertools import batched, combinations
 from typing import Callable, Iterable
 
 UNICODE_VERSION = "17.0.0"
 """The version of the Unicode data files to download."""
 
+GLIBC_VERSION = "2.36"
+"""The version of glibc whose `wcwidth` `compat::glibc` reproduces."""
+
+PYTHON_WCWIDTH_VERSION = "0.7.0"
+"""The version of the Python `wcwidth` package that `compat::python_wcwidth` reproduces."""
+
+PYTHON_WCWIDTH_SAMPLES = [
+    "",
+    "hello, world",
+    "ｈｅｌｌｏ, ｗｏｒｌｄ",
+    "a\0b",
+    "a\x1bb",
+    "a\nb",
+    "\u00e9\u0301",
+    "\u00b1\u00d7\u2018\u2460",
+    "\u1100\u1161\u11a8",
+    # Indic conjuncts and spacing marks
+    "\u0915\u094d\u0937",
+    "\u0915\u094d\u0937\u093f",
+    "\u0915\u094d\u200d\u0937",
+    "\u0915\u094d",
+    "\u0915\u093e\u0903",
+    "\u093e",
+    "\u0b95\u0bcd\u0bb7",
+    "\u0d15\u0d4d\u0d15\u0d4d\u0d15",
+    # Variation selectors
+    "\u2764\ufe0f",
+    "\u2764\ufe0f\ufe0f",
+    "\ufe0f\u2764",
+    "\u231a\ufe0e",
+    "1\ufe0f\u20e3",
+    "a\ufe0f",
+    # Zero width joiners
+    "\U0001f469\u200d\U0001f52c",
+    "a\u200db",
+    "a\u200d",
+    "\u200d\u200da",
+    "\u05d0\u200d\u05dc",
+    # Regional indicators
+    "\U0001f1ee",
+    "\U0001f1ee\U0001f1f8",
+    "\U0001f1ee\U0001f1f8\U0001f1f3",
+    "\U0001f1ee\U0001f1f8a\U0001f1f3\U0001f1f4",
+    "\U0001f1ee\u200d\U0001f1f8\U0001f1f3",
+    # Emoji modifiers
+    "\U0001f44b\U0001f3fd",
+    "a\U0001f3fd",
+    "\U0001f3fd",
+    "\U0001f1ee\U0001f1f8\U0001f3fd",
+]
+"""Strings whose `wcswidth` is recorded for the `compat::python_wcwidth` tests, on top of the
+sequences in `emoji-test.txt`."""
+
 NUM_CODEPOINTS = 0x110000
 """An upper bound for which `range(0, NUM_CODEPOINTS)` contains Unicode's codespace."""
 
@@ -68,6 +130,18 @@ class OffsetType(enum.IntEnum):
 MODULE_PATH = "../src/tables.rs"
 """The path of the emitted Rust module (relative to the working directory)"""
 
+GLIBC_MODULE_PATH = "../src/compat/glibc/tables.rs"
+"""The path of the emitted Rust module for `compat::glibc` (relative to the working directory)"""
+
+PYTHON_WCWIDTH_MODULE_PATH = "../src/compat/python_wcwidth/tables.rs"
+"""The path of the emitted Rust module for `compat::python_wcwidth`
+(relative to the working directory)"""
+
+PYTHON_WCWIDTH_CHARS_TEST_PATH = "../tests/python-wcwidth-chars.txt"
+PYTHON_WCWIDTH_STRINGS_TEST_PATH = "../tests/python-wcwidth-strings.txt"
+"""The paths of the emitted test data for `compat::python_wcwidth`
+(relative to the working directory)"""
+
 TABLE_SPLITS = [7, 13]
 """The splits between the bits of the codepoint used to index each subtable.
 Adjust these values to change the sizes of the subtables"""
@@ -98,6 +172,25 @@ def fetch_open(filename: str, local_prefix: str = "", emoji: bool = False):
         sys.exit(1)
 
 
+def fetch_glibc_open(path: str):
+    """Opens `path` from glibc's `localedata` directory at `GLIBC_VERSION` and returns its
+    corresponding file object. If it isn't on disk, fetches it from `https://sourceware.org/git/`.
+    Exits with code 1 on failure.
+    """
+    localname = os.path.basename(path)
+    if not os.path.exists(localname):
+        urllib.request.urlretrieve(
+            "https://sourceware.org/git/?p=glibc.git;a=blob_plain;"
+            f"f=localedata/{path};hb=refs/tags/glibc-{GLIBC_VERSION}",
+            localname,
+        )
+    try:
+        return open(localname, encoding="utf-8")
+    except OSError:
+        sys.stderr.write(f"cannot load {localname}")
+        sys.exit(1)
+
+
 def load_unicode_version() -> tuple[int, int, int]:
     """Returns the current Unicode version by fetching and processing `ReadMe.txt`."""
     with fetch_open("ReadMe.txt") as readme:
@@ -402,6 +495,28 @@ class WidthState(enum.IntEnum):
 assert len(set([v.value for v in WidthState])) == len([v.value for v in WidthState])
 
 
+def width_info_states_list() -> list[int]:
+    """Returns every `WidthInfo` value the generated `width_in_str` functions can produce,
+    sorted in ascending order.
+
+    Width states that are transparent to emoji and text presentation (those with the 3rd bit
+    from the top set) can additionally carry any combination of the variation selector bits."""
+    selectors = [
+        WidthState.VARIATION_SELECTOR_1_2_OR_3,
+        WidthState.VARIATION_SELECTOR_15,
+        WidthState.VARIATION_SELECTOR_16,
+    ]
+    states = {0}
+    for variant in WidthState:
+        if variant.is_carried():
+            states.add(variant.value)
+            if variant.value & 0b0010_0000_0000_0000:
+                for count in range(1, len(selectors) + 1):
+                    for combo in combinations(selectors, count):
+                        states.add(variant.value | sum(vs.value for vs in combo))
+    return sorted(states)
+
+
 def load_east_asian_widths() -> list[EastAsianWidth]:
     """Return a list of effective widths, indexed by codepoint.
     Widths are determined by fetching and parsing `EastAsianWidth.txt`.
@@ -483,6 +598,82 @@ def load_east_asian_widths() -> list[EastAsianWidth]:
     return width_map
 
 
+EAST_ASIAN_WIDTH_NAMES = {
+    "Na": "Narrow",
+    "H": "Halfwidth",
+    "W": "Wide",
+    "F": "Fullwidth",
+    "A": "Ambiguous",
+}
+
+
+def load_east_asian_width_values() -> list[tuple[Codepoint, Codepoint, str]]:
+    """Returns a sorted list of the codepoint ranges (inclusive) with an `East_Asian_Width`
+    other than `Neutral`, and the name of their `East_Asian_Width`, as given by
+    `EastAsianWidth.txt` with none of the adjustments of `load_east_asian_widths`."""
+    values: dict[Codepoint, str] = {}
+    for code, name in EAST_ASIAN_WIDTH_NAMES.items():
+        load_property(
+            "EastAsianWidth.txt",
+            code,
+            lambda cp, name=name: operator.setitem(values, cp, name),
+        )
+    ranges: list[tuple[Codepoint, Codepoint, str]] = []
+    for cp in sorted(values):
+        if ranges and ranges[-1][1] == cp - 1 and ranges[-1][2] == values[cp]:
+            ranges[-1] = (ranges[-1][0], cp, values[cp])
+        else:
+            ranges.append((cp, cp, values[cp]))
+    return ranges
+
+
+def load_prepended_zero_widths() -> list[tuple[Codepoint, Codepoint]]:
+    """Returns a sorted list of the codepoint ranges (inclusive) that `load_zero_widths`
+    treats as zero-width because they are prepended to the following character:
+    the zero-width `Prepended_Concatenation_Mark`s, the other characters with a
+    `Grapheme_Cluster_Break` of `Prepend`, and U+A8FA DEVANAGARI CARET.
+    Versions 0.1.x of this crate gave these characters width 1."""
+    prepended = {0x0605, 0x070F, 0x0890, 0x0891, 0x08E2, 0xA8FA}
+    gcb_prepend = set()
+    load_property(
+        "auxiliary/GraphemeBreakProperty.txt",
+        "Prepend",
+        lambda cp: gcb_prepend.add(cp),
+    )
+    load_property(
+        "PropList.txt",
+        "Prepended_Concatenation_Mark",
+        lambda cp: gcb_prepend.discard(cp),
+    )
+    return to_sorted_ranges(prepended | gcb_prepend)
+
+
+def load_ages() -> list[tuple[Codepoint, Codepoint, tuple[int, int]]]:
+    """Returns a sorted list of codepoint ranges (inclusive), each with the version of Unicode,
+    as `(major, minor)`, in which its characters were assigned. Determined from `DerivedAge.txt`.
+    Unassigned codepoints are left out."""
+    ages: list[tuple[int, int] | None] = [None] * NUM_CODEPOINTS
+    with fetch_open("DerivedAge.txt") as derived_age:
+        # matches an age assignment, i.e. "0000..001F    ; 1.1 #  [32] <control-0000>..<control-001F>"
+        line_re = re.compile(r"^([0-9A-F]+)(?:\.\.([0-9A-F]+))?\s*;\s*(\d+)\.(\d+)\s")
+        for line in derived_age.readlines():
+            if match := line_re.match(line):
+                low = int(match.group(1), 16)
+                high = int(match.group(2) or match.group(1), 16)
+                for cp in range(low, high + 1):
+                    ages[cp] = (int(match.group(3)), int(match.group(4)))
+
+    ranges: list[tuple[Codepoint, Codepoint, tuple[int, int]]] = []
+    for cp, age in enumerate(ages):
+        if age is None:
+            continue
+        if len(ranges) > 0 and ranges[-1][1] == cp - 1 and ranges[-1][2] == age:
+            ranges[-1] = (ranges[-1][0], cp, age)
+        else:
+            ranges.append((cp, cp, age))
+    return ranges
+
+
 def load_zero_widths() -> list[bool]:
     """Returns a list `l` where `l[c]` is true if codepoint `c` is considered a zero-width
     character. `c` is considered a zero-width character if
@@ -794,6 +985,107 @@ def load_normalization_tests() -> list[tuple[str, str, str, str, str]]:
         return ret
 
 
+def load_glibc_unicode_version() -> tuple[int, int, int]:
+    """Returns the version of Unicode that glibc's `GLIBC_VERSION` locale data is generated from."""
+    with fetch_glibc_open("locales/i18n_ctype") as ctype:
+        pattern = r"Generated automatically by gen_unicode_ctype.py for Unicode (\d+)\.(\d+)\.(\d+)"
+        return tuple(map(int, re.search(pattern, ctype.read()).groups()))  # type: ignore
+
+
+def load_glibc_widths() -> list[tuple[Codepoint, Codepoint, int]]:
+    """Returns the sorted codepoint ranges (inclusive) for which glibc's `wcwidth` doesn't return 1,
+    along with what it returns for them.
+
+    `wcwidth` returns -1 for characters outside the `print` class of the `i18n_ctype` locale file,
+    and otherwise the width given in the `WIDTH` section of the `UTF-8` charmap, which is 1 for
+    characters not listed there. (It also returns 0 for U+0000, but that is hardcoded in glibc.)
+    """
+
+    def parse_codepoint(symbol: str) -> Codepoint:
+        return int(symbol.strip().removeprefix("<U").removesuffix(">"), 16)
+
+    widths = [1] * NUM_CODEPOINTS
+
+    with fetch_glibc_open("charmaps/UTF-8") as charmap:
+        in_width = False
+        for line in charmap.readlines():
+            line = line.strip()
+            if line == "WIDTH":
+                in_width = True
+            elif line == "END WIDTH":
+                break
+            elif in_width and line and not line.startswith("%"):
+                symbols, width = line.split()
+                (low, _, high) = symbols.partition("...")
+                low = parse_codepoint(low)
+                high = parse_codepoint(high) if high else low
+                for cp in range(low, high + 1):
+                    widths[cp] = int(width)
+
+    printable = [False] * NUM_CODEPOINTS
+    with fetch_glibc_open("locales/i18n_ctype") as ctype:
+        body = re.search(r"^print /\n(.*?[^/])$", ctype.read(), re.M | re.S).group(1)  # type: ignore
+        for item in body.replace("/\n", "").split(";"):
+            # Ranges are written `<U0020>..<U007E>`
+            symbols = item.split("..")
+            for cp in range(parse_codepoint(symbols[0]), parse_codepoint(symbols[-1]) + 1):
+                printable[cp] = True
+
+    ret = []
+    for cp in range(NUM_CODEPOINTS):
+        width = widths[cp] if printable[cp] else -1
+        if width == 1:
+            continue
+        if len(ret) > 0 and ret[-1][1] == cp - 1 and ret[-1][2] == width:
+            ret[-1] = (ret[-1][0], cp, width)
+        else:
+            ret.append((cp, cp, width))
+    return ret
+
+
+def import_python_wcwidth():
+    """Imports the Python `wcwidth` package and returns it.
+    Exits with code 1 if it isn't installed at `PYTHON_WCWIDTH_VERSION`."""
+    try:
+        import wcwidth
+    except ImportError:
+        sys.stderr.write(
+            f"cannot import wcwidth, install it with `pip install wcwidth=={PYTHON_WCWIDTH_VERSION}`"
+        )
+        sys.exit(1)
+    if wcwidth.__version__ != PYTHON_WCWIDTH_VERSION:
+        sys.stderr.write(
+            f"wcwidth {wcwidth.__version__} is installed, but {PYTHON_WCWIDTH_VERSION} is needed"
+        )
+        sys.exit(1)
+    return wcwidth
+
+
+def load_python_wcwidth_tables() -> dict[str, list[tuple[Codepoint, Codepoint]]]:
+    """Returns the codepoint ranges (inclusive) of each table used by the Python `wcwidth`
+    package's `wcwidth` and `wcswidth`, by the name of the table in the emitted module."""
+    import_python_wcwidth()
+    from wcwidth import _constants  # type: ignore
+    from wcwidth.table_grapheme import ISC_CONSONANT  # type: ignore
+    from wcwidth.table_vs16 import VS16_NARROW_TO_WIDE  # type: ignore
+
+    def ranges(table: Iterable[tuple[Codepoint, Codepoint]]) -> list[tuple[Codepoint, Codepoint]]:
+        return to_sorted_ranges(cp for lo, hi in table for cp in range(lo, hi + 1))
+
+    return {
+        "ZERO_WIDTH": ranges(_constants._ZERO_WIDTH_TABLE),
+        "WIDE_EASTASIAN": ranges(_constants._WIDE_EASTASIAN_TABLE),
+        "AMBIGUOUS": ranges(_constants._AMBIGUOUS_TABLE),
+        "CATEGORY_MC": ranges(_constants._CATEGORY_MC_TABLE),
+        "VS16_NARROW_TO_WIDE": ranges(VS16_NARROW_TO_WIDE["9.0.0"]),
+        "ISC_CONSONANT": ranges(ISC_CONSONANT),
+        "ISC_VIRAMA": to_sorted_ranges(_constants._ISC_VIRAMA_SET),
+        "EMOJI_ZWJ": to_sorted_ranges(_constants._EMOJI_ZWJ_SET),
+        "REGIONAL_INDICATOR": to_sorted_ranges(_constants._REGIONAL_INDICATOR_SET),
+        "FITZPATRICK": [_constants._FITZPATRICK_RANGE],
+    }
+
+
 def make_special_ranges(
     width_map: list[WidthState],
 ) -> list[tuple[tuple[Codepoint, Codepoint], WidthState]]:
@@ -1105,6 +1397,84 @@ def load_emoji_modifier_bases() -> list[Codepoint]:
     return ret
 
 
+EMOJI_PROPERTIES = [
+    "Emoji",
+    "Emoji_Presentation",
+    "Emoji_Component",
+    "Extended_Pictographic",
+    "Emoji_Modifier",
+]
+
+
+def load_emoji_properties() -> list[tuple[Codepoint, Codepoint, int]]:
+    """Returns a sorted list of the codepoint ranges (inclusive) with any of the
+    `EMOJI_PROPERTIES`, each with a bitset of its properties, where bit `i` is set
+    if the range has `EMOJI_PROPERTIES[i]`."""
+    flags: dict[Codepoint, int] = defaultdict(int)
+    for i, prop in enumerate(EMOJI_PROPERTIES):
+        load_property(
+            "emoji/emoji-data.txt",
+            prop,
+            lambda cp, bit=1 << i: operator.setitem(flags, cp, flags[cp] | bit),
+        )
+    ranges: list[tuple[Codepoint, Codepoint, int]] = []
+    for cp in sorted(flags):
+        if ranges and ranges[-1][1] == cp - 1 and ranges[-1][2] == flags[cp]:
+            ranges[-1] = (ranges[-1][0], cp, flags[cp])
+        else:
+            ranges.append((cp, cp, flags[cp]))
+    return ranges
+
+
+def load_emoji_versions() -> tuple[
+    list[tuple[Codepoint, Codepoint, tuple[int, int]]],
+    list[tuple[list[Codepoint], tuple[int, int], tuple[int, int]]],
+]:
+    """Determines from `emoji-test.txt` the emoji version in which each emoji was introduced.
+
+    Returns a sorted list of codepoint ranges (inclusive) of single-codepoint emoji, each with
+    its version, and a sorted list of emoji ZWJ sequences, each with the versions in which it
+    was introduced without and with emoji modifiers. The ZWJ sequences are given without
+    variation selectors and emoji modifiers, since those don't change which emoji they form."""
+
+    singles: dict[Codepoint, tuple[int, int]] = {}
+    zwj_sequences: dict[tuple[Codepoint, ...], list[tuple[int, int] | None]] = {}
+    with fetch_open("emoji-test.txt", "../tests", emoji=True) as emoji_test:
+        # matches a sequence with its version, i.e. "1F600 ; fully-qualified # 😀 E1.0 grinning face"
+        line_re = re.compile(r"^([0-9A-F ]+?)\s*;\s*[\w-]+\s*#\s*\S+\s+E(\d+)\.(\d+)\s")
+        for line in emoji_test.readlines():
+            if match := line_re.match(line):
+                cps = [int(cp, 16) for cp in match.group(1).split()]
+                version = (int(match.group(2)), int(match.group(3)))
+                skeleton = tuple(
+                    cp for cp in cps if cp != 0xFE0F and not 0x1F3FB <= cp <= 0x1F3FF
+                )
+                if len(cps) - cps.count(0xFE0F) == 1:
+                    singles[cps[0]] = min(singles.get(cps[0], version), version)
+                elif 0x200D in cps:
+                    toned = len(skeleton) < len(cps) - cps.count(0xFE0F)
+                    versions = zwj_sequences.setdefault(skeleton, [None, None])
+                    if versions[toned] is None or version < versions[toned]:
+                        versions[toned] = version
+
+    single_ranges: list[tuple[Codepoint, Codepoint, tuple[int, int]]] = []
+    for cp in sorted(singles):
+        if (
+            len(single_ranges) > 0
+            and single_ranges[-1][1] == cp - 1
+            and single_ranges[-1][2] == singles[cp]
+        ):
+            single_ranges[-1] = (single_ranges[-1][0], cp, singles[cp])
+        else:
+            single_ranges.append((cp, cp, singles[cp]))
+
+    sequences = []
+    for skeleton in sorted(zwj_sequences):
+        (untoned, toned) = zwj_sequences[skeleton]
+        sequences.append((list(skeleton), untoned or toned, toned or untoned))
+    return (single_ranges, sequences)
+
+
 def make_presentation_sequence_table(
     seqs: list[Codepoint],
     lsb: int = 10,
@@ -1237,11 +1607,19 @@ fn lookup_width{cjk_lo}(c: char) -> (u8, WidthInfo) {{
     if width < 3 {{
         (width, WidthInfo::DEFAULT)
     }} else {{
-        match c {{
+        special_width{cjk_lo}(c)
+    }}
+}}
+
+/// Returns the width of `c`, and the state it passes on, for a character that the lookup
+/// table marks as special with a width of 3.
+{cfg}#[inline]
+pub fn special_width{cjk_lo}(c: char) -> (u8, WidthInfo) {{
+    match c {{
 """
 
     for (lo, hi), width in special_ranges:
-        s += f"            '\\u{{{lo:X}}}'"
+        s += f"        '\\u{{{lo:X}}}'"
         if hi != lo:
             s += f"..='\\u{{{hi:X}}}'"
         if width.is_carried():
@@ -1250,8 +1628,7 @@ fn lookup_width{cjk_lo}(c: char) -> (u8, WidthInfo) {{
             width_info = "DEFAULT"
         s += f" => ({width.width_alone()}, WidthInfo::{width_info}),\n"
 
-    s += f"""            _ => (2, WidthInfo::EMOJI_PRESENTATION),
-        }}
+    s += f"""        _ => (2, WidthInfo::EMOJI_PRESENTATION),
     }}
 }}
 
@@ -1260,6 +1637,12 @@ fn lookup_width{cjk_lo}(c: char) -> (u8, WidthInfo) {{
 /// Ambiguous width characters are treated as {ambig}.
 {cfg}#[inline]
 pub fn single_char_width{cjk_lo}(c: char) -> Option<usize> {{
+    single_char_width_with{cjk_lo}(Builtin, c)
+}}
+
+/// Like [`single_char_width{cjk_lo}`], but looks `c` up in `tables`.
+{cfg}#[inline]
+pub fn single_char_width_with{cjk_lo}(tables: impl WidthLookup, c: char) -> Option<usize> {{
     if c < '\\u{{7F}}' {{
         if c >= '\\u{{20}}' {{
             // U+0020 to U+007F (exclusive) are single-width ASCII codepoints
@@ -1270,7 +1653,7 @@ pub fn single_char_width{cjk_lo}(c: char) -> Option<usize> {{
         }}
     }} else if c >= '\\u{{A0}}' {{
         // No characters >= U+00A0 are control codes, so we can consult the lookup tables
-        Some(lookup_width{cjk_lo}(c).0.into())
+        Some(tables.lookup_width{cjk_lo}(c).0.into())
     }} else {{
         // U+007F to U+00A0 (exclusive) are control codes
         None
@@ -1280,9 +1663,19 @@ pub fn single_char_width{cjk_lo}(c: char) -> Option<usize> {{
 /// Returns the [UAX #11](https://www.unicode.org/reports/tr11/) based width of `c`.
 /// Ambiguous width characters are treated as {ambig}.
 {cfg}#[inline]
-fn width_in_str{cjk_lo}(c: char, mut next_info: WidthInfo) -> (i8, WidthInfo) {{
+pub fn width_in_str{cjk_lo}(c: char, next_info: WidthInfo) -> (i8, WidthInfo) {{
+    width_in_str_with{cjk_lo}(Builtin, c, next_info)
+}}
+
+/// Like [`width_in_str{cjk_lo}`], but looks `c` up in `tables`.
+{cfg}#[inline]
+pub fn width_in_str_with{cjk_lo}(
+    tables: impl WidthLookup,
+    c: char,
+    mut next_info: WidthInfo,
+) -> (i8, WidthInfo) {{
     if next_info.is_emoji_presentation() {{
-        if starts_emoji_presentation_seq(c) {{
+        if tables.starts_emoji_presentation_seq(c) {{
             let width = if next_info.is_zwj_emoji_presentation() {{
                 0
             }} else {{
@@ -1364,7 +1757,7 @@ fn width_in_str{cjk_lo}(c: char, mut next_info: WidthInfo) -> (i8, WidthInfo) {{
         s += """
                 (WidthInfo::COMBINING_LONG_SOLIDUS_OVERLAY, _) if is_solidus_transparent(c) => {
                     return (
-                        lookup_width_cjk(c).0 as i8,
+                        tables.lookup_width_cjk(c).0 as i8,
                         WidthInfo::COMBINING_LONG_SOLIDUS_OVERLAY,
                     );
                 }
@@ -1459,7 +1852,9 @@ fn width_in_str{cjk_lo}(c: char, mut next_info: WidthInfo) -> (i8, WidthInfo) {{
                 (WidthInfo::ZWJ_EMOJI_PRESENTATION, '\\u{{20E3}}') => {{
                     return (0, WidthInfo::KEYCAP_ZWJ_EMOJI_PRESENTATION);
                 }}
-                (WidthInfo::VS16_ZWJ_EMOJI_PRESENTATION, _) if starts_emoji_presentation_seq(c) => {{
+                (WidthInfo::VS16_ZWJ_EMOJI_PRESENTATION, _)
+                    if tables.starts_emoji_presentation_seq(c) =>
+                {{
                     return (0, WidthInfo::EMOJI_PRESENTATION)
                 }}
                 (WidthInfo::VS16_KEYCAP_ZWJ_EMOJI_PRESENTATION, '0'..='9' | '#' | '*') => {{
@@ -1524,7 +1919,7 @@ fn width_in_str{cjk_lo}(c: char, mut next_info: WidthInfo) -> (i8, WidthInfo) {{
                     '\\u{{1F3F4}}',
                 ) => return (0, WidthInfo::EMOJI_PRESENTATION),
                 (WidthInfo::ZWJ_EMOJI_PRESENTATION, _)
-                    if lookup_width{cjk_lo}(c).1 == WidthInfo::EMOJI_PRESENTATION =>
+                    if tables.lookup_width{cjk_lo}(c).1 == WidthInfo::EMOJI_PRESENTATION =>
                 {{
                     return (0, WidthInfo::EMOJI_PRESENTATION)
                 }}
@@ -1550,18 +1945,24 @@ fn width_in_str{cjk_lo}(c: char, mut next_info: WidthInfo) -> (i8, WidthInfo) {{
             }}
         }}
 
-        let ret = lookup_width{cjk_lo}(c);
+        let ret = tables.lookup_width{cjk_lo}(c);
         (ret.0 as i8, ret.1)
     }}
 }}
 
 {cfg}#[inline]
 pub fn str_width{cjk_lo}(s: &str) -> usize {{
+    str_width_with{cjk_lo}(Builtin, s)
+}}
+
+/// Like [`str_width{cjk_lo}`], but looks characters up in `tables`.
+{cfg}#[inline]
+pub fn str_width_with{cjk_lo}(tables: impl WidthLookup, s: &str) -> usize {{
     s.chars()
         .rfold(
             (0, WidthInfo::DEFAULT),
             |(sum, next_info), c| -> (usize, WidthInfo) {{
-                let (add, info) = width_in_str{cjk_lo}(c, next_info);
+                let (add, info) = width_in_str_with{cjk_lo}(tables, c, next_info);
                 (sum.wrapping_add_signed(isize::from(add)), info)
             }},
         )
@@ -1581,10 +1982,16 @@ def emit_module(
     emoji_presentation_table: tuple[list[tuple[int, int]], list[list[int]]],
     text_presentation_table: tuple[list[tuple[int, int]], list[list[tuple[int, int]]]],
     emoji_modifier_table: tuple[list[tuple[int, int]], list[list[tuple[int, int]]]],
+    emoji_properties: list[tuple[Codepoint, Codepoint, int]],
     joining_group_lam: list[tuple[Codepoint, Codepoint]],
     non_transparent_zero_widths: list[tuple[Codepoint, Codepoint]],
     ligature_transparent: list[tuple[Codepoint, Codepoint]],
     solidus_transparent: list[tuple[Codepoint, Codepoint]],
+    east_asian_width_values: list[tuple[Codepoint, Codepoint, str]],
+    prepended_zero_widths: list[tuple[Codepoint, Codepoint]],
+    ages: list[tuple[Codepoint, Codepoint, tuple[int, int]]],
+    emoji_versions: list[tuple[Codepoint, Codepoint, tuple[int, int]]],
+    zwj_sequence_versions: list[tuple[list[Codepoint], tuple[int, int], tuple[int, int]]],
     normalization_tests: list[tuple[str, str, str, str, str]],
 ):
     """Outputs a Rust module to `out_name` using table data from `tables`.
@@ -1608,14 +2015,16 @@ def emit_module(
 
 use core::cmp::Ordering;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-struct WidthInfo(u16);
+use crate::uax11::EastAsianWidth;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct WidthInfo(u16);
 
 const LIGATURE_TRANSPARENT_MASK: u16 = 0b0010_0000_0000_0000;
 
 impl WidthInfo {
     /// No special handling necessary
-    const DEFAULT: Self = Self(0);
+    pub const DEFAULT: Self = Self(0);
 """
         )
 
@@ -1627,6 +2036,10 @@ impl WidthInfo {
                     f"    const {variant.name}: Self = Self(0b{variant.value:016b});\n"
                 )
 
+        width_info_states = "".join(
+            f"    WidthInfo(0b{state:016b}),\n" for state in width_info_states_list()
+        )
+
         module.write(
             f"""
     /// Whether this width mode is ligature_transparent
@@ -1724,9 +2137,59 @@ impl WidthInfo {
     }}
 }}
 
+/// The number of distinct `WidthInfo`s the string width state machines can produce.
+pub const WIDTH_INFO_STATES_LEN: usize = {len(width_info_states_list())};
+
+/// Every `WidthInfo` that `width_in_str` or `width_in_str_cjk` can return, in ascending order.
+/// This is each carried width state, plus every combination of variation selector bits
+/// on top of the ones that are transparent to emoji and text presentation.
+#[rustfmt::skip]
+pub static WIDTH_INFO_STATES: [WidthInfo; WIDTH_INFO_STATES_LEN] = [
+{width_info_states}];
+
 /// The version of [Unicode](http://www.unicode.org/)
 /// that this version of unicode-width is based on.
 pub const UNICODE_VERSION: (u8, u8, u8) = {unicode_version};
+
+/// The tables that the width state machine looks characters up in.
+///
+/// [`Builtin`] uses the tables in this module, and [`WidthTables`](crate::WidthTables)
+/// can also use tables loaded at runtime.
+pub trait WidthLookup: Copy {{
+    /// Returns the width of `c`, and the state it passes on, with ambiguous width
+    /// characters treated as narrow.
+    fn lookup_width(self, c: char) -> (u8, WidthInfo);
+
+    /// Returns the width of `c`, and the state it passes on, with ambiguous width
+    /// characters treated as wide.
+    #[cfg(feature = "cjk")]
+    fn lookup_width_cjk(self, c: char) -> (u8, WidthInfo);
+
+    /// Whether `c` forms an emoji presentation sequence when followed by `'\\u{{FE0F}}'`.
+    fn starts_emoji_presentation_seq(self, c: char) -> bool;
+}}
+
+/// The tables in this module.
+#[derive(Clone, Copy, Debug)]
+pub struct Builtin;
+
+impl WidthLookup for Builtin {{
+    #[inline]
+    fn lookup_width(self, c: char) -> (u8, WidthInfo) {{
+        lookup_width(c)
+    }}
+
+    #[cfg(feature = "cjk")]
+    #[inline]
+    fn lookup_width_cjk(self, c: char) -> (u8, WidthInfo) {{
+        lookup_width_cjk(c)
+    }}
+
+    #[inline]
+    fn starts_emoji_presentation_seq(self, c: char) -> bool {{
+        starts_emoji_presentation_seq(c)
+    }}
+}}
 """
         )
 
@@ -1806,6 +2269,104 @@ fn is_solidus_transparent(c: char) -> bool {
             .is_ok()
 }
 
+/// Returns the `East_Asian_Width` of this character.
+pub fn east_asian_width(c: char) -> EastAsianWidth {
+    let cp: u32 = c.into();
+    EAST_ASIAN_WIDTHS
+        .binary_search_by(|&(lo, hi, _)| {
+            let lo = u32::from_le_bytes([lo[0], lo[1], lo[2], 0]);
+            let hi = u32::from_le_bytes([hi[0], hi[1], hi[2], 0]);
+            if cp < lo {
+                Ordering::Greater
+            } else if cp > hi {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+        .map_or(EastAsianWidth::Neutral, |i| EAST_ASIAN_WIDTHS[i].2)
+}
+
+/// Whether this character has width 0 only because it is prepended
+/// to the following character.
+pub fn is_prepended_zero_width(c: char) -> bool {
+    let cp: u32 = c.into();
+    PREPENDED_ZERO_WIDTHS
+        .binary_search_by(|&(lo, hi)| {
+            let lo = u32::from_le_bytes([lo[0], lo[1], lo[2], 0]);
+            let hi = u32::from_le_bytes([hi[0], hi[1], hi[2], 0]);
+            if cp < lo {
+                Ordering::Greater
+            } else if cp > hi {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// Returns the version of Unicode, as `(major, minor)`, in which this character
+/// was assigned, or `None` if it is unassigned.
+pub fn age(c: char) -> Option<(u8, u8)> {
+    let cp: u32 = c.into();
+    AGES.binary_search_by(|&(lo, hi, _)| {
+        let lo = u32::from_le_bytes([lo[0], lo[1], lo[2], 0]);
+        let hi = u32::from_le_bytes([hi[0], hi[1], hi[2], 0]);
+        if cp < lo {
+            Ordering::Greater
+        } else if cp > hi {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        }
+    })
+    .ok()
+    .map(|i| (AGES[i].2[0], AGES[i].2[1]))
+}
+
+/// Returns the emoji version, as `(major, minor)`, in which this character
+/// was introduced as an emoji, or `None` if it isn't an emoji on its own.
+pub fn emoji_version(c: char) -> Option<(u8, u8)> {
+    let cp: u32 = c.into();
+    EMOJI_VERSIONS
+        .binary_search_by(|&(lo, hi, _)| {
+            let lo = u32::from_le_bytes([lo[0], lo[1], lo[2], 0]);
+            let hi = u32::from_le_bytes([hi[0], hi[1], hi[2], 0]);
+            if cp < lo {
+                Ordering::Greater
+            } else if cp > hi {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|i| (EMOJI_VERSIONS[i].2[0], EMOJI_VERSIONS[i].2[1]))
+}
+
+/// Returns the emoji version, as `(major, minor)`, in which the emoji ZWJ sequence
+/// `skeleton` was introduced, or `None` if it isn't a recommended emoji ZWJ sequence.
+/// `skeleton` must have no variation selectors or emoji modifiers, and `toned`
+/// says whether the sequence had emoji modifiers.
+pub fn zwj_sequence_version(
+    skeleton: impl Iterator<Item = char> + Clone,
+    toned: bool,
+) -> Option<(u8, u8)> {
+    ZWJ_SEQUENCE_VERSIONS
+        .binary_search_by(|&(seq, _, _)| seq.chars().cmp(skeleton.clone()))
+        .ok()
+        .map(|i| {
+            let (_, untoned_version, toned_version) = ZWJ_SEQUENCE_VERSIONS[i];
+            let version = if toned {
+                toned_version
+            } else {
+                untoned_version
+            };
+            (version[0], version[1])
+        })
+}
+
 /// Whether this character forms an [emoji presentation sequence]
 /// (https://www.unicode.org/reports/tr51/#def_emoji_presentation_sequence)
 /// when followed by `'\\u{FEOF}'`.
@@ -1896,14 +2457,63 @@ pub fn is_emoji_modifier_base(c: char) -> bool {
     .is_ok()
 }
 
+/// Returns the bitset of the emoji properties of `c` in `EMOJI_PROPERTIES`,
+/// with bit `i` set if `c` has `EMOJI_PROPERTIES[i]` in `unicode.py`.
+fn emoji_properties(c: char) -> u8 {
+    let cp: u32 = c.into();
+    EMOJI_PROPERTIES
+        .binary_search_by(|&(lo, hi, _)| {
+            let lo = u32::from_le_bytes([lo[0], lo[1], lo[2], 0]);
+            let hi = u32::from_le_bytes([hi[0], hi[1], hi[2], 0]);
+            if cp < lo {
+                Ordering::Greater
+            } else if cp > hi {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+        .map_or(0, |i| EMOJI_PROPERTIES[i].2)
+}
+
+/// Returns `true` if `c` has the `Emoji` property.
+#[inline]
+pub fn is_emoji(c: char) -> bool {
+    emoji_properties(c) & 1 != 0
+}
+
+/// Returns `true` if `c` has the `Emoji_Presentation` property.
+#[inline]
+pub fn is_emoji_presentation(c: char) -> bool {
+    emoji_properties(c) & 2 != 0
+}
+
+/// Returns `true` if `c` has the `Emoji_Component` property.
+#[inline]
+pub fn is_emoji_component(c: char) -> bool {
+    emoji_properties(c) & 4 != 0
+}
+
+/// Returns `true` if `c` has the `Extended_Pictographic` property.
+#[inline]
+pub fn is_extended_pictographic(c: char) -> bool {
+    emoji_properties(c) & 8 != 0
+}
+
+/// Returns `true` if `c` has the `Emoji_Modifier` property.
+#[inline]
+pub fn is_emoji_modifier(c: char) -> bool {
+    emoji_properties(c) & 16 != 0
+}
+
 #[repr(align(32))]
-struct Align32<T>(T);
+pub struct Align32<T>(pub T);
 
 #[repr(align(64))]
-struct Align64<T>(T);
+pub struct Align64<T>(pub T);
 
 #[repr(align(128))]
-struct Align128<T>(T);
+pub struct Align128<T>(pub T);
 """
         )
 
@@ -1921,7 +2531,7 @@ struct Align128<T>(T);
                 if table.cfged:
                     module.write('#[cfg(feature = "cjk")]\n')
                 module.write(
-                    f"static {table.name}: Align{table.align}<[u8; {len(byte_array)}]> = Align{table.align}(["
+                    f"pub static {table.name}: Align{table.align}<[u8; {len(byte_array)}]> = Align{table.align}(["
                 )
                 for j, byte in enumerate(byte_array):
                     # Add line breaks for every 15th entry (chosen to match what rustfmt does)
@@ -1939,11 +2549,11 @@ struct Align128<T>(T);
                 module.write(
                     f"""
 #[cfg(feature = "cjk")]
-const {table.name}_LEN: usize = {num_rows};
+pub const {table.name}_LEN: usize = {num_rows};
 #[cfg(not(feature = "cjk"))]
-const {table.name}_LEN: usize = {num_primary_rows};
+pub const {table.name}_LEN: usize = {num_primary_rows};
 /// Autogenerated. {subtable_count} sub-table(s). Consult [`lookup_width`] for layout info.
-static {table.name}: Align{table.align}<[[u8; {table.bytes_per_row}]; {table.name}_LEN]> = Align{table.align}([\n"""
+pub static {table.name}: Align{table.align}<[[u8; {table.bytes_per_row}]; {table.name}_LEN]> = Align{table.align}([\n"""
                 )
                 for row_num in range(0, num_rows):
                     if row_num >= num_primary_rows:
@@ -1998,6 +2608,105 @@ static SOLIDUS_TRANSPARENT: [([u8; 3], [u8; 3]); {len(solidus_transparent)}] = [
                 f"    ([0x{lo & 0xFF:02X}, 0x{lo >> 8 & 0xFF:02X}, 0x{lo >> 16:02X}], [0x{hi & 0xFF:02X}, 0x{hi >> 8 & 0xFF:02X}, 0x{hi >> 16:02X}]),\n"
             )
 
+        # East Asian width table
+
+        module.write(
+            f"""];
+
+/// Sorted list of codepoint ranges (inclusive)
+/// with an `East_Asian_Width` other than `Neutral`, and their `East_Asian_Width`.
+static EAST_ASIAN_WIDTHS: [([u8; 3], [u8; 3], EastAsianWidth); {len(east_asian_width_values)}] = [
+"""
+        )
+
+        for lo, hi, name in east_asian_width_values:
+            module.write(
+                f"    ([0x{lo & 0xFF:02X}, 0x{lo >> 8 & 0xFF:02X}, 0x{lo >> 16:02X}], [0x{hi & 0xFF:02X}, 0x{hi >> 8 & 0xFF:02X}, 0x{hi >> 16:02X}], EastAsianWidth::{name}),\n"
+            )
+
+        # emoji properties table
+
+        module.write(
+            f"""];
+
+/// Sorted list of codepoint ranges (inclusive) with any of the emoji properties
+/// in `EMOJI_PROPERTIES` in `unicode.py`, and the bitset of their properties.
+static EMOJI_PROPERTIES: [([u8; 3], [u8; 3], u8); {len(emoji_properties)}] = [
+"""
+        )
+
+        for lo, hi, flags in emoji_properties:
+            module.write(
+                f"    ([0x{lo & 0xFF:02X}, 0x{lo >> 8 & 0xFF:02X}, 0x{lo >> 16:02X}], [0x{hi & 0xFF:02X}, 0x{hi >> 8 & 0xFF:02X}, 0x{hi >> 16:02X}], 0b{flags:04b}),\n"
+            )
+
+        # prepended zero widths table
+
+        module.write(
+            f"""];
+
+/// Sorted list of codepoint ranges (inclusive)
+/// that have width 0 because they are prepended to the following character.
+static PREPENDED_ZERO_WIDTHS: [([u8; 3], [u8; 3]); {len(prepended_zero_widths)}] = [
+"""
+        )
+
+        for lo, hi in prepended_zero_widths:
+            module.write(
+                f"    ([0x{lo & 0xFF:02X}, 0x{lo >> 8 & 0xFF:02X}, 0x{lo >> 16:02X}], [0x{hi & 0xFF:02X}, 0x{hi >> 8 & 0xFF:02X}, 0x{hi >> 16:02X}]),\n"
+            )
+
+        # ages table
+
+        module.write(
+            f"""];
+
+/// Sorted list of assigned codepoint ranges (inclusive), each with the version
+/// of Unicode, as `[major, minor]`, in which it was assigned.
+static AGES: [([u8; 3], [u8; 3], [u8; 2]); {len(ages)}] = [
+"""
+        )
+
+        for lo, hi, (major, minor) in ages:
+            module.write(
+                f"    ([0x{lo & 0xFF:02X}, 0x{lo >> 8 & 0xFF:02X}, 0x{lo >> 16:02X}], [0x{hi & 0xFF:02X}, 0x{hi >> 8 & 0xFF:02X}, 0x{hi >> 16:02X}], [{major}, {minor}]),\n"
+            )
+
+        # emoji versions table
+
+        module.write(
+            f"""];
+
+/// Sorted list of codepoint ranges (inclusive) of emoji, each with the emoji version,
+/// as `[major, minor]`, in which it was introduced.
+static EMOJI_VERSIONS: [([u8; 3], [u8; 3], [u8; 2]); {len(emoji_versions)}] = [
+"""
+        )
+
+        for lo, hi, (major, minor) in emoji_versions:
+            module.write(
+                f"    ([0x{lo & 0xFF:02X}, 0x{lo >> 8 & 0xFF:02X}, 0x{lo >> 16:02X}], [0x{hi & 0xFF:02X}, 0x{hi >> 8 & 0xFF:02X}, 0x{hi >> 16:02X}], [{major}, {minor}]),\n"
+            )
+
+        # ZWJ sequence versions table
+
+        module.write(
+            f"""];
+
+/// Sorted list of emoji ZWJ sequences, without variation selectors and emoji modifiers,
+/// each with the emoji versions, as `[major, minor]`, in which it was introduced
+/// without and with emoji modifiers.
+#[rustfmt::skip]
+static ZWJ_SEQUENCE_VERSIONS: [(&str, [u8; 2], [u8; 2]); {len(zwj_sequence_versions)}] = [
+"""
+        )
+
+        for skeleton, (major, minor), (toned_major, toned_minor) in zwj_sequence_versions:
+            seq = "".join(f"\\u{{{cp:X}}}" for cp in skeleton)
+            module.write(
+                f'    ("{seq}", [{major}, {minor}], [{toned_major}, {toned_minor}]),\n'
+            )
+
         # emoji table
 
         module.write(
@@ -2155,6 +2864,178 @@ mod tests {{
         module.write("    ];\n}\n")
 
 
+def emit_glibc_module(
+    out_name: str,
+    glibc_unicode_version: tuple[int, int, int],
+    glibc_widths: list[tuple[Codepoint, Codepoint, int]],
+):
+    """Outputs the Rust module for `compat::glibc` to `out_name`."""
+    glibc_version = tuple(map(int, GLIBC_VERSION.split(".")))
+    if os.path.exists(out_name):
+        os.remove(out_name)
+    with open(out_name, "w", newline="\n", encoding="utf-8") as module:
+        module.write(
+            f"""// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// NOTE: The following code was generated by "scripts/unicode.py", do not edit directly
+
+use core::cmp::Ordering;
+
+/// The version of glibc whose tables these are.
+pub const GLIBC_VERSION: (u8, u8) = {glibc_version};
+
+/// The version of Unicode that glibc's tables are generated from.
+pub const UNICODE_VERSION: (u8, u8, u8) = {glibc_unicode_version};
+
+/// Returns what glibc's `wcwidth` returns for `c`, except for `'\\0'`.
+pub fn lookup_width(c: char) -> i8 {{
+    let cp: u32 = c.into();
+    WIDTHS
+        .binary_search_by(|&(lo, hi, _)| {{
+            let lo = u32::from_le_bytes([lo[0], lo[1], lo[2], 0]);
+            let hi = u32::from_le_bytes([hi[0], hi[1], hi[2], 0]);
+            if cp < lo {{
+                Ordering::Greater
+            }} else if cp > hi {{
+                Ordering::Less
+            }} else {{
+                Ordering::Equal
+            }}
+        }})
+        .map_or(1, |i| WIDTHS[i].2)
+}}
+
+/// Sorted list of codepoint ranges (inclusive)
+/// for which `wcwidth` doesn't return 1, and what it returns for them.
+#[rustfmt::skip]
+static WIDTHS: [([u8; 3], [u8; 3], i8); {len(glibc_widths)}] = [
+"""
+        )
+
+        for lo, hi, width in glibc_widths:
+            module.write(
+                f"    ([0x{lo & 0xFF:02X}, 0x{lo >> 8 & 0xFF:02X}, 0x{lo >> 16:02X}], [0x{hi & 0xFF:02X}, 0x{hi >> 8 & 0xFF:02X}, 0x{hi >> 16:02X}], {width}),\n"
+            )
+
+        module.write("];\n")
+
+
+def emit_python_wcwidth_module(
+    out_name: str,
+    tables: dict[str, list[tuple[Codepoint, Codepoint]]],
+):
+    """Outputs the Rust module for `compat::python_wcwidth` to `out_name`."""
+    python_wcwidth_version = tuple(map(int, PYTHON_WCWIDTH_VERSION.split(".")))
+    if os.path.exists(out_name):
+        os.remove(out_name)
+    with open(out_name, "w", newline="\n", encoding="utf-8") as module:
+        module.write(
+            f"""// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// NOTE: The following code was generated by "scripts/unicode.py", do not edit directly
+
+use core::cmp::Ordering;
+
+/// The version of the Python `wcwidth` package whose tables these are.
+pub const PYTHON_WCWIDTH_VERSION: (u8, u8, u8) = {python_wcwidth_version};
+
+/// Whether `c` is in `table`, a sorted list of codepoint ranges (inclusive).
+pub fn bisearch(c: char, table: &[([u8; 3], [u8; 3])]) -> bool {{
+    let cp: u32 = c.into();
+    table
+        .binary_search_by(|&(lo, hi)| {{
+            let lo = u32::from_le_bytes([lo[0], lo[1], lo[2], 0]);
+            let hi = u32::from_le_bytes([hi[0], hi[1], hi[2], 0]);
+            if cp < lo {{
+                Ordering::Greater
+            }} else if cp > hi {{
+                Ordering::Less
+            }} else {{
+                Ordering::Equal
+            }}
+        }})
+        .is_ok()
+}}
+"""
+        )
+
+        for name, table in tables.items():
+            module.write(
+                f"""
+/// Sorted list of codepoint ranges (inclusive) in the package's `{name}` table.
+"""
+            )
+            if name == "AMBIGUOUS":
+                module.write('#[cfg(feature = "cjk")]\n')
+            module.write(
+                f"""#[rustfmt::skip]
+pub static {name}: [([u8; 3], [u8; 3]); {len(table)}] = [
+"""
+            )
+            for lo, hi in table:
+                module.write(
+                    f"    ([0x{lo & 0xFF:02X}, 0x{lo >> 8 & 0xFF:02X}, 0x{lo >> 16:02X}], [0x{hi & 0xFF:02X}, 0x{hi >> 8 & 0xFF:02X}, 0x{hi >> 16:02X}]),\n"
+                )
+            module.write("];\n")
+
+
+def emit_python_wcwidth_tests(chars_name: str, strings_name: str):
+    """Outputs what the Python `wcwidth` package's `wcwidth` returns for every codepoint to
+    `chars_name`, and what its `wcswidth` returns for `PYTHON_WCWIDTH_SAMPLES` and
+    the sequences in `emoji-test.txt` to `strings_name`, with ambiguous widths of 1 and 2."""
+    wcwidth = import_python_wcwidth()
+
+    with open(chars_name, "w", newline="\n", encoding="utf-8") as chars:
+        chars.write(
+            f"# wcwidth(c) and wcwidth(c, ambiguous_width=2) of Python wcwidth {PYTHON_WCWIDTH_VERSION}\n"
+            "# generated by scripts/unicode.py, for each range of codepoints\n"
+        )
+        ranges: list[tuple[Codepoint, Codepoint, int, int]] = []
+        for cp in range(NUM_CODEPOINTS):
+            if 0xD800 <= cp <= 0xDFFF:
+                continue
+            widths = (wcwidth.wcwidth(chr(cp)), wcwidth.wcwidth(chr(cp), ambiguous_width=2))
+            if len(ranges) > 0 and ranges[-1][2:] == widths:
+                ranges[-1] = (ranges[-1][0], cp, *widths)
+            else:
+                ranges.append((cp, cp, *widths))
+        for lo, hi, width, width_cjk in ranges:
+            chars.write(f"{lo:04X}..{hi:04X}; {width}; {width_cjk}\n")
+
+    samples = list(PYTHON_WCWIDTH_SAMPLES)
+    with fetch_open("emoji-test.txt", "../tests", emoji=True) as emoji_test:
+        for line in emoji_test.readlines():
+            if match := re.match(r"^([0-9A-F ]+);", line):
+                samples.append("".join(chr(int(cp, 16)) for cp in match.group(1).split()))
+
+    with open(strings_name, "w", newline="\n", encoding="utf-8") as strings:
+        strings.write(
+            f"# wcswidth(s) and wcswidth(s, ambiguous_width=2) of Python wcwidth {PYTHON_WCWIDTH_VERSION}\n"
+            "# generated by scripts/unicode.py, for each string of codepoints\n"
+        )
+        for sample in samples:
+            codepoints = " ".join(f"{ord(c):04X}" for c in sample)
+            width = wcwidth.wcswidth(sample)
+            width_cjk = wcwidth.wcswidth(sample, ambiguous_width=2)
+            strings.write(f"{codepoints}; {width}; {width_cjk}\n")
+
+
 def main(module_path: str):
     """Obtain character data from the latest version of Unicode, transform it into a multi-level
     lookup table for character width, and write a Rust module utilizing that table to
@@ -2181,10 +3062,16 @@ def main(module_path: str):
     emoji_modifier_bases = load_emoji_modifier_bases()
     emoji_modifier_table = make_ranges_table(emoji_modifier_bases)
 
+    emoji_properties = load_emoji_properties()
+
     joining_group_lam = load_joining_group_lam()
     non_transparent_zero_widths = load_non_transparent_zero_widths(width_map)
     ligature_transparent = load_ligature_transparent()
     solidus_transparent = load_solidus_transparent(ligature_transparent, cjk_width_map)
+    east_asian_width_values = load_east_asian_width_values()
+    prepended_zero_widths = load_prepended_zero_widths()
+    ages = load_ages()
+    (emoji_versions, zwj_sequence_versions) = load_emoji_versions()
 
     normalization_tests = load_normalization_tests()
 
@@ -2221,10 +3108,28 @@ def main(module_path: str):
     for s, table in [
         ("Non transparent zero width", non_transparent_zero_widths),
         ("Solidus transparent", solidus_transparent),
+        ("Prepended zero width", prepended_zero_widths),
     ]:
         table_size = 6 * len(table)
         print(f"{s} table size: {table_size} bytes")
         total_size += table_size
+    for s, table in [
+        ("East Asian width", east_asian_width_values),
+        ("Emoji properties", emoji_properties),
+    ]:
+        table_size = 7 * len(table)
+        print(f"{s} table size: {table_size} bytes")
+        total_size += table_size
+    for s, table in [
+        ("Ages", ages),
+        ("Emoji versions", emoji_versions),
+    ]:
+        table_size = 8 * len(table)
+        print(f"{s} table size: {table_size} bytes")
+        total_size += table_size
+    table_size = sum(20 + 4 * len(seq) for (seq, _, _) in zwj_sequence_versions)
+    print(f"ZWJ sequence versions table size: {table_size} bytes")
+    total_size += table_size
     print("------------------------")
     print(f"  Total size: {total_size} bytes")
 
@@ -2237,14 +3142,30 @@ def main(module_path: str):
         emoji_presentation_table=emoji_presentation_table,
         text_presentation_table=text_presentation_table,
         emoji_modifier_table=emoji_modifier_table,
+        emoji_properties=emoji_properties,
         joining_group_lam=joining_group_lam,
         non_transparent_zero_widths=non_transparent_zero_widths,
         ligature_transparent=ligature_transparent,
         solidus_transparent=solidus_transparent,
+        east_asian_width_values=east_asian_width_values,
+        prepended_zero_widths=prepended_zero_widths,
+        ages=ages,
+        emoji_versions=emoji_versions,
+        zwj_sequence_versions=zwj_sequence_versions,
         normalization_tests=normalization_tests,
     )
     print(f'Wrote to "{module_path}"')
 
+    glibc_unicode_version = load_glibc_unicode_version()
+    glibc_widths = load_glibc_widths()
+    emit_glibc_module(GLIBC_MODULE_PATH, glibc_unicode_version, glibc_widths)
+    print(f'Wrote to "{GLIBC_MODULE_PATH}"')
+
+    python_wcwidth_tables = load_python_wcwidth_tables()
+    emit_python_wcwidth_module(PYTHON_WCWIDTH_MODULE_PATH, python_wcwidth_tables)
+    print(f'Wrote to "{PYTHON_WCWIDTH_MODULE_PATH}"')
+    emit_python_wcwidth_tests(PYTHON_WCWIDTH_CHARS_TEST_PATH, PYTHON_WCWIDTH_STRINGS_TEST_PATH)
+
 
 if __name__ == "__main__":
     main(MODULE_PATH)
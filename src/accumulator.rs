@@ -0,0 +1,280 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt;
+
+use crate::tables::{WidthInfo, WIDTH_INFO_STATES, WIDTH_INFO_STATES_LEN};
+use crate::width_tables::{StateMachine, WidthTables};
+
+/// How many characters a [`WidthAccumulator`] buffers before it folds them into
+/// the width they would have when followed by each state.
+const PENDING_CHARS_LEN: usize = 64;
+
+/// Computes the displayed width of a string that arrives one character or chunk at a time.
+///
+/// Pushing a string into a `WidthAccumulator`, in any number of pieces, gives exactly the
+/// width that [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width) would return for the
+/// whole string, including for the emoji sequences and ligatures listed in the
+/// [rules for determining width](crate#rules-for-determining-width).
+/// It works with any forward-only iterator of `char`s, and never allocates.
+///
+/// ```rust
+/// use unicode_width::{UnicodeWidthStr, WidthAccumulator};
+///
+/// let mut acc = WidthAccumulator::new();
+/// acc.push_str("👩");
+/// assert_eq!(acc.width(), 2);
+/// acc.push('\u{200D}');
+/// acc.push_str("🔬");
+/// assert_eq!(acc.width(), "👩‍🔬".width());
+///
+/// acc.extend("🇮🇸🇳🇴".chars());
+/// assert_eq!(acc.width(), 6);
+/// ```
+#[derive(Clone)]
+pub struct WidthAccumulator<'t> {
+    machine: StateMachine<'t>,
+    /// The width of the characters before `folded` and `chars`.
+    /// Nothing pushed from now on can change it.
+    committed: usize,
+    /// The width that the characters between `committed` and `chars` would have if
+    /// the first of `chars` passed on each of the states in `WIDTH_INFO_STATES`,
+    /// or `None` if there are no such characters.
+    folded: Option<[usize; WIDTH_INFO_STATES_LEN]>,
+    /// The last characters pushed, whose width may still depend on what comes after them.
+    chars: [char; PENDING_CHARS_LEN],
+    len: usize,
+}
+
+impl WidthAccumulator<'static> {
+    /// Creates an empty `WidthAccumulator` that computes the same width
+    /// as [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+    ///
+    /// Characters in the Ambiguous category are treated as 1 column wide.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_state_machine(StateMachine::DEFAULT)
+    }
+
+    /// Creates an empty `WidthAccumulator` that computes the same width
+    /// as [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+    ///
+    /// Characters in the Ambiguous category are treated as 2 columns wide.
+    #[cfg(feature = "cjk")]
+    #[inline]
+    pub fn new_cjk() -> Self {
+        Self::with_state_machine(StateMachine::CJK)
+    }
+}
+
+impl<'t> WidthAccumulator<'t> {
+    /// Creates an empty `WidthAccumulator` that computes the same width
+    /// as [`WidthTables::width`] with `tables`.
+    #[inline]
+    pub fn with_tables(tables: WidthTables<'t>) -> Self {
+        Self::with_state_machine(StateMachine::new(tables))
+    }
+
+    /// Creates an empty `WidthAccumulator` that computes the same width
+    /// as [`WidthTables::width_cjk`] with `tables`.
+    #[cfg(feature = "cjk")]
+    #[inline]
+    pub fn with_tables_cjk(tables: WidthTables<'t>) -> Self {
+        Self::with_state_machine(StateMachine::new_cjk(tables))
+    }
+
+    #[inline]
+    pub(crate) fn with_state_machine(machine: StateMachine<'t>) -> Self {
+        Self {
+            machine,
+            committed: 0,
+            folded: None,
+            chars: ['\0'; PENDING_CHARS_LEN],
+            len: 0,
+        }
+    }
+
+    /// Returns the displayed width in columns of everything pushed so far.
+    pub fn width(&self) -> usize {
+        let (pending, info) =
+            width_before(self.machine, &self.chars[..self.len], WidthInfo::DEFAULT);
+        self.committed
+            .wrapping_add(pending)
+            .wrapping_add(self.folded_width(info))
+    }
+
+    /// Appends a character.
+    pub fn push(&mut self, c: char) {
+        if c <= '\u{A0}' && !self.machine.starts_emoji_presentation_seq(c) {
+            // Fast path: what these pass on never depends on what follows them.
+            let info = self.machine.width_in_str(c, WidthInfo::DEFAULT).1;
+            let (pending, info) = width_before(self.machine, &self.chars[..self.len], info);
+            self.committed = self
+                .committed
+                .wrapping_add(pending)
+                .wrapping_add(self.folded_width(info));
+            self.folded = None;
+            self.len = 0;
+        } else if self.len == PENDING_CHARS_LEN {
+            self.fold();
+        }
+        self.chars[self.len] = c;
+        self.len += 1;
+    }
+
+    /// Returns the width of the folded characters when the first of `chars`
+    /// passes on `info`.
+    #[inline]
+    fn folded_width(&self, info: WidthInfo) -> usize {
+        self.folded
+            .as_ref()
+            .map_or(0, |widths| widths[state_index(info)])
+    }
+
+    /// Moves the buffered characters into `folded`, and commits their width
+    /// if it turns out not to depend on what comes after them.
+    fn fold(&mut self) {
+        let machine = self.machine;
+        let Some((&last, rest)) = self.chars[..self.len].split_last() else {
+            return;
+        };
+
+        // Most characters pass on one of only a few states whatever follows them,
+        // so follow each distinct state passed on by `last` through the rest of the buffer,
+        // merging paths once they pass on the same state.
+        let mut infos = [WidthInfo::DEFAULT; WIDTH_INFO_STATES_LEN];
+        let mut sums = [0_usize; WIDTH_INFO_STATES_LEN];
+        let mut paths = 0;
+        let mut first = [(0, 0); WIDTH_INFO_STATES_LEN];
+        for (first, &next_info) in first.iter_mut().zip(WIDTH_INFO_STATES.iter()) {
+            let (add, info) = machine.width_in_str(last, next_info);
+            let path = match infos[..paths].iter().position(|&other| other == info) {
+                Some(path) => path,
+                None => {
+                    infos[paths] = info;
+                    paths += 1;
+                    paths - 1
+                }
+            };
+            *first = (add, path);
+        }
+
+        // A merged path has the width of an earlier one, plus a difference
+        let mut merged = [None; WIDTH_INFO_STATES_LEN];
+        let mut active = [0; WIDTH_INFO_STATES_LEN];
+        for (i, path) in active[..paths].iter_mut().enumerate() {
+            *path = i;
+        }
+        let mut active_len = paths;
+        for &c in rest.iter().rev() {
+            let mut kept = 0;
+            for i in 0..active_len {
+                let path = active[i];
+                let (add, info) = machine.width_in_str(c, infos[path]);
+                infos[path] = info;
+                sums[path] = sums[path].wrapping_add_signed(isize::from(add));
+                if let Some(&other) = active[..kept].iter().find(|&&other| infos[other] == info) {
+                    merged[path] = Some((other, sums[path].wrapping_sub(sums[other])));
+                } else {
+                    active[kept] = path;
+                    kept += 1;
+                }
+            }
+            active_len = kept;
+        }
+
+        // Paths only merge into earlier ones, so their totals are known by then
+        let mut totals = [0_usize; WIDTH_INFO_STATES_LEN];
+        for path in 0..paths {
+            totals[path] = match merged[path] {
+                Some((other, diff)) => totals[other].wrapping_add(diff),
+                None => sums[path].wrapping_add(self.folded_width(infos[path])),
+            };
+        }
+
+        let mut widths = [0; WIDTH_INFO_STATES_LEN];
+        for (width, &(add, path)) in widths.iter_mut().zip(first.iter()) {
+            *width = totals[path].wrapping_add_signed(isize::from(add));
+        }
+        self.len = 0;
+        if widths.iter().all(|&width| width == widths[0]) {
+            self.committed = self.committed.wrapping_add(widths[0]);
+            self.folded = None;
+        } else {
+            self.folded = Some(widths);
+        }
+    }
+
+    /// Appends a string slice.
+    #[inline]
+    pub fn push_str(&mut self, s: &str) {
+        self.extend(s.chars());
+    }
+}
+
+impl Default for WidthAccumulator<'static> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for WidthAccumulator<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WidthAccumulator")
+            .field("width", &self.width())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Extend<char> for WidthAccumulator<'_> {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for c in iter {
+            self.push(c);
+        }
+    }
+}
+
+impl<'a> Extend<&'a char> for WidthAccumulator<'_> {
+    fn extend<I: IntoIterator<Item = &'a char>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<'a> Extend<&'a str> for WidthAccumulator<'_> {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for s in iter {
+            self.push_str(s);
+        }
+    }
+}
+
+/// Returns the width of `chars` when followed by a character that passed on `next_info`,
+/// along with what the first of them passes on.
+fn width_before(
+    machine: StateMachine<'_>,
+    chars: &[char],
+    next_info: WidthInfo,
+) -> (usize, WidthInfo) {
+    chars.iter().rfold(
+        (0, next_info),
+        |(sum, next_info), &c| -> (usize, WidthInfo) {
+            let (add, info) = machine.width_in_str(c, next_info);
+            (sum.wrapping_add_signed(isize::from(add)), info)
+        },
+    )
+}
+
+/// Returns the index of `info` in `WIDTH_INFO_STATES`.
+#[inline]
+fn state_index(info: WidthInfo) -> usize {
+    // `WIDTH_INFO_STATES` contains every state that the state machine can return
+    WIDTH_INFO_STATES.binary_search(&info).unwrap()
+}
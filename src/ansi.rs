@@ -0,0 +1,518 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::ops::Range;
+
+use crate::accumulator::WidthAccumulator;
+#[cfg(feature = "alloc")]
+use crate::column::{byte_at_column_with, Rounding};
+#[cfg(feature = "alloc")]
+use crate::truncate::{fit_ellipsis, Cut};
+use crate::width_tables::StateMachine;
+
+/// Returns the displayed width of `s` in columns, with [ECMA-48] escape sequences
+/// taking up no space.
+///
+/// The following are recognized, with both their 7-bit (`ESC`-prefixed)
+/// and 8-bit (C1 control) introducers:
+/// - Control sequences (CSI), such as `"\x1b[31m"`.
+/// - Operating system commands (OSC), such as hyperlinks and window titles,
+///   terminated by ST or `BEL`.
+/// - Device control strings (DCS), and the SOS, PM and APC strings, terminated by ST.
+/// - Other escape sequences, such as `"\x1b(B"` or `"\x1b7"`.
+///
+/// Other C1 controls are zero width as well. A sequence that is cut off by the end
+/// of the string runs to the end of it, and a sequence interrupted by a character
+/// that can't be part of it ends before that character.
+///
+/// Everything else is measured as by [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width),
+/// as if the escape sequences weren't there, so an emoji sequence split up by a color
+/// change is still 2 columns wide. Characters in the Ambiguous category are treated
+/// as 1 column wide.
+///
+/// ```rust
+/// use unicode_width::width_ansi;
+///
+/// assert_eq!(width_ansi("\x1b[1;31merror\x1b[0m: 中文"), 11);
+/// assert_eq!(width_ansi("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\"), 4);
+/// ```
+///
+/// [ECMA-48]: https://ecma-international.org/publications-and-standards/standards/ecma-48/
+pub fn width_ansi(s: &str) -> usize {
+    width_ansi_with(StateMachine::DEFAULT, s)
+}
+
+/// Returns the displayed width of `s` in columns, with [ECMA-48] escape sequences
+/// taking up no space.
+///
+/// This is [`width_ansi`], but with characters in the Ambiguous category
+/// treated as 2 columns wide, as in
+/// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+///
+/// [ECMA-48]: https://ecma-international.org/publications-and-standards/standards/ecma-48/
+#[cfg(feature = "cjk")]
+pub fn width_ansi_cjk(s: &str) -> usize {
+    width_ansi_with(StateMachine::CJK, s)
+}
+
+pub(crate) fn width_ansi_with(machine: StateMachine<'_>, s: &str) -> usize {
+    let mut acc = WidthAccumulator::with_state_machine(machine);
+    for token in Tokens::new(s) {
+        if let Token::Text(text) = token {
+            acc.push_str(text);
+        }
+    }
+    acc.width()
+}
+
+/// Shortens `s` to at most `max_width` columns by cutting off its end and putting
+/// `ellipsis` in its place, leaving escape sequences intact.
+///
+/// Columns are counted as by [`width_ansi`], and the cut is made as by
+/// [`truncate_end`](crate::truncate_end). Escape sequences before the cut are kept, and
+/// those after it are dropped. If the kept ones leave any [SGR] attributes (such as colors)
+/// active, `"\x1b[0m"` is added after `ellipsis` to reset them, and if they leave an
+/// [OSC 8] hyperlink open, `"\x1b]8;;\x1b\\"` is added to close it.
+/// `s` is returned unchanged if it already fits.
+///
+/// Characters in the Ambiguous category, in `s` and in `ellipsis`, are treated as
+/// 1 column wide, as in [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+///
+/// ```rust
+/// use unicode_width::truncate_ansi;
+///
+/// assert_eq!(truncate_ansi("\x1b[31merror\x1b[0m: 中文", 6, "…"), "\x1b[31merror…\x1b[0m");
+/// ```
+///
+/// [SGR]: https://en.wikipedia.org/wiki/ANSI_escape_code#SGR
+/// [OSC 8]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+#[cfg(feature = "alloc")]
+pub fn truncate_ansi<'a>(s: &'a str, max_width: usize, ellipsis: &str) -> Cow<'a, str> {
+    truncate_ansi_with(StateMachine::DEFAULT, s, max_width, ellipsis)
+}
+
+/// Shortens `s` to at most `max_width` columns by cutting off its end and putting
+/// `ellipsis` in its place, leaving escape sequences intact.
+///
+/// This is [`truncate_ansi`], but with characters in the Ambiguous category
+/// treated as 2 columns wide, as in
+/// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+#[cfg(all(feature = "alloc", feature = "cjk"))]
+pub fn truncate_ansi_cjk<'a>(s: &'a str, max_width: usize, ellipsis: &str) -> Cow<'a, str> {
+    truncate_ansi_with(StateMachine::CJK, s, max_width, ellipsis)
+}
+
+/// Returns the part of `s` that is displayed in the columns `cols`, leaving escape
+/// sequences intact.
+///
+/// Columns are counted as by [`width_ansi`]. A wide character, emoji sequence or
+/// ligature that only partly falls within `cols` is left out, as by
+/// [`byte_at_column`](crate::byte_at_column).
+///
+/// The result starts with the [SGR] escape sequences (such as colors) that are
+/// in effect at the start of the slice, and the [OSC 8] hyperlink it is in, if any,
+/// followed by the text and escape sequences within it. If any SGR attributes are
+/// still active at its end, `"\x1b[0m"` is added to reset them, and if a hyperlink
+/// is still open, `"\x1b]8;;\x1b\\"` is added to close it. Other escape sequences
+/// outside of the slice are dropped.
+///
+/// Characters in the Ambiguous category are treated as 1 column wide,
+/// as in [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+///
+/// ```rust
+/// use unicode_width::slice_ansi;
+///
+/// let s = "\x1b[1mbold \x1b[32mgreen\x1b[0m plain";
+/// assert_eq!(slice_ansi(s, 2..7), "\x1b[1mld \x1b[32mgr\x1b[0m");
+/// assert_eq!(slice_ansi(s, 11..14), "pla");
+/// ```
+///
+/// [SGR]: https://en.wikipedia.org/wiki/ANSI_escape_code#SGR
+/// [OSC 8]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+#[cfg(feature = "alloc")]
+pub fn slice_ansi(s: &str, cols: Range<usize>) -> String {
+    slice_ansi_with(StateMachine::DEFAULT, s, cols)
+}
+
+/// Returns the part of `s` that is displayed in the columns `cols`, leaving escape
+/// sequences intact.
+///
+/// This is [`slice_ansi`], but with characters in the Ambiguous category
+/// treated as 2 columns wide, as in
+/// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+#[cfg(all(feature = "alloc", feature = "cjk"))]
+pub fn slice_ansi_cjk(s: &str, cols: Range<usize>) -> String {
+    slice_ansi_with(StateMachine::CJK, s, cols)
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn truncate_ansi_with<'a>(
+    machine: StateMachine<'_>,
+    s: &'a str,
+    max_width: usize,
+    ellipsis: &str,
+) -> Cow<'a, str> {
+    let stripped = Stripped::new(s);
+    let width = machine.str_width(&stripped.text);
+    if width <= max_width {
+        return Cow::Borrowed(s);
+    }
+
+    let (end, _, ellipsis) = fit_ellipsis(
+        machine,
+        Cut::End,
+        &stripped.text,
+        width,
+        max_width,
+        ellipsis,
+    );
+
+    let mut renderer = Renderer::new(&stripped);
+    let mut truncated = String::with_capacity(s.len());
+    renderer.render(&mut truncated, 0..end);
+    truncated.push_str(ellipsis);
+    renderer.reset(&mut truncated);
+    Cow::Owned(truncated)
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn slice_ansi_with(machine: StateMachine<'_>, s: &str, cols: Range<usize>) -> String {
+    let stripped = Stripped::new(s);
+    let width = machine.str_width(&stripped.text);
+    let start = byte_at_column_with(machine, width, &stripped.text, cols.start, Rounding::Up);
+    let end = byte_at_column_with(machine, width, &stripped.text, cols.end, Rounding::Down);
+
+    let mut renderer = Renderer::new(&stripped);
+    let mut sliced = String::new();
+    renderer.render(&mut sliced, start..end.max(start));
+    renderer.reset(&mut sliced);
+    sliced
+}
+
+/// A string with its escape sequences taken out.
+#[cfg(feature = "alloc")]
+pub(crate) struct Stripped<'a> {
+    /// The text of the string without any escape sequences.
+    pub(crate) text: String,
+    /// The escape sequences, along with the index in `text` they were at.
+    escapes: Vec<(usize, &'a str)>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Stripped<'a> {
+    pub(crate) fn new(s: &'a str) -> Self {
+        let mut text = String::with_capacity(s.len());
+        let mut escapes = Vec::new();
+        for token in Tokens::new(s) {
+            match token {
+                Token::Text(t) => text.push_str(t),
+                Token::Escape(escape) => escapes.push((text.len(), escape)),
+            }
+        }
+        Self { text, escapes }
+    }
+}
+
+/// Puts the escape sequences of a [`Stripped`] string back into parts of its text,
+/// keeping track of which SGR sequences are in effect, and which hyperlink is open,
+/// along the way.
+#[cfg(feature = "alloc")]
+pub(crate) struct Renderer<'s, 'a> {
+    stripped: &'s Stripped<'a>,
+    /// How many of the escape sequences have been gone past.
+    escapes_seen: usize,
+    /// The SGR sequences since the last one that reset all attributes.
+    sgr: Vec<&'a str>,
+    /// The OSC 8 sequence that opened the current hyperlink, if there is one.
+    link: Option<&'a str>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'s, 'a> Renderer<'s, 'a> {
+    pub(crate) fn new(stripped: &'s Stripped<'a>) -> Self {
+        Self {
+            stripped,
+            escapes_seen: 0,
+            sgr: Vec::new(),
+            link: None,
+        }
+    }
+
+    /// Writes the SGR sequences in effect and the hyperlink open at `range.start`,
+    /// followed by the text in `range` with the escape sequences in it.
+    ///
+    /// Ranges must be passed in increasing order, without overlapping.
+    pub(crate) fn render(&mut self, out: &mut String, range: Range<usize>) {
+        let escapes = &self.stripped.escapes[self.escapes_seen..];
+        let mut skipped = escapes.partition_point(|&(i, _)| i < range.start);
+        for &(_, escape) in &escapes[..skipped] {
+            self.update(escape);
+        }
+        // SGR and OSC 8 sequences right at the start are written out along with the rest
+        while let Some(&(i, escape)) = escapes.get(skipped) {
+            if i != range.start || !self.update(escape) {
+                break;
+            }
+            skipped += 1;
+        }
+        for escape in &self.sgr {
+            out.push_str(escape);
+        }
+        if let Some(link) = self.link {
+            out.push_str(link);
+        }
+
+        let inside = escapes[skipped..].partition_point(|&(i, _)| i < range.end);
+        let mut start = range.start;
+        for &(i, escape) in &escapes[skipped..skipped + inside] {
+            out.push_str(&self.stripped.text[start..i]);
+            out.push_str(escape);
+            self.update(escape);
+            start = i;
+        }
+        out.push_str(&self.stripped.text[start..range.end]);
+        self.escapes_seen += skipped + inside;
+    }
+
+    /// Writes an SGR sequence that resets all attributes, if any are in effect,
+    /// and an OSC 8 sequence that closes the hyperlink, if one is open.
+    pub(crate) fn reset(&self, out: &mut String) {
+        if !self.sgr.is_empty() {
+            out.push_str("\x1b[0m");
+        }
+        if self.link.is_some() {
+            out.push_str("\x1b]8;;\x1b\\");
+        }
+    }
+
+    /// Keeps track of `escape` if it is an SGR or OSC 8 sequence,
+    /// and returns whether it is one.
+    fn update(&mut self, escape: &'a str) -> bool {
+        if let Some(uri) = hyperlink_uri(escape) {
+            self.link = (!uri.is_empty()).then_some(escape);
+            return true;
+        }
+        update_sgr(&mut self.sgr, escape)
+    }
+}
+
+/// Returns the URI of `escape` if it is an OSC 8 sequence,
+/// which is empty if it closes a hyperlink.
+#[cfg(feature = "alloc")]
+fn hyperlink_uri(escape: &str) -> Option<&str> {
+    let body = escape
+        .strip_prefix("\x1b]8;")
+        .or_else(|| escape.strip_prefix("\u{9D}8;"))?;
+    let body = body
+        .strip_suffix(BEL)
+        .or_else(|| body.strip_suffix("\x1b\\"))
+        .or_else(|| body.strip_suffix(ST))
+        .unwrap_or(body);
+    // The parameters come before the URI
+    body.split_once(';').map(|(_, uri)| uri)
+}
+
+/// Adds `escape` to the SGR sequences in effect, if it is one,
+/// clearing them first if it resets all attributes.
+///
+/// Returns whether `escape` is an SGR sequence.
+#[cfg(feature = "alloc")]
+fn update_sgr<'a>(sgr: &mut Vec<&'a str>, escape: &'a str) -> bool {
+    let Some(params) = escape
+        .strip_prefix("\x1b[")
+        .or_else(|| escape.strip_prefix('\u{9B}'))
+        .and_then(|escape| escape.strip_suffix('m'))
+        .filter(|params| params.chars().all(|c| matches!(c, '\x30'..='\x3F')))
+    else {
+        return false;
+    };
+
+    // Find the last `0` or empty parameter, skipping over the arguments of extended colors
+    let mut last_reset = None;
+    let mut params = params.split(';').enumerate();
+    while let Some((i, param)) = params.next() {
+        match param {
+            "" | "0" => last_reset = Some(i),
+            "38" | "48" | "58" => match params.next() {
+                Some((_, "5")) => {
+                    params.next();
+                }
+                Some((_, "2")) => {
+                    params.nth(2);
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    match last_reset {
+        Some(i) => {
+            sgr.clear();
+            // Only the parameters after the reset still have an effect
+            if escape.matches(';').count() > i {
+                sgr.push(escape);
+            }
+        }
+        None => sgr.push(escape),
+    }
+    true
+}
+
+/// A piece of a string with escape sequences in it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Token<'a> {
+    /// A run of characters that aren't part of any escape sequence.
+    Text(&'a str),
+    /// A single escape sequence or C1 control.
+    Escape(&'a str),
+}
+
+/// An iterator over the text and escape sequences of a string, in order.
+#[derive(Clone, Debug)]
+pub(crate) struct Tokens<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Tokens<'a> {
+    pub(crate) fn new(s: &'a str) -> Self {
+        Self { rest: s }
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let (token, rest) = match self.rest.find(starts_escape) {
+            Some(0) => {
+                let (escape, rest) = self.rest.split_at(escape_len(self.rest));
+                (Token::Escape(escape), rest)
+            }
+            Some(i) => {
+                let (text, rest) = self.rest.split_at(i);
+                (Token::Text(text), rest)
+            }
+            None => (Token::Text(self.rest), ""),
+        };
+        self.rest = rest;
+        Some(token)
+    }
+}
+
+const ESC: char = '\x1b';
+const BEL: char = '\x07';
+/// The 8-bit STRING TERMINATOR.
+const ST: char = '\u{9C}';
+
+fn starts_escape(c: char) -> bool {
+    c == ESC || is_c1_control(c)
+}
+
+fn is_c1_control(c: char) -> bool {
+    matches!(c, '\u{80}'..='\u{9F}')
+}
+
+/// How an escape sequence continues after its introducer.
+enum Body {
+    /// Parameter and intermediate bytes, then a final byte.
+    ControlSequence,
+    /// Intermediate bytes, then a final byte.
+    Escape,
+    /// Anything, up to a string terminator.
+    String { bel_terminates: bool },
+}
+
+/// Returns the length of the escape sequence or C1 control at the start of `s`.
+fn escape_len(s: &str) -> usize {
+    let mut chars = s.chars();
+    let (body, start) = match chars.next() {
+        Some(ESC) => match chars.next() {
+            Some('[') => (Body::ControlSequence, 2),
+            Some(']') => (
+                Body::String {
+                    bel_terminates: true,
+                },
+                2,
+            ),
+            Some('P' | 'X' | '^' | '_') => (
+                Body::String {
+                    bel_terminates: false,
+                },
+                2,
+            ),
+            Some('\x20'..='\x2F') => (Body::Escape, 1),
+            Some('\x30'..='\x7E') => return 2,
+            // A lone `ESC`
+            _ => return 1,
+        },
+        Some('\u{9B}') => (Body::ControlSequence, 2),
+        Some('\u{9D}') => (
+            Body::String {
+                bel_terminates: true,
+            },
+            2,
+        ),
+        Some('\u{90}' | '\u{98}' | '\u{9E}' | '\u{9F}') => (
+            Body::String {
+                bel_terminates: false,
+            },
+            2,
+        ),
+        Some(c) => return c.len_utf8(),
+        None => return 0,
+    };
+
+    let body_str = &s[start..];
+    let len = match body {
+        Body::ControlSequence => {
+            let params = body_str.len()
+                - body_str
+                    .trim_start_matches(|c| matches!(c, '\x30'..='\x3F'))
+                    .len();
+            final_len(&body_str[params..], '\x40'..='\x7E') + params
+        }
+        Body::Escape => final_len(body_str, '\x30'..='\x7E'),
+        Body::String { bel_terminates } => {
+            let mut chars = body_str.char_indices().peekable();
+            loop {
+                match chars.next() {
+                    Some((i, BEL)) if bel_terminates => break i + 1,
+                    Some((i, ST)) => break i + ST.len_utf8(),
+                    Some((i, ESC)) => match chars.peek() {
+                        Some((_, '\\')) => break i + 2,
+                        // Any other escape sequence cancels the string
+                        _ => break i,
+                    },
+                    Some(_) => {}
+                    None => break body_str.len(),
+                }
+            }
+        }
+    };
+    start + len
+}
+
+/// Returns the length of the intermediate bytes at the start of `s`,
+/// along with the final byte after them if it's in `finals`.
+fn final_len(s: &str, finals: core::ops::RangeInclusive<char>) -> usize {
+    let intermediates = s.len() - s.trim_start_matches(|c| matches!(c, '\x20'..='\x2F')).len();
+    match s[intermediates..].chars().next() {
+        Some(c) if finals.contains(&c) => intermediates + 1,
+        _ => intermediates,
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::tables;
+use crate::uax11::{self, Context, EastAsianWidth};
+
+/// Why a character has the width that this crate gives it, as returned by
+/// [`char_width_class`].
+///
+/// ```rust
+/// use unicode_width::{char_width_class, CharWidthClass};
+///
+/// assert_eq!(char_width_class('\x1b'), CharWidthClass::Control);
+/// assert_eq!(char_width_class('\u{301}'), CharWidthClass::ZeroWidth);
+/// assert_eq!(char_width_class('a'), CharWidthClass::Narrow);
+/// assert_eq!(char_width_class('中'), CharWidthClass::Wide);
+/// assert_eq!(char_width_class('±'), CharWidthClass::Ambiguous);
+/// // U+17D8 KHMER SIGN BEYYAL is drawn as a ligature 3 columns wide
+/// assert_eq!(char_width_class('\u{17D8}'), CharWidthClass::Special);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CharWidthClass {
+    /// A control character, which has no width of its own.
+    Control,
+    /// A character that takes up no columns, such as a combining mark
+    /// or a default ignorable character.
+    ZeroWidth,
+    /// A character that is 1 column wide because of its `East_Asian_Width`
+    /// of Narrow, Halfwidth or Neutral.
+    Narrow,
+    /// A character that is 2 columns wide because of its `East_Asian_Width`
+    /// of Wide or Fullwidth.
+    Wide,
+    /// A character in the Ambiguous category, which is 1 column wide,
+    /// or 2 columns wide in an East Asian context.
+    Ambiguous,
+    /// A character whose width doesn't follow from its `East_Asian_Width`
+    /// as described in [UAX #11](crate::uax11), such as
+    /// `'\u{115F}'` HANGUL CHOSEONG FILLER, which is 2 columns wide so that
+    /// it can start a syllable on its own, or the Khmer characters that are
+    /// drawn as ligatures 3 columns wide.
+    Special,
+}
+
+/// Returns why `c` has the width that [`UnicodeWidthChar::width`] and
+/// [`UnicodeWidthChar::width_cjk`] give it.
+///
+/// [`UnicodeWidthChar::width`]: crate::UnicodeWidthChar::width
+/// [`UnicodeWidthChar::width_cjk`]: crate::UnicodeWidthChar::width_cjk
+pub fn char_width_class(c: char) -> CharWidthClass {
+    let Some(width) = tables::single_char_width(c) else {
+        return CharWidthClass::Control;
+    };
+    if uax11::width(c, Context::NonEastAsian) != Some(width) || !follows_uax11_cjk(c) {
+        return CharWidthClass::Special;
+    }
+    match uax11::east_asian_width(c) {
+        _ if width == 0 => CharWidthClass::ZeroWidth,
+        EastAsianWidth::Wide | EastAsianWidth::Fullwidth => CharWidthClass::Wide,
+        EastAsianWidth::Ambiguous => CharWidthClass::Ambiguous,
+        _ => CharWidthClass::Narrow,
+    }
+}
+
+#[cfg(feature = "cjk")]
+fn follows_uax11_cjk(c: char) -> bool {
+    uax11::width(c, Context::EastAsian) == tables::single_char_width_cjk(c)
+}
+
+#[cfg(not(feature = "cjk"))]
+fn follows_uax11_cjk(_c: char) -> bool {
+    true
+}
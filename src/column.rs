@@ -0,0 +1,170 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::split::Boundaries;
+use crate::tables;
+use crate::width_tables::StateMachine;
+
+/// Which way [`column_at_byte`] and [`byte_at_column`] move a position that falls
+/// inside something that can't be split, such as a wide character, an emoji sequence
+/// or a ligature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Move the position to the start of what it falls inside.
+    Down,
+    /// Move the position to the end of what it falls inside.
+    Up,
+}
+
+/// Returns the display column at which the byte offset `byte_idx` of `s` is drawn,
+/// that is, the width of `s[..byte_idx]`.
+///
+/// If `byte_idx` falls inside a character, or inside a sequence that
+/// [`split_at_width`](crate::split_at_width) would not split, it is first moved
+/// to the start or the end of it according to `rounding`.
+/// Byte offsets past the end of `s` are treated as `s.len()`.
+///
+/// Characters in the Ambiguous category are treated as 1 column wide,
+/// as in [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+///
+/// ```rust
+/// use unicode_width::{column_at_byte, Rounding};
+///
+/// assert_eq!(column_at_byte("a中b", 4, Rounding::Down), 3);
+/// assert_eq!(column_at_byte("a中b", 2, Rounding::Down), 1);
+/// assert_eq!(column_at_byte("a中b", 2, Rounding::Up), 3);
+/// // The ZWJ sequence is drawn as a single emoji
+/// assert_eq!(column_at_byte("👩‍🔬!", 4, Rounding::Up), 2);
+/// ```
+pub fn column_at_byte(s: &str, byte_idx: usize, rounding: Rounding) -> usize {
+    column_at_byte_with(
+        StateMachine::DEFAULT,
+        tables::str_width(s),
+        s,
+        byte_idx,
+        rounding,
+    )
+}
+
+/// Returns the display column at which the byte offset `byte_idx` of `s` is drawn,
+/// that is, the width of `s[..byte_idx]`.
+///
+/// This is [`column_at_byte`], but with characters in the Ambiguous category
+/// treated as 2 columns wide, as in
+/// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+#[cfg(feature = "cjk")]
+pub fn column_at_byte_cjk(s: &str, byte_idx: usize, rounding: Rounding) -> usize {
+    column_at_byte_with(
+        StateMachine::CJK,
+        tables::str_width_cjk(s),
+        s,
+        byte_idx,
+        rounding,
+    )
+}
+
+/// Returns the byte offset of `s` at which the display column `col` starts,
+/// that is, the length of the prefix of `s` that is `col` columns wide.
+///
+/// If `col` falls inside a wide character, or inside a sequence that
+/// [`split_at_width`](crate::split_at_width) would not split, the offset of its start
+/// or its end is returned according to `rounding`. If several offsets are at `col`,
+/// because `s` starts with zero-width characters, the first one is returned.
+/// Columns past the end of `s` give `s.len()`.
+///
+/// Characters in the Ambiguous category are treated as 1 column wide,
+/// as in [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+///
+/// ```rust
+/// use unicode_width::{byte_at_column, Rounding};
+///
+/// assert_eq!(byte_at_column("a中b", 3, Rounding::Down), 4);
+/// assert_eq!(byte_at_column("a中b", 2, Rounding::Down), 1);
+/// assert_eq!(byte_at_column("a中b", 2, Rounding::Up), 4);
+/// assert_eq!(byte_at_column("👩‍🔬!", 1, Rounding::Up), 11);
+/// ```
+pub fn byte_at_column(s: &str, col: usize, rounding: Rounding) -> usize {
+    byte_at_column_with(
+        StateMachine::DEFAULT,
+        tables::str_width(s),
+        s,
+        col,
+        rounding,
+    )
+}
+
+/// Returns the byte offset of `s` at which the display column `col` starts,
+/// that is, the length of the prefix of `s` that is `col` columns wide.
+///
+/// This is [`byte_at_column`], but with characters in the Ambiguous category
+/// treated as 2 columns wide, as in
+/// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+#[cfg(feature = "cjk")]
+pub fn byte_at_column_cjk(s: &str, col: usize, rounding: Rounding) -> usize {
+    byte_at_column_with(
+        StateMachine::CJK,
+        tables::str_width_cjk(s),
+        s,
+        col,
+        rounding,
+    )
+}
+
+pub(crate) fn column_at_byte_with(
+    machine: StateMachine<'_>,
+    width: usize,
+    s: &str,
+    byte_idx: usize,
+    rounding: Rounding,
+) -> usize {
+    let mut boundaries = Boundaries::new(machine, s);
+    // Boundaries come from last to first, so the one to round down to
+    // is the first at or before `byte_idx`, and the one to round up to
+    // is the last at or after it.
+    let suffix_width = match rounding {
+        Rounding::Down => boundaries.find(|&(mid, _)| mid <= byte_idx),
+        Rounding::Up => boundaries.take_while(|&(mid, _)| mid >= byte_idx).last(),
+    }
+    .map_or(0, |(_, suffix_width)| suffix_width);
+    width - suffix_width
+}
+
+pub(crate) fn byte_at_column_with(
+    machine: StateMachine<'_>,
+    width: usize,
+    s: &str,
+    col: usize,
+    rounding: Rounding,
+) -> usize {
+    if col > width {
+        return s.len();
+    }
+
+    // Boundaries come from last to first, and their columns never increase along
+    // the way, since every piece between two of them has the same width on its own as in `s`.
+    let boundaries =
+        Boundaries::new(machine, s).map(|(mid, suffix_width)| (mid, width - suffix_width));
+    match rounding {
+        Rounding::Down => {
+            let mut boundaries = boundaries.skip_while(|&(_, mid_col)| mid_col > col);
+            boundaries.next().map(|(mid, mid_col)| {
+                boundaries
+                    .take_while(|&(_, c)| c == mid_col)
+                    .last()
+                    .map_or(mid, |(mid, _)| mid)
+            })
+        }
+        Rounding::Up => boundaries
+            .take_while(|&(_, mid_col)| mid_col >= col)
+            .last()
+            .map(|(mid, _)| mid),
+    }
+    .unwrap_or(0)
+}
@@ -0,0 +1,19 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Widths as other implementations determine them, for agreeing on where columns fall
+//! with programs that use those implementations.
+//!
+//! These deliberately reproduce the other implementation, including where it disagrees
+//! with the [rules for determining width](crate#rules-for-determining-width) of this crate.
+
+pub mod glibc;
+pub mod legacy_0_1;
+pub mod python_wcwidth;
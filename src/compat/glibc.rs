@@ -0,0 +1,74 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The widths that glibc's [`wcwidth`](https://man7.org/linux/man-pages/man3/wcwidth.3.html)
+//! and [`wcswidth`](https://man7.org/linux/man-pages/man3/wcswidth.3.html) return
+//! in a UTF-8 locale, as used by shell tools and ncurses applications.
+//!
+//! The tables are generated by `scripts/unicode.py` from the locale data of the glibc
+//! release given by [`GLIBC_VERSION`], which is itself generated from the Unicode version
+//! given by [`UNICODE_VERSION`]. To match another release, set `GLIBC_VERSION` in the
+//! script and regenerate them.
+//!
+//! glibc treats characters that were unassigned in its version of Unicode as non-printable,
+//! and counts each character on its own, with no special handling for emoji sequences
+//! or ligatures.
+//!
+//! ```rust
+//! use unicode_width::compat::glibc::{wcswidth, wcwidth};
+//!
+//! assert_eq!(wcwidth('a'), 1);
+//! assert_eq!(wcwidth('中'), 2);
+//! assert_eq!(wcwidth('\u{301}'), 0);
+//! assert_eq!(wcwidth('\x1b'), -1);
+//! assert_eq!(wcswidth("👩‍🔬"), 4);
+//! ```
+
+mod tables;
+
+pub use tables::{GLIBC_VERSION, UNICODE_VERSION};
+
+/// Returns the width of `c` in columns as glibc's `wcwidth` does,
+/// or -1 if glibc considers it non-printable.
+///
+/// Control characters, characters that were unassigned in [`UNICODE_VERSION`],
+/// noncharacters, and `'\u{2028}'` and `'\u{2029}'` are non-printable.
+/// `'\0'` is 0 columns wide.
+pub fn wcwidth(c: char) -> i32 {
+    if c == '\0' {
+        0
+    } else {
+        tables::lookup_width(c).into()
+    }
+}
+
+/// Returns the width of `s` in columns as glibc's `wcswidth` does,
+/// or -1 if any character in it is non-printable.
+///
+/// This is the sum of [`wcwidth`] over the characters of `s`. As `wcswidth` takes
+/// a null-terminated string, the characters after a `'\0'` are ignored.
+///
+/// ```rust
+/// use unicode_width::compat::glibc::wcswidth;
+///
+/// assert_eq!(wcswidth("ｈｅｌｌｏ"), 10);
+/// assert_eq!(wcswidth("a\nb"), -1);
+/// assert_eq!(wcswidth("ab\0\n"), 2);
+/// ```
+pub fn wcswidth(s: &str) -> i32 {
+    let mut width = 0;
+    for c in s.chars().take_while(|&c| c != '\0') {
+        match wcwidth(c) {
+            -1 => return -1,
+            w => width += w,
+        }
+    }
+    width
+}
@@ -0,0 +1,1216 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// NOTE: The following code was generated by "scripts/unicode.py", do not edit directly
+
+use core::cmp::Ordering;
+
+/// The version of glibc whose tables these are.
+pub const GLIBC_VERSION: (u8, u8) = (2, 36);
+
+/// The version of Unicode that glibc's tables are generated from.
+pub const UNICODE_VERSION: (u8, u8, u8) = (14, 0, 0);
+
+/// Returns what glibc's `wcwidth` returns for `c`, except for `'\0'`.
+pub fn lookup_width(c: char) -> i8 {
+    let cp: u32 = c.into();
+    WIDTHS
+        .binary_search_by(|&(lo, hi, _)| {
+            let lo = u32::from_le_bytes([lo[0], lo[1], lo[2], 0]);
+            let hi = u32::from_le_bytes([hi[0], hi[1], hi[2], 0]);
+            if cp < lo {
+                Ordering::Greater
+            } else if cp > hi {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+        .map_or(1, |i| WIDTHS[i].2)
+}
+
+/// Sorted list of codepoint ranges (inclusive)
+/// for which `wcwidth` doesn't return 1, and what it returns for them.
+#[rustfmt::skip]
+static WIDTHS: [([u8; 3], [u8; 3], i8); 1173] = [
+    ([0x00, 0x00, 0x00], [0x1F, 0x00, 0x00], -1),
+    ([0x7F, 0x00, 0x00], [0x9F, 0x00, 0x00], -1),
+    ([0x00, 0x03, 0x00], [0x6F, 0x03, 0x00], 0),
+    ([0x78, 0x03, 0x00], [0x79, 0x03, 0x00], -1),
+    ([0x80, 0x03, 0x00], [0x83, 0x03, 0x00], -1),
+    ([0x8B, 0x03, 0x00], [0x8B, 0x03, 0x00], -1),
+    ([0x8D, 0x03, 0x00], [0x8D, 0x03, 0x00], -1),
+    ([0xA2, 0x03, 0x00], [0xA2, 0x03, 0x00], -1),
+    ([0x83, 0x04, 0x00], [0x89, 0x04, 0x00], 0),
+    ([0x30, 0x05, 0x00], [0x30, 0x05, 0x00], -1),
+    ([0x57, 0x05, 0x00], [0x58, 0x05, 0x00], -1),
+    ([0x8B, 0x05, 0x00], [0x8C, 0x05, 0x00], -1),
+    ([0x90, 0x05, 0x00], [0x90, 0x05, 0x00], -1),
+    ([0x91, 0x05, 0x00], [0xBD, 0x05, 0x00], 0),
+    ([0xBF, 0x05, 0x00], [0xBF, 0x05, 0x00], 0),
+    ([0xC1, 0x05, 0x00], [0xC2, 0x05, 0x00], 0),
+    ([0xC4, 0x05, 0x00], [0xC5, 0x05, 0x00], 0),
+    ([0xC7, 0x05, 0x00], [0xC7, 0x05, 0x00], 0),
+    ([0xC8, 0x05, 0x00], [0xCF, 0x05, 0x00], -1),
+    ([0xEB, 0x05, 0x00], [0xEE, 0x05, 0x00], -1),
+    ([0xF5, 0x05, 0x00], [0xFF, 0x05, 0x00], -1),
+    ([0x10, 0x06, 0x00], [0x1A, 0x06, 0x00], 0),
+    ([0x1C, 0x06, 0x00], [0x1C, 0x06, 0x00], 0),
+    ([0x4B, 0x06, 0x00], [0x5F, 0x06, 0x00], 0),
+    ([0x70, 0x06, 0x00], [0x70, 0x06, 0x00], 0),
+    ([0xD6, 0x06, 0x00], [0xDC, 0x06, 0x00], 0),
+    ([0xDF, 0x06, 0x00], [0xE4, 0x06, 0x00], 0),
+    ([0xE7, 0x06, 0x00], [0xE8, 0x06, 0x00], 0),
+    ([0xEA, 0x06, 0x00], [0xED, 0x06, 0x00], 0),
+    ([0x0E, 0x07, 0x00], [0x0E, 0x07, 0x00], -1),
+    ([0x11, 0x07, 0x00], [0x11, 0x07, 0x00], 0),
+    ([0x30, 0x07, 0x00], [0x4A, 0x07, 0x00], 0),
+    ([0x4B, 0x07, 0x00], [0x4C, 0x07, 0x00], -1),
+    ([0xA6, 0x07, 0x00], [0xB0, 0x07, 0x00], 0),
+    ([0xB2, 0x07, 0x00], [0xBF, 0x07, 0x00], -1),
+    ([0xEB, 0x07, 0x00], [0xF3, 0x07, 0x00], 0),
+    ([0xFB, 0x07, 0x00], [0xFC, 0x07, 0x00], -1),
+    ([0xFD, 0x07, 0x00], [0xFD, 0x07, 0x00], 0),
+    ([0x16, 0x08, 0x00], [0x19, 0x08, 0x00], 0),
+    ([0x1B, 0x08, 0x00], [0x23, 0x08, 0x00], 0),
+    ([0x25, 0x08, 0x00], [0x27, 0x08, 0x00], 0),
+    ([0x29, 0x08, 0x00], [0x2D, 0x08, 0x00], 0),
+    ([0x2E, 0x08, 0x00], [0x2F, 0x08, 0x00], -1),
+    ([0x3F, 0x08, 0x00], [0x3F, 0x08, 0x00], -1),
+    ([0x59, 0x08, 0x00], [0x5B, 0x08, 0x00], 0),
+    ([0x5C, 0x08, 0x00], [0x5D, 0x08, 0x00], -1),
+    ([0x5F, 0x08, 0x00], [0x5F, 0x08, 0x00], -1),
+    ([0x6B, 0x08, 0x00], [0x6F, 0x08, 0x00], -1),
+    ([0x8F, 0x08, 0x00], [0x8F, 0x08, 0x00], -1),
+    ([0x92, 0x08, 0x00], [0x97, 0x08, 0x00], -1),
+    ([0x98, 0x08, 0x00], [0x9F, 0x08, 0x00], 0),
+    ([0xCA, 0x08, 0x00], [0xE1, 0x08, 0x00], 0),
+    ([0xE3, 0x08, 0x00], [0x02, 0x09, 0x00], 0),
+    ([0x3A, 0x09, 0x00], [0x3A, 0x09, 0x00], 0),
+    ([0x3C, 0x09, 0x00], [0x3C, 0x09, 0x00], 0),
+    ([0x41, 0x09, 0x00], [0x48, 0x09, 0x00], 0),
+    ([0x4D, 0x09, 0x00], [0x4D, 0x09, 0x00], 0),
+    ([0x51, 0x09, 0x00], [0x57, 0x09, 0x00], 0),
+    ([0x62, 0x09, 0x00], [0x63, 0x09, 0x00], 0),
+    ([0x81, 0x09, 0x00], [0x81, 0x09, 0x00], 0),
+    ([0x84, 0x09, 0x00], [0x84, 0x09, 0x00], -1),
+    ([0x8D, 0x09, 0x00], [0x8E, 0x09, 0x00], -1),
+    ([0x91, 0x09, 0x00], [0x92, 0x09, 0x00], -1),
+    ([0xA9, 0x09, 0x00], [0xA9, 0x09, 0x00], -1),
+    ([0xB1, 0x09, 0x00], [0xB1, 0x09, 0x00], -1),
+    ([0xB3, 0x09, 0x00], [0xB5, 0x09, 0x00], -1),
+    ([0xBA, 0x09, 0x00], [0xBB, 0x09, 0x00], -1),
+    ([0xBC, 0x09, 0x00], [0xBC, 0x09, 0x00], 0),
+    ([0xC1, 0x09, 0x00], [0xC4, 0x09, 0x00], 0),
+    ([0xC5, 0x09, 0x00], [0xC6, 0x09, 0x00], -1),
+    ([0xC9, 0x09, 0x00], [0xCA, 0x09, 0x00], -1),
+    ([0xCD, 0x09, 0x00], [0xCD, 0x09, 0x00], 0),
+    ([0xCF, 0x09, 0x00], [0xD6, 0x09, 0x00], -1),
+    ([0xD8, 0x09, 0x00], [0xDB, 0x09, 0x00], -1),
+    ([0xDE, 0x09, 0x00], [0xDE, 0x09, 0x00], -1),
+    ([0xE2, 0x09, 0x00], [0xE3, 0x09, 0x00], 0),
+    ([0xE4, 0x09, 0x00], [0xE5, 0x09, 0x00], -1),
+    ([0xFE, 0x09, 0x00], [0xFE, 0x09, 0x00], 0),
+    ([0xFF, 0x09, 0x00], [0x00, 0x0A, 0x00], -1),
+    ([0x01, 0x0A, 0x00], [0x02, 0x0A, 0x00], 0),
+    ([0x04, 0x0A, 0x00], [0x04, 0x0A, 0x00], -1),
+    ([0x0B, 0x0A, 0x00], [0x0E, 0x0A, 0x00], -1),
+    ([0x11, 0x0A, 0x00], [0x12, 0x0A, 0x00], -1),
+    ([0x29, 0x0A, 0x00], [0x29, 0x0A, 0x00], -1),
+    ([0x31, 0x0A, 0x00], [0x31, 0x0A, 0x00], -1),
+    ([0x34, 0x0A, 0x00], [0x34, 0x0A, 0x00], -1),
+    ([0x37, 0x0A, 0x00], [0x37, 0x0A, 0x00], -1),
+    ([0x3A, 0x0A, 0x00], [0x3B, 0x0A, 0x00], -1),
+    ([0x3C, 0x0A, 0x00], [0x3C, 0x0A, 0x00], 0),
+    ([0x3D, 0x0A, 0x00], [0x3D, 0x0A, 0x00], -1),
+    ([0x41, 0x0A, 0x00], [0x42, 0x0A, 0x00], 0),
+    ([0x43, 0x0A, 0x00], [0x46, 0x0A, 0x00], -1),
+    ([0x47, 0x0A, 0x00], [0x48, 0x0A, 0x00], 0),
+    ([0x49, 0x0A, 0x00], [0x4A, 0x0A, 0x00], -1),
+    ([0x4B, 0x0A, 0x00], [0x4D, 0x0A, 0x00], 0),
+    ([0x4E, 0x0A, 0x00], [0x50, 0x0A, 0x00], -1),
+    ([0x51, 0x0A, 0x00], [0x51, 0x0A, 0x00], 0),
+    ([0x52, 0x0A, 0x00], [0x58, 0x0A, 0x00], -1),
+    ([0x5D, 0x0A, 0x00], [0x5D, 0x0A, 0x00], -1),
+    ([0x5F, 0x0A, 0x00], [0x65, 0x0A, 0x00], -1),
+    ([0x70, 0x0A, 0x00], [0x71, 0x0A, 0x00], 0),
+    ([0x75, 0x0A, 0x00], [0x75, 0x0A, 0x00], 0),
+    ([0x77, 0x0A, 0x00], [0x80, 0x0A, 0x00], -1),
+    ([0x81, 0x0A, 0x00], [0x82, 0x0A, 0x00], 0),
+    ([0x84, 0x0A, 0x00], [0x84, 0x0A, 0x00], -1),
+    ([0x8E, 0x0A, 0x00], [0x8E, 0x0A, 0x00], -1),
+    ([0x92, 0x0A, 0x00], [0x92, 0x0A, 0x00], -1),
+    ([0xA9, 0x0A, 0x00], [0xA9, 0x0A, 0x00], -1),
+    ([0xB1, 0x0A, 0x00], [0xB1, 0x0A, 0x00], -1),
+    ([0xB4, 0x0A, 0x00], [0xB4, 0x0A, 0x00], -1),
+    ([0xBA, 0x0A, 0x00], [0xBB, 0x0A, 0x00], -1),
+    ([0xBC, 0x0A, 0x00], [0xBC, 0x0A, 0x00], 0),
+    ([0xC1, 0x0A, 0x00], [0xC5, 0x0A, 0x00], 0),
+    ([0xC6, 0x0A, 0x00], [0xC6, 0x0A, 0x00], -1),
+    ([0xC7, 0x0A, 0x00], [0xC8, 0x0A, 0x00], 0),
+    ([0xCA, 0x0A, 0x00], [0xCA, 0x0A, 0x00], -1),
+    ([0xCD, 0x0A, 0x00], [0xCD, 0x0A, 0x00], 0),
+    ([0xCE, 0x0A, 0x00], [0xCF, 0x0A, 0x00], -1),
+    ([0xD1, 0x0A, 0x00], [0xDF, 0x0A, 0x00], -1),
+    ([0xE2, 0x0A, 0x00], [0xE3, 0x0A, 0x00], 0),
+    ([0xE4, 0x0A, 0x00], [0xE5, 0x0A, 0x00], -1),
+    ([0xF2, 0x0A, 0x00], [0xF8, 0x0A, 0x00], -1),
+    ([0xFA, 0x0A, 0x00], [0xFF, 0x0A, 0x00], 0),
+    ([0x00, 0x0B, 0x00], [0x00, 0x0B, 0x00], -1),
+    ([0x01, 0x0B, 0x00], [0x01, 0x0B, 0x00], 0),
+    ([0x04, 0x0B, 0x00], [0x04, 0x0B, 0x00], -1),
+    ([0x0D, 0x0B, 0x00], [0x0E, 0x0B, 0x00], -1),
+    ([0x11, 0x0B, 0x00], [0x12, 0x0B, 0x00], -1),
+    ([0x29, 0x0B, 0x00], [0x29, 0x0B, 0x00], -1),
+    ([0x31, 0x0B, 0x00], [0x31, 0x0B, 0x00], -1),
+    ([0x34, 0x0B, 0x00], [0x34, 0x0B, 0x00], -1),
+    ([0x3A, 0x0B, 0x00], [0x3B, 0x0B, 0x00], -1),
+    ([0x3C, 0x0B, 0x00], [0x3C, 0x0B, 0x00], 0),
+    ([0x3F, 0x0B, 0x00], [0x3F, 0x0B, 0x00], 0),
+    ([0x41, 0x0B, 0x00], [0x44, 0x0B, 0x00], 0),
+    ([0x45, 0x0B, 0x00], [0x46, 0x0B, 0x00], -1),
+    ([0x49, 0x0B, 0x00], [0x4A, 0x0B, 0x00], -1),
+    ([0x4D, 0x0B, 0x00], [0x4D, 0x0B, 0x00], 0),
+    ([0x4E, 0x0B, 0x00], [0x54, 0x0B, 0x00], -1),
+    ([0x55, 0x0B, 0x00], [0x56, 0x0B, 0x00], 0),
+    ([0x58, 0x0B, 0x00], [0x5B, 0x0B, 0x00], -1),
+    ([0x5E, 0x0B, 0x00], [0x5E, 0x0B, 0x00], -1),
+    ([0x62, 0x0B, 0x00], [0x63, 0x0B, 0x00], 0),
+    ([0x64, 0x0B, 0x00], [0x65, 0x0B, 0x00], -1),
+    ([0x78, 0x0B, 0x00], [0x81, 0x0B, 0x00], -1),
+    ([0x82, 0x0B, 0x00], [0x82, 0x0B, 0x00], 0),
+    ([0x84, 0x0B, 0x00], [0x84, 0x0B, 0x00], -1),
+    ([0x8B, 0x0B, 0x00], [0x8D, 0x0B, 0x00], -1),
+    ([0x91, 0x0B, 0x00], [0x91, 0x0B, 0x00], -1),
+    ([0x96, 0x0B, 0x00], [0x98, 0x0B, 0x00], -1),
+    ([0x9B, 0x0B, 0x00], [0x9B, 0x0B, 0x00], -1),
+    ([0x9D, 0x0B, 0x00], [0x9D, 0x0B, 0x00], -1),
+    ([0xA0, 0x0B, 0x00], [0xA2, 0x0B, 0x00], -1),
+    ([0xA5, 0x0B, 0x00], [0xA7, 0x0B, 0x00], -1),
+    ([0xAB, 0x0B, 0x00], [0xAD, 0x0B, 0x00], -1),
+    ([0xBA, 0x0B, 0x00], [0xBD, 0x0B, 0x00], -1),
+    ([0xC0, 0x0B, 0x00], [0xC0, 0x0B, 0x00], 0),
+    ([0xC3, 0x0B, 0x00], [0xC5, 0x0B, 0x00], -1),
+    ([0xC9, 0x0B, 0x00], [0xC9, 0x0B, 0x00], -1),
+    ([0xCD, 0x0B, 0x00], [0xCD, 0x0B, 0x00], 0),
+    ([0xCE, 0x0B, 0x00], [0xCF, 0x0B, 0x00], -1),
+    ([0xD1, 0x0B, 0x00], [0xD6, 0x0B, 0x00], -1),
+    ([0xD8, 0x0B, 0x00], [0xE5, 0x0B, 0x00], -1),
+    ([0xFB, 0x0B, 0x00], [0xFF, 0x0B, 0x00], -1),
+    ([0x00, 0x0C, 0x00], [0x00, 0x0C, 0x00], 0),
+    ([0x04, 0x0C, 0x00], [0x04, 0x0C, 0x00], 0),
+    ([0x0D, 0x0C, 0x00], [0x0D, 0x0C, 0x00], -1),
+    ([0x11, 0x0C, 0x00], [0x11, 0x0C, 0x00], -1),
+    ([0x29, 0x0C, 0x00], [0x29, 0x0C, 0x00], -1),
+    ([0x3A, 0x0C, 0x00], [0x3B, 0x0C, 0x00], -1),
+    ([0x3C, 0x0C, 0x00], [0x3C, 0x0C, 0x00], 0),
+    ([0x3E, 0x0C, 0x00], [0x40, 0x0C, 0x00], 0),
+    ([0x45, 0x0C, 0x00], [0x45, 0x0C, 0x00], -1),
+    ([0x46, 0x0C, 0x00], [0x48, 0x0C, 0x00], 0),
+    ([0x49, 0x0C, 0x00], [0x49, 0x0C, 0x00], -1),
+    ([0x4A, 0x0C, 0x00], [0x4D, 0x0C, 0x00], 0),
+    ([0x4E, 0x0C, 0x00], [0x54, 0x0C, 0x00], -1),
+    ([0x55, 0x0C, 0x00], [0x56, 0x0C, 0x00], 0),
+    ([0x57, 0x0C, 0x00], [0x57, 0x0C, 0x00], -1),
+    ([0x5B, 0x0C, 0x00], [0x5C, 0x0C, 0x00], -1),
+    ([0x5E, 0x0C, 0x00], [0x5F, 0x0C, 0x00], -1),
+    ([0x62, 0x0C, 0x00], [0x63, 0x0C, 0x00], 0),
+    ([0x64, 0x0C, 0x00], [0x65, 0x0C, 0x00], -1),
+    ([0x70, 0x0C, 0x00], [0x76, 0x0C, 0x00], -1),
+    ([0x81, 0x0C, 0x00], [0x81, 0x0C, 0x00], 0),
+    ([0x8D, 0x0C, 0x00], [0x8D, 0x0C, 0x00], -1),
+    ([0x91, 0x0C, 0x00], [0x91, 0x0C, 0x00], -1),
+    ([0xA9, 0x0C, 0x00], [0xA9, 0x0C, 0x00], -1),
+    ([0xB4, 0x0C, 0x00], [0xB4, 0x0C, 0x00], -1),
+    ([0xBA, 0x0C, 0x00], [0xBB, 0x0C, 0x00], -1),
+    ([0xBC, 0x0C, 0x00], [0xBC, 0x0C, 0x00], 0),
+    ([0xBF, 0x0C, 0x00], [0xBF, 0x0C, 0x00], 0),
+    ([0xC5, 0x0C, 0x00], [0xC5, 0x0C, 0x00], -1),
+    ([0xC6, 0x0C, 0x00], [0xC6, 0x0C, 0x00], 0),
+    ([0xC9, 0x0C, 0x00], [0xC9, 0x0C, 0x00], -1),
+    ([0xCC, 0x0C, 0x00], [0xCD, 0x0C, 0x00], 0),
+    ([0xCE, 0x0C, 0x00], [0xD4, 0x0C, 0x00], -1),
+    ([0xD7, 0x0C, 0x00], [0xDC, 0x0C, 0x00], -1),
+    ([0xDF, 0x0C, 0x00], [0xDF, 0x0C, 0x00], -1),
+    ([0xE2, 0x0C, 0x00], [0xE3, 0x0C, 0x00], 0),
+    ([0xE4, 0x0C, 0x00], [0xE5, 0x0C, 0x00], -1),
+    ([0xF0, 0x0C, 0x00], [0xF0, 0x0C, 0x00], -1),
+    ([0xF3, 0x0C, 0x00], [0xFF, 0x0C, 0x00], -1),
+    ([0x00, 0x0D, 0x00], [0x01, 0x0D, 0x00], 0),
+    ([0x0D, 0x0D, 0x00], [0x0D, 0x0D, 0x00], -1),
+    ([0x11, 0x0D, 0x00], [0x11, 0x0D, 0x00], -1),
+    ([0x3B, 0x0D, 0x00], [0x3C, 0x0D, 0x00], 0),
+    ([0x41, 0x0D, 0x00], [0x44, 0x0D, 0x00], 0),
+    ([0x45, 0x0D, 0x00], [0x45, 0x0D, 0x00], -1),
+    ([0x49, 0x0D, 0x00], [0x49, 0x0D, 0x00], -1),
+    ([0x4D, 0x0D, 0x00], [0x4D, 0x0D, 0x00], 0),
+    ([0x50, 0x0D, 0x00], [0x53, 0x0D, 0x00], -1),
+    ([0x62, 0x0D, 0x00], [0x63, 0x0D, 0x00], 0),
+    ([0x64, 0x0D, 0x00], [0x65, 0x0D, 0x00], -1),
+    ([0x80, 0x0D, 0x00], [0x80, 0x0D, 0x00], -1),
+    ([0x81, 0x0D, 0x00], [0x81, 0x0D, 0x00], 0),
+    ([0x84, 0x0D, 0x00], [0x84, 0x0D, 0x00], -1),
+    ([0x97, 0x0D, 0x00], [0x99, 0x0D, 0x00], -1),
+    ([0xB2, 0x0D, 0x00], [0xB2, 0x0D, 0x00], -1),
+    ([0xBC, 0x0D, 0x00], [0xBC, 0x0D, 0x00], -1),
+    ([0xBE, 0x0D, 0x00], [0xBF, 0x0D, 0x00], -1),
+    ([0xC7, 0x0D, 0x00], [0xC9, 0x0D, 0x00], -1),
+    ([0xCA, 0x0D, 0x00], [0xCA, 0x0D, 0x00], 0),
+    ([0xCB, 0x0D, 0x00], [0xCE, 0x0D, 0x00], -1),
+    ([0xD2, 0x0D, 0x00], [0xD4, 0x0D, 0x00], 0),
+    ([0xD5, 0x0D, 0x00], [0xD5, 0x0D, 0x00], -1),
+    ([0xD6, 0x0D, 0x00], [0xD6, 0x0D, 0x00], 0),
+    ([0xD7, 0x0D, 0x00], [0xD7, 0x0D, 0x00], -1),
+    ([0xE0, 0x0D, 0x00], [0xE5, 0x0D, 0x00], -1),
+    ([0xF0, 0x0D, 0x00], [0xF1, 0x0D, 0x00], -1),
+    ([0xF5, 0x0D, 0x00], [0x00, 0x0E, 0x00], -1),
+    ([0x31, 0x0E, 0x00], [0x31, 0x0E, 0x00], 0),
+    ([0x34, 0x0E, 0x00], [0x3A, 0x0E, 0x00], 0),
+    ([0x3B, 0x0E, 0x00], [0x3E, 0x0E, 0x00], -1),
+    ([0x47, 0x0E, 0x00], [0x4E, 0x0E, 0x00], 0),
+    ([0x5C, 0x0E, 0x00], [0x80, 0x0E, 0x00], -1),
+    ([0x83, 0x0E, 0x00], [0x83, 0x0E, 0x00], -1),
+    ([0x85, 0x0E, 0x00], [0x85, 0x0E, 0x00], -1),
+    ([0x8B, 0x0E, 0x00], [0x8B, 0x0E, 0x00], -1),
+    ([0xA4, 0x0E, 0x00], [0xA4, 0x0E, 0x00], -1),
+    ([0xA6, 0x0E, 0x00], [0xA6, 0x0E, 0x00], -1),
+    ([0xB1, 0x0E, 0x00], [0xB1, 0x0E, 0x00], 0),
+    ([0xB4, 0x0E, 0x00], [0xBC, 0x0E, 0x00], 0),
+    ([0xBE, 0x0E, 0x00], [0xBF, 0x0E, 0x00], -1),
+    ([0xC5, 0x0E, 0x00], [0xC5, 0x0E, 0x00], -1),
+    ([0xC7, 0x0E, 0x00], [0xC7, 0x0E, 0x00], -1),
+    ([0xC8, 0x0E, 0x00], [0xCD, 0x0E, 0x00], 0),
+    ([0xCE, 0x0E, 0x00], [0xCF, 0x0E, 0x00], -1),
+    ([0xDA, 0x0E, 0x00], [0xDB, 0x0E, 0x00], -1),
+    ([0xE0, 0x0E, 0x00], [0xFF, 0x0E, 0x00], -1),
+    ([0x18, 0x0F, 0x00], [0x19, 0x0F, 0x00], 0),
+    ([0x35, 0x0F, 0x00], [0x35, 0x0F, 0x00], 0),
+    ([0x37, 0x0F, 0x00], [0x37, 0x0F, 0x00], 0),
+    ([0x39, 0x0F, 0x00], [0x39, 0x0F, 0x00], 0),
+    ([0x48, 0x0F, 0x00], [0x48, 0x0F, 0x00], -1),
+    ([0x6D, 0x0F, 0x00], [0x70, 0x0F, 0x00], -1),
+    ([0x71, 0x0F, 0x00], [0x7E, 0x0F, 0x00], 0),
+    ([0x80, 0x0F, 0x00], [0x84, 0x0F, 0x00], 0),
+    ([0x86, 0x0F, 0x00], [0x87, 0x0F, 0x00], 0),
+    ([0x8D, 0x0F, 0x00], [0x97, 0x0F, 0x00], 0),
+    ([0x98, 0x0F, 0x00], [0x98, 0x0F, 0x00], -1),
+    ([0x99, 0x0F, 0x00], [0xBC, 0x0F, 0x00], 0),
+    ([0xBD, 0x0F, 0x00], [0xBD, 0x0F, 0x00], -1),
+    ([0xC6, 0x0F, 0x00], [0xC6, 0x0F, 0x00], 0),
+    ([0xCD, 0x0F, 0x00], [0xCD, 0x0F, 0x00], -1),
+    ([0xDB, 0x0F, 0x00], [0xFF, 0x0F, 0x00], -1),
+    ([0x2D, 0x10, 0x00], [0x30, 0x10, 0x00], 0),
+    ([0x32, 0x10, 0x00], [0x37, 0x10, 0x00], 0),
+    ([0x39, 0x10, 0x00], [0x3A, 0x10, 0x00], 0),
+    ([0x3D, 0x10, 0x00], [0x3E, 0x10, 0x00], 0),
+    ([0x58, 0x10, 0x00], [0x59, 0x10, 0x00], 0),
+    ([0x5E, 0x10, 0x00], [0x60, 0x10, 0x00], 0),
+    ([0x71, 0x10, 0x00], [0x74, 0x10, 0x00], 0),
+    ([0x82, 0x10, 0x00], [0x82, 0x10, 0x00], 0),
+    ([0x85, 0x10, 0x00], [0x86, 0x10, 0x00], 0),
+    ([0x8D, 0x10, 0x00], [0x8D, 0x10, 0x00], 0),
+    ([0x9D, 0x10, 0x00], [0x9D, 0x10, 0x00], 0),
+    ([0xC6, 0x10, 0x00], [0xC6, 0x10, 0x00], -1),
+    ([0xC8, 0x10, 0x00], [0xCC, 0x10, 0x00], -1),
+    ([0xCE, 0x10, 0x00], [0xCF, 0x10, 0x00], -1),
+    ([0x00, 0x11, 0x00], [0x5F, 0x11, 0x00], 2),
+    ([0x60, 0x11, 0x00], [0xFF, 0x11, 0x00], 0),
+    ([0x49, 0x12, 0x00], [0x49, 0x12, 0x00], -1),
+    ([0x4E, 0x12, 0x00], [0x4F, 0x12, 0x00], -1),
+    ([0x57, 0x12, 0x00], [0x57, 0x12, 0x00], -1),
+    ([0x59, 0x12, 0x00], [0x59, 0x12, 0x00], -1),
+    ([0x5E, 0x12, 0x00], [0x5F, 0x12, 0x00], -1),
+    ([0x89, 0x12, 0x00], [0x89, 0x12, 0x00], -1),
+    ([0x8E, 0x12, 0x00], [0x8F, 0x12, 0x00], -1),
+    ([0xB1, 0x12, 0x00], [0xB1, 0x12, 0x00], -1),
+    ([0xB6, 0x12, 0x00], [0xB7, 0x12, 0x00], -1),
+    ([0xBF, 0x12, 0x00], [0xBF, 0x12, 0x00], -1),
+    ([0xC1, 0x12, 0x00], [0xC1, 0x12, 0x00], -1),
+    ([0xC6, 0x12, 0x00], [0xC7, 0x12, 0x00], -1),
+    ([0xD7, 0x12, 0x00], [0xD7, 0x12, 0x00], -1),
+    ([0x11, 0x13, 0x00], [0x11, 0x13, 0x00], -1),
+    ([0x16, 0x13, 0x00], [0x17, 0x13, 0x00], -1),
+    ([0x5B, 0x13, 0x00], [0x5C, 0x13, 0x00], -1),
+    ([0x5D, 0x13, 0x00], [0x5F, 0x13, 0x00], 0),
+    ([0x7D, 0x13, 0x00], [0x7F, 0x13, 0x00], -1),
+    ([0x9A, 0x13, 0x00], [0x9F, 0x13, 0x00], -1),
+    ([0xF6, 0x13, 0x00], [0xF7, 0x13, 0x00], -1),
+    ([0xFE, 0x13, 0x00], [0xFF, 0x13, 0x00], -1),
+    ([0x9D, 0x16, 0x00], [0x9F, 0x16, 0x00], -1),
+    ([0xF9, 0x16, 0x00], [0xFF, 0x16, 0x00], -1),
+    ([0x12, 0x17, 0x00], [0x14, 0x17, 0x00], 0),
+    ([0x16, 0x17, 0x00], [0x1E, 0x17, 0x00], -1),
+    ([0x32, 0x17, 0x00], [0x33, 0x17, 0x00], 0),
+    ([0x37, 0x17, 0x00], [0x3F, 0x17, 0x00], -1),
+    ([0x52, 0x17, 0x00], [0x53, 0x17, 0x00], 0),
+    ([0x54, 0x17, 0x00], [0x5F, 0x17, 0x00], -1),
+    ([0x6D, 0x17, 0x00], [0x6D, 0x17, 0x00], -1),
+    ([0x71, 0x17, 0x00], [0x71, 0x17, 0x00], -1),
+    ([0x72, 0x17, 0x00], [0x73, 0x17, 0x00], 0),
+    ([0x74, 0x17, 0x00], [0x7F, 0x17, 0x00], -1),
+    ([0xB4, 0x17, 0x00], [0xB5, 0x17, 0x00], 0),
+    ([0xB7, 0x17, 0x00], [0xBD, 0x17, 0x00], 0),
+    ([0xC6, 0x17, 0x00], [0xC6, 0x17, 0x00], 0),
+    ([0xC9, 0x17, 0x00], [0xD3, 0x17, 0x00], 0),
+    ([0xDD, 0x17, 0x00], [0xDD, 0x17, 0x00], 0),
+    ([0xDE, 0x17, 0x00], [0xDF, 0x17, 0x00], -1),
+    ([0xEA, 0x17, 0x00], [0xEF, 0x17, 0x00], -1),
+    ([0xFA, 0x17, 0x00], [0xFF, 0x17, 0x00], -1),
+    ([0x0B, 0x18, 0x00], [0x0F, 0x18, 0x00], 0),
+    ([0x1A, 0x18, 0x00], [0x1F, 0x18, 0x00], -1),
+    ([0x79, 0x18, 0x00], [0x7F, 0x18, 0x00], -1),
+    ([0x85, 0x18, 0x00], [0x86, 0x18, 0x00], 0),
+    ([0xA9, 0x18, 0x00], [0xA9, 0x18, 0x00], 0),
+    ([0xAB, 0x18, 0x00], [0xAF, 0x18, 0x00], -1),
+    ([0xF6, 0x18, 0x00], [0xFF, 0x18, 0x00], -1),
+    ([0x1F, 0x19, 0x00], [0x1F, 0x19, 0x00], -1),
+    ([0x20, 0x19, 0x00], [0x22, 0x19, 0x00], 0),
+    ([0x27, 0x19, 0x00], [0x28, 0x19, 0x00], 0),
+    ([0x2C, 0x19, 0x00], [0x2F, 0x19, 0x00], -1),
+    ([0x32, 0x19, 0x00], [0x32, 0x19, 0x00], 0),
+    ([0x39, 0x19, 0x00], [0x3B, 0x19, 0x00], 0),
+    ([0x3C, 0x19, 0x00], [0x3F, 0x19, 0x00], -1),
+    ([0x41, 0x19, 0x00], [0x43, 0x19, 0x00], -1),
+    ([0x6E, 0x19, 0x00], [0x6F, 0x19, 0x00], -1),
+    ([0x75, 0x19, 0x00], [0x7F, 0x19, 0x00], -1),
+    ([0xAC, 0x19, 0x00], [0xAF, 0x19, 0x00], -1),
+    ([0xCA, 0x19, 0x00], [0xCF, 0x19, 0x00], -1),
+    ([0xDB, 0x19, 0x00], [0xDD, 0x19, 0x00], -1),
+    ([0x17, 0x1A, 0x00], [0x18, 0x1A, 0x00], 0),
+    ([0x1B, 0x1A, 0x00], [0x1B, 0x1A, 0x00], 0),
+    ([0x1C, 0x1A, 0x00], [0x1D, 0x1A, 0x00], -1),
+    ([0x56, 0x1A, 0x00], [0x56, 0x1A, 0x00], 0),
+    ([0x58, 0x1A, 0x00], [0x5E, 0x1A, 0x00], 0),
+    ([0x5F, 0x1A, 0x00], [0x5F, 0x1A, 0x00], -1),
+    ([0x60, 0x1A, 0x00], [0x60, 0x1A, 0x00], 0),
+    ([0x62, 0x1A, 0x00], [0x62, 0x1A, 0x00], 0),
+    ([0x65, 0x1A, 0x00], [0x6C, 0x1A, 0x00], 0),
+    ([0x73, 0x1A, 0x00], [0x7C, 0x1A, 0x00], 0),
+    ([0x7D, 0x1A, 0x00], [0x7E, 0x1A, 0x00], -1),
+    ([0x7F, 0x1A, 0x00], [0x7F, 0x1A, 0x00], 0),
+    ([0x8A, 0x1A, 0x00], [0x8F, 0x1A, 0x00], -1),
+    ([0x9A, 0x1A, 0x00], [0x9F, 0x1A, 0x00], -1),
+    ([0xAE, 0x1A, 0x00], [0xAF, 0x1A, 0x00], -1),
+    ([0xB0, 0x1A, 0x00], [0xCE, 0x1A, 0x00], 0),
+    ([0xCF, 0x1A, 0x00], [0xFF, 0x1A, 0x00], -1),
+    ([0x00, 0x1B, 0x00], [0x03, 0x1B, 0x00], 0),
+    ([0x34, 0x1B, 0x00], [0x34, 0x1B, 0x00], 0),
+    ([0x36, 0x1B, 0x00], [0x3A, 0x1B, 0x00], 0),
+    ([0x3C, 0x1B, 0x00], [0x3C, 0x1B, 0x00], 0),
+    ([0x42, 0x1B, 0x00], [0x42, 0x1B, 0x00], 0),
+    ([0x4D, 0x1B, 0x00], [0x4F, 0x1B, 0x00], -1),
+    ([0x6B, 0x1B, 0x00], [0x73, 0x1B, 0x00], 0),
+    ([0x7F, 0x1B, 0x00], [0x7F, 0x1B, 0x00], -1),
+    ([0x80, 0x1B, 0x00], [0x81, 0x1B, 0x00], 0),
+    ([0xA2, 0x1B, 0x00], [0xA5, 0x1B, 0x00], 0),
+    ([0xA8, 0x1B, 0x00], [0xA9, 0x1B, 0x00], 0),
+    ([0xAB, 0x1B, 0x00], [0xAD, 0x1B, 0x00], 0),
+    ([0xE6, 0x1B, 0x00], [0xE6, 0x1B, 0x00], 0),
+    ([0xE8, 0x1B, 0x00], [0xE9, 0x1B, 0x00], 0),
+    ([0xED, 0x1B, 0x00], [0xED, 0x1B, 0x00], 0),
+    ([0xEF, 0x1B, 0x00], [0xF1, 0x1B, 0x00], 0),
+    ([0xF4, 0x1B, 0x00], [0xFB, 0x1B, 0x00], -1),
+    ([0x2C, 0x1C, 0x00], [0x33, 0x1C, 0x00], 0),
+    ([0x36, 0x1C, 0x00], [0x37, 0x1C, 0x00], 0),
+    ([0x38, 0x1C, 0x00], [0x3A, 0x1C, 0x00], -1),
+    ([0x4A, 0x1C, 0x00], [0x4C, 0x1C, 0x00], -1),
+    ([0x89, 0x1C, 0x00], [0x8F, 0x1C, 0x00], -1),
+    ([0xBB, 0x1C, 0x00], [0xBC, 0x1C, 0x00], -1),
+    ([0xC8, 0x1C, 0x00], [0xCF, 0x1C, 0x00], -1),
+    ([0xD0, 0x1C, 0x00], [0xD2, 0x1C, 0x00], 0),
+    ([0xD4, 0x1C, 0x00], [0xE0, 0x1C, 0x00], 0),
+    ([0xE2, 0x1C, 0x00], [0xE8, 0x1C, 0x00], 0),
+    ([0xED, 0x1C, 0x00], [0xED, 0x1C, 0x00], 0),
+    ([0xF4, 0x1C, 0x00], [0xF4, 0x1C, 0x00], 0),
+    ([0xF8, 0x1C, 0x00], [0xF9, 0x1C, 0x00], 0),
+    ([0xFB, 0x1C, 0x00], [0xFF, 0x1C, 0x00], -1),
+    ([0xC0, 0x1D, 0x00], [0xFF, 0x1D, 0x00], 0),
+    ([0x16, 0x1F, 0x00], [0x17, 0x1F, 0x00], -1),
+    ([0x1E, 0x1F, 0x00], [0x1F, 0x1F, 0x00], -1),
+    ([0x46, 0x1F, 0x00], [0x47, 0x1F, 0x00], -1),
+    ([0x4E, 0x1F, 0x00], [0x4F, 0x1F, 0x00], -1),
+    ([0x58, 0x1F, 0x00], [0x58, 0x1F, 0x00], -1),
+    ([0x5A, 0x1F, 0x00], [0x5A, 0x1F, 0x00], -1),
+    ([0x5C, 0x1F, 0x00], [0x5C, 0x1F, 0x00], -1),
+    ([0x5E, 0x1F, 0x00], [0x5E, 0x1F, 0x00], -1),
+    ([0x7E, 0x1F, 0x00], [0x7F, 0x1F, 0x00], -1),
+    ([0xB5, 0x1F, 0x00], [0xB5, 0x1F, 0x00], -1),
+    ([0xC5, 0x1F, 0x00], [0xC5, 0x1F, 0x00], -1),
+    ([0xD4, 0x1F, 0x00], [0xD5, 0x1F, 0x00], -1),
+    ([0xDC, 0x1F, 0x00], [0xDC, 0x1F, 0x00], -1),
+    ([0xF0, 0x1F, 0x00], [0xF1, 0x1F, 0x00], -1),
+    ([0xF5, 0x1F, 0x00], [0xF5, 0x1F, 0x00], -1),
+    ([0xFF, 0x1F, 0x00], [0xFF, 0x1F, 0x00], -1),
+    ([0x0B, 0x20, 0x00], [0x0F, 0x20, 0x00], 0),
+    ([0x28, 0x20, 0x00], [0x29, 0x20, 0x00], -1),
+    ([0x2A, 0x20, 0x00], [0x2E, 0x20, 0x00], 0),
+    ([0x60, 0x20, 0x00], [0x64, 0x20, 0x00], 0),
+    ([0x65, 0x20, 0x00], [0x65, 0x20, 0x00], -1),
+    ([0x66, 0x20, 0x00], [0x6F, 0x20, 0x00], 0),
+    ([0x72, 0x20, 0x00], [0x73, 0x20, 0x00], -1),
+    ([0x8F, 0x20, 0x00], [0x8F, 0x20, 0x00], -1),
+    ([0x9D, 0x20, 0x00], [0x9F, 0x20, 0x00], -1),
+    ([0xC1, 0x20, 0x00], [0xCF, 0x20, 0x00], -1),
+    ([0xD0, 0x20, 0x00], [0xF0, 0x20, 0x00], 0),
+    ([0xF1, 0x20, 0x00], [0xFF, 0x20, 0x00], -1),
+    ([0x8C, 0x21, 0x00], [0x8F, 0x21, 0x00], -1),
+    ([0x1A, 0x23, 0x00], [0x1B, 0x23, 0x00], 2),
+    ([0x29, 0x23, 0x00], [0x2A, 0x23, 0x00], 2),
+    ([0xE9, 0x23, 0x00], [0xEC, 0x23, 0x00], 2),
+    ([0xF0, 0x23, 0x00], [0xF0, 0x23, 0x00], 2),
+    ([0xF3, 0x23, 0x00], [0xF3, 0x23, 0x00], 2),
+    ([0x27, 0x24, 0x00], [0x3F, 0x24, 0x00], -1),
+    ([0x4B, 0x24, 0x00], [0x5F, 0x24, 0x00], -1),
+    ([0xFD, 0x25, 0x00], [0xFE, 0x25, 0x00], 2),
+    ([0x14, 0x26, 0x00], [0x15, 0x26, 0x00], 2),
+    ([0x48, 0x26, 0x00], [0x53, 0x26, 0x00], 2),
+    ([0x7F, 0x26, 0x00], [0x7F, 0x26, 0x00], 2),
+    ([0x93, 0x26, 0x00], [0x93, 0x26, 0x00], 2),
+    ([0xA1, 0x26, 0x00], [0xA1, 0x26, 0x00], 2),
+    ([0xAA, 0x26, 0x00], [0xAB, 0x26, 0x00], 2),
+    ([0xBD, 0x26, 0x00], [0xBE, 0x26, 0x00], 2),
+    ([0xC4, 0x26, 0x00], [0xC5, 0x26, 0x00], 2),
+    ([0xCE, 0x26, 0x00], [0xCE, 0x26, 0x00], 2),
+    ([0xD4, 0x26, 0x00], [0xD4, 0x26, 0x00], 2),
+    ([0xEA, 0x26, 0x00], [0xEA, 0x26, 0x00], 2),
+    ([0xF2, 0x26, 0x00], [0xF3, 0x26, 0x00], 2),
+    ([0xF5, 0x26, 0x00], [0xF5, 0x26, 0x00], 2),
+    ([0xFA, 0x26, 0x00], [0xFA, 0x26, 0x00], 2),
+    ([0xFD, 0x26, 0x00], [0xFD, 0x26, 0x00], 2),
+    ([0x05, 0x27, 0x00], [0x05, 0x27, 0x00], 2),
+    ([0x0A, 0x27, 0x00], [0x0B, 0x27, 0x00], 2),
+    ([0x28, 0x27, 0x00], [0x28, 0x27, 0x00], 2),
+    ([0x4C, 0x27, 0x00], [0x4C, 0x27, 0x00], 2),
+    ([0x4E, 0x27, 0x00], [0x4E, 0x27, 0x00], 2),
+    ([0x53, 0x27, 0x00], [0x55, 0x27, 0x00], 2),
+    ([0x57, 0x27, 0x00], [0x57, 0x27, 0x00], 2),
+    ([0x95, 0x27, 0x00], [0x97, 0x27, 0x00], 2),
+    ([0xB0, 0x27, 0x00], [0xB0, 0x27, 0x00], 2),
+    ([0xBF, 0x27, 0x00], [0xBF, 0x27, 0x00], 2),
+    ([0x1B, 0x2B, 0x00], [0x1C, 0x2B, 0x00], 2),
+    ([0x50, 0x2B, 0x00], [0x50, 0x2B, 0x00], 2),
+    ([0x55, 0x2B, 0x00], [0x55, 0x2B, 0x00], 2),
+    ([0x74, 0x2B, 0x00], [0x75, 0x2B, 0x00], -1),
+    ([0x96, 0x2B, 0x00], [0x96, 0x2B, 0x00], -1),
+    ([0xEF, 0x2C, 0x00], [0xF1, 0x2C, 0x00], 0),
+    ([0xF4, 0x2C, 0x00], [0xF8, 0x2C, 0x00], -1),
+    ([0x26, 0x2D, 0x00], [0x26, 0x2D, 0x00], -1),
+    ([0x28, 0x2D, 0x00], [0x2C, 0x2D, 0x00], -1),
+    ([0x2E, 0x2D, 0x00], [0x2F, 0x2D, 0x00], -1),
+    ([0x68, 0x2D, 0x00], [0x6E, 0x2D, 0x00], -1),
+    ([0x71, 0x2D, 0x00], [0x7E, 0x2D, 0x00], -1),
+    ([0x7F, 0x2D, 0x00], [0x7F, 0x2D, 0x00], 0),
+    ([0x97, 0x2D, 0x00], [0x9F, 0x2D, 0x00], -1),
+    ([0xA7, 0x2D, 0x00], [0xA7, 0x2D, 0x00], -1),
+    ([0xAF, 0x2D, 0x00], [0xAF, 0x2D, 0x00], -1),
+    ([0xB7, 0x2D, 0x00], [0xB7, 0x2D, 0x00], -1),
+    ([0xBF, 0x2D, 0x00], [0xBF, 0x2D, 0x00], -1),
+    ([0xC7, 0x2D, 0x00], [0xC7, 0x2D, 0x00], -1),
+    ([0xCF, 0x2D, 0x00], [0xCF, 0x2D, 0x00], -1),
+    ([0xD7, 0x2D, 0x00], [0xD7, 0x2D, 0x00], -1),
+    ([0xDF, 0x2D, 0x00], [0xDF, 0x2D, 0x00], -1),
+    ([0xE0, 0x2D, 0x00], [0xFF, 0x2D, 0x00], 0),
+    ([0x5E, 0x2E, 0x00], [0x7F, 0x2E, 0x00], -1),
+    ([0x80, 0x2E, 0x00], [0x99, 0x2E, 0x00], 2),
+    ([0x9A, 0x2E, 0x00], [0x9A, 0x2E, 0x00], -1),
+    ([0x9B, 0x2E, 0x00], [0xF3, 0x2E, 0x00], 2),
+    ([0xF4, 0x2E, 0x00], [0xFF, 0x2E, 0x00], -1),
+    ([0x00, 0x2F, 0x00], [0xD5, 0x2F, 0x00], 2),
+    ([0xD6, 0x2F, 0x00], [0xEF, 0x2F, 0x00], -1),
+    ([0xF0, 0x2F, 0x00], [0xFB, 0x2F, 0x00], 2),
+    ([0xFC, 0x2F, 0x00], [0xFF, 0x2F, 0x00], -1),
+    ([0x00, 0x30, 0x00], [0x29, 0x30, 0x00], 2),
+    ([0x2A, 0x30, 0x00], [0x2D, 0x30, 0x00], 0),
+    ([0x2E, 0x30, 0x00], [0x3E, 0x30, 0x00], 2),
+    ([0x40, 0x30, 0x00], [0x40, 0x30, 0x00], -1),
+    ([0x41, 0x30, 0x00], [0x96, 0x30, 0x00], 2),
+    ([0x97, 0x30, 0x00], [0x98, 0x30, 0x00], -1),
+    ([0x99, 0x30, 0x00], [0x9A, 0x30, 0x00], 0),
+    ([0x9B, 0x30, 0x00], [0xFF, 0x30, 0x00], 2),
+    ([0x00, 0x31, 0x00], [0x04, 0x31, 0x00], -1),
+    ([0x05, 0x31, 0x00], [0x2F, 0x31, 0x00], 2),
+    ([0x30, 0x31, 0x00], [0x30, 0x31, 0x00], -1),
+    ([0x31, 0x31, 0x00], [0x8E, 0x31, 0x00], 2),
+    ([0x8F, 0x31, 0x00], [0x8F, 0x31, 0x00], -1),
+    ([0x90, 0x31, 0x00], [0xE3, 0x31, 0x00], 2),
+    ([0xE4, 0x31, 0x00], [0xEF, 0x31, 0x00], -1),
+    ([0xF0, 0x31, 0x00], [0x1E, 0x32, 0x00], 2),
+    ([0x1F, 0x32, 0x00], [0x1F, 0x32, 0x00], -1),
+    ([0x20, 0x32, 0x00], [0x8C, 0xA4, 0x00], 2),
+    ([0x8D, 0xA4, 0x00], [0x8F, 0xA4, 0x00], -1),
+    ([0x90, 0xA4, 0x00], [0xC6, 0xA4, 0x00], 2),
+    ([0xC7, 0xA4, 0x00], [0xCF, 0xA4, 0x00], -1),
+    ([0x2C, 0xA6, 0x00], [0x3F, 0xA6, 0x00], -1),
+    ([0x6F, 0xA6, 0x00], [0x72, 0xA6, 0x00], 0),
+    ([0x74, 0xA6, 0x00], [0x7D, 0xA6, 0x00], 0),
+    ([0x9E, 0xA6, 0x00], [0x9F, 0xA6, 0x00], 0),
+    ([0xF0, 0xA6, 0x00], [0xF1, 0xA6, 0x00], 0),
+    ([0xF8, 0xA6, 0x00], [0xFF, 0xA6, 0x00], -1),
+    ([0xCB, 0xA7, 0x00], [0xCF, 0xA7, 0x00], -1),
+    ([0xD2, 0xA7, 0x00], [0xD2, 0xA7, 0x00], -1),
+    ([0xD4, 0xA7, 0x00], [0xD4, 0xA7, 0x00], -1),
+    ([0xDA, 0xA7, 0x00], [0xF1, 0xA7, 0x00], -1),
+    ([0x02, 0xA8, 0x00], [0x02, 0xA8, 0x00], 0),
+    ([0x06, 0xA8, 0x00], [0x06, 0xA8, 0x00], 0),
+    ([0x0B, 0xA8, 0x00], [0x0B, 0xA8, 0x00], 0),
+    ([0x25, 0xA8, 0x00], [0x26, 0xA8, 0x00], 0),
+    ([0x2C, 0xA8, 0x00], [0x2C, 0xA8, 0x00], 0),
+    ([0x2D, 0xA8, 0x00], [0x2F, 0xA8, 0x00], -1),
+    ([0x3A, 0xA8, 0x00], [0x3F, 0xA8, 0x00], -1),
+    ([0x78, 0xA8, 0x00], [0x7F, 0xA8, 0x00], -1),
+    ([0xC4, 0xA8, 0x00], [0xC5, 0xA8, 0x00], 0),
+    ([0xC6, 0xA8, 0x00], [0xCD, 0xA8, 0x00], -1),
+    ([0xDA, 0xA8, 0x00], [0xDF, 0xA8, 0x00], -1),
+    ([0xE0, 0xA8, 0x00], [0xF1, 0xA8, 0x00], 0),
+    ([0xFF, 0xA8, 0x00], [0xFF, 0xA8, 0x00], 0),
+    ([0x26, 0xA9, 0x00], [0x2D, 0xA9, 0x00], 0),
+    ([0x47, 0xA9, 0x00], [0x51, 0xA9, 0x00], 0),
+    ([0x54, 0xA9, 0x00], [0x5E, 0xA9, 0x00], -1),
+    ([0x60, 0xA9, 0x00], [0x7C, 0xA9, 0x00], 2),
+    ([0x7D, 0xA9, 0x00], [0x7F, 0xA9, 0x00], -1),
+    ([0x80, 0xA9, 0x00], [0x82, 0xA9, 0x00], 0),
+    ([0xB3, 0xA9, 0x00], [0xB3, 0xA9, 0x00], 0),
+    ([0xB6, 0xA9, 0x00], [0xB9, 0xA9, 0x00], 0),
+    ([0xBC, 0xA9, 0x00], [0xBD, 0xA9, 0x00], 0),
+    ([0xCE, 0xA9, 0x00], [0xCE, 0xA9, 0x00], -1),
+    ([0xDA, 0xA9, 0x00], [0xDD, 0xA9, 0x00], -1),
+    ([0xE5, 0xA9, 0x00], [0xE5, 0xA9, 0x00], 0),
+    ([0xFF, 0xA9, 0x00], [0xFF, 0xA9, 0x00], -1),
+    ([0x29, 0xAA, 0x00], [0x2E, 0xAA, 0x00], 0),
+    ([0x31, 0xAA, 0x00], [0x32, 0xAA, 0x00], 0),
+    ([0x35, 0xAA, 0x00], [0x36, 0xAA, 0x00], 0),
+    ([0x37, 0xAA, 0x00], [0x3F, 0xAA, 0x00], -1),
+    ([0x43, 0xAA, 0x00], [0x43, 0xAA, 0x00], 0),
+    ([0x4C, 0xAA, 0x00], [0x4C, 0xAA, 0x00], 0),
+    ([0x4E, 0xAA, 0x00], [0x4F, 0xAA, 0x00], -1),
+    ([0x5A, 0xAA, 0x00], [0x5B, 0xAA, 0x00], -1),
+    ([0x7C, 0xAA, 0x00], [0x7C, 0xAA, 0x00], 0),
+    ([0xB0, 0xAA, 0x00], [0xB0, 0xAA, 0x00], 0),
+    ([0xB2, 0xAA, 0x00], [0xB4, 0xAA, 0x00], 0),
+    ([0xB7, 0xAA, 0x00], [0xB8, 0xAA, 0x00], 0),
+    ([0xBE, 0xAA, 0x00], [0xBF, 0xAA, 0x00], 0),
+    ([0xC1, 0xAA, 0x00], [0xC1, 0xAA, 0x00], 0),
+    ([0xC3, 0xAA, 0x00], [0xDA, 0xAA, 0x00], -1),
+    ([0xEC, 0xAA, 0x00], [0xED, 0xAA, 0x00], 0),
+    ([0xF6, 0xAA, 0x00], [0xF6, 0xAA, 0x00], 0),
+    ([0xF7, 0xAA, 0x00], [0x00, 0xAB, 0x00], -1),
+    ([0x07, 0xAB, 0x00], [0x08, 0xAB, 0x00], -1),
+    ([0x0F, 0xAB, 0x00], [0x10, 0xAB, 0x00], -1),
+    ([0x17, 0xAB, 0x00], [0x1F, 0xAB, 0x00], -1),
+    ([0x27, 0xAB, 0x00], [0x27, 0xAB, 0x00], -1),
+    ([0x2F, 0xAB, 0x00], [0x2F, 0xAB, 0x00], -1),
+    ([0x6C, 0xAB, 0x00], [0x6F, 0xAB, 0x00], -1),
+    ([0xE5, 0xAB, 0x00], [0xE5, 0xAB, 0x00], 0),
+    ([0xE8, 0xAB, 0x00], [0xE8, 0xAB, 0x00], 0),
+    ([0xED, 0xAB, 0x00], [0xED, 0xAB, 0x00], 0),
+    ([0xEE, 0xAB, 0x00], [0xEF, 0xAB, 0x00], -1),
+    ([0xFA, 0xAB, 0x00], [0xFF, 0xAB, 0x00], -1),
+    ([0x00, 0xAC, 0x00], [0xA3, 0xD7, 0x00], 2),
+    ([0xA4, 0xD7, 0x00], [0xAF, 0xD7, 0x00], -1),
+    ([0xB0, 0xD7, 0x00], [0xC6, 0xD7, 0x00], 0),
+    ([0xC7, 0xD7, 0x00], [0xCA, 0xD7, 0x00], -1),
+    ([0xCB, 0xD7, 0x00], [0xFB, 0xD7, 0x00], 0),
+    ([0xFC, 0xD7, 0x00], [0xFF, 0xDF, 0x00], -1),
+    ([0x00, 0xF9, 0x00], [0x6D, 0xFA, 0x00], 2),
+    ([0x6E, 0xFA, 0x00], [0x6F, 0xFA, 0x00], -1),
+    ([0x70, 0xFA, 0x00], [0xD9, 0xFA, 0x00], 2),
+    ([0xDA, 0xFA, 0x00], [0xFF, 0xFA, 0x00], -1),
+    ([0x07, 0xFB, 0x00], [0x12, 0xFB, 0x00], -1),
+    ([0x18, 0xFB, 0x00], [0x1C, 0xFB, 0x00], -1),
+    ([0x1E, 0xFB, 0x00], [0x1E, 0xFB, 0x00], 0),
+    ([0x37, 0xFB, 0x00], [0x37, 0xFB, 0x00], -1),
+    ([0x3D, 0xFB, 0x00], [0x3D, 0xFB, 0x00], -1),
+    ([0x3F, 0xFB, 0x00], [0x3F, 0xFB, 0x00], -1),
+    ([0x42, 0xFB, 0x00], [0x42, 0xFB, 0x00], -1),
+    ([0x45, 0xFB, 0x00], [0x45, 0xFB, 0x00], -1),
+    ([0xC3, 0xFB, 0x00], [0xD2, 0xFB, 0x00], -1),
+    ([0x90, 0xFD, 0x00], [0x91, 0xFD, 0x00], -1),
+    ([0xC8, 0xFD, 0x00], [0xCE, 0xFD, 0x00], -1),
+    ([0xD0, 0xFD, 0x00], [0xEF, 0xFD, 0x00], -1),
+    ([0x00, 0xFE, 0x00], [0x0F, 0xFE, 0x00], 0),
+    ([0x10, 0xFE, 0x00], [0x19, 0xFE, 0x00], 2),
+    ([0x1A, 0xFE, 0x00], [0x1F, 0xFE, 0x00], -1),
+    ([0x20, 0xFE, 0x00], [0x2F, 0xFE, 0x00], 0),
+    ([0x30, 0xFE, 0x00], [0x52, 0xFE, 0x00], 2),
+    ([0x53, 0xFE, 0x00], [0x53, 0xFE, 0x00], -1),
+    ([0x54, 0xFE, 0x00], [0x66, 0xFE, 0x00], 2),
+    ([0x67, 0xFE, 0x00], [0x67, 0xFE, 0x00], -1),
+    ([0x68, 0xFE, 0x00], [0x6B, 0xFE, 0x00], 2),
+    ([0x6C, 0xFE, 0x00], [0x6F, 0xFE, 0x00], -1),
+    ([0x75, 0xFE, 0x00], [0x75, 0xFE, 0x00], -1),
+    ([0xFD, 0xFE, 0x00], [0xFE, 0xFE, 0x00], -1),
+    ([0xFF, 0xFE, 0x00], [0xFF, 0xFE, 0x00], 0),
+    ([0x00, 0xFF, 0x00], [0x00, 0xFF, 0x00], -1),
+    ([0x01, 0xFF, 0x00], [0x60, 0xFF, 0x00], 2),
+    ([0xBF, 0xFF, 0x00], [0xC1, 0xFF, 0x00], -1),
+    ([0xC8, 0xFF, 0x00], [0xC9, 0xFF, 0x00], -1),
+    ([0xD0, 0xFF, 0x00], [0xD1, 0xFF, 0x00], -1),
+    ([0xD8, 0xFF, 0x00], [0xD9, 0xFF, 0x00], -1),
+    ([0xDD, 0xFF, 0x00], [0xDF, 0xFF, 0x00], -1),
+    ([0xE0, 0xFF, 0x00], [0xE6, 0xFF, 0x00], 2),
+    ([0xE7, 0xFF, 0x00], [0xE7, 0xFF, 0x00], -1),
+    ([0xEF, 0xFF, 0x00], [0xF8, 0xFF, 0x00], -1),
+    ([0xF9, 0xFF, 0x00], [0xFB, 0xFF, 0x00], 0),
+    ([0xFE, 0xFF, 0x00], [0xFF, 0xFF, 0x00], -1),
+    ([0x0C, 0x00, 0x01], [0x0C, 0x00, 0x01], -1),
+    ([0x27, 0x00, 0x01], [0x27, 0x00, 0x01], -1),
+    ([0x3B, 0x00, 0x01], [0x3B, 0x00, 0x01], -1),
+    ([0x3E, 0x00, 0x01], [0x3E, 0x00, 0x01], -1),
+    ([0x4E, 0x00, 0x01], [0x4F, 0x00, 0x01], -1),
+    ([0x5E, 0x00, 0x01], [0x7F, 0x00, 0x01], -1),
+    ([0xFB, 0x00, 0x01], [0xFF, 0x00, 0x01], -1),
+    ([0x03, 0x01, 0x01], [0x06, 0x01, 0x01], -1),
+    ([0x34, 0x01, 0x01], [0x36, 0x01, 0x01], -1),
+    ([0x8F, 0x01, 0x01], [0x8F, 0x01, 0x01], -1),
+    ([0x9D, 0x01, 0x01], [0x9F, 0x01, 0x01], -1),
+    ([0xA1, 0x01, 0x01], [0xCF, 0x01, 0x01], -1),
+    ([0xFD, 0x01, 0x01], [0xFD, 0x01, 0x01], 0),
+    ([0xFE, 0x01, 0x01], [0x7F, 0x02, 0x01], -1),
+    ([0x9D, 0x02, 0x01], [0x9F, 0x02, 0x01], -1),
+    ([0xD1, 0x02, 0x01], [0xDF, 0x02, 0x01], -1),
+    ([0xE0, 0x02, 0x01], [0xE0, 0x02, 0x01], 0),
+    ([0xFC, 0x02, 0x01], [0xFF, 0x02, 0x01], -1),
+    ([0x24, 0x03, 0x01], [0x2C, 0x03, 0x01], -1),
+    ([0x4B, 0x03, 0x01], [0x4F, 0x03, 0x01], -1),
+    ([0x76, 0x03, 0x01], [0x7A, 0x03, 0x01], 0),
+    ([0x7B, 0x03, 0x01], [0x7F, 0x03, 0x01], -1),
+    ([0x9E, 0x03, 0x01], [0x9E, 0x03, 0x01], -1),
+    ([0xC4, 0x03, 0x01], [0xC7, 0x03, 0x01], -1),
+    ([0xD6, 0x03, 0x01], [0xFF, 0x03, 0x01], -1),
+    ([0x9E, 0x04, 0x01], [0x9F, 0x04, 0x01], -1),
+    ([0xAA, 0x04, 0x01], [0xAF, 0x04, 0x01], -1),
+    ([0xD4, 0x04, 0x01], [0xD7, 0x04, 0x01], -1),
+    ([0xFC, 0x04, 0x01], [0xFF, 0x04, 0x01], -1),
+    ([0x28, 0x05, 0x01], [0x2F, 0x05, 0x01], -1),
+    ([0x64, 0x05, 0x01], [0x6E, 0x05, 0x01], -1),
+    ([0x7B, 0x05, 0x01], [0x7B, 0x05, 0x01], -1),
+    ([0x8B, 0x05, 0x01], [0x8B, 0x05, 0x01], -1),
+    ([0x93, 0x05, 0x01], [0x93, 0x05, 0x01], -1),
+    ([0x96, 0x05, 0x01], [0x96, 0x05, 0x01], -1),
+    ([0xA2, 0x05, 0x01], [0xA2, 0x05, 0x01], -1),
+    ([0xB2, 0x05, 0x01], [0xB2, 0x05, 0x01], -1),
+    ([0xBA, 0x05, 0x01], [0xBA, 0x05, 0x01], -1),
+    ([0xBD, 0x05, 0x01], [0xFF, 0x05, 0x01], -1),
+    ([0x37, 0x07, 0x01], [0x3F, 0x07, 0x01], -1),
+    ([0x56, 0x07, 0x01], [0x5F, 0x07, 0x01], -1),
+    ([0x68, 0x07, 0x01], [0x7F, 0x07, 0x01], -1),
+    ([0x86, 0x07, 0x01], [0x86, 0x07, 0x01], -1),
+    ([0xB1, 0x07, 0x01], [0xB1, 0x07, 0x01], -1),
+    ([0xBB, 0x07, 0x01], [0xFF, 0x07, 0x01], -1),
+    ([0x06, 0x08, 0x01], [0x07, 0x08, 0x01], -1),
+    ([0x09, 0x08, 0x01], [0x09, 0x08, 0x01], -1),
+    ([0x36, 0x08, 0x01], [0x36, 0x08, 0x01], -1),
+    ([0x39, 0x08, 0x01], [0x3B, 0x08, 0x01], -1),
+    ([0x3D, 0x08, 0x01], [0x3E, 0x08, 0x01], -1),
+    ([0x56, 0x08, 0x01], [0x56, 0x08, 0x01], -1),
+    ([0x9F, 0x08, 0x01], [0xA6, 0x08, 0x01], -1),
+    ([0xB0, 0x08, 0x01], [0xDF, 0x08, 0x01], -1),
+    ([0xF3, 0x08, 0x01], [0xF3, 0x08, 0x01], -1),
+    ([0xF6, 0x08, 0x01], [0xFA, 0x08, 0x01], -1),
+    ([0x1C, 0x09, 0x01], [0x1E, 0x09, 0x01], -1),
+    ([0x3A, 0x09, 0x01], [0x3E, 0x09, 0x01], -1),
+    ([0x40, 0x09, 0x01], [0x7F, 0x09, 0x01], -1),
+    ([0xB8, 0x09, 0x01], [0xBB, 0x09, 0x01], -1),
+    ([0xD0, 0x09, 0x01], [0xD1, 0x09, 0x01], -1),
+    ([0x01, 0x0A, 0x01], [0x03, 0x0A, 0x01], 0),
+    ([0x04, 0x0A, 0x01], [0x04, 0x0A, 0x01], -1),
+    ([0x05, 0x0A, 0x01], [0x06, 0x0A, 0x01], 0),
+    ([0x07, 0x0A, 0x01], [0x0B, 0x0A, 0x01], -1),
+    ([0x0C, 0x0A, 0x01], [0x0F, 0x0A, 0x01], 0),
+    ([0x14, 0x0A, 0x01], [0x14, 0x0A, 0x01], -1),
+    ([0x18, 0x0A, 0x01], [0x18, 0x0A, 0x01], -1),
+    ([0x36, 0x0A, 0x01], [0x37, 0x0A, 0x01], -1),
+    ([0x38, 0x0A, 0x01], [0x3A, 0x0A, 0x01], 0),
+    ([0x3B, 0x0A, 0x01], [0x3E, 0x0A, 0x01], -1),
+    ([0x3F, 0x0A, 0x01], [0x3F, 0x0A, 0x01], 0),
+    ([0x49, 0x0A, 0x01], [0x4F, 0x0A, 0x01], -1),
+    ([0x59, 0x0A, 0x01], [0x5F, 0x0A, 0x01], -1),
+    ([0xA0, 0x0A, 0x01], [0xBF, 0x0A, 0x01], -1),
+    ([0xE5, 0x0A, 0x01], [0xE6, 0x0A, 0x01], 0),
+    ([0xE7, 0x0A, 0x01], [0xEA, 0x0A, 0x01], -1),
+    ([0xF7, 0x0A, 0x01], [0xFF, 0x0A, 0x01], -1),
+    ([0x36, 0x0B, 0x01], [0x38, 0x0B, 0x01], -1),
+    ([0x56, 0x0B, 0x01], [0x57, 0x0B, 0x01], -1),
+    ([0x73, 0x0B, 0x01], [0x77, 0x0B, 0x01], -1),
+    ([0x92, 0x0B, 0x01], [0x98, 0x0B, 0x01], -1),
+    ([0x9D, 0x0B, 0x01], [0xA8, 0x0B, 0x01], -1),
+    ([0xB0, 0x0B, 0x01], [0xFF, 0x0B, 0x01], -1),
+    ([0x49, 0x0C, 0x01], [0x7F, 0x0C, 0x01], -1),
+    ([0xB3, 0x0C, 0x01], [0xBF, 0x0C, 0x01], -1),
+    ([0xF3, 0x0C, 0x01], [0xF9, 0x0C, 0x01], -1),
+    ([0x24, 0x0D, 0x01], [0x27, 0x0D, 0x01], 0),
+    ([0x28, 0x0D, 0x01], [0x2F, 0x0D, 0x01], -1),
+    ([0x3A, 0x0D, 0x01], [0x5F, 0x0E, 0x01], -1),
+    ([0x7F, 0x0E, 0x01], [0x7F, 0x0E, 0x01], -1),
+    ([0xAA, 0x0E, 0x01], [0xAA, 0x0E, 0x01], -1),
+    ([0xAB, 0x0E, 0x01], [0xAC, 0x0E, 0x01], 0),
+    ([0xAE, 0x0E, 0x01], [0xAF, 0x0E, 0x01], -1),
+    ([0xB2, 0x0E, 0x01], [0xFF, 0x0E, 0x01], -1),
+    ([0x28, 0x0F, 0x01], [0x2F, 0x0F, 0x01], -1),
+    ([0x46, 0x0F, 0x01], [0x50, 0x0F, 0x01], 0),
+    ([0x5A, 0x0F, 0x01], [0x6F, 0x0F, 0x01], -1),
+    ([0x82, 0x0F, 0x01], [0x85, 0x0F, 0x01], 0),
+    ([0x8A, 0x0F, 0x01], [0xAF, 0x0F, 0x01], -1),
+    ([0xCC, 0x0F, 0x01], [0xDF, 0x0F, 0x01], -1),
+    ([0xF7, 0x0F, 0x01], [0xFF, 0x0F, 0x01], -1),
+    ([0x01, 0x10, 0x01], [0x01, 0x10, 0x01], 0),
+    ([0x38, 0x10, 0x01], [0x46, 0x10, 0x01], 0),
+    ([0x4E, 0x10, 0x01], [0x51, 0x10, 0x01], -1),
+    ([0x70, 0x10, 0x01], [0x70, 0x10, 0x01], 0),
+    ([0x73, 0x10, 0x01], [0x74, 0x10, 0x01], 0),
+    ([0x76, 0x10, 0x01], [0x7E, 0x10, 0x01], -1),
+    ([0x7F, 0x10, 0x01], [0x81, 0x10, 0x01], 0),
+    ([0xB3, 0x10, 0x01], [0xB6, 0x10, 0x01], 0),
+    ([0xB9, 0x10, 0x01], [0xBA, 0x10, 0x01], 0),
+    ([0xC2, 0x10, 0x01], [0xC2, 0x10, 0x01], 0),
+    ([0xC3, 0x10, 0x01], [0xCC, 0x10, 0x01], -1),
+    ([0xCE, 0x10, 0x01], [0xCF, 0x10, 0x01], -1),
+    ([0xE9, 0x10, 0x01], [0xEF, 0x10, 0x01], -1),
+    ([0xFA, 0x10, 0x01], [0xFF, 0x10, 0x01], -1),
+    ([0x00, 0x11, 0x01], [0x02, 0x11, 0x01], 0),
+    ([0x27, 0x11, 0x01], [0x2B, 0x11, 0x01], 0),
+    ([0x2D, 0x11, 0x01], [0x34, 0x11, 0x01], 0),
+    ([0x35, 0x11, 0x01], [0x35, 0x11, 0x01], -1),
+    ([0x48, 0x11, 0x01], [0x4F, 0x11, 0x01], -1),
+    ([0x73, 0x11, 0x01], [0x73, 0x11, 0x01], 0),
+    ([0x77, 0x11, 0x01], [0x7F, 0x11, 0x01], -1),
+    ([0x80, 0x11, 0x01], [0x81, 0x11, 0x01], 0),
+    ([0xB6, 0x11, 0x01], [0xBE, 0x11, 0x01], 0),
+    ([0xC9, 0x11, 0x01], [0xCC, 0x11, 0x01], 0),
+    ([0xCF, 0x11, 0x01], [0xCF, 0x11, 0x01], 0),
+    ([0xE0, 0x11, 0x01], [0xE0, 0x11, 0x01], -1),
+    ([0xF5, 0x11, 0x01], [0xFF, 0x11, 0x01], -1),
+    ([0x12, 0x12, 0x01], [0x12, 0x12, 0x01], -1),
+    ([0x2F, 0x12, 0x01], [0x31, 0x12, 0x01], 0),
+    ([0x34, 0x12, 0x01], [0x34, 0x12, 0x01], 0),
+    ([0x36, 0x12, 0x01], [0x37, 0x12, 0x01], 0),
+    ([0x3E, 0x12, 0x01], [0x3E, 0x12, 0x01], 0),
+    ([0x3F, 0x12, 0x01], [0x7F, 0x12, 0x01], -1),
+    ([0x87, 0x12, 0x01], [0x87, 0x12, 0x01], -1),
+    ([0x89, 0x12, 0x01], [0x89, 0x12, 0x01], -1),
+    ([0x8E, 0x12, 0x01], [0x8E, 0x12, 0x01], -1),
+    ([0x9E, 0x12, 0x01], [0x9E, 0x12, 0x01], -1),
+    ([0xAA, 0x12, 0x01], [0xAF, 0x12, 0x01], -1),
+    ([0xDF, 0x12, 0x01], [0xDF, 0x12, 0x01], 0),
+    ([0xE3, 0x12, 0x01], [0xEA, 0x12, 0x01], 0),
+    ([0xEB, 0x12, 0x01], [0xEF, 0x12, 0x01], -1),
+    ([0xFA, 0x12, 0x01], [0xFF, 0x12, 0x01], -1),
+    ([0x00, 0x13, 0x01], [0x01, 0x13, 0x01], 0),
+    ([0x04, 0x13, 0x01], [0x04, 0x13, 0x01], -1),
+    ([0x0D, 0x13, 0x01], [0x0E, 0x13, 0x01], -1),
+    ([0x11, 0x13, 0x01], [0x12, 0x13, 0x01], -1),
+    ([0x29, 0x13, 0x01], [0x29, 0x13, 0x01], -1),
+    ([0x31, 0x13, 0x01], [0x31, 0x13, 0x01], -1),
+    ([0x34, 0x13, 0x01], [0x34, 0x13, 0x01], -1),
+    ([0x3A, 0x13, 0x01], [0x3A, 0x13, 0x01], -1),
+    ([0x3B, 0x13, 0x01], [0x3C, 0x13, 0x01], 0),
+    ([0x40, 0x13, 0x01], [0x40, 0x13, 0x01], 0),
+    ([0x45, 0x13, 0x01], [0x46, 0x13, 0x01], -1),
+    ([0x49, 0x13, 0x01], [0x4A, 0x13, 0x01], -1),
+    ([0x4E, 0x13, 0x01], [0x4F, 0x13, 0x01], -1),
+    ([0x51, 0x13, 0x01], [0x56, 0x13, 0x01], -1),
+    ([0x58, 0x13, 0x01], [0x5C, 0x13, 0x01], -1),
+    ([0x64, 0x13, 0x01], [0x65, 0x13, 0x01], -1),
+    ([0x66, 0x13, 0x01], [0x6C, 0x13, 0x01], 0),
+    ([0x6D, 0x13, 0x01], [0x6F, 0x13, 0x01], -1),
+    ([0x70, 0x13, 0x01], [0x74, 0x13, 0x01], 0),
+    ([0x75, 0x13, 0x01], [0xFF, 0x13, 0x01], -1),
+    ([0x38, 0x14, 0x01], [0x3F, 0x14, 0x01], 0),
+    ([0x42, 0x14, 0x01], [0x44, 0x14, 0x01], 0),
+    ([0x46, 0x14, 0x01], [0x46, 0x14, 0x01], 0),
+    ([0x5C, 0x14, 0x01], [0x5C, 0x14, 0x01], -1),
+    ([0x5E, 0x14, 0x01], [0x5E, 0x14, 0x01], 0),
+    ([0x62, 0x14, 0x01], [0x7F, 0x14, 0x01], -1),
+    ([0xB3, 0x14, 0x01], [0xB8, 0x14, 0x01], 0),
+    ([0xBA, 0x14, 0x01], [0xBA, 0x14, 0x01], 0),
+    ([0xBF, 0x14, 0x01], [0xC0, 0x14, 0x01], 0),
+    ([0xC2, 0x14, 0x01], [0xC3, 0x14, 0x01], 0),
+    ([0xC8, 0x14, 0x01], [0xCF, 0x14, 0x01], -1),
+    ([0xDA, 0x14, 0x01], [0x7F, 0x15, 0x01], -1),
+    ([0xB2, 0x15, 0x01], [0xB5, 0x15, 0x01], 0),
+    ([0xB6, 0x15, 0x01], [0xB7, 0x15, 0x01], -1),
+    ([0xBC, 0x15, 0x01], [0xBD, 0x15, 0x01], 0),
+    ([0xBF, 0x15, 0x01], [0xC0, 0x15, 0x01], 0),
+    ([0xDC, 0x15, 0x01], [0xDD, 0x15, 0x01], 0),
+    ([0xDE, 0x15, 0x01], [0xFF, 0x15, 0x01], -1),
+    ([0x33, 0x16, 0x01], [0x3A, 0x16, 0x01], 0),
+    ([0x3D, 0x16, 0x01], [0x3D, 0x16, 0x01], 0),
+    ([0x3F, 0x16, 0x01], [0x40, 0x16, 0x01], 0),
+    ([0x45, 0x16, 0x01], [0x4F, 0x16, 0x01], -1),
+    ([0x5A, 0x16, 0x01], [0x5F, 0x16, 0x01], -1),
+    ([0x6D, 0x16, 0x01], [0x7F, 0x16, 0x01], -1),
+    ([0xAB, 0x16, 0x01], [0xAB, 0x16, 0x01], 0),
+    ([0xAD, 0x16, 0x01], [0xAD, 0x16, 0x01], 0),
+    ([0xB0, 0x16, 0x01], [0xB5, 0x16, 0x01], 0),
+    ([0xB7, 0x16, 0x01], [0xB7, 0x16, 0x01], 0),
+    ([0xBA, 0x16, 0x01], [0xBF, 0x16, 0x01], -1),
+    ([0xCA, 0x16, 0x01], [0xFF, 0x16, 0x01], -1),
+    ([0x1B, 0x17, 0x01], [0x1C, 0x17, 0x01], -1),
+    ([0x1D, 0x17, 0x01], [0x1F, 0x17, 0x01], 0),
+    ([0x22, 0x17, 0x01], [0x25, 0x17, 0x01], 0),
+    ([0x27, 0x17, 0x01], [0x2B, 0x17, 0x01], 0),
+    ([0x2C, 0x17, 0x01], [0x2F, 0x17, 0x01], -1),
+    ([0x47, 0x17, 0x01], [0xFF, 0x17, 0x01], -1),
+    ([0x2F, 0x18, 0x01], [0x37, 0x18, 0x01], 0),
+    ([0x39, 0x18, 0x01], [0x3A, 0x18, 0x01], 0),
+    ([0x3C, 0x18, 0x01], [0x9F, 0x18, 0x01], -1),
+    ([0xF3, 0x18, 0x01], [0xFE, 0x18, 0x01], -1),
+    ([0x07, 0x19, 0x01], [0x08, 0x19, 0x01], -1),
+    ([0x0A, 0x19, 0x01], [0x0B, 0x19, 0x01], -1),
+    ([0x14, 0x19, 0x01], [0x14, 0x19, 0x01], -1),
+    ([0x17, 0x19, 0x01], [0x17, 0x19, 0x01], -1),
+    ([0x36, 0x19, 0x01], [0x36, 0x19, 0x01], -1),
+    ([0x39, 0x19, 0x01], [0x3A, 0x19, 0x01], -1),
+    ([0x3B, 0x19, 0x01], [0x3C, 0x19, 0x01], 0),
+    ([0x3E, 0x19, 0x01], [0x3E, 0x19, 0x01], 0),
+    ([0x43, 0x19, 0x01], [0x43, 0x19, 0x01], 0),
+    ([0x47, 0x19, 0x01], [0x4F, 0x19, 0x01], -1),
+    ([0x5A, 0x19, 0x01], [0x9F, 0x19, 0x01], -1),
+    ([0xA8, 0x19, 0x01], [0xA9, 0x19, 0x01], -1),
+    ([0xD4, 0x19, 0x01], [0xD7, 0x19, 0x01], 0),
+    ([0xD8, 0x19, 0x01], [0xD9, 0x19, 0x01], -1),
+    ([0xDA, 0x19, 0x01], [0xDB, 0x19, 0x01], 0),
+    ([0xE0, 0x19, 0x01], [0xE0, 0x19, 0x01], 0),
+    ([0xE5, 0x19, 0x01], [0xFF, 0x19, 0x01], -1),
+    ([0x01, 0x1A, 0x01], [0x0A, 0x1A, 0x01], 0),
+    ([0x33, 0x1A, 0x01], [0x38, 0x1A, 0x01], 0),
+    ([0x3B, 0x1A, 0x01], [0x3E, 0x1A, 0x01], 0),
+    ([0x47, 0x1A, 0x01], [0x47, 0x1A, 0x01], 0),
+    ([0x48, 0x1A, 0x01], [0x4F, 0x1A, 0x01], -1),
+    ([0x51, 0x1A, 0x01], [0x56, 0x1A, 0x01], 0),
+    ([0x59, 0x1A, 0x01], [0x5B, 0x1A, 0x01], 0),
+    ([0x8A, 0x1A, 0x01], [0x96, 0x1A, 0x01], 0),
+    ([0x98, 0x1A, 0x01], [0x99, 0x1A, 0x01], 0),
+    ([0xA3, 0x1A, 0x01], [0xAF, 0x1A, 0x01], -1),
+    ([0xF9, 0x1A, 0x01], [0xFF, 0x1B, 0x01], -1),
+    ([0x09, 0x1C, 0x01], [0x09, 0x1C, 0x01], -1),
+    ([0x30, 0x1C, 0x01], [0x36, 0x1C, 0x01], 0),
+    ([0x37, 0x1C, 0x01], [0x37, 0x1C, 0x01], -1),
+    ([0x38, 0x1C, 0x01], [0x3D, 0x1C, 0x01], 0),
+    ([0x3F, 0x1C, 0x01], [0x3F, 0x1C, 0x01], 0),
+    ([0x46, 0x1C, 0x01], [0x4F, 0x1C, 0x01], -1),
+    ([0x6D, 0x1C, 0x01], [0x6F, 0x1C, 0x01], -1),
+    ([0x90, 0x1C, 0x01], [0x91, 0x1C, 0x01], -1),
+    ([0x92, 0x1C, 0x01], [0xA7, 0x1C, 0x01], 0),
+    ([0xA8, 0x1C, 0x01], [0xA8, 0x1C, 0x01], -1),
+    ([0xAA, 0x1C, 0x01], [0xB0, 0x1C, 0x01], 0),
+    ([0xB2, 0x1C, 0x01], [0xB3, 0x1C, 0x01], 0),
+    ([0xB5, 0x1C, 0x01], [0xB6, 0x1C, 0x01], 0),
+    ([0xB7, 0x1C, 0x01], [0xFF, 0x1C, 0x01], -1),
+    ([0x07, 0x1D, 0x01], [0x07, 0x1D, 0x01], -1),
+    ([0x0A, 0x1D, 0x01], [0x0A, 0x1D, 0x01], -1),
+    ([0x31, 0x1D, 0x01], [0x36, 0x1D, 0x01], 0),
+    ([0x37, 0x1D, 0x01], [0x39, 0x1D, 0x01], -1),
+    ([0x3A, 0x1D, 0x01], [0x3A, 0x1D, 0x01], 0),
+    ([0x3B, 0x1D, 0x01], [0x3B, 0x1D, 0x01], -1),
+    ([0x3C, 0x1D, 0x01], [0x3D, 0x1D, 0x01], 0),
+    ([0x3E, 0x1D, 0x01], [0x3E, 0x1D, 0x01], -1),
+    ([0x3F, 0x1D, 0x01], [0x45, 0x1D, 0x01], 0),
+    ([0x47, 0x1D, 0x01], [0x47, 0x1D, 0x01], 0),
+    ([0x48, 0x1D, 0x01], [0x4F, 0x1D, 0x01], -1),
+    ([0x5A, 0x1D, 0x01], [0x5F, 0x1D, 0x01], -1),
+    ([0x66, 0x1D, 0x01], [0x66, 0x1D, 0x01], -1),
+    ([0x69, 0x1D, 0x01], [0x69, 0x1D, 0x01], -1),
+    ([0x8F, 0x1D, 0x01], [0x8F, 0x1D, 0x01], -1),
+    ([0x90, 0x1D, 0x01], [0x91, 0x1D, 0x01], 0),
+    ([0x92, 0x1D, 0x01], [0x92, 0x1D, 0x01], -1),
+    ([0x95, 0x1D, 0x01], [0x95, 0x1D, 0x01], 0),
+    ([0x97, 0x1D, 0x01], [0x97, 0x1D, 0x01], 0),
+    ([0x99, 0x1D, 0x01], [0x9F, 0x1D, 0x01], -1),
+    ([0xAA, 0x1D, 0x01], [0xDF, 0x1E, 0x01], -1),
+    ([0xF3, 0x1E, 0x01], [0xF4, 0x1E, 0x01], 0),
+    ([0xF9, 0x1E, 0x01], [0xAF, 0x1F, 0x01], -1),
+    ([0xB1, 0x1F, 0x01], [0xBF, 0x1F, 0x01], -1),
+    ([0xF2, 0x1F, 0x01], [0xFE, 0x1F, 0x01], -1),
+    ([0x9A, 0x23, 0x01], [0xFF, 0x23, 0x01], -1),
+    ([0x6F, 0x24, 0x01], [0x6F, 0x24, 0x01], -1),
+    ([0x75, 0x24, 0x01], [0x7F, 0x24, 0x01], -1),
+    ([0x44, 0x25, 0x01], [0x8F, 0x2F, 0x01], -1),
+    ([0xF3, 0x2F, 0x01], [0xFF, 0x2F, 0x01], -1),
+    ([0x2F, 0x34, 0x01], [0x2F, 0x34, 0x01], -1),
+    ([0x30, 0x34, 0x01], [0x38, 0x34, 0x01], 0),
+    ([0x39, 0x34, 0x01], [0xFF, 0x43, 0x01], -1),
+    ([0x47, 0x46, 0x01], [0xFF, 0x67, 0x01], -1),
+    ([0x39, 0x6A, 0x01], [0x3F, 0x6A, 0x01], -1),
+    ([0x5F, 0x6A, 0x01], [0x5F, 0x6A, 0x01], -1),
+    ([0x6A, 0x6A, 0x01], [0x6D, 0x6A, 0x01], -1),
+    ([0xBF, 0x6A, 0x01], [0xBF, 0x6A, 0x01], -1),
+    ([0xCA, 0x6A, 0x01], [0xCF, 0x6A, 0x01], -1),
+    ([0xEE, 0x6A, 0x01], [0xEF, 0x6A, 0x01], -1),
+    ([0xF0, 0x6A, 0x01], [0xF4, 0x6A, 0x01], 0),
+    ([0xF6, 0x6A, 0x01], [0xFF, 0x6A, 0x01], -1),
+    ([0x30, 0x6B, 0x01], [0x36, 0x6B, 0x01], 0),
+    ([0x46, 0x6B, 0x01], [0x4F, 0x6B, 0x01], -1),
+    ([0x5A, 0x6B, 0x01], [0x5A, 0x6B, 0x01], -1),
+    ([0x62, 0x6B, 0x01], [0x62, 0x6B, 0x01], -1),
+    ([0x78, 0x6B, 0x01], [0x7C, 0x6B, 0x01], -1),
+    ([0x90, 0x6B, 0x01], [0x3F, 0x6E, 0x01], -1),
+    ([0x9B, 0x6E, 0x01], [0xFF, 0x6E, 0x01], -1),
+    ([0x4B, 0x6F, 0x01], [0x4E, 0x6F, 0x01], -1),
+    ([0x4F, 0x6F, 0x01], [0x4F, 0x6F, 0x01], 0),
+    ([0x88, 0x6F, 0x01], [0x8E, 0x6F, 0x01], -1),
+    ([0x8F, 0x6F, 0x01], [0x92, 0x6F, 0x01], 0),
+    ([0xA0, 0x6F, 0x01], [0xDF, 0x6F, 0x01], -1),
+    ([0xE0, 0x6F, 0x01], [0xE3, 0x6F, 0x01], 2),
+    ([0xE4, 0x6F, 0x01], [0xE4, 0x6F, 0x01], 0),
+    ([0xE5, 0x6F, 0x01], [0xEF, 0x6F, 0x01], -1),
+    ([0xF0, 0x6F, 0x01], [0xF1, 0x6F, 0x01], 2),
+    ([0xF2, 0x6F, 0x01], [0xFF, 0x6F, 0x01], -1),
+    ([0x00, 0x70, 0x01], [0xF7, 0x87, 0x01], 2),
+    ([0xF8, 0x87, 0x01], [0xFF, 0x87, 0x01], -1),
+    ([0x00, 0x88, 0x01], [0xD5, 0x8C, 0x01], 2),
+    ([0xD6, 0x8C, 0x01], [0xFF, 0x8C, 0x01], -1),
+    ([0x00, 0x8D, 0x01], [0x08, 0x8D, 0x01], 2),
+    ([0x09, 0x8D, 0x01], [0xEF, 0xAF, 0x01], -1),
+    ([0xF0, 0xAF, 0x01], [0xF3, 0xAF, 0x01], 2),
+    ([0xF4, 0xAF, 0x01], [0xF4, 0xAF, 0x01], -1),
+    ([0xF5, 0xAF, 0x01], [0xFB, 0xAF, 0x01], 2),
+    ([0xFC, 0xAF, 0x01], [0xFC, 0xAF, 0x01], -1),
+    ([0xFD, 0xAF, 0x01], [0xFE, 0xAF, 0x01], 2),
+    ([0xFF, 0xAF, 0x01], [0xFF, 0xAF, 0x01], -1),
+    ([0x00, 0xB0, 0x01], [0x22, 0xB1, 0x01], 2),
+    ([0x23, 0xB1, 0x01], [0x4F, 0xB1, 0x01], -1),
+    ([0x50, 0xB1, 0x01], [0x52, 0xB1, 0x01], 2),
+    ([0x53, 0xB1, 0x01], [0x63, 0xB1, 0x01], -1),
+    ([0x64, 0xB1, 0x01], [0x67, 0xB1, 0x01], 2),
+    ([0x68, 0xB1, 0x01], [0x6F, 0xB1, 0x01], -1),
+    ([0x70, 0xB1, 0x01], [0xFB, 0xB2, 0x01], 2),
+    ([0xFC, 0xB2, 0x01], [0xFF, 0xBB, 0x01], -1),
+    ([0x6B, 0xBC, 0x01], [0x6F, 0xBC, 0x01], -1),
+    ([0x7D, 0xBC, 0x01], [0x7F, 0xBC, 0x01], -1),
+    ([0x89, 0xBC, 0x01], [0x8F, 0xBC, 0x01], -1),
+    ([0x9A, 0xBC, 0x01], [0x9B, 0xBC, 0x01], -1),
+    ([0x9D, 0xBC, 0x01], [0x9E, 0xBC, 0x01], 0),
+    ([0xA0, 0xBC, 0x01], [0xA3, 0xBC, 0x01], 0),
+    ([0xA4, 0xBC, 0x01], [0xFF, 0xCE, 0x01], -1),
+    ([0x00, 0xCF, 0x01], [0x2D, 0xCF, 0x01], 0),
+    ([0x2E, 0xCF, 0x01], [0x2F, 0xCF, 0x01], -1),
+    ([0x30, 0xCF, 0x01], [0x46, 0xCF, 0x01], 0),
+    ([0x47, 0xCF, 0x01], [0x4F, 0xCF, 0x01], -1),
+    ([0xC4, 0xCF, 0x01], [0xFF, 0xCF, 0x01], -1),
+    ([0xF6, 0xD0, 0x01], [0xFF, 0xD0, 0x01], -1),
+    ([0x27, 0xD1, 0x01], [0x28, 0xD1, 0x01], -1),
+    ([0x67, 0xD1, 0x01], [0x69, 0xD1, 0x01], 0),
+    ([0x73, 0xD1, 0x01], [0x82, 0xD1, 0x01], 0),
+    ([0x85, 0xD1, 0x01], [0x8B, 0xD1, 0x01], 0),
+    ([0xAA, 0xD1, 0x01], [0xAD, 0xD1, 0x01], 0),
+    ([0xEB, 0xD1, 0x01], [0xFF, 0xD1, 0x01], -1),
+    ([0x42, 0xD2, 0x01], [0x44, 0xD2, 0x01], 0),
+    ([0x46, 0xD2, 0x01], [0xDF, 0xD2, 0x01], -1),
+    ([0xF4, 0xD2, 0x01], [0xFF, 0xD2, 0x01], -1),
+    ([0x57, 0xD3, 0x01], [0x5F, 0xD3, 0x01], -1),
+    ([0x79, 0xD3, 0x01], [0xFF, 0xD3, 0x01], -1),
+    ([0x55, 0xD4, 0x01], [0x55, 0xD4, 0x01], -1),
+    ([0x9D, 0xD4, 0x01], [0x9D, 0xD4, 0x01], -1),
+    ([0xA0, 0xD4, 0x01], [0xA1, 0xD4, 0x01], -1),
+    ([0xA3, 0xD4, 0x01], [0xA4, 0xD4, 0x01], -1),
+    ([0xA7, 0xD4, 0x01], [0xA8, 0xD4, 0x01], -1),
+    ([0xAD, 0xD4, 0x01], [0xAD, 0xD4, 0x01], -1),
+    ([0xBA, 0xD4, 0x01], [0xBA, 0xD4, 0x01], -1),
+    ([0xBC, 0xD4, 0x01], [0xBC, 0xD4, 0x01], -1),
+    ([0xC4, 0xD4, 0x01], [0xC4, 0xD4, 0x01], -1),
+    ([0x06, 0xD5, 0x01], [0x06, 0xD5, 0x01], -1),
+    ([0x0B, 0xD5, 0x01], [0x0C, 0xD5, 0x01], -1),
+    ([0x15, 0xD5, 0x01], [0x15, 0xD5, 0x01], -1),
+    ([0x1D, 0xD5, 0x01], [0x1D, 0xD5, 0x01], -1),
+    ([0x3A, 0xD5, 0x01], [0x3A, 0xD5, 0x01], -1),
+    ([0x3F, 0xD5, 0x01], [0x3F, 0xD5, 0x01], -1),
+    ([0x45, 0xD5, 0x01], [0x45, 0xD5, 0x01], -1),
+    ([0x47, 0xD5, 0x01], [0x49, 0xD5, 0x01], -1),
+    ([0x51, 0xD5, 0x01], [0x51, 0xD5, 0x01], -1),
+    ([0xA6, 0xD6, 0x01], [0xA7, 0xD6, 0x01], -1),
+    ([0xCC, 0xD7, 0x01], [0xCD, 0xD7, 0x01], -1),
+    ([0x00, 0xDA, 0x01], [0x36, 0xDA, 0x01], 0),
+    ([0x3B, 0xDA, 0x01], [0x6C, 0xDA, 0x01], 0),
+    ([0x75, 0xDA, 0x01], [0x75, 0xDA, 0x01], 0),
+    ([0x84, 0xDA, 0x01], [0x84, 0xDA, 0x01], 0),
+    ([0x8C, 0xDA, 0x01], [0x9A, 0xDA, 0x01], -1),
+    ([0x9B, 0xDA, 0x01], [0x9F, 0xDA, 0x01], 0),
+    ([0xA0, 0xDA, 0x01], [0xA0, 0xDA, 0x01], -1),
+    ([0xA1, 0xDA, 0x01], [0xAF, 0xDA, 0x01], 0),
+    ([0xB0, 0xDA, 0x01], [0xFF, 0xDE, 0x01], -1),
+    ([0x1F, 0xDF, 0x01], [0xFF, 0xDF, 0x01], -1),
+    ([0x00, 0xE0, 0x01], [0x06, 0xE0, 0x01], 0),
+    ([0x07, 0xE0, 0x01], [0x07, 0xE0, 0x01], -1),
+    ([0x08, 0xE0, 0x01], [0x18, 0xE0, 0x01], 0),
+    ([0x19, 0xE0, 0x01], [0x1A, 0xE0, 0x01], -1),
+    ([0x1B, 0xE0, 0x01], [0x21, 0xE0, 0x01], 0),
+    ([0x22, 0xE0, 0x01], [0x22, 0xE0, 0x01], -1),
+    ([0x23, 0xE0, 0x01], [0x24, 0xE0, 0x01], 0),
+    ([0x25, 0xE0, 0x01], [0x25, 0xE0, 0x01], -1),
+    ([0x26, 0xE0, 0x01], [0x2A, 0xE0, 0x01], 0),
+    ([0x2B, 0xE0, 0x01], [0xFF, 0xE0, 0x01], -1),
+    ([0x2D, 0xE1, 0x01], [0x2F, 0xE1, 0x01], -1),
+    ([0x30, 0xE1, 0x01], [0x36, 0xE1, 0x01], 0),
+    ([0x3E, 0xE1, 0x01], [0x3F, 0xE1, 0x01], -1),
+    ([0x4A, 0xE1, 0x01], [0x4D, 0xE1, 0x01], -1),
+    ([0x50, 0xE1, 0x01], [0x8F, 0xE2, 0x01], -1),
+    ([0xAE, 0xE2, 0x01], [0xAE, 0xE2, 0x01], 0),
+    ([0xAF, 0xE2, 0x01], [0xBF, 0xE2, 0x01], -1),
+    ([0xEC, 0xE2, 0x01], [0xEF, 0xE2, 0x01], 0),
+    ([0xFA, 0xE2, 0x01], [0xFE, 0xE2, 0x01], -1),
+    ([0x00, 0xE3, 0x01], [0xDF, 0xE7, 0x01], -1),
+    ([0xE7, 0xE7, 0x01], [0xE7, 0xE7, 0x01], -1),
+    ([0xEC, 0xE7, 0x01], [0xEC, 0xE7, 0x01], -1),
+    ([0xEF, 0xE7, 0x01], [0xEF, 0xE7, 0x01], -1),
+    ([0xFF, 0xE7, 0x01], [0xFF, 0xE7, 0x01], -1),
+    ([0xC5, 0xE8, 0x01], [0xC6, 0xE8, 0x01], -1),
+    ([0xD0, 0xE8, 0x01], [0xD6, 0xE8, 0x01], 0),
+    ([0xD7, 0xE8, 0x01], [0xFF, 0xE8, 0x01], -1),
+    ([0x44, 0xE9, 0x01], [0x4A, 0xE9, 0x01], 0),
+    ([0x4C, 0xE9, 0x01], [0x4F, 0xE9, 0x01], -1),
+    ([0x5A, 0xE9, 0x01], [0x5D, 0xE9, 0x01], -1),
+    ([0x60, 0xE9, 0x01], [0x70, 0xEC, 0x01], -1),
+    ([0xB5, 0xEC, 0x01], [0x00, 0xED, 0x01], -1),
+    ([0x3E, 0xED, 0x01], [0xFF, 0xED, 0x01], -1),
+    ([0x04, 0xEE, 0x01], [0x04, 0xEE, 0x01], -1),
+    ([0x20, 0xEE, 0x01], [0x20, 0xEE, 0x01], -1),
+    ([0x23, 0xEE, 0x01], [0x23, 0xEE, 0x01], -1),
+    ([0x25, 0xEE, 0x01], [0x26, 0xEE, 0x01], -1),
+    ([0x28, 0xEE, 0x01], [0x28, 0xEE, 0x01], -1),
+    ([0x33, 0xEE, 0x01], [0x33, 0xEE, 0x01], -1),
+    ([0x38, 0xEE, 0x01], [0x38, 0xEE, 0x01], -1),
+    ([0x3A, 0xEE, 0x01], [0x3A, 0xEE, 0x01], -1),
+    ([0x3C, 0xEE, 0x01], [0x41, 0xEE, 0x01], -1),
+    ([0x43, 0xEE, 0x01], [0x46, 0xEE, 0x01], -1),
+    ([0x48, 0xEE, 0x01], [0x48, 0xEE, 0x01], -1),
+    ([0x4A, 0xEE, 0x01], [0x4A, 0xEE, 0x01], -1),
+    ([0x4C, 0xEE, 0x01], [0x4C, 0xEE, 0x01], -1),
+    ([0x50, 0xEE, 0x01], [0x50, 0xEE, 0x01], -1),
+    ([0x53, 0xEE, 0x01], [0x53, 0xEE, 0x01], -1),
+    ([0x55, 0xEE, 0x01], [0x56, 0xEE, 0x01], -1),
+    ([0x58, 0xEE, 0x01], [0x58, 0xEE, 0x01], -1),
+    ([0x5A, 0xEE, 0x01], [0x5A, 0xEE, 0x01], -1),
+    ([0x5C, 0xEE, 0x01], [0x5C, 0xEE, 0x01], -1),
+    ([0x5E, 0xEE, 0x01], [0x5E, 0xEE, 0x01], -1),
+    ([0x60, 0xEE, 0x01], [0x60, 0xEE, 0x01], -1),
+    ([0x63, 0xEE, 0x01], [0x63, 0xEE, 0x01], -1),
+    ([0x65, 0xEE, 0x01], [0x66, 0xEE, 0x01], -1),
+    ([0x6B, 0xEE, 0x01], [0x6B, 0xEE, 0x01], -1),
+    ([0x73, 0xEE, 0x01], [0x73, 0xEE, 0x01], -1),
+    ([0x78, 0xEE, 0x01], [0x78, 0xEE, 0x01], -1),
+    ([0x7D, 0xEE, 0x01], [0x7D, 0xEE, 0x01], -1),
+    ([0x7F, 0xEE, 0x01], [0x7F, 0xEE, 0x01], -1),
+    ([0x8A, 0xEE, 0x01], [0x8A, 0xEE, 0x01], -1),
+    ([0x9C, 0xEE, 0x01], [0xA0, 0xEE, 0x01], -1),
+    ([0xA4, 0xEE, 0x01], [0xA4, 0xEE, 0x01], -1),
+    ([0xAA, 0xEE, 0x01], [0xAA, 0xEE, 0x01], -1),
+    ([0xBC, 0xEE, 0x01], [0xEF, 0xEE, 0x01], -1),
+    ([0xF2, 0xEE, 0x01], [0xFF, 0xEF, 0x01], -1),
+    ([0x04, 0xF0, 0x01], [0x04, 0xF0, 0x01], 2),
+    ([0x2C, 0xF0, 0x01], [0x2F, 0xF0, 0x01], -1),
+    ([0x94, 0xF0, 0x01], [0x9F, 0xF0, 0x01], -1),
+    ([0xAF, 0xF0, 0x01], [0xB0, 0xF0, 0x01], -1),
+    ([0xC0, 0xF0, 0x01], [0xC0, 0xF0, 0x01], -1),
+    ([0xCF, 0xF0, 0x01], [0xCF, 0xF0, 0x01], 2),
+    ([0xD0, 0xF0, 0x01], [0xD0, 0xF0, 0x01], -1),
+    ([0xF6, 0xF0, 0x01], [0xFF, 0xF0, 0x01], -1),
+    ([0x8E, 0xF1, 0x01], [0x8E, 0xF1, 0x01], 2),
+    ([0x91, 0xF1, 0x01], [0x9A, 0xF1, 0x01], 2),
+    ([0xAE, 0xF1, 0x01], [0xE5, 0xF1, 0x01], -1),
+    ([0x00, 0xF2, 0x01], [0x02, 0xF2, 0x01], 2),
+    ([0x03, 0xF2, 0x01], [0x0F, 0xF2, 0x01], -1),
+    ([0x10, 0xF2, 0x01], [0x3B, 0xF2, 0x01], 2),
+    ([0x3C, 0xF2, 0x01], [0x3F, 0xF2, 0x01], -1),
+    ([0x40, 0xF2, 0x01], [0x48, 0xF2, 0x01], 2),
+    ([0x49, 0xF2, 0x01], [0x4F, 0xF2, 0x01], -1),
+    ([0x50, 0xF2, 0x01], [0x51, 0xF2, 0x01], 2),
+    ([0x52, 0xF2, 0x01], [0x5F, 0xF2, 0x01], -1),
+    ([0x60, 0xF2, 0x01], [0x65, 0xF2, 0x01], 2),
+    ([0x66, 0xF2, 0x01], [0xFF, 0xF2, 0x01], -1),
+    ([0x00, 0xF3, 0x01], [0x20, 0xF3, 0x01], 2),
+    ([0x2D, 0xF3, 0x01], [0x35, 0xF3, 0x01], 2),
+    ([0x37, 0xF3, 0x01], [0x7C, 0xF3, 0x01], 2),
+    ([0x7E, 0xF3, 0x01], [0x93, 0xF3, 0x01], 2),
+    ([0xA0, 0xF3, 0x01], [0xCA, 0xF3, 0x01], 2),
+    ([0xCF, 0xF3, 0x01], [0xD3, 0xF3, 0x01], 2),
+    ([0xE0, 0xF3, 0x01], [0xF0, 0xF3, 0x01], 2),
+    ([0xF4, 0xF3, 0x01], [0xF4, 0xF3, 0x01], 2),
+    ([0xF8, 0xF3, 0x01], [0x3E, 0xF4, 0x01], 2),
+    ([0x40, 0xF4, 0x01], [0x40, 0xF4, 0x01], 2),
+    ([0x42, 0xF4, 0x01], [0xFC, 0xF4, 0x01], 2),
+    ([0xFF, 0xF4, 0x01], [0x3D, 0xF5, 0x01], 2),
+    ([0x4B, 0xF5, 0x01], [0x4E, 0xF5, 0x01], 2),
+    ([0x50, 0xF5, 0x01], [0x67, 0xF5, 0x01], 2),
+    ([0x7A, 0xF5, 0x01], [0x7A, 0xF5, 0x01], 2),
+    ([0x95, 0xF5, 0x01], [0x96, 0xF5, 0x01], 2),
+    ([0xA4, 0xF5, 0x01], [0xA4, 0xF5, 0x01], 2),
+    ([0xFB, 0xF5, 0x01], [0x4F, 0xF6, 0x01], 2),
+    ([0x80, 0xF6, 0x01], [0xC5, 0xF6, 0x01], 2),
+    ([0xCC, 0xF6, 0x01], [0xCC, 0xF6, 0x01], 2),
+    ([0xD0, 0xF6, 0x01], [0xD2, 0xF6, 0x01], 2),
+    ([0xD5, 0xF6, 0x01], [0xD7, 0xF6, 0x01], 2),
+    ([0xD8, 0xF6, 0x01], [0xDC, 0xF6, 0x01], -1),
+    ([0xDD, 0xF6, 0x01], [0xDF, 0xF6, 0x01], 2),
+    ([0xEB, 0xF6, 0x01], [0xEC, 0xF6, 0x01], 2),
+    ([0xED, 0xF6, 0x01], [0xEF, 0xF6, 0x01], -1),
+    ([0xF4, 0xF6, 0x01], [0xFC, 0xF6, 0x01], 2),
+    ([0xFD, 0xF6, 0x01], [0xFF, 0xF6, 0x01], -1),
+    ([0x74, 0xF7, 0x01], [0x7F, 0xF7, 0x01], -1),
+    ([0xD9, 0xF7, 0x01], [0xDF, 0xF7, 0x01], -1),
+    ([0xE0, 0xF7, 0x01], [0xEB, 0xF7, 0x01], 2),
+    ([0xEC, 0xF7, 0x01], [0xEF, 0xF7, 0x01], -1),
+    ([0xF0, 0xF7, 0x01], [0xF0, 0xF7, 0x01], 2),
+    ([0xF1, 0xF7, 0x01], [0xFF, 0xF7, 0x01], -1),
+    ([0x0C, 0xF8, 0x01], [0x0F, 0xF8, 0x01], -1),
+    ([0x48, 0xF8, 0x01], [0x4F, 0xF8, 0x01], -1),
+    ([0x5A, 0xF8, 0x01], [0x5F, 0xF8, 0x01], -1),
+    ([0x88, 0xF8, 0x01], [0x8F, 0xF8, 0x01], -1),
+    ([0xAE, 0xF8, 0x01], [0xAF, 0xF8, 0x01], -1),
+    ([0xB2, 0xF8, 0x01], [0xFF, 0xF8, 0x01], -1),
+    ([0x0C, 0xF9, 0x01], [0x3A, 0xF9, 0x01], 2),
+    ([0x3C, 0xF9, 0x01], [0x45, 0xF9, 0x01], 2),
+    ([0x47, 0xF9, 0x01], [0xFF, 0xF9, 0x01], 2),
+    ([0x54, 0xFA, 0x01], [0x5F, 0xFA, 0x01], -1),
+    ([0x6E, 0xFA, 0x01], [0x6F, 0xFA, 0x01], -1),
+    ([0x70, 0xFA, 0x01], [0x74, 0xFA, 0x01], 2),
+    ([0x75, 0xFA, 0x01], [0x77, 0xFA, 0x01], -1),
+    ([0x78, 0xFA, 0x01], [0x7C, 0xFA, 0x01], 2),
+    ([0x7D, 0xFA, 0x01], [0x7F, 0xFA, 0x01], -1),
+    ([0x80, 0xFA, 0x01], [0x86, 0xFA, 0x01], 2),
+    ([0x87, 0xFA, 0x01], [0x8F, 0xFA, 0x01], -1),
+    ([0x90, 0xFA, 0x01], [0xAC, 0xFA, 0x01], 2),
+    ([0xAD, 0xFA, 0x01], [0xAF, 0xFA, 0x01], -1),
+    ([0xB0, 0xFA, 0x01], [0xBA, 0xFA, 0x01], 2),
+    ([0xBB, 0xFA, 0x01], [0xBF, 0xFA, 0x01], -1),
+    ([0xC0, 0xFA, 0x01], [0xC5, 0xFA, 0x01], 2),
+    ([0xC6, 0xFA, 0x01], [0xCF, 0xFA, 0x01], -1),
+    ([0xD0, 0xFA, 0x01], [0xD9, 0xFA, 0x01], 2),
+    ([0xDA, 0xFA, 0x01], [0xDF, 0xFA, 0x01], -1),
+    ([0xE0, 0xFA, 0x01], [0xE7, 0xFA, 0x01], 2),
+    ([0xE8, 0xFA, 0x01], [0xEF, 0xFA, 0x01], -1),
+    ([0xF0, 0xFA, 0x01], [0xF6, 0xFA, 0x01], 2),
+    ([0xF7, 0xFA, 0x01], [0xFF, 0xFA, 0x01], -1),
+    ([0x93, 0xFB, 0x01], [0x93, 0xFB, 0x01], -1),
+    ([0xCB, 0xFB, 0x01], [0xEF, 0xFB, 0x01], -1),
+    ([0xFA, 0xFB, 0x01], [0xFF, 0xFF, 0x01], -1),
+    ([0x00, 0x00, 0x02], [0xDF, 0xA6, 0x02], 2),
+    ([0xE0, 0xA6, 0x02], [0xFF, 0xA6, 0x02], -1),
+    ([0x00, 0xA7, 0x02], [0x38, 0xB7, 0x02], 2),
+    ([0x39, 0xB7, 0x02], [0x3F, 0xB7, 0x02], -1),
+    ([0x40, 0xB7, 0x02], [0x1D, 0xB8, 0x02], 2),
+    ([0x1E, 0xB8, 0x02], [0x1F, 0xB8, 0x02], -1),
+    ([0x20, 0xB8, 0x02], [0xA1, 0xCE, 0x02], 2),
+    ([0xA2, 0xCE, 0x02], [0xAF, 0xCE, 0x02], -1),
+    ([0xB0, 0xCE, 0x02], [0xE0, 0xEB, 0x02], 2),
+    ([0xE1, 0xEB, 0x02], [0xFF, 0xF7, 0x02], -1),
+    ([0x00, 0xF8, 0x02], [0x1D, 0xFA, 0x02], 2),
+    ([0x1E, 0xFA, 0x02], [0xFF, 0xFF, 0x02], -1),
+    ([0x00, 0x00, 0x03], [0x4A, 0x13, 0x03], 2),
+    ([0x4B, 0x13, 0x03], [0x00, 0x00, 0x0E], -1),
+    ([0x01, 0x00, 0x0E], [0x01, 0x00, 0x0E], 0),
+    ([0x02, 0x00, 0x0E], [0x1F, 0x00, 0x0E], -1),
+    ([0x20, 0x00, 0x0E], [0x7F, 0x00, 0x0E], 0),
+    ([0x80, 0x00, 0x0E], [0xFF, 0x00, 0x0E], -1),
+    ([0x00, 0x01, 0x0E], [0xEF, 0x01, 0x0E], 0),
+    ([0xF0, 0x01, 0x0E], [0xFF, 0xFF, 0x0E], -1),
+    ([0xFE, 0xFF, 0x0F], [0xFF, 0xFF, 0x0F], -1),
+    ([0xFE, 0xFF, 0x10], [0xFF, 0xFF, 0x10], -1),
+];
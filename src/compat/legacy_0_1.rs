@@ -0,0 +1,110 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The widths that version 0.1.12 of this crate returns, for comparing against
+//! layouts made with 0.1.x while migrating.
+//!
+//! Those versions follow simpler rules than the current ones:
+//!
+//! - The width of a string is the sum of the widths of its characters,
+//!   except that [emoji presentation sequences] have width 2. There are no other
+//!   ligatures, and `"\r\n"` has width 0.
+//! - Control characters have no width, and count as 0 columns in a string.
+//!   `'\0'` has width 0.
+//! - `'\u{AD}'` SOFT HYPHEN has width 1.
+//! - `Prepend` characters, such as `'\u{0605}'` and `'\u{0D4E}'`, and `'\u{A8FA}'`
+//!   have width 1.
+//! - The widths of other characters follow [`uax11::width`],
+//!   except that `'\u{115F}'` has width 2. In particular, all characters in the
+//!   Ambiguous category, including letters and modifier symbols, are treated as
+//!   2 columns wide by the `_cjk` functions.
+//!
+//! The rules are applied to the Unicode version of this crate, [`UNICODE_VERSION`](crate::UNICODE_VERSION),
+//! so characters whose properties changed since Unicode 15.1 may have different
+//! widths than with 0.1.12. Later 0.1.x releases already include some of the
+//! changes of 0.2.0.
+//!
+//! ```rust
+//! use unicode_width::compat::legacy_0_1;
+//! use unicode_width::UnicodeWidthStr;
+//!
+//! assert_eq!(legacy_0_1::str_width("a\nb"), 2);
+//! assert_eq!("a\nb".width(), 3);
+//! assert_eq!(legacy_0_1::str_width("👩‍🔬"), 4);
+//! assert_eq!("👩‍🔬".width(), 2);
+//! ```
+//!
+//! [emoji presentation sequences]: https://unicode.org/reports/tr51/#def_emoji_presentation_sequence
+
+use crate::tables;
+use crate::uax11::{self, Context};
+
+/// Returns the width of `c` in columns as `UnicodeWidthChar::width` did in 0.1.12,
+/// or `None` if `c` is a control character.
+///
+/// Characters in the Ambiguous category are treated as 1 column wide.
+#[inline]
+pub fn char_width(c: char) -> Option<usize> {
+    char_width_in(c, Context::NonEastAsian)
+}
+
+/// Returns the width of `c` in columns as `UnicodeWidthChar::width_cjk` did in 0.1.12,
+/// or `None` if `c` is a control character.
+///
+/// Characters in the Ambiguous category are treated as 2 columns wide.
+#[cfg(feature = "cjk")]
+#[inline]
+pub fn char_width_cjk(c: char) -> Option<usize> {
+    char_width_in(c, Context::EastAsian)
+}
+
+/// Returns the width of `s` in columns as `UnicodeWidthStr::width` did in 0.1.12.
+///
+/// Characters in the Ambiguous category are treated as 1 column wide.
+#[inline]
+pub fn str_width(s: &str) -> usize {
+    str_width_in(s, Context::NonEastAsian)
+}
+
+/// Returns the width of `s` in columns as `UnicodeWidthStr::width_cjk` did in 0.1.12.
+///
+/// Characters in the Ambiguous category are treated as 2 columns wide.
+#[cfg(feature = "cjk")]
+#[inline]
+pub fn str_width_cjk(s: &str) -> usize {
+    str_width_in(s, Context::EastAsian)
+}
+
+fn char_width_in(c: char, context: Context) -> Option<usize> {
+    match c {
+        '\0' => Some(0),
+        '\u{AD}' => Some(1),
+        '\u{115F}' => Some(2),
+        _ if tables::is_prepended_zero_width(c) => Some(1),
+        _ => uax11::width(c, context),
+    }
+}
+
+fn str_width_in(s: &str, context: Context) -> usize {
+    s.chars()
+        .rfold((0, false), |(sum, next_is_vs16), c| {
+            if c == '\u{FE0F}' {
+                (sum, true)
+            } else {
+                let width = if next_is_vs16 && tables::starts_emoji_presentation_seq(c) {
+                    2
+                } else {
+                    char_width_in(c, context).unwrap_or(0)
+                };
+                (sum + width, false)
+            }
+        })
+        .0
+}
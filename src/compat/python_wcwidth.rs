@@ -0,0 +1,186 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The widths that the `wcwidth` and `wcswidth` functions of the Python
+//! [`wcwidth`](https://github.com/jquast/wcwidth) package return.
+//!
+//! The tables are generated by `scripts/unicode.py` from the release of the package given by
+//! [`PYTHON_WCWIDTH_VERSION`]. To match another release, set `PYTHON_WCWIDTH_VERSION`
+//! in the script, install that release, and regenerate them.
+//!
+//! Like this crate, the package gives emoji sequences and Indic conjuncts their own width,
+//! but by different rules: for example, it doesn't check that emoji ZWJ sequences
+//! are well-formed, and it counts spacing marks as 1 column wide.
+//!
+//! ```rust
+//! use unicode_width::compat::python_wcwidth::{wcswidth, wcwidth};
+//!
+//! assert_eq!(wcwidth('中'), 2);
+//! assert_eq!(wcwidth('\x1b'), -1);
+//! assert_eq!(wcswidth("👩‍🔬"), 2);
+//! assert_eq!(wcswidth("a\u{200D}b"), 1);
+//! assert_eq!(wcswidth("क्ष"), 2);
+//! ```
+
+mod tables;
+
+use tables::bisearch;
+pub use tables::PYTHON_WCWIDTH_VERSION;
+
+/// Returns the width of `c` in columns as the package's `wcwidth(c)` does,
+/// or -1 if `c` is a control character other than `'\0'`.
+///
+/// Characters in the Ambiguous category are treated as 1 column wide.
+pub fn wcwidth(c: char) -> i32 {
+    wcwidth_with(c, &[])
+}
+
+/// Returns the width of `c` in columns as the package's
+/// `wcwidth(c, ambiguous_width=2)` does, or -1 if `c` is a control character
+/// other than `'\0'`.
+///
+/// Characters in the Ambiguous category are treated as 2 columns wide.
+#[cfg(feature = "cjk")]
+pub fn wcwidth_cjk(c: char) -> i32 {
+    wcwidth_with(c, &tables::AMBIGUOUS)
+}
+
+/// Returns the width of `s` in columns as the package's `wcswidth(s)` does,
+/// or -1 if it contains a control character other than `'\0'`.
+///
+/// Characters in the Ambiguous category are treated as 1 column wide.
+///
+/// ```rust
+/// use unicode_width::compat::python_wcwidth::wcswidth;
+///
+/// assert_eq!(wcswidth("🇮🇸🇳"), 4);
+/// assert_eq!(wcswidth("\u{2764}\u{FE0F}"), 2);
+/// assert_eq!(wcswidth("a\r\n"), -1);
+/// ```
+pub fn wcswidth(s: &str) -> i32 {
+    wcswidth_with(s, &[])
+}
+
+/// Returns the width of `s` in columns as the package's
+/// `wcswidth(s, ambiguous_width=2)` does, or -1 if it contains a control character
+/// other than `'\0'`.
+///
+/// Characters in the Ambiguous category are treated as 2 columns wide.
+#[cfg(feature = "cjk")]
+pub fn wcswidth_cjk(s: &str) -> i32 {
+    wcswidth_with(s, &tables::AMBIGUOUS)
+}
+
+/// Returns the width of `c`, with the characters in `ambiguous` being 2 columns wide.
+fn wcwidth_with(c: char, ambiguous: &[([u8; 3], [u8; 3])]) -> i32 {
+    match c {
+        '\u{20}'..='\u{7E}' => 1,
+        '\u{1}'..='\u{1F}' | '\u{7F}'..='\u{9F}' => -1,
+        _ if bisearch(c, &tables::ZERO_WIDTH) => 0,
+        _ if bisearch(c, &tables::WIDE_EASTASIAN) => 2,
+        _ if bisearch(c, ambiguous) => 2,
+        _ => 1,
+    }
+}
+
+/// Follows the package's `wcswidth` step by step, so that it gives the same results
+/// even where that doesn't follow Unicode's rules.
+fn wcswidth_with(s: &str, ambiguous: &[([u8; 3], [u8; 3])]) -> i32 {
+    let mut width = 0;
+    // The last character that was measured, as long as a VS16 can still widen it
+    let mut last_measured = None;
+    // The last character that was measured, or that completed a flag
+    let mut last_measured_or_flag = None;
+    let mut last_was_virama = false;
+    let mut conjunct_pending = false;
+    // How many regional indicators come right before the current character
+    let mut regional_indicators = 0;
+
+    let mut chars = s.chars();
+    let mut advance = |regional_indicators: &mut usize| {
+        let c = chars.next()?;
+        *regional_indicators = if bisearch(c, &tables::REGIONAL_INDICATOR) {
+            *regional_indicators + 1
+        } else {
+            0
+        };
+        Some(c)
+    };
+
+    loop {
+        let before = regional_indicators;
+        let Some(c) = advance(&mut regional_indicators) else {
+            break;
+        };
+
+        if c == '\u{200D}' {
+            if !last_was_virama {
+                // Skip the character joined to the previous one
+                advance(&mut regional_indicators);
+            }
+            continue;
+        }
+
+        if c == '\u{FE0F}' {
+            if let Some(prev) = last_measured.take() {
+                if bisearch(prev, &tables::VS16_NARROW_TO_WIDE) {
+                    width += 1;
+                }
+                continue;
+            }
+        }
+
+        if bisearch(c, &tables::REGIONAL_INDICATOR) {
+            if before % 2 == 1 {
+                // Completes a flag
+                last_measured_or_flag = Some(c);
+                continue;
+            }
+        } else if bisearch(c, &tables::FITZPATRICK)
+            && matches!(last_measured_or_flag, Some(prev) if bisearch(prev, &tables::EMOJI_ZWJ))
+        {
+            continue;
+        }
+
+        if last_was_virama && bisearch(c, &tables::ISC_CONSONANT) {
+            last_measured = Some(c);
+            last_measured_or_flag = Some(c);
+            last_was_virama = false;
+            conjunct_pending = true;
+            continue;
+        }
+
+        match wcwidth_with(c, ambiguous) {
+            -1 => return -1,
+            0 if last_measured.is_some() && bisearch(c, &tables::CATEGORY_MC) => {
+                width += 1;
+                last_measured = None;
+                last_was_virama = false;
+                conjunct_pending = false;
+            }
+            0 => last_was_virama = bisearch(c, &tables::ISC_VIRAMA),
+            w => {
+                if conjunct_pending {
+                    width += 1;
+                    conjunct_pending = false;
+                }
+                width += w;
+                last_measured = Some(c);
+                last_measured_or_flag = Some(c);
+                last_was_virama = false;
+            }
+        }
+    }
+
+    if conjunct_pending {
+        width += 1;
+    }
+    width
+}
@@ -0,0 +1,1376 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// NOTE: The following code was generated by "scripts/unicode.py", do not edit directly
+
+use core::cmp::Ordering;
+
+/// The version of the Python `wcwidth` package whose tables these are.
+pub const PYTHON_WCWIDTH_VERSION: (u8, u8, u8) = (0, 7, 0);
+
+/// Whether `c` is in `table`, a sorted list of codepoint ranges (inclusive).
+pub fn bisearch(c: char, table: &[([u8; 3], [u8; 3])]) -> bool {
+    let cp: u32 = c.into();
+    table
+        .binary_search_by(|&(lo, hi)| {
+            let lo = u32::from_le_bytes([lo[0], lo[1], lo[2], 0]);
+            let hi = u32::from_le_bytes([hi[0], hi[1], hi[2], 0]);
+            if cp < lo {
+                Ordering::Greater
+            } else if cp > hi {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// Sorted list of codepoint ranges (inclusive) in the package's `ZERO_WIDTH` table.
+#[rustfmt::skip]
+pub static ZERO_WIDTH: [([u8; 3], [u8; 3]); 337] = [
+    ([0x00, 0x00, 0x00], [0x00, 0x00, 0x00]),
+    ([0x00, 0x03, 0x00], [0x6F, 0x03, 0x00]),
+    ([0x83, 0x04, 0x00], [0x89, 0x04, 0x00]),
+    ([0x91, 0x05, 0x00], [0xBD, 0x05, 0x00]),
+    ([0xBF, 0x05, 0x00], [0xBF, 0x05, 0x00]),
+    ([0xC1, 0x05, 0x00], [0xC2, 0x05, 0x00]),
+    ([0xC4, 0x05, 0x00], [0xC5, 0x05, 0x00]),
+    ([0xC7, 0x05, 0x00], [0xC7, 0x05, 0x00]),
+    ([0x10, 0x06, 0x00], [0x1A, 0x06, 0x00]),
+    ([0x1C, 0x06, 0x00], [0x1C, 0x06, 0x00]),
+    ([0x4B, 0x06, 0x00], [0x5F, 0x06, 0x00]),
+    ([0x70, 0x06, 0x00], [0x70, 0x06, 0x00]),
+    ([0xD6, 0x06, 0x00], [0xDC, 0x06, 0x00]),
+    ([0xDF, 0x06, 0x00], [0xE4, 0x06, 0x00]),
+    ([0xE7, 0x06, 0x00], [0xE8, 0x06, 0x00]),
+    ([0xEA, 0x06, 0x00], [0xED, 0x06, 0x00]),
+    ([0x11, 0x07, 0x00], [0x11, 0x07, 0x00]),
+    ([0x30, 0x07, 0x00], [0x4A, 0x07, 0x00]),
+    ([0xA6, 0x07, 0x00], [0xB0, 0x07, 0x00]),
+    ([0xEB, 0x07, 0x00], [0xF3, 0x07, 0x00]),
+    ([0xFD, 0x07, 0x00], [0xFD, 0x07, 0x00]),
+    ([0x16, 0x08, 0x00], [0x19, 0x08, 0x00]),
+    ([0x1B, 0x08, 0x00], [0x23, 0x08, 0x00]),
+    ([0x25, 0x08, 0x00], [0x27, 0x08, 0x00]),
+    ([0x29, 0x08, 0x00], [0x2D, 0x08, 0x00]),
+    ([0x59, 0x08, 0x00], [0x5B, 0x08, 0x00]),
+    ([0x97, 0x08, 0x00], [0x9F, 0x08, 0x00]),
+    ([0xCA, 0x08, 0x00], [0xE1, 0x08, 0x00]),
+    ([0xE3, 0x08, 0x00], [0x03, 0x09, 0x00]),
+    ([0x3A, 0x09, 0x00], [0x3C, 0x09, 0x00]),
+    ([0x3E, 0x09, 0x00], [0x4F, 0x09, 0x00]),
+    ([0x51, 0x09, 0x00], [0x57, 0x09, 0x00]),
+    ([0x62, 0x09, 0x00], [0x63, 0x09, 0x00]),
+    ([0x81, 0x09, 0x00], [0x83, 0x09, 0x00]),
+    ([0xBC, 0x09, 0x00], [0xBC, 0x09, 0x00]),
+    ([0xBE, 0x09, 0x00], [0xC4, 0x09, 0x00]),
+    ([0xC7, 0x09, 0x00], [0xC8, 0x09, 0x00]),
+    ([0xCB, 0x09, 0x00], [0xCD, 0x09, 0x00]),
+    ([0xD7, 0x09, 0x00], [0xD7, 0x09, 0x00]),
+    ([0xE2, 0x09, 0x00], [0xE3, 0x09, 0x00]),
+    ([0xFE, 0x09, 0x00], [0xFE, 0x09, 0x00]),
+    ([0x01, 0x0A, 0x00], [0x03, 0x0A, 0x00]),
+    ([0x3C, 0x0A, 0x00], [0x3C, 0x0A, 0x00]),
+    ([0x3E, 0x0A, 0x00], [0x42, 0x0A, 0x00]),
+    ([0x47, 0x0A, 0x00], [0x48, 0x0A, 0x00]),
+    ([0x4B, 0x0A, 0x00], [0x4D, 0x0A, 0x00]),
+    ([0x51, 0x0A, 0x00], [0x51, 0x0A, 0x00]),
+    ([0x70, 0x0A, 0x00], [0x71, 0x0A, 0x00]),
+    ([0x75, 0x0A, 0x00], [0x75, 0x0A, 0x00]),
+    ([0x81, 0x0A, 0x00], [0x83, 0x0A, 0x00]),
+    ([0xBC, 0x0A, 0x00], [0xBC, 0x0A, 0x00]),
+    ([0xBE, 0x0A, 0x00], [0xC5, 0x0A, 0x00]),
+    ([0xC7, 0x0A, 0x00], [0xC9, 0x0A, 0x00]),
+    ([0xCB, 0x0A, 0x00], [0xCD, 0x0A, 0x00]),
+    ([0xE2, 0x0A, 0x00], [0xE3, 0x0A, 0x00]),
+    ([0xFA, 0x0A, 0x00], [0xFF, 0x0A, 0x00]),
+    ([0x01, 0x0B, 0x00], [0x03, 0x0B, 0x00]),
+    ([0x3C, 0x0B, 0x00], [0x3C, 0x0B, 0x00]),
+    ([0x3E, 0x0B, 0x00], [0x44, 0x0B, 0x00]),
+    ([0x47, 0x0B, 0x00], [0x48, 0x0B, 0x00]),
+    ([0x4B, 0x0B, 0x00], [0x4D, 0x0B, 0x00]),
+    ([0x55, 0x0B, 0x00], [0x57, 0x0B, 0x00]),
+    ([0x62, 0x0B, 0x00], [0x63, 0x0B, 0x00]),
+    ([0x82, 0x0B, 0x00], [0x82, 0x0B, 0x00]),
+    ([0xBE, 0x0B, 0x00], [0xC2, 0x0B, 0x00]),
+    ([0xC6, 0x0B, 0x00], [0xC8, 0x0B, 0x00]),
+    ([0xCA, 0x0B, 0x00], [0xCD, 0x0B, 0x00]),
+    ([0xD7, 0x0B, 0x00], [0xD7, 0x0B, 0x00]),
+    ([0x00, 0x0C, 0x00], [0x04, 0x0C, 0x00]),
+    ([0x3C, 0x0C, 0x00], [0x3C, 0x0C, 0x00]),
+    ([0x3E, 0x0C, 0x00], [0x44, 0x0C, 0x00]),
+    ([0x46, 0x0C, 0x00], [0x48, 0x0C, 0x00]),
+    ([0x4A, 0x0C, 0x00], [0x4D, 0x0C, 0x00]),
+    ([0x55, 0x0C, 0x00], [0x56, 0x0C, 0x00]),
+    ([0x62, 0x0C, 0x00], [0x63, 0x0C, 0x00]),
+    ([0x81, 0x0C, 0x00], [0x83, 0x0C, 0x00]),
+    ([0xBC, 0x0C, 0x00], [0xBC, 0x0C, 0x00]),
+    ([0xBE, 0x0C, 0x00], [0xC4, 0x0C, 0x00]),
+    ([0xC6, 0x0C, 0x00], [0xC8, 0x0C, 0x00]),
+    ([0xCA, 0x0C, 0x00], [0xCD, 0x0C, 0x00]),
+    ([0xD5, 0x0C, 0x00], [0xD6, 0x0C, 0x00]),
+    ([0xE2, 0x0C, 0x00], [0xE3, 0x0C, 0x00]),
+    ([0xF3, 0x0C, 0x00], [0xF3, 0x0C, 0x00]),
+    ([0x00, 0x0D, 0x00], [0x03, 0x0D, 0x00]),
+    ([0x3B, 0x0D, 0x00], [0x3C, 0x0D, 0x00]),
+    ([0x3E, 0x0D, 0x00], [0x44, 0x0D, 0x00]),
+    ([0x46, 0x0D, 0x00], [0x48, 0x0D, 0x00]),
+    ([0x4A, 0x0D, 0x00], [0x4D, 0x0D, 0x00]),
+    ([0x57, 0x0D, 0x00], [0x57, 0x0D, 0x00]),
+    ([0x62, 0x0D, 0x00], [0x63, 0x0D, 0x00]),
+    ([0x81, 0x0D, 0x00], [0x83, 0x0D, 0x00]),
+    ([0xCA, 0x0D, 0x00], [0xCA, 0x0D, 0x00]),
+    ([0xCF, 0x0D, 0x00], [0xD4, 0x0D, 0x00]),
+    ([0xD6, 0x0D, 0x00], [0xD6, 0x0D, 0x00]),
+    ([0xD8, 0x0D, 0x00], [0xDF, 0x0D, 0x00]),
+    ([0xF2, 0x0D, 0x00], [0xF3, 0x0D, 0x00]),
+    ([0x31, 0x0E, 0x00], [0x31, 0x0E, 0x00]),
+    ([0x34, 0x0E, 0x00], [0x3A, 0x0E, 0x00]),
+    ([0x47, 0x0E, 0x00], [0x4E, 0x0E, 0x00]),
+    ([0xB1, 0x0E, 0x00], [0xB1, 0x0E, 0x00]),
+    ([0xB4, 0x0E, 0x00], [0xBC, 0x0E, 0x00]),
+    ([0xC8, 0x0E, 0x00], [0xCE, 0x0E, 0x00]),
+    ([0x18, 0x0F, 0x00], [0x19, 0x0F, 0x00]),
+    ([0x35, 0x0F, 0x00], [0x35, 0x0F, 0x00]),
+    ([0x37, 0x0F, 0x00], [0x37, 0x0F, 0x00]),
+    ([0x39, 0x0F, 0x00], [0x39, 0x0F, 0x00]),
+    ([0x3E, 0x0F, 0x00], [0x3F, 0x0F, 0x00]),
+    ([0x71, 0x0F, 0x00], [0x84, 0x0F, 0x00]),
+    ([0x86, 0x0F, 0x00], [0x87, 0x0F, 0x00]),
+    ([0x8D, 0x0F, 0x00], [0x97, 0x0F, 0x00]),
+    ([0x99, 0x0F, 0x00], [0xBC, 0x0F, 0x00]),
+    ([0xC6, 0x0F, 0x00], [0xC6, 0x0F, 0x00]),
+    ([0x2B, 0x10, 0x00], [0x3E, 0x10, 0x00]),
+    ([0x56, 0x10, 0x00], [0x59, 0x10, 0x00]),
+    ([0x5E, 0x10, 0x00], [0x60, 0x10, 0x00]),
+    ([0x62, 0x10, 0x00], [0x64, 0x10, 0x00]),
+    ([0x67, 0x10, 0x00], [0x6D, 0x10, 0x00]),
+    ([0x71, 0x10, 0x00], [0x74, 0x10, 0x00]),
+    ([0x82, 0x10, 0x00], [0x8D, 0x10, 0x00]),
+    ([0x8F, 0x10, 0x00], [0x8F, 0x10, 0x00]),
+    ([0x9A, 0x10, 0x00], [0x9D, 0x10, 0x00]),
+    ([0x60, 0x11, 0x00], [0xFF, 0x11, 0x00]),
+    ([0x5D, 0x13, 0x00], [0x5F, 0x13, 0x00]),
+    ([0x12, 0x17, 0x00], [0x15, 0x17, 0x00]),
+    ([0x32, 0x17, 0x00], [0x34, 0x17, 0x00]),
+    ([0x52, 0x17, 0x00], [0x53, 0x17, 0x00]),
+    ([0x72, 0x17, 0x00], [0x73, 0x17, 0x00]),
+    ([0xB4, 0x17, 0x00], [0xD3, 0x17, 0x00]),
+    ([0xDD, 0x17, 0x00], [0xDD, 0x17, 0x00]),
+    ([0x0B, 0x18, 0x00], [0x0F, 0x18, 0x00]),
+    ([0x85, 0x18, 0x00], [0x86, 0x18, 0x00]),
+    ([0xA9, 0x18, 0x00], [0xA9, 0x18, 0x00]),
+    ([0x20, 0x19, 0x00], [0x2B, 0x19, 0x00]),
+    ([0x30, 0x19, 0x00], [0x3B, 0x19, 0x00]),
+    ([0x17, 0x1A, 0x00], [0x1B, 0x1A, 0x00]),
+    ([0x55, 0x1A, 0x00], [0x5E, 0x1A, 0x00]),
+    ([0x60, 0x1A, 0x00], [0x7C, 0x1A, 0x00]),
+    ([0x7F, 0x1A, 0x00], [0x7F, 0x1A, 0x00]),
+    ([0xB0, 0x1A, 0x00], [0xDD, 0x1A, 0x00]),
+    ([0xE0, 0x1A, 0x00], [0xEB, 0x1A, 0x00]),
+    ([0x00, 0x1B, 0x00], [0x04, 0x1B, 0x00]),
+    ([0x34, 0x1B, 0x00], [0x44, 0x1B, 0x00]),
+    ([0x6B, 0x1B, 0x00], [0x73, 0x1B, 0x00]),
+    ([0x80, 0x1B, 0x00], [0x82, 0x1B, 0x00]),
+    ([0xA1, 0x1B, 0x00], [0xAD, 0x1B, 0x00]),
+    ([0xE6, 0x1B, 0x00], [0xF3, 0x1B, 0x00]),
+    ([0x24, 0x1C, 0x00], [0x37, 0x1C, 0x00]),
+    ([0xD0, 0x1C, 0x00], [0xD2, 0x1C, 0x00]),
+    ([0xD4, 0x1C, 0x00], [0xE8, 0x1C, 0x00]),
+    ([0xED, 0x1C, 0x00], [0xED, 0x1C, 0x00]),
+    ([0xF4, 0x1C, 0x00], [0xF4, 0x1C, 0x00]),
+    ([0xF7, 0x1C, 0x00], [0xF9, 0x1C, 0x00]),
+    ([0xC0, 0x1D, 0x00], [0xFF, 0x1D, 0x00]),
+    ([0x0B, 0x20, 0x00], [0x0F, 0x20, 0x00]),
+    ([0x28, 0x20, 0x00], [0x2E, 0x20, 0x00]),
+    ([0x60, 0x20, 0x00], [0x6F, 0x20, 0x00]),
+    ([0xD0, 0x20, 0x00], [0xF0, 0x20, 0x00]),
+    ([0xEF, 0x2C, 0x00], [0xF1, 0x2C, 0x00]),
+    ([0x7F, 0x2D, 0x00], [0x7F, 0x2D, 0x00]),
+    ([0xE0, 0x2D, 0x00], [0xFF, 0x2D, 0x00]),
+    ([0x2A, 0x30, 0x00], [0x2F, 0x30, 0x00]),
+    ([0x99, 0x30, 0x00], [0x9A, 0x30, 0x00]),
+    ([0x64, 0x31, 0x00], [0x64, 0x31, 0x00]),
+    ([0x6F, 0xA6, 0x00], [0x72, 0xA6, 0x00]),
+    ([0x74, 0xA6, 0x00], [0x7D, 0xA6, 0x00]),
+    ([0x9E, 0xA6, 0x00], [0x9F, 0xA6, 0x00]),
+    ([0xF0, 0xA6, 0x00], [0xF1, 0xA6, 0x00]),
+    ([0x02, 0xA8, 0x00], [0x02, 0xA8, 0x00]),
+    ([0x06, 0xA8, 0x00], [0x06, 0xA8, 0x00]),
+    ([0x0B, 0xA8, 0x00], [0x0B, 0xA8, 0x00]),
+    ([0x23, 0xA8, 0x00], [0x27, 0xA8, 0x00]),
+    ([0x2C, 0xA8, 0x00], [0x2C, 0xA8, 0x00]),
+    ([0x80, 0xA8, 0x00], [0x81, 0xA8, 0x00]),
+    ([0xB4, 0xA8, 0x00], [0xC5, 0xA8, 0x00]),
+    ([0xE0, 0xA8, 0x00], [0xF1, 0xA8, 0x00]),
+    ([0xFF, 0xA8, 0x00], [0xFF, 0xA8, 0x00]),
+    ([0x26, 0xA9, 0x00], [0x2D, 0xA9, 0x00]),
+    ([0x47, 0xA9, 0x00], [0x53, 0xA9, 0x00]),
+    ([0x80, 0xA9, 0x00], [0x83, 0xA9, 0x00]),
+    ([0xB3, 0xA9, 0x00], [0xC0, 0xA9, 0x00]),
+    ([0xE5, 0xA9, 0x00], [0xE5, 0xA9, 0x00]),
+    ([0x29, 0xAA, 0x00], [0x36, 0xAA, 0x00]),
+    ([0x43, 0xAA, 0x00], [0x43, 0xAA, 0x00]),
+    ([0x4C, 0xAA, 0x00], [0x4D, 0xAA, 0x00]),
+    ([0x7B, 0xAA, 0x00], [0x7D, 0xAA, 0x00]),
+    ([0xB0, 0xAA, 0x00], [0xB0, 0xAA, 0x00]),
+    ([0xB2, 0xAA, 0x00], [0xB4, 0xAA, 0x00]),
+    ([0xB7, 0xAA, 0x00], [0xB8, 0xAA, 0x00]),
+    ([0xBE, 0xAA, 0x00], [0xBF, 0xAA, 0x00]),
+    ([0xC1, 0xAA, 0x00], [0xC1, 0xAA, 0x00]),
+    ([0xEB, 0xAA, 0x00], [0xEF, 0xAA, 0x00]),
+    ([0xF5, 0xAA, 0x00], [0xF6, 0xAA, 0x00]),
+    ([0xE3, 0xAB, 0x00], [0xEA, 0xAB, 0x00]),
+    ([0xEC, 0xAB, 0x00], [0xED, 0xAB, 0x00]),
+    ([0xB0, 0xD7, 0x00], [0xFF, 0xD7, 0x00]),
+    ([0x1E, 0xFB, 0x00], [0x1E, 0xFB, 0x00]),
+    ([0x00, 0xFE, 0x00], [0x0F, 0xFE, 0x00]),
+    ([0x20, 0xFE, 0x00], [0x2F, 0xFE, 0x00]),
+    ([0xFF, 0xFE, 0x00], [0xFF, 0xFE, 0x00]),
+    ([0xA0, 0xFF, 0x00], [0xA0, 0xFF, 0x00]),
+    ([0xF0, 0xFF, 0x00], [0xFB, 0xFF, 0x00]),
+    ([0xFD, 0x01, 0x01], [0xFD, 0x01, 0x01]),
+    ([0xE0, 0x02, 0x01], [0xE0, 0x02, 0x01]),
+    ([0x76, 0x03, 0x01], [0x7A, 0x03, 0x01]),
+    ([0x01, 0x0A, 0x01], [0x03, 0x0A, 0x01]),
+    ([0x05, 0x0A, 0x01], [0x06, 0x0A, 0x01]),
+    ([0x0C, 0x0A, 0x01], [0x0F, 0x0A, 0x01]),
+    ([0x38, 0x0A, 0x01], [0x3A, 0x0A, 0x01]),
+    ([0x3F, 0x0A, 0x01], [0x3F, 0x0A, 0x01]),
+    ([0xE5, 0x0A, 0x01], [0xE6, 0x0A, 0x01]),
+    ([0x24, 0x0D, 0x01], [0x27, 0x0D, 0x01]),
+    ([0x69, 0x0D, 0x01], [0x6D, 0x0D, 0x01]),
+    ([0xAB, 0x0E, 0x01], [0xAC, 0x0E, 0x01]),
+    ([0xFA, 0x0E, 0x01], [0xFF, 0x0E, 0x01]),
+    ([0x46, 0x0F, 0x01], [0x50, 0x0F, 0x01]),
+    ([0x82, 0x0F, 0x01], [0x85, 0x0F, 0x01]),
+    ([0x00, 0x10, 0x01], [0x02, 0x10, 0x01]),
+    ([0x38, 0x10, 0x01], [0x46, 0x10, 0x01]),
+    ([0x70, 0x10, 0x01], [0x70, 0x10, 0x01]),
+    ([0x73, 0x10, 0x01], [0x74, 0x10, 0x01]),
+    ([0x7F, 0x10, 0x01], [0x82, 0x10, 0x01]),
+    ([0xB0, 0x10, 0x01], [0xBA, 0x10, 0x01]),
+    ([0xC2, 0x10, 0x01], [0xC2, 0x10, 0x01]),
+    ([0x00, 0x11, 0x01], [0x02, 0x11, 0x01]),
+    ([0x27, 0x11, 0x01], [0x34, 0x11, 0x01]),
+    ([0x45, 0x11, 0x01], [0x46, 0x11, 0x01]),
+    ([0x73, 0x11, 0x01], [0x73, 0x11, 0x01]),
+    ([0x80, 0x11, 0x01], [0x82, 0x11, 0x01]),
+    ([0xB3, 0x11, 0x01], [0xC0, 0x11, 0x01]),
+    ([0xC9, 0x11, 0x01], [0xCC, 0x11, 0x01]),
+    ([0xCE, 0x11, 0x01], [0xCF, 0x11, 0x01]),
+    ([0x2C, 0x12, 0x01], [0x37, 0x12, 0x01]),
+    ([0x3E, 0x12, 0x01], [0x3E, 0x12, 0x01]),
+    ([0x41, 0x12, 0x01], [0x41, 0x12, 0x01]),
+    ([0xDF, 0x12, 0x01], [0xEA, 0x12, 0x01]),
+    ([0x00, 0x13, 0x01], [0x03, 0x13, 0x01]),
+    ([0x3B, 0x13, 0x01], [0x3C, 0x13, 0x01]),
+    ([0x3E, 0x13, 0x01], [0x44, 0x13, 0x01]),
+    ([0x47, 0x13, 0x01], [0x48, 0x13, 0x01]),
+    ([0x4B, 0x13, 0x01], [0x4D, 0x13, 0x01]),
+    ([0x57, 0x13, 0x01], [0x57, 0x13, 0x01]),
+    ([0x62, 0x13, 0x01], [0x63, 0x13, 0x01]),
+    ([0x66, 0x13, 0x01], [0x6C, 0x13, 0x01]),
+    ([0x70, 0x13, 0x01], [0x74, 0x13, 0x01]),
+    ([0xB8, 0x13, 0x01], [0xC0, 0x13, 0x01]),
+    ([0xC2, 0x13, 0x01], [0xC2, 0x13, 0x01]),
+    ([0xC5, 0x13, 0x01], [0xC5, 0x13, 0x01]),
+    ([0xC7, 0x13, 0x01], [0xCA, 0x13, 0x01]),
+    ([0xCC, 0x13, 0x01], [0xD0, 0x13, 0x01]),
+    ([0xD2, 0x13, 0x01], [0xD2, 0x13, 0x01]),
+    ([0xE1, 0x13, 0x01], [0xE2, 0x13, 0x01]),
+    ([0x35, 0x14, 0x01], [0x46, 0x14, 0x01]),
+    ([0x5E, 0x14, 0x01], [0x5E, 0x14, 0x01]),
+    ([0xB0, 0x14, 0x01], [0xC3, 0x14, 0x01]),
+    ([0xAF, 0x15, 0x01], [0xB5, 0x15, 0x01]),
+    ([0xB8, 0x15, 0x01], [0xC0, 0x15, 0x01]),
+    ([0xDC, 0x15, 0x01], [0xDD, 0x15, 0x01]),
+    ([0x30, 0x16, 0x01], [0x40, 0x16, 0x01]),
+    ([0xAB, 0x16, 0x01], [0xB7, 0x16, 0x01]),
+    ([0x1D, 0x17, 0x01], [0x2B, 0x17, 0x01]),
+    ([0x2C, 0x18, 0x01], [0x3A, 0x18, 0x01]),
+    ([0x30, 0x19, 0x01], [0x35, 0x19, 0x01]),
+    ([0x37, 0x19, 0x01], [0x38, 0x19, 0x01]),
+    ([0x3B, 0x19, 0x01], [0x3E, 0x19, 0x01]),
+    ([0x40, 0x19, 0x01], [0x40, 0x19, 0x01]),
+    ([0x42, 0x19, 0x01], [0x43, 0x19, 0x01]),
+    ([0xD1, 0x19, 0x01], [0xD7, 0x19, 0x01]),
+    ([0xDA, 0x19, 0x01], [0xE0, 0x19, 0x01]),
+    ([0xE4, 0x19, 0x01], [0xE4, 0x19, 0x01]),
+    ([0x01, 0x1A, 0x01], [0x0A, 0x1A, 0x01]),
+    ([0x33, 0x1A, 0x01], [0x39, 0x1A, 0x01]),
+    ([0x3B, 0x1A, 0x01], [0x3E, 0x1A, 0x01]),
+    ([0x47, 0x1A, 0x01], [0x47, 0x1A, 0x01]),
+    ([0x51, 0x1A, 0x01], [0x5B, 0x1A, 0x01]),
+    ([0x8A, 0x1A, 0x01], [0x99, 0x1A, 0x01]),
+    ([0x60, 0x1B, 0x01], [0x67, 0x1B, 0x01]),
+    ([0x2F, 0x1C, 0x01], [0x36, 0x1C, 0x01]),
+    ([0x38, 0x1C, 0x01], [0x3F, 0x1C, 0x01]),
+    ([0x92, 0x1C, 0x01], [0xA7, 0x1C, 0x01]),
+    ([0xA9, 0x1C, 0x01], [0xB6, 0x1C, 0x01]),
+    ([0x31, 0x1D, 0x01], [0x36, 0x1D, 0x01]),
+    ([0x3A, 0x1D, 0x01], [0x3A, 0x1D, 0x01]),
+    ([0x3C, 0x1D, 0x01], [0x3D, 0x1D, 0x01]),
+    ([0x3F, 0x1D, 0x01], [0x45, 0x1D, 0x01]),
+    ([0x47, 0x1D, 0x01], [0x47, 0x1D, 0x01]),
+    ([0x8A, 0x1D, 0x01], [0x8E, 0x1D, 0x01]),
+    ([0x90, 0x1D, 0x01], [0x91, 0x1D, 0x01]),
+    ([0x93, 0x1D, 0x01], [0x97, 0x1D, 0x01]),
+    ([0xF3, 0x1E, 0x01], [0xF6, 0x1E, 0x01]),
+    ([0x00, 0x1F, 0x01], [0x01, 0x1F, 0x01]),
+    ([0x03, 0x1F, 0x01], [0x03, 0x1F, 0x01]),
+    ([0x34, 0x1F, 0x01], [0x3A, 0x1F, 0x01]),
+    ([0x3E, 0x1F, 0x01], [0x42, 0x1F, 0x01]),
+    ([0x5A, 0x1F, 0x01], [0x5A, 0x1F, 0x01]),
+    ([0x30, 0x34, 0x01], [0x40, 0x34, 0x01]),
+    ([0x47, 0x34, 0x01], [0x55, 0x34, 0x01]),
+    ([0x1E, 0x61, 0x01], [0x2F, 0x61, 0x01]),
+    ([0xF0, 0x6A, 0x01], [0xF4, 0x6A, 0x01]),
+    ([0x30, 0x6B, 0x01], [0x36, 0x6B, 0x01]),
+    ([0x4F, 0x6F, 0x01], [0x4F, 0x6F, 0x01]),
+    ([0x51, 0x6F, 0x01], [0x87, 0x6F, 0x01]),
+    ([0x8F, 0x6F, 0x01], [0x92, 0x6F, 0x01]),
+    ([0xE4, 0x6F, 0x01], [0xE4, 0x6F, 0x01]),
+    ([0xF0, 0x6F, 0x01], [0xF1, 0x6F, 0x01]),
+    ([0x9D, 0xBC, 0x01], [0x9E, 0xBC, 0x01]),
+    ([0xA0, 0xBC, 0x01], [0xA3, 0xBC, 0x01]),
+    ([0x00, 0xCF, 0x01], [0x2D, 0xCF, 0x01]),
+    ([0x30, 0xCF, 0x01], [0x46, 0xCF, 0x01]),
+    ([0x65, 0xD1, 0x01], [0x69, 0xD1, 0x01]),
+    ([0x6D, 0xD1, 0x01], [0x82, 0xD1, 0x01]),
+    ([0x85, 0xD1, 0x01], [0x8B, 0xD1, 0x01]),
+    ([0xAA, 0xD1, 0x01], [0xAD, 0xD1, 0x01]),
+    ([0x42, 0xD2, 0x01], [0x44, 0xD2, 0x01]),
+    ([0x00, 0xDA, 0x01], [0x36, 0xDA, 0x01]),
+    ([0x3B, 0xDA, 0x01], [0x6C, 0xDA, 0x01]),
+    ([0x75, 0xDA, 0x01], [0x75, 0xDA, 0x01]),
+    ([0x84, 0xDA, 0x01], [0x84, 0xDA, 0x01]),
+    ([0x9B, 0xDA, 0x01], [0x9F, 0xDA, 0x01]),
+    ([0xA1, 0xDA, 0x01], [0xAF, 0xDA, 0x01]),
+    ([0x00, 0xE0, 0x01], [0x06, 0xE0, 0x01]),
+    ([0x08, 0xE0, 0x01], [0x18, 0xE0, 0x01]),
+    ([0x1B, 0xE0, 0x01], [0x21, 0xE0, 0x01]),
+    ([0x23, 0xE0, 0x01], [0x24, 0xE0, 0x01]),
+    ([0x26, 0xE0, 0x01], [0x2A, 0xE0, 0x01]),
+    ([0x8F, 0xE0, 0x01], [0x8F, 0xE0, 0x01]),
+    ([0x30, 0xE1, 0x01], [0x36, 0xE1, 0x01]),
+    ([0xAE, 0xE2, 0x01], [0xAE, 0xE2, 0x01]),
+    ([0xEC, 0xE2, 0x01], [0xEF, 0xE2, 0x01]),
+    ([0xEC, 0xE4, 0x01], [0xEF, 0xE4, 0x01]),
+    ([0xEE, 0xE5, 0x01], [0xEF, 0xE5, 0x01]),
+    ([0xE3, 0xE6, 0x01], [0xE3, 0xE6, 0x01]),
+    ([0xE6, 0xE6, 0x01], [0xE6, 0xE6, 0x01]),
+    ([0xEE, 0xE6, 0x01], [0xEF, 0xE6, 0x01]),
+    ([0xF5, 0xE6, 0x01], [0xF5, 0xE6, 0x01]),
+    ([0xD0, 0xE8, 0x01], [0xD6, 0xE8, 0x01]),
+    ([0x44, 0xE9, 0x01], [0x4A, 0xE9, 0x01]),
+    ([0x00, 0x00, 0x0E], [0xFF, 0x0F, 0x0E]),
+];
+
+/// Sorted list of codepoint ranges (inclusive) in the package's `WIDE_EASTASIAN` table.
+#[rustfmt::skip]
+pub static WIDE_EASTASIAN: [([u8; 3], [u8; 3]); 125] = [
+    ([0x00, 0x11, 0x00], [0x5F, 0x11, 0x00]),
+    ([0x1A, 0x23, 0x00], [0x1B, 0x23, 0x00]),
+    ([0x29, 0x23, 0x00], [0x2A, 0x23, 0x00]),
+    ([0xE9, 0x23, 0x00], [0xEC, 0x23, 0x00]),
+    ([0xF0, 0x23, 0x00], [0xF0, 0x23, 0x00]),
+    ([0xF3, 0x23, 0x00], [0xF3, 0x23, 0x00]),
+    ([0xFD, 0x25, 0x00], [0xFE, 0x25, 0x00]),
+    ([0x14, 0x26, 0x00], [0x15, 0x26, 0x00]),
+    ([0x30, 0x26, 0x00], [0x37, 0x26, 0x00]),
+    ([0x48, 0x26, 0x00], [0x53, 0x26, 0x00]),
+    ([0x7F, 0x26, 0x00], [0x7F, 0x26, 0x00]),
+    ([0x8A, 0x26, 0x00], [0x8F, 0x26, 0x00]),
+    ([0x93, 0x26, 0x00], [0x93, 0x26, 0x00]),
+    ([0xA1, 0x26, 0x00], [0xA1, 0x26, 0x00]),
+    ([0xAA, 0x26, 0x00], [0xAB, 0x26, 0x00]),
+    ([0xBD, 0x26, 0x00], [0xBE, 0x26, 0x00]),
+    ([0xC4, 0x26, 0x00], [0xC5, 0x26, 0x00]),
+    ([0xCE, 0x26, 0x00], [0xCE, 0x26, 0x00]),
+    ([0xD4, 0x26, 0x00], [0xD4, 0x26, 0x00]),
+    ([0xEA, 0x26, 0x00], [0xEA, 0x26, 0x00]),
+    ([0xF2, 0x26, 0x00], [0xF3, 0x26, 0x00]),
+    ([0xF5, 0x26, 0x00], [0xF5, 0x26, 0x00]),
+    ([0xFA, 0x26, 0x00], [0xFA, 0x26, 0x00]),
+    ([0xFD, 0x26, 0x00], [0xFD, 0x26, 0x00]),
+    ([0x05, 0x27, 0x00], [0x05, 0x27, 0x00]),
+    ([0x0A, 0x27, 0x00], [0x0B, 0x27, 0x00]),
+    ([0x28, 0x27, 0x00], [0x28, 0x27, 0x00]),
+    ([0x4C, 0x27, 0x00], [0x4C, 0x27, 0x00]),
+    ([0x4E, 0x27, 0x00], [0x4E, 0x27, 0x00]),
+    ([0x53, 0x27, 0x00], [0x55, 0x27, 0x00]),
+    ([0x57, 0x27, 0x00], [0x57, 0x27, 0x00]),
+    ([0x95, 0x27, 0x00], [0x97, 0x27, 0x00]),
+    ([0xB0, 0x27, 0x00], [0xB0, 0x27, 0x00]),
+    ([0xBF, 0x27, 0x00], [0xBF, 0x27, 0x00]),
+    ([0x1B, 0x2B, 0x00], [0x1C, 0x2B, 0x00]),
+    ([0x50, 0x2B, 0x00], [0x50, 0x2B, 0x00]),
+    ([0x55, 0x2B, 0x00], [0x55, 0x2B, 0x00]),
+    ([0x80, 0x2E, 0x00], [0x99, 0x2E, 0x00]),
+    ([0x9B, 0x2E, 0x00], [0xF3, 0x2E, 0x00]),
+    ([0x00, 0x2F, 0x00], [0xD5, 0x2F, 0x00]),
+    ([0xF0, 0x2F, 0x00], [0x29, 0x30, 0x00]),
+    ([0x30, 0x30, 0x00], [0x3E, 0x30, 0x00]),
+    ([0x41, 0x30, 0x00], [0x96, 0x30, 0x00]),
+    ([0x9B, 0x30, 0x00], [0xFF, 0x30, 0x00]),
+    ([0x05, 0x31, 0x00], [0x2F, 0x31, 0x00]),
+    ([0x31, 0x31, 0x00], [0x63, 0x31, 0x00]),
+    ([0x65, 0x31, 0x00], [0x8E, 0x31, 0x00]),
+    ([0x90, 0x31, 0x00], [0xE5, 0x31, 0x00]),
+    ([0xEF, 0x31, 0x00], [0x1E, 0x32, 0x00]),
+    ([0x20, 0x32, 0x00], [0x47, 0x32, 0x00]),
+    ([0x50, 0x32, 0x00], [0x8C, 0xA4, 0x00]),
+    ([0x90, 0xA4, 0x00], [0xC6, 0xA4, 0x00]),
+    ([0x60, 0xA9, 0x00], [0x7C, 0xA9, 0x00]),
+    ([0x00, 0xAC, 0x00], [0xA3, 0xD7, 0x00]),
+    ([0x00, 0xF9, 0x00], [0xFF, 0xFA, 0x00]),
+    ([0x10, 0xFE, 0x00], [0x19, 0xFE, 0x00]),
+    ([0x30, 0xFE, 0x00], [0x52, 0xFE, 0x00]),
+    ([0x54, 0xFE, 0x00], [0x66, 0xFE, 0x00]),
+    ([0x68, 0xFE, 0x00], [0x6B, 0xFE, 0x00]),
+    ([0x01, 0xFF, 0x00], [0x60, 0xFF, 0x00]),
+    ([0xE0, 0xFF, 0x00], [0xE6, 0xFF, 0x00]),
+    ([0xE0, 0x6F, 0x01], [0xE3, 0x6F, 0x01]),
+    ([0xF2, 0x6F, 0x01], [0xF6, 0x6F, 0x01]),
+    ([0x00, 0x70, 0x01], [0xD5, 0x8C, 0x01]),
+    ([0xFF, 0x8C, 0x01], [0x1E, 0x8D, 0x01]),
+    ([0x80, 0x8D, 0x01], [0xF2, 0x8D, 0x01]),
+    ([0xF0, 0xAF, 0x01], [0xF3, 0xAF, 0x01]),
+    ([0xF5, 0xAF, 0x01], [0xFB, 0xAF, 0x01]),
+    ([0xFD, 0xAF, 0x01], [0xFE, 0xAF, 0x01]),
+    ([0x00, 0xB0, 0x01], [0x22, 0xB1, 0x01]),
+    ([0x32, 0xB1, 0x01], [0x32, 0xB1, 0x01]),
+    ([0x50, 0xB1, 0x01], [0x52, 0xB1, 0x01]),
+    ([0x55, 0xB1, 0x01], [0x55, 0xB1, 0x01]),
+    ([0x64, 0xB1, 0x01], [0x67, 0xB1, 0x01]),
+    ([0x70, 0xB1, 0x01], [0xFB, 0xB2, 0x01]),
+    ([0x00, 0xD3, 0x01], [0x56, 0xD3, 0x01]),
+    ([0x60, 0xD3, 0x01], [0x76, 0xD3, 0x01]),
+    ([0x04, 0xF0, 0x01], [0x04, 0xF0, 0x01]),
+    ([0xCF, 0xF0, 0x01], [0xCF, 0xF0, 0x01]),
+    ([0x8E, 0xF1, 0x01], [0x8E, 0xF1, 0x01]),
+    ([0x91, 0xF1, 0x01], [0x9A, 0xF1, 0x01]),
+    ([0xE6, 0xF1, 0x01], [0x02, 0xF2, 0x01]),
+    ([0x10, 0xF2, 0x01], [0x3B, 0xF2, 0x01]),
+    ([0x40, 0xF2, 0x01], [0x48, 0xF2, 0x01]),
+    ([0x50, 0xF2, 0x01], [0x51, 0xF2, 0x01]),
+    ([0x60, 0xF2, 0x01], [0x65, 0xF2, 0x01]),
+    ([0x00, 0xF3, 0x01], [0x20, 0xF3, 0x01]),
+    ([0x2D, 0xF3, 0x01], [0x35, 0xF3, 0x01]),
+    ([0x37, 0xF3, 0x01], [0x7C, 0xF3, 0x01]),
+    ([0x7E, 0xF3, 0x01], [0x93, 0xF3, 0x01]),
+    ([0xA0, 0xF3, 0x01], [0xCA, 0xF3, 0x01]),
+    ([0xCF, 0xF3, 0x01], [0xD3, 0xF3, 0x01]),
+    ([0xE0, 0xF3, 0x01], [0xF0, 0xF3, 0x01]),
+    ([0xF4, 0xF3, 0x01], [0xF4, 0xF3, 0x01]),
+    ([0xF8, 0xF3, 0x01], [0x3E, 0xF4, 0x01]),
+    ([0x40, 0xF4, 0x01], [0x40, 0xF4, 0x01]),
+    ([0x42, 0xF4, 0x01], [0xFC, 0xF4, 0x01]),
+    ([0xFF, 0xF4, 0x01], [0x3D, 0xF5, 0x01]),
+    ([0x4B, 0xF5, 0x01], [0x4E, 0xF5, 0x01]),
+    ([0x50, 0xF5, 0x01], [0x67, 0xF5, 0x01]),
+    ([0x7A, 0xF5, 0x01], [0x7A, 0xF5, 0x01]),
+    ([0x95, 0xF5, 0x01], [0x96, 0xF5, 0x01]),
+    ([0xA4, 0xF5, 0x01], [0xA4, 0xF5, 0x01]),
+    ([0xFB, 0xF5, 0x01], [0x4F, 0xF6, 0x01]),
+    ([0x80, 0xF6, 0x01], [0xC5, 0xF6, 0x01]),
+    ([0xCC, 0xF6, 0x01], [0xCC, 0xF6, 0x01]),
+    ([0xD0, 0xF6, 0x01], [0xD2, 0xF6, 0x01]),
+    ([0xD5, 0xF6, 0x01], [0xD8, 0xF6, 0x01]),
+    ([0xDC, 0xF6, 0x01], [0xDF, 0xF6, 0x01]),
+    ([0xEB, 0xF6, 0x01], [0xEC, 0xF6, 0x01]),
+    ([0xF4, 0xF6, 0x01], [0xFC, 0xF6, 0x01]),
+    ([0xE0, 0xF7, 0x01], [0xEB, 0xF7, 0x01]),
+    ([0xF0, 0xF7, 0x01], [0xF0, 0xF7, 0x01]),
+    ([0x0C, 0xF9, 0x01], [0x3A, 0xF9, 0x01]),
+    ([0x3C, 0xF9, 0x01], [0x45, 0xF9, 0x01]),
+    ([0x47, 0xF9, 0x01], [0xFF, 0xF9, 0x01]),
+    ([0x70, 0xFA, 0x01], [0x7C, 0xFA, 0x01]),
+    ([0x80, 0xFA, 0x01], [0x8A, 0xFA, 0x01]),
+    ([0x8E, 0xFA, 0x01], [0xC6, 0xFA, 0x01]),
+    ([0xC8, 0xFA, 0x01], [0xC8, 0xFA, 0x01]),
+    ([0xCD, 0xFA, 0x01], [0xDC, 0xFA, 0x01]),
+    ([0xDF, 0xFA, 0x01], [0xEA, 0xFA, 0x01]),
+    ([0xEF, 0xFA, 0x01], [0xF8, 0xFA, 0x01]),
+    ([0x00, 0x00, 0x02], [0xFD, 0xFF, 0x02]),
+    ([0x00, 0x00, 0x03], [0xFD, 0xFF, 0x03]),
+];
+
+/// Sorted list of codepoint ranges (inclusive) in the package's `AMBIGUOUS` table.
+#[cfg(feature = "cjk")]
+#[rustfmt::skip]
+pub static AMBIGUOUS: [([u8; 3], [u8; 3]); 176] = [
+    ([0xA1, 0x00, 0x00], [0xA1, 0x00, 0x00]),
+    ([0xA4, 0x00, 0x00], [0xA4, 0x00, 0x00]),
+    ([0xA7, 0x00, 0x00], [0xA8, 0x00, 0x00]),
+    ([0xAA, 0x00, 0x00], [0xAA, 0x00, 0x00]),
+    ([0xAD, 0x00, 0x00], [0xAE, 0x00, 0x00]),
+    ([0xB0, 0x00, 0x00], [0xB4, 0x00, 0x00]),
+    ([0xB6, 0x00, 0x00], [0xBA, 0x00, 0x00]),
+    ([0xBC, 0x00, 0x00], [0xBF, 0x00, 0x00]),
+    ([0xC6, 0x00, 0x00], [0xC6, 0x00, 0x00]),
+    ([0xD0, 0x00, 0x00], [0xD0, 0x00, 0x00]),
+    ([0xD7, 0x00, 0x00], [0xD8, 0x00, 0x00]),
+    ([0xDE, 0x00, 0x00], [0xE1, 0x00, 0x00]),
+    ([0xE6, 0x00, 0x00], [0xE6, 0x00, 0x00]),
+    ([0xE8, 0x00, 0x00], [0xEA, 0x00, 0x00]),
+    ([0xEC, 0x00, 0x00], [0xED, 0x00, 0x00]),
+    ([0xF0, 0x00, 0x00], [0xF0, 0x00, 0x00]),
+    ([0xF2, 0x00, 0x00], [0xF3, 0x00, 0x00]),
+    ([0xF7, 0x00, 0x00], [0xFA, 0x00, 0x00]),
+    ([0xFC, 0x00, 0x00], [0xFC, 0x00, 0x00]),
+    ([0xFE, 0x00, 0x00], [0xFE, 0x00, 0x00]),
+    ([0x01, 0x01, 0x00], [0x01, 0x01, 0x00]),
+    ([0x11, 0x01, 0x00], [0x11, 0x01, 0x00]),
+    ([0x13, 0x01, 0x00], [0x13, 0x01, 0x00]),
+    ([0x1B, 0x01, 0x00], [0x1B, 0x01, 0x00]),
+    ([0x26, 0x01, 0x00], [0x27, 0x01, 0x00]),
+    ([0x2B, 0x01, 0x00], [0x2B, 0x01, 0x00]),
+    ([0x31, 0x01, 0x00], [0x33, 0x01, 0x00]),
+    ([0x38, 0x01, 0x00], [0x38, 0x01, 0x00]),
+    ([0x3F, 0x01, 0x00], [0x42, 0x01, 0x00]),
+    ([0x44, 0x01, 0x00], [0x44, 0x01, 0x00]),
+    ([0x48, 0x01, 0x00], [0x4B, 0x01, 0x00]),
+    ([0x4D, 0x01, 0x00], [0x4D, 0x01, 0x00]),
+    ([0x52, 0x01, 0x00], [0x53, 0x01, 0x00]),
+    ([0x66, 0x01, 0x00], [0x67, 0x01, 0x00]),
+    ([0x6B, 0x01, 0x00], [0x6B, 0x01, 0x00]),
+    ([0xCE, 0x01, 0x00], [0xCE, 0x01, 0x00]),
+    ([0xD0, 0x01, 0x00], [0xD0, 0x01, 0x00]),
+    ([0xD2, 0x01, 0x00], [0xD2, 0x01, 0x00]),
+    ([0xD4, 0x01, 0x00], [0xD4, 0x01, 0x00]),
+    ([0xD6, 0x01, 0x00], [0xD6, 0x01, 0x00]),
+    ([0xD8, 0x01, 0x00], [0xD8, 0x01, 0x00]),
+    ([0xDA, 0x01, 0x00], [0xDA, 0x01, 0x00]),
+    ([0xDC, 0x01, 0x00], [0xDC, 0x01, 0x00]),
+    ([0x51, 0x02, 0x00], [0x51, 0x02, 0x00]),
+    ([0x61, 0x02, 0x00], [0x61, 0x02, 0x00]),
+    ([0xC4, 0x02, 0x00], [0xC4, 0x02, 0x00]),
+    ([0xC7, 0x02, 0x00], [0xC7, 0x02, 0x00]),
+    ([0xC9, 0x02, 0x00], [0xCB, 0x02, 0x00]),
+    ([0xCD, 0x02, 0x00], [0xCD, 0x02, 0x00]),
+    ([0xD0, 0x02, 0x00], [0xD0, 0x02, 0x00]),
+    ([0xD8, 0x02, 0x00], [0xDB, 0x02, 0x00]),
+    ([0xDD, 0x02, 0x00], [0xDD, 0x02, 0x00]),
+    ([0xDF, 0x02, 0x00], [0xDF, 0x02, 0x00]),
+    ([0x91, 0x03, 0x00], [0xA1, 0x03, 0x00]),
+    ([0xA3, 0x03, 0x00], [0xA9, 0x03, 0x00]),
+    ([0xB1, 0x03, 0x00], [0xC1, 0x03, 0x00]),
+    ([0xC3, 0x03, 0x00], [0xC9, 0x03, 0x00]),
+    ([0x01, 0x04, 0x00], [0x01, 0x04, 0x00]),
+    ([0x10, 0x04, 0x00], [0x4F, 0x04, 0x00]),
+    ([0x51, 0x04, 0x00], [0x51, 0x04, 0x00]),
+    ([0x10, 0x20, 0x00], [0x10, 0x20, 0x00]),
+    ([0x13, 0x20, 0x00], [0x16, 0x20, 0x00]),
+    ([0x18, 0x20, 0x00], [0x19, 0x20, 0x00]),
+    ([0x1C, 0x20, 0x00], [0x1D, 0x20, 0x00]),
+    ([0x20, 0x20, 0x00], [0x22, 0x20, 0x00]),
+    ([0x24, 0x20, 0x00], [0x27, 0x20, 0x00]),
+    ([0x30, 0x20, 0x00], [0x30, 0x20, 0x00]),
+    ([0x32, 0x20, 0x00], [0x33, 0x20, 0x00]),
+    ([0x35, 0x20, 0x00], [0x35, 0x20, 0x00]),
+    ([0x3B, 0x20, 0x00], [0x3B, 0x20, 0x00]),
+    ([0x3E, 0x20, 0x00], [0x3E, 0x20, 0x00]),
+    ([0x74, 0x20, 0x00], [0x74, 0x20, 0x00]),
+    ([0x7F, 0x20, 0x00], [0x7F, 0x20, 0x00]),
+    ([0x81, 0x20, 0x00], [0x84, 0x20, 0x00]),
+    ([0xAC, 0x20, 0x00], [0xAC, 0x20, 0x00]),
+    ([0x03, 0x21, 0x00], [0x03, 0x21, 0x00]),
+    ([0x05, 0x21, 0x00], [0x05, 0x21, 0x00]),
+    ([0x09, 0x21, 0x00], [0x09, 0x21, 0x00]),
+    ([0x13, 0x21, 0x00], [0x13, 0x21, 0x00]),
+    ([0x16, 0x21, 0x00], [0x16, 0x21, 0x00]),
+    ([0x21, 0x21, 0x00], [0x22, 0x21, 0x00]),
+    ([0x26, 0x21, 0x00], [0x26, 0x21, 0x00]),
+    ([0x2B, 0x21, 0x00], [0x2B, 0x21, 0x00]),
+    ([0x53, 0x21, 0x00], [0x54, 0x21, 0x00]),
+    ([0x5B, 0x21, 0x00], [0x5E, 0x21, 0x00]),
+    ([0x60, 0x21, 0x00], [0x6B, 0x21, 0x00]),
+    ([0x70, 0x21, 0x00], [0x79, 0x21, 0x00]),
+    ([0x89, 0x21, 0x00], [0x89, 0x21, 0x00]),
+    ([0x90, 0x21, 0x00], [0x99, 0x21, 0x00]),
+    ([0xB8, 0x21, 0x00], [0xB9, 0x21, 0x00]),
+    ([0xD2, 0x21, 0x00], [0xD2, 0x21, 0x00]),
+    ([0xD4, 0x21, 0x00], [0xD4, 0x21, 0x00]),
+    ([0xE7, 0x21, 0x00], [0xE7, 0x21, 0x00]),
+    ([0x00, 0x22, 0x00], [0x00, 0x22, 0x00]),
+    ([0x02, 0x22, 0x00], [0x03, 0x22, 0x00]),
+    ([0x07, 0x22, 0x00], [0x08, 0x22, 0x00]),
+    ([0x0B, 0x22, 0x00], [0x0B, 0x22, 0x00]),
+    ([0x0F, 0x22, 0x00], [0x0F, 0x22, 0x00]),
+    ([0x11, 0x22, 0x00], [0x11, 0x22, 0x00]),
+    ([0x15, 0x22, 0x00], [0x15, 0x22, 0x00]),
+    ([0x1A, 0x22, 0x00], [0x1A, 0x22, 0x00]),
+    ([0x1D, 0x22, 0x00], [0x20, 0x22, 0x00]),
+    ([0x23, 0x22, 0x00], [0x23, 0x22, 0x00]),
+    ([0x25, 0x22, 0x00], [0x25, 0x22, 0x00]),
+    ([0x27, 0x22, 0x00], [0x2C, 0x22, 0x00]),
+    ([0x2E, 0x22, 0x00], [0x2E, 0x22, 0x00]),
+    ([0x34, 0x22, 0x00], [0x37, 0x22, 0x00]),
+    ([0x3C, 0x22, 0x00], [0x3D, 0x22, 0x00]),
+    ([0x48, 0x22, 0x00], [0x48, 0x22, 0x00]),
+    ([0x4C, 0x22, 0x00], [0x4C, 0x22, 0x00]),
+    ([0x52, 0x22, 0x00], [0x52, 0x22, 0x00]),
+    ([0x60, 0x22, 0x00], [0x61, 0x22, 0x00]),
+    ([0x64, 0x22, 0x00], [0x67, 0x22, 0x00]),
+    ([0x6A, 0x22, 0x00], [0x6B, 0x22, 0x00]),
+    ([0x6E, 0x22, 0x00], [0x6F, 0x22, 0x00]),
+    ([0x82, 0x22, 0x00], [0x83, 0x22, 0x00]),
+    ([0x86, 0x22, 0x00], [0x87, 0x22, 0x00]),
+    ([0x95, 0x22, 0x00], [0x95, 0x22, 0x00]),
+    ([0x99, 0x22, 0x00], [0x99, 0x22, 0x00]),
+    ([0xA5, 0x22, 0x00], [0xA5, 0x22, 0x00]),
+    ([0xBF, 0x22, 0x00], [0xBF, 0x22, 0x00]),
+    ([0x12, 0x23, 0x00], [0x12, 0x23, 0x00]),
+    ([0x60, 0x24, 0x00], [0xE9, 0x24, 0x00]),
+    ([0xEB, 0x24, 0x00], [0x4B, 0x25, 0x00]),
+    ([0x50, 0x25, 0x00], [0x73, 0x25, 0x00]),
+    ([0x80, 0x25, 0x00], [0x8F, 0x25, 0x00]),
+    ([0x92, 0x25, 0x00], [0x95, 0x25, 0x00]),
+    ([0xA0, 0x25, 0x00], [0xA1, 0x25, 0x00]),
+    ([0xA3, 0x25, 0x00], [0xA9, 0x25, 0x00]),
+    ([0xB2, 0x25, 0x00], [0xB3, 0x25, 0x00]),
+    ([0xB6, 0x25, 0x00], [0xB7, 0x25, 0x00]),
+    ([0xBC, 0x25, 0x00], [0xBD, 0x25, 0x00]),
+    ([0xC0, 0x25, 0x00], [0xC1, 0x25, 0x00]),
+    ([0xC6, 0x25, 0x00], [0xC8, 0x25, 0x00]),
+    ([0xCB, 0x25, 0x00], [0xCB, 0x25, 0x00]),
+    ([0xCE, 0x25, 0x00], [0xD1, 0x25, 0x00]),
+    ([0xE2, 0x25, 0x00], [0xE5, 0x25, 0x00]),
+    ([0xEF, 0x25, 0x00], [0xEF, 0x25, 0x00]),
+    ([0x05, 0x26, 0x00], [0x06, 0x26, 0x00]),
+    ([0x09, 0x26, 0x00], [0x09, 0x26, 0x00]),
+    ([0x0E, 0x26, 0x00], [0x0F, 0x26, 0x00]),
+    ([0x1C, 0x26, 0x00], [0x1C, 0x26, 0x00]),
+    ([0x1E, 0x26, 0x00], [0x1E, 0x26, 0x00]),
+    ([0x40, 0x26, 0x00], [0x40, 0x26, 0x00]),
+    ([0x42, 0x26, 0x00], [0x42, 0x26, 0x00]),
+    ([0x60, 0x26, 0x00], [0x61, 0x26, 0x00]),
+    ([0x63, 0x26, 0x00], [0x65, 0x26, 0x00]),
+    ([0x67, 0x26, 0x00], [0x6A, 0x26, 0x00]),
+    ([0x6C, 0x26, 0x00], [0x6D, 0x26, 0x00]),
+    ([0x6F, 0x26, 0x00], [0x6F, 0x26, 0x00]),
+    ([0x9E, 0x26, 0x00], [0x9F, 0x26, 0x00]),
+    ([0xBF, 0x26, 0x00], [0xBF, 0x26, 0x00]),
+    ([0xC6, 0x26, 0x00], [0xCD, 0x26, 0x00]),
+    ([0xCF, 0x26, 0x00], [0xD3, 0x26, 0x00]),
+    ([0xD5, 0x26, 0x00], [0xE1, 0x26, 0x00]),
+    ([0xE3, 0x26, 0x00], [0xE3, 0x26, 0x00]),
+    ([0xE8, 0x26, 0x00], [0xE9, 0x26, 0x00]),
+    ([0xEB, 0x26, 0x00], [0xF1, 0x26, 0x00]),
+    ([0xF4, 0x26, 0x00], [0xF4, 0x26, 0x00]),
+    ([0xF6, 0x26, 0x00], [0xF9, 0x26, 0x00]),
+    ([0xFB, 0x26, 0x00], [0xFC, 0x26, 0x00]),
+    ([0xFE, 0x26, 0x00], [0xFF, 0x26, 0x00]),
+    ([0x3D, 0x27, 0x00], [0x3D, 0x27, 0x00]),
+    ([0x76, 0x27, 0x00], [0x7F, 0x27, 0x00]),
+    ([0x56, 0x2B, 0x00], [0x59, 0x2B, 0x00]),
+    ([0x48, 0x32, 0x00], [0x4F, 0x32, 0x00]),
+    ([0x00, 0xE0, 0x00], [0xFF, 0xF8, 0x00]),
+    ([0xFD, 0xFF, 0x00], [0xFD, 0xFF, 0x00]),
+    ([0x00, 0xF1, 0x01], [0x0A, 0xF1, 0x01]),
+    ([0x10, 0xF1, 0x01], [0x2D, 0xF1, 0x01]),
+    ([0x30, 0xF1, 0x01], [0x69, 0xF1, 0x01]),
+    ([0x70, 0xF1, 0x01], [0x8D, 0xF1, 0x01]),
+    ([0x8F, 0xF1, 0x01], [0x90, 0xF1, 0x01]),
+    ([0x9B, 0xF1, 0x01], [0xAC, 0xF1, 0x01]),
+    ([0x00, 0x00, 0x0F], [0xFD, 0xFF, 0x0F]),
+    ([0x00, 0x00, 0x10], [0xFD, 0xFF, 0x10]),
+];
+
+/// Sorted list of codepoint ranges (inclusive) in the package's `CATEGORY_MC` table.
+#[rustfmt::skip]
+pub static CATEGORY_MC: [([u8; 3], [u8; 3]); 193] = [
+    ([0x03, 0x09, 0x00], [0x03, 0x09, 0x00]),
+    ([0x3B, 0x09, 0x00], [0x3B, 0x09, 0x00]),
+    ([0x3E, 0x09, 0x00], [0x40, 0x09, 0x00]),
+    ([0x49, 0x09, 0x00], [0x4C, 0x09, 0x00]),
+    ([0x4E, 0x09, 0x00], [0x4F, 0x09, 0x00]),
+    ([0x82, 0x09, 0x00], [0x83, 0x09, 0x00]),
+    ([0xBE, 0x09, 0x00], [0xC0, 0x09, 0x00]),
+    ([0xC7, 0x09, 0x00], [0xC8, 0x09, 0x00]),
+    ([0xCB, 0x09, 0x00], [0xCC, 0x09, 0x00]),
+    ([0xD7, 0x09, 0x00], [0xD7, 0x09, 0x00]),
+    ([0x03, 0x0A, 0x00], [0x03, 0x0A, 0x00]),
+    ([0x3E, 0x0A, 0x00], [0x40, 0x0A, 0x00]),
+    ([0x83, 0x0A, 0x00], [0x83, 0x0A, 0x00]),
+    ([0xBE, 0x0A, 0x00], [0xC0, 0x0A, 0x00]),
+    ([0xC9, 0x0A, 0x00], [0xC9, 0x0A, 0x00]),
+    ([0xCB, 0x0A, 0x00], [0xCC, 0x0A, 0x00]),
+    ([0x02, 0x0B, 0x00], [0x03, 0x0B, 0x00]),
+    ([0x3E, 0x0B, 0x00], [0x3E, 0x0B, 0x00]),
+    ([0x40, 0x0B, 0x00], [0x40, 0x0B, 0x00]),
+    ([0x47, 0x0B, 0x00], [0x48, 0x0B, 0x00]),
+    ([0x4B, 0x0B, 0x00], [0x4C, 0x0B, 0x00]),
+    ([0x57, 0x0B, 0x00], [0x57, 0x0B, 0x00]),
+    ([0xBE, 0x0B, 0x00], [0xBF, 0x0B, 0x00]),
+    ([0xC1, 0x0B, 0x00], [0xC2, 0x0B, 0x00]),
+    ([0xC6, 0x0B, 0x00], [0xC8, 0x0B, 0x00]),
+    ([0xCA, 0x0B, 0x00], [0xCC, 0x0B, 0x00]),
+    ([0xD7, 0x0B, 0x00], [0xD7, 0x0B, 0x00]),
+    ([0x01, 0x0C, 0x00], [0x03, 0x0C, 0x00]),
+    ([0x41, 0x0C, 0x00], [0x44, 0x0C, 0x00]),
+    ([0x82, 0x0C, 0x00], [0x83, 0x0C, 0x00]),
+    ([0xBE, 0x0C, 0x00], [0xBE, 0x0C, 0x00]),
+    ([0xC0, 0x0C, 0x00], [0xC4, 0x0C, 0x00]),
+    ([0xC7, 0x0C, 0x00], [0xC8, 0x0C, 0x00]),
+    ([0xCA, 0x0C, 0x00], [0xCB, 0x0C, 0x00]),
+    ([0xD5, 0x0C, 0x00], [0xD6, 0x0C, 0x00]),
+    ([0xF3, 0x0C, 0x00], [0xF3, 0x0C, 0x00]),
+    ([0x02, 0x0D, 0x00], [0x03, 0x0D, 0x00]),
+    ([0x3E, 0x0D, 0x00], [0x40, 0x0D, 0x00]),
+    ([0x46, 0x0D, 0x00], [0x48, 0x0D, 0x00]),
+    ([0x4A, 0x0D, 0x00], [0x4C, 0x0D, 0x00]),
+    ([0x57, 0x0D, 0x00], [0x57, 0x0D, 0x00]),
+    ([0x82, 0x0D, 0x00], [0x83, 0x0D, 0x00]),
+    ([0xCF, 0x0D, 0x00], [0xD1, 0x0D, 0x00]),
+    ([0xD8, 0x0D, 0x00], [0xDF, 0x0D, 0x00]),
+    ([0xF2, 0x0D, 0x00], [0xF3, 0x0D, 0x00]),
+    ([0x3E, 0x0F, 0x00], [0x3F, 0x0F, 0x00]),
+    ([0x7F, 0x0F, 0x00], [0x7F, 0x0F, 0x00]),
+    ([0x2B, 0x10, 0x00], [0x2C, 0x10, 0x00]),
+    ([0x31, 0x10, 0x00], [0x31, 0x10, 0x00]),
+    ([0x38, 0x10, 0x00], [0x38, 0x10, 0x00]),
+    ([0x3B, 0x10, 0x00], [0x3C, 0x10, 0x00]),
+    ([0x56, 0x10, 0x00], [0x57, 0x10, 0x00]),
+    ([0x62, 0x10, 0x00], [0x64, 0x10, 0x00]),
+    ([0x67, 0x10, 0x00], [0x6D, 0x10, 0x00]),
+    ([0x83, 0x10, 0x00], [0x84, 0x10, 0x00]),
+    ([0x87, 0x10, 0x00], [0x8C, 0x10, 0x00]),
+    ([0x8F, 0x10, 0x00], [0x8F, 0x10, 0x00]),
+    ([0x9A, 0x10, 0x00], [0x9C, 0x10, 0x00]),
+    ([0x15, 0x17, 0x00], [0x15, 0x17, 0x00]),
+    ([0x34, 0x17, 0x00], [0x34, 0x17, 0x00]),
+    ([0xB6, 0x17, 0x00], [0xB6, 0x17, 0x00]),
+    ([0xBE, 0x17, 0x00], [0xC5, 0x17, 0x00]),
+    ([0xC7, 0x17, 0x00], [0xC8, 0x17, 0x00]),
+    ([0x23, 0x19, 0x00], [0x26, 0x19, 0x00]),
+    ([0x29, 0x19, 0x00], [0x2B, 0x19, 0x00]),
+    ([0x30, 0x19, 0x00], [0x31, 0x19, 0x00]),
+    ([0x33, 0x19, 0x00], [0x38, 0x19, 0x00]),
+    ([0x19, 0x1A, 0x00], [0x1A, 0x1A, 0x00]),
+    ([0x55, 0x1A, 0x00], [0x55, 0x1A, 0x00]),
+    ([0x57, 0x1A, 0x00], [0x57, 0x1A, 0x00]),
+    ([0x61, 0x1A, 0x00], [0x61, 0x1A, 0x00]),
+    ([0x63, 0x1A, 0x00], [0x64, 0x1A, 0x00]),
+    ([0x6D, 0x1A, 0x00], [0x72, 0x1A, 0x00]),
+    ([0x04, 0x1B, 0x00], [0x04, 0x1B, 0x00]),
+    ([0x35, 0x1B, 0x00], [0x35, 0x1B, 0x00]),
+    ([0x3B, 0x1B, 0x00], [0x3B, 0x1B, 0x00]),
+    ([0x3D, 0x1B, 0x00], [0x41, 0x1B, 0x00]),
+    ([0x43, 0x1B, 0x00], [0x44, 0x1B, 0x00]),
+    ([0x82, 0x1B, 0x00], [0x82, 0x1B, 0x00]),
+    ([0xA1, 0x1B, 0x00], [0xA1, 0x1B, 0x00]),
+    ([0xA6, 0x1B, 0x00], [0xA7, 0x1B, 0x00]),
+    ([0xAA, 0x1B, 0x00], [0xAA, 0x1B, 0x00]),
+    ([0xE7, 0x1B, 0x00], [0xE7, 0x1B, 0x00]),
+    ([0xEA, 0x1B, 0x00], [0xEC, 0x1B, 0x00]),
+    ([0xEE, 0x1B, 0x00], [0xEE, 0x1B, 0x00]),
+    ([0xF2, 0x1B, 0x00], [0xF3, 0x1B, 0x00]),
+    ([0x24, 0x1C, 0x00], [0x2B, 0x1C, 0x00]),
+    ([0x34, 0x1C, 0x00], [0x35, 0x1C, 0x00]),
+    ([0xE1, 0x1C, 0x00], [0xE1, 0x1C, 0x00]),
+    ([0xF7, 0x1C, 0x00], [0xF7, 0x1C, 0x00]),
+    ([0x2E, 0x30, 0x00], [0x2F, 0x30, 0x00]),
+    ([0x23, 0xA8, 0x00], [0x24, 0xA8, 0x00]),
+    ([0x27, 0xA8, 0x00], [0x27, 0xA8, 0x00]),
+    ([0x80, 0xA8, 0x00], [0x81, 0xA8, 0x00]),
+    ([0xB4, 0xA8, 0x00], [0xC3, 0xA8, 0x00]),
+    ([0x52, 0xA9, 0x00], [0x53, 0xA9, 0x00]),
+    ([0x83, 0xA9, 0x00], [0x83, 0xA9, 0x00]),
+    ([0xB4, 0xA9, 0x00], [0xB5, 0xA9, 0x00]),
+    ([0xBA, 0xA9, 0x00], [0xBB, 0xA9, 0x00]),
+    ([0xBE, 0xA9, 0x00], [0xC0, 0xA9, 0x00]),
+    ([0x2F, 0xAA, 0x00], [0x30, 0xAA, 0x00]),
+    ([0x33, 0xAA, 0x00], [0x34, 0xAA, 0x00]),
+    ([0x4D, 0xAA, 0x00], [0x4D, 0xAA, 0x00]),
+    ([0x7B, 0xAA, 0x00], [0x7B, 0xAA, 0x00]),
+    ([0x7D, 0xAA, 0x00], [0x7D, 0xAA, 0x00]),
+    ([0xEB, 0xAA, 0x00], [0xEB, 0xAA, 0x00]),
+    ([0xEE, 0xAA, 0x00], [0xEF, 0xAA, 0x00]),
+    ([0xF5, 0xAA, 0x00], [0xF5, 0xAA, 0x00]),
+    ([0xE3, 0xAB, 0x00], [0xE4, 0xAB, 0x00]),
+    ([0xE6, 0xAB, 0x00], [0xE7, 0xAB, 0x00]),
+    ([0xE9, 0xAB, 0x00], [0xEA, 0xAB, 0x00]),
+    ([0xEC, 0xAB, 0x00], [0xEC, 0xAB, 0x00]),
+    ([0x00, 0x10, 0x01], [0x00, 0x10, 0x01]),
+    ([0x02, 0x10, 0x01], [0x02, 0x10, 0x01]),
+    ([0x82, 0x10, 0x01], [0x82, 0x10, 0x01]),
+    ([0xB0, 0x10, 0x01], [0xB2, 0x10, 0x01]),
+    ([0xB7, 0x10, 0x01], [0xB8, 0x10, 0x01]),
+    ([0x2C, 0x11, 0x01], [0x2C, 0x11, 0x01]),
+    ([0x45, 0x11, 0x01], [0x46, 0x11, 0x01]),
+    ([0x82, 0x11, 0x01], [0x82, 0x11, 0x01]),
+    ([0xB3, 0x11, 0x01], [0xB5, 0x11, 0x01]),
+    ([0xBF, 0x11, 0x01], [0xC0, 0x11, 0x01]),
+    ([0xCE, 0x11, 0x01], [0xCE, 0x11, 0x01]),
+    ([0x2C, 0x12, 0x01], [0x2E, 0x12, 0x01]),
+    ([0x32, 0x12, 0x01], [0x33, 0x12, 0x01]),
+    ([0x35, 0x12, 0x01], [0x35, 0x12, 0x01]),
+    ([0xE0, 0x12, 0x01], [0xE2, 0x12, 0x01]),
+    ([0x02, 0x13, 0x01], [0x03, 0x13, 0x01]),
+    ([0x3E, 0x13, 0x01], [0x3F, 0x13, 0x01]),
+    ([0x41, 0x13, 0x01], [0x44, 0x13, 0x01]),
+    ([0x47, 0x13, 0x01], [0x48, 0x13, 0x01]),
+    ([0x4B, 0x13, 0x01], [0x4D, 0x13, 0x01]),
+    ([0x57, 0x13, 0x01], [0x57, 0x13, 0x01]),
+    ([0x62, 0x13, 0x01], [0x63, 0x13, 0x01]),
+    ([0xB8, 0x13, 0x01], [0xBA, 0x13, 0x01]),
+    ([0xC2, 0x13, 0x01], [0xC2, 0x13, 0x01]),
+    ([0xC5, 0x13, 0x01], [0xC5, 0x13, 0x01]),
+    ([0xC7, 0x13, 0x01], [0xCA, 0x13, 0x01]),
+    ([0xCC, 0x13, 0x01], [0xCD, 0x13, 0x01]),
+    ([0xCF, 0x13, 0x01], [0xCF, 0x13, 0x01]),
+    ([0x35, 0x14, 0x01], [0x37, 0x14, 0x01]),
+    ([0x40, 0x14, 0x01], [0x41, 0x14, 0x01]),
+    ([0x45, 0x14, 0x01], [0x45, 0x14, 0x01]),
+    ([0xB0, 0x14, 0x01], [0xB2, 0x14, 0x01]),
+    ([0xB9, 0x14, 0x01], [0xB9, 0x14, 0x01]),
+    ([0xBB, 0x14, 0x01], [0xBE, 0x14, 0x01]),
+    ([0xC1, 0x14, 0x01], [0xC1, 0x14, 0x01]),
+    ([0xAF, 0x15, 0x01], [0xB1, 0x15, 0x01]),
+    ([0xB8, 0x15, 0x01], [0xBB, 0x15, 0x01]),
+    ([0xBE, 0x15, 0x01], [0xBE, 0x15, 0x01]),
+    ([0x30, 0x16, 0x01], [0x32, 0x16, 0x01]),
+    ([0x3B, 0x16, 0x01], [0x3C, 0x16, 0x01]),
+    ([0x3E, 0x16, 0x01], [0x3E, 0x16, 0x01]),
+    ([0xAC, 0x16, 0x01], [0xAC, 0x16, 0x01]),
+    ([0xAE, 0x16, 0x01], [0xAF, 0x16, 0x01]),
+    ([0xB6, 0x16, 0x01], [0xB6, 0x16, 0x01]),
+    ([0x1E, 0x17, 0x01], [0x1E, 0x17, 0x01]),
+    ([0x20, 0x17, 0x01], [0x21, 0x17, 0x01]),
+    ([0x26, 0x17, 0x01], [0x26, 0x17, 0x01]),
+    ([0x2C, 0x18, 0x01], [0x2E, 0x18, 0x01]),
+    ([0x38, 0x18, 0x01], [0x38, 0x18, 0x01]),
+    ([0x30, 0x19, 0x01], [0x35, 0x19, 0x01]),
+    ([0x37, 0x19, 0x01], [0x38, 0x19, 0x01]),
+    ([0x3D, 0x19, 0x01], [0x3D, 0x19, 0x01]),
+    ([0x40, 0x19, 0x01], [0x40, 0x19, 0x01]),
+    ([0x42, 0x19, 0x01], [0x42, 0x19, 0x01]),
+    ([0xD1, 0x19, 0x01], [0xD3, 0x19, 0x01]),
+    ([0xDC, 0x19, 0x01], [0xDF, 0x19, 0x01]),
+    ([0xE4, 0x19, 0x01], [0xE4, 0x19, 0x01]),
+    ([0x39, 0x1A, 0x01], [0x39, 0x1A, 0x01]),
+    ([0x57, 0x1A, 0x01], [0x58, 0x1A, 0x01]),
+    ([0x97, 0x1A, 0x01], [0x97, 0x1A, 0x01]),
+    ([0x61, 0x1B, 0x01], [0x61, 0x1B, 0x01]),
+    ([0x65, 0x1B, 0x01], [0x65, 0x1B, 0x01]),
+    ([0x67, 0x1B, 0x01], [0x67, 0x1B, 0x01]),
+    ([0x2F, 0x1C, 0x01], [0x2F, 0x1C, 0x01]),
+    ([0x3E, 0x1C, 0x01], [0x3E, 0x1C, 0x01]),
+    ([0xA9, 0x1C, 0x01], [0xA9, 0x1C, 0x01]),
+    ([0xB1, 0x1C, 0x01], [0xB1, 0x1C, 0x01]),
+    ([0xB4, 0x1C, 0x01], [0xB4, 0x1C, 0x01]),
+    ([0x8A, 0x1D, 0x01], [0x8E, 0x1D, 0x01]),
+    ([0x93, 0x1D, 0x01], [0x94, 0x1D, 0x01]),
+    ([0x96, 0x1D, 0x01], [0x96, 0x1D, 0x01]),
+    ([0xF5, 0x1E, 0x01], [0xF6, 0x1E, 0x01]),
+    ([0x03, 0x1F, 0x01], [0x03, 0x1F, 0x01]),
+    ([0x34, 0x1F, 0x01], [0x35, 0x1F, 0x01]),
+    ([0x3E, 0x1F, 0x01], [0x3F, 0x1F, 0x01]),
+    ([0x41, 0x1F, 0x01], [0x41, 0x1F, 0x01]),
+    ([0x2A, 0x61, 0x01], [0x2C, 0x61, 0x01]),
+    ([0x51, 0x6F, 0x01], [0x87, 0x6F, 0x01]),
+    ([0xF0, 0x6F, 0x01], [0xF1, 0x6F, 0x01]),
+    ([0x65, 0xD1, 0x01], [0x66, 0xD1, 0x01]),
+    ([0x6D, 0xD1, 0x01], [0x72, 0xD1, 0x01]),
+];
+
+/// Sorted list of codepoint ranges (inclusive) in the package's `VS16_NARROW_TO_WIDE` table.
+#[rustfmt::skip]
+pub static VS16_NARROW_TO_WIDE: [([u8; 3], [u8; 3]); 113] = [
+    ([0x23, 0x00, 0x00], [0x23, 0x00, 0x00]),
+    ([0x2A, 0x00, 0x00], [0x2A, 0x00, 0x00]),
+    ([0x30, 0x00, 0x00], [0x39, 0x00, 0x00]),
+    ([0xA9, 0x00, 0x00], [0xA9, 0x00, 0x00]),
+    ([0xAE, 0x00, 0x00], [0xAE, 0x00, 0x00]),
+    ([0x3C, 0x20, 0x00], [0x3C, 0x20, 0x00]),
+    ([0x49, 0x20, 0x00], [0x49, 0x20, 0x00]),
+    ([0x22, 0x21, 0x00], [0x22, 0x21, 0x00]),
+    ([0x39, 0x21, 0x00], [0x39, 0x21, 0x00]),
+    ([0x94, 0x21, 0x00], [0x99, 0x21, 0x00]),
+    ([0xA9, 0x21, 0x00], [0xAA, 0x21, 0x00]),
+    ([0x28, 0x23, 0x00], [0x28, 0x23, 0x00]),
+    ([0xCF, 0x23, 0x00], [0xCF, 0x23, 0x00]),
+    ([0xED, 0x23, 0x00], [0xEF, 0x23, 0x00]),
+    ([0xF1, 0x23, 0x00], [0xF2, 0x23, 0x00]),
+    ([0xF8, 0x23, 0x00], [0xFA, 0x23, 0x00]),
+    ([0xC2, 0x24, 0x00], [0xC2, 0x24, 0x00]),
+    ([0xAA, 0x25, 0x00], [0xAB, 0x25, 0x00]),
+    ([0xB6, 0x25, 0x00], [0xB6, 0x25, 0x00]),
+    ([0xC0, 0x25, 0x00], [0xC0, 0x25, 0x00]),
+    ([0xFB, 0x25, 0x00], [0xFC, 0x25, 0x00]),
+    ([0x00, 0x26, 0x00], [0x04, 0x26, 0x00]),
+    ([0x0E, 0x26, 0x00], [0x0E, 0x26, 0x00]),
+    ([0x11, 0x26, 0x00], [0x11, 0x26, 0x00]),
+    ([0x18, 0x26, 0x00], [0x18, 0x26, 0x00]),
+    ([0x1D, 0x26, 0x00], [0x1D, 0x26, 0x00]),
+    ([0x20, 0x26, 0x00], [0x20, 0x26, 0x00]),
+    ([0x22, 0x26, 0x00], [0x23, 0x26, 0x00]),
+    ([0x26, 0x26, 0x00], [0x26, 0x26, 0x00]),
+    ([0x2A, 0x26, 0x00], [0x2A, 0x26, 0x00]),
+    ([0x2E, 0x26, 0x00], [0x2F, 0x26, 0x00]),
+    ([0x38, 0x26, 0x00], [0x3A, 0x26, 0x00]),
+    ([0x40, 0x26, 0x00], [0x40, 0x26, 0x00]),
+    ([0x42, 0x26, 0x00], [0x42, 0x26, 0x00]),
+    ([0x5F, 0x26, 0x00], [0x60, 0x26, 0x00]),
+    ([0x63, 0x26, 0x00], [0x63, 0x26, 0x00]),
+    ([0x65, 0x26, 0x00], [0x66, 0x26, 0x00]),
+    ([0x68, 0x26, 0x00], [0x68, 0x26, 0x00]),
+    ([0x7B, 0x26, 0x00], [0x7B, 0x26, 0x00]),
+    ([0x7E, 0x26, 0x00], [0x7E, 0x26, 0x00]),
+    ([0x92, 0x26, 0x00], [0x92, 0x26, 0x00]),
+    ([0x94, 0x26, 0x00], [0x97, 0x26, 0x00]),
+    ([0x99, 0x26, 0x00], [0x99, 0x26, 0x00]),
+    ([0x9B, 0x26, 0x00], [0x9C, 0x26, 0x00]),
+    ([0xA0, 0x26, 0x00], [0xA0, 0x26, 0x00]),
+    ([0xA7, 0x26, 0x00], [0xA7, 0x26, 0x00]),
+    ([0xB0, 0x26, 0x00], [0xB1, 0x26, 0x00]),
+    ([0xC8, 0x26, 0x00], [0xC8, 0x26, 0x00]),
+    ([0xCF, 0x26, 0x00], [0xCF, 0x26, 0x00]),
+    ([0xD1, 0x26, 0x00], [0xD1, 0x26, 0x00]),
+    ([0xD3, 0x26, 0x00], [0xD3, 0x26, 0x00]),
+    ([0xE9, 0x26, 0x00], [0xE9, 0x26, 0x00]),
+    ([0xF0, 0x26, 0x00], [0xF1, 0x26, 0x00]),
+    ([0xF4, 0x26, 0x00], [0xF4, 0x26, 0x00]),
+    ([0xF7, 0x26, 0x00], [0xF9, 0x26, 0x00]),
+    ([0x02, 0x27, 0x00], [0x02, 0x27, 0x00]),
+    ([0x08, 0x27, 0x00], [0x09, 0x27, 0x00]),
+    ([0x0C, 0x27, 0x00], [0x0D, 0x27, 0x00]),
+    ([0x0F, 0x27, 0x00], [0x0F, 0x27, 0x00]),
+    ([0x12, 0x27, 0x00], [0x12, 0x27, 0x00]),
+    ([0x14, 0x27, 0x00], [0x14, 0x27, 0x00]),
+    ([0x16, 0x27, 0x00], [0x16, 0x27, 0x00]),
+    ([0x1D, 0x27, 0x00], [0x1D, 0x27, 0x00]),
+    ([0x21, 0x27, 0x00], [0x21, 0x27, 0x00]),
+    ([0x33, 0x27, 0x00], [0x34, 0x27, 0x00]),
+    ([0x44, 0x27, 0x00], [0x44, 0x27, 0x00]),
+    ([0x47, 0x27, 0x00], [0x47, 0x27, 0x00]),
+    ([0x63, 0x27, 0x00], [0x64, 0x27, 0x00]),
+    ([0xA1, 0x27, 0x00], [0xA1, 0x27, 0x00]),
+    ([0x34, 0x29, 0x00], [0x35, 0x29, 0x00]),
+    ([0x05, 0x2B, 0x00], [0x07, 0x2B, 0x00]),
+    ([0x70, 0xF1, 0x01], [0x71, 0xF1, 0x01]),
+    ([0x7E, 0xF1, 0x01], [0x7F, 0xF1, 0x01]),
+    ([0x21, 0xF3, 0x01], [0x21, 0xF3, 0x01]),
+    ([0x24, 0xF3, 0x01], [0x2C, 0xF3, 0x01]),
+    ([0x36, 0xF3, 0x01], [0x36, 0xF3, 0x01]),
+    ([0x7D, 0xF3, 0x01], [0x7D, 0xF3, 0x01]),
+    ([0x96, 0xF3, 0x01], [0x97, 0xF3, 0x01]),
+    ([0x99, 0xF3, 0x01], [0x9B, 0xF3, 0x01]),
+    ([0x9E, 0xF3, 0x01], [0x9F, 0xF3, 0x01]),
+    ([0xCB, 0xF3, 0x01], [0xCE, 0xF3, 0x01]),
+    ([0xD4, 0xF3, 0x01], [0xDF, 0xF3, 0x01]),
+    ([0xF3, 0xF3, 0x01], [0xF3, 0xF3, 0x01]),
+    ([0xF5, 0xF3, 0x01], [0xF5, 0xF3, 0x01]),
+    ([0xF7, 0xF3, 0x01], [0xF7, 0xF3, 0x01]),
+    ([0x3F, 0xF4, 0x01], [0x3F, 0xF4, 0x01]),
+    ([0x41, 0xF4, 0x01], [0x41, 0xF4, 0x01]),
+    ([0xFD, 0xF4, 0x01], [0xFD, 0xF4, 0x01]),
+    ([0x49, 0xF5, 0x01], [0x4A, 0xF5, 0x01]),
+    ([0x6F, 0xF5, 0x01], [0x70, 0xF5, 0x01]),
+    ([0x73, 0xF5, 0x01], [0x79, 0xF5, 0x01]),
+    ([0x87, 0xF5, 0x01], [0x87, 0xF5, 0x01]),
+    ([0x8A, 0xF5, 0x01], [0x8D, 0xF5, 0x01]),
+    ([0x90, 0xF5, 0x01], [0x90, 0xF5, 0x01]),
+    ([0xA5, 0xF5, 0x01], [0xA5, 0xF5, 0x01]),
+    ([0xA8, 0xF5, 0x01], [0xA8, 0xF5, 0x01]),
+    ([0xB1, 0xF5, 0x01], [0xB2, 0xF5, 0x01]),
+    ([0xBC, 0xF5, 0x01], [0xBC, 0xF5, 0x01]),
+    ([0xC2, 0xF5, 0x01], [0xC4, 0xF5, 0x01]),
+    ([0xD1, 0xF5, 0x01], [0xD3, 0xF5, 0x01]),
+    ([0xDC, 0xF5, 0x01], [0xDE, 0xF5, 0x01]),
+    ([0xE1, 0xF5, 0x01], [0xE1, 0xF5, 0x01]),
+    ([0xE3, 0xF5, 0x01], [0xE3, 0xF5, 0x01]),
+    ([0xE8, 0xF5, 0x01], [0xE8, 0xF5, 0x01]),
+    ([0xEF, 0xF5, 0x01], [0xEF, 0xF5, 0x01]),
+    ([0xF3, 0xF5, 0x01], [0xF3, 0xF5, 0x01]),
+    ([0xFA, 0xF5, 0x01], [0xFA, 0xF5, 0x01]),
+    ([0xCB, 0xF6, 0x01], [0xCB, 0xF6, 0x01]),
+    ([0xCD, 0xF6, 0x01], [0xCF, 0xF6, 0x01]),
+    ([0xE0, 0xF6, 0x01], [0xE5, 0xF6, 0x01]),
+    ([0xE9, 0xF6, 0x01], [0xE9, 0xF6, 0x01]),
+    ([0xF0, 0xF6, 0x01], [0xF0, 0xF6, 0x01]),
+    ([0xF3, 0xF6, 0x01], [0xF3, 0xF6, 0x01]),
+];
+
+/// Sorted list of codepoint ranges (inclusive) in the package's `ISC_CONSONANT` table.
+#[rustfmt::skip]
+pub static ISC_CONSONANT: [([u8; 3], [u8; 3]); 162] = [
+    ([0x15, 0x09, 0x00], [0x39, 0x09, 0x00]),
+    ([0x58, 0x09, 0x00], [0x5F, 0x09, 0x00]),
+    ([0x78, 0x09, 0x00], [0x7F, 0x09, 0x00]),
+    ([0x95, 0x09, 0x00], [0xA8, 0x09, 0x00]),
+    ([0xAA, 0x09, 0x00], [0xB0, 0x09, 0x00]),
+    ([0xB2, 0x09, 0x00], [0xB2, 0x09, 0x00]),
+    ([0xB6, 0x09, 0x00], [0xB9, 0x09, 0x00]),
+    ([0xDC, 0x09, 0x00], [0xDD, 0x09, 0x00]),
+    ([0xDF, 0x09, 0x00], [0xDF, 0x09, 0x00]),
+    ([0xF0, 0x09, 0x00], [0xF1, 0x09, 0x00]),
+    ([0x15, 0x0A, 0x00], [0x28, 0x0A, 0x00]),
+    ([0x2A, 0x0A, 0x00], [0x30, 0x0A, 0x00]),
+    ([0x32, 0x0A, 0x00], [0x33, 0x0A, 0x00]),
+    ([0x35, 0x0A, 0x00], [0x36, 0x0A, 0x00]),
+    ([0x38, 0x0A, 0x00], [0x39, 0x0A, 0x00]),
+    ([0x59, 0x0A, 0x00], [0x5C, 0x0A, 0x00]),
+    ([0x5E, 0x0A, 0x00], [0x5E, 0x0A, 0x00]),
+    ([0x95, 0x0A, 0x00], [0xA8, 0x0A, 0x00]),
+    ([0xAA, 0x0A, 0x00], [0xB0, 0x0A, 0x00]),
+    ([0xB2, 0x0A, 0x00], [0xB3, 0x0A, 0x00]),
+    ([0xB5, 0x0A, 0x00], [0xB9, 0x0A, 0x00]),
+    ([0xF9, 0x0A, 0x00], [0xF9, 0x0A, 0x00]),
+    ([0x15, 0x0B, 0x00], [0x28, 0x0B, 0x00]),
+    ([0x2A, 0x0B, 0x00], [0x30, 0x0B, 0x00]),
+    ([0x32, 0x0B, 0x00], [0x33, 0x0B, 0x00]),
+    ([0x35, 0x0B, 0x00], [0x39, 0x0B, 0x00]),
+    ([0x5C, 0x0B, 0x00], [0x5D, 0x0B, 0x00]),
+    ([0x5F, 0x0B, 0x00], [0x5F, 0x0B, 0x00]),
+    ([0x71, 0x0B, 0x00], [0x71, 0x0B, 0x00]),
+    ([0x95, 0x0B, 0x00], [0x95, 0x0B, 0x00]),
+    ([0x99, 0x0B, 0x00], [0x9A, 0x0B, 0x00]),
+    ([0x9C, 0x0B, 0x00], [0x9C, 0x0B, 0x00]),
+    ([0x9E, 0x0B, 0x00], [0x9F, 0x0B, 0x00]),
+    ([0xA3, 0x0B, 0x00], [0xA4, 0x0B, 0x00]),
+    ([0xA8, 0x0B, 0x00], [0xAA, 0x0B, 0x00]),
+    ([0xAE, 0x0B, 0x00], [0xB9, 0x0B, 0x00]),
+    ([0x15, 0x0C, 0x00], [0x28, 0x0C, 0x00]),
+    ([0x2A, 0x0C, 0x00], [0x39, 0x0C, 0x00]),
+    ([0x58, 0x0C, 0x00], [0x5A, 0x0C, 0x00]),
+    ([0x95, 0x0C, 0x00], [0xA8, 0x0C, 0x00]),
+    ([0xAA, 0x0C, 0x00], [0xB3, 0x0C, 0x00]),
+    ([0xB5, 0x0C, 0x00], [0xB9, 0x0C, 0x00]),
+    ([0xDE, 0x0C, 0x00], [0xDE, 0x0C, 0x00]),
+    ([0x15, 0x0D, 0x00], [0x3A, 0x0D, 0x00]),
+    ([0x9A, 0x0D, 0x00], [0xB1, 0x0D, 0x00]),
+    ([0xB3, 0x0D, 0x00], [0xBB, 0x0D, 0x00]),
+    ([0xBD, 0x0D, 0x00], [0xBD, 0x0D, 0x00]),
+    ([0xC0, 0x0D, 0x00], [0xC6, 0x0D, 0x00]),
+    ([0x01, 0x0E, 0x00], [0x2E, 0x0E, 0x00]),
+    ([0x81, 0x0E, 0x00], [0x82, 0x0E, 0x00]),
+    ([0x84, 0x0E, 0x00], [0x84, 0x0E, 0x00]),
+    ([0x86, 0x0E, 0x00], [0x8A, 0x0E, 0x00]),
+    ([0x8C, 0x0E, 0x00], [0xA3, 0x0E, 0x00]),
+    ([0xA5, 0x0E, 0x00], [0xA5, 0x0E, 0x00]),
+    ([0xA7, 0x0E, 0x00], [0xAE, 0x0E, 0x00]),
+    ([0xDC, 0x0E, 0x00], [0xDF, 0x0E, 0x00]),
+    ([0x40, 0x0F, 0x00], [0x47, 0x0F, 0x00]),
+    ([0x49, 0x0F, 0x00], [0x6C, 0x0F, 0x00]),
+    ([0x00, 0x10, 0x00], [0x20, 0x10, 0x00]),
+    ([0x3F, 0x10, 0x00], [0x3F, 0x10, 0x00]),
+    ([0x50, 0x10, 0x00], [0x51, 0x10, 0x00]),
+    ([0x5A, 0x10, 0x00], [0x5D, 0x10, 0x00]),
+    ([0x61, 0x10, 0x00], [0x61, 0x10, 0x00]),
+    ([0x65, 0x10, 0x00], [0x66, 0x10, 0x00]),
+    ([0x6E, 0x10, 0x00], [0x70, 0x10, 0x00]),
+    ([0x75, 0x10, 0x00], [0x81, 0x10, 0x00]),
+    ([0x8E, 0x10, 0x00], [0x8E, 0x10, 0x00]),
+    ([0x03, 0x17, 0x00], [0x11, 0x17, 0x00]),
+    ([0x1F, 0x17, 0x00], [0x1F, 0x17, 0x00]),
+    ([0x23, 0x17, 0x00], [0x31, 0x17, 0x00]),
+    ([0x43, 0x17, 0x00], [0x51, 0x17, 0x00]),
+    ([0x63, 0x17, 0x00], [0x6C, 0x17, 0x00]),
+    ([0x6E, 0x17, 0x00], [0x70, 0x17, 0x00]),
+    ([0x80, 0x17, 0x00], [0xA2, 0x17, 0x00]),
+    ([0x00, 0x19, 0x00], [0x1E, 0x19, 0x00]),
+    ([0x50, 0x19, 0x00], [0x62, 0x19, 0x00]),
+    ([0x80, 0x19, 0x00], [0xAB, 0x19, 0x00]),
+    ([0x00, 0x1A, 0x00], [0x16, 0x1A, 0x00]),
+    ([0x20, 0x1A, 0x00], [0x4C, 0x1A, 0x00]),
+    ([0x53, 0x1A, 0x00], [0x54, 0x1A, 0x00]),
+    ([0x13, 0x1B, 0x00], [0x33, 0x1B, 0x00]),
+    ([0x45, 0x1B, 0x00], [0x4C, 0x1B, 0x00]),
+    ([0x8A, 0x1B, 0x00], [0xA0, 0x1B, 0x00]),
+    ([0xAE, 0x1B, 0x00], [0xAF, 0x1B, 0x00]),
+    ([0xBB, 0x1B, 0x00], [0xBD, 0x1B, 0x00]),
+    ([0xC0, 0x1B, 0x00], [0xE3, 0x1B, 0x00]),
+    ([0x00, 0x1C, 0x00], [0x23, 0x1C, 0x00]),
+    ([0x4D, 0x1C, 0x00], [0x4F, 0x1C, 0x00]),
+    ([0x07, 0xA8, 0x00], [0x0A, 0xA8, 0x00]),
+    ([0x0C, 0xA8, 0x00], [0x22, 0xA8, 0x00]),
+    ([0x40, 0xA8, 0x00], [0x5D, 0xA8, 0x00]),
+    ([0x62, 0xA8, 0x00], [0x65, 0xA8, 0x00]),
+    ([0x69, 0xA8, 0x00], [0x70, 0xA8, 0x00]),
+    ([0x72, 0xA8, 0x00], [0x72, 0xA8, 0x00]),
+    ([0x92, 0xA8, 0x00], [0xB3, 0xA8, 0x00]),
+    ([0x0A, 0xA9, 0x00], [0x21, 0xA9, 0x00]),
+    ([0x30, 0xA9, 0x00], [0x46, 0xA9, 0x00]),
+    ([0x89, 0xA9, 0x00], [0x8B, 0xA9, 0x00]),
+    ([0x8F, 0xA9, 0x00], [0xB2, 0xA9, 0x00]),
+    ([0xE0, 0xA9, 0x00], [0xE4, 0xA9, 0x00]),
+    ([0xE7, 0xA9, 0x00], [0xEF, 0xA9, 0x00]),
+    ([0xFA, 0xA9, 0x00], [0xFE, 0xA9, 0x00]),
+    ([0x06, 0xAA, 0x00], [0x28, 0xAA, 0x00]),
+    ([0x60, 0xAA, 0x00], [0x6F, 0xAA, 0x00]),
+    ([0x71, 0xAA, 0x00], [0x73, 0xAA, 0x00]),
+    ([0x7A, 0xAA, 0x00], [0x7A, 0xAA, 0x00]),
+    ([0x7E, 0xAA, 0x00], [0xAF, 0xAA, 0x00]),
+    ([0xE2, 0xAA, 0x00], [0xEA, 0xAA, 0x00]),
+    ([0xC0, 0xAB, 0x00], [0xCD, 0xAB, 0x00]),
+    ([0xD0, 0xAB, 0x00], [0xD0, 0xAB, 0x00]),
+    ([0xD2, 0xAB, 0x00], [0xDA, 0xAB, 0x00]),
+    ([0x00, 0x0A, 0x01], [0x00, 0x0A, 0x01]),
+    ([0x10, 0x0A, 0x01], [0x13, 0x0A, 0x01]),
+    ([0x15, 0x0A, 0x01], [0x17, 0x0A, 0x01]),
+    ([0x19, 0x0A, 0x01], [0x35, 0x0A, 0x01]),
+    ([0x13, 0x10, 0x01], [0x37, 0x10, 0x01]),
+    ([0x75, 0x10, 0x01], [0x75, 0x10, 0x01]),
+    ([0x8D, 0x10, 0x01], [0xAF, 0x10, 0x01]),
+    ([0x07, 0x11, 0x01], [0x26, 0x11, 0x01]),
+    ([0x44, 0x11, 0x01], [0x44, 0x11, 0x01]),
+    ([0x47, 0x11, 0x01], [0x47, 0x11, 0x01]),
+    ([0x55, 0x11, 0x01], [0x72, 0x11, 0x01]),
+    ([0x91, 0x11, 0x01], [0xB2, 0x11, 0x01]),
+    ([0x08, 0x12, 0x01], [0x11, 0x12, 0x01]),
+    ([0x13, 0x12, 0x01], [0x2B, 0x12, 0x01]),
+    ([0x3F, 0x12, 0x01], [0x3F, 0x12, 0x01]),
+    ([0x84, 0x12, 0x01], [0x86, 0x12, 0x01]),
+    ([0x88, 0x12, 0x01], [0x88, 0x12, 0x01]),
+    ([0x8A, 0x12, 0x01], [0x8D, 0x12, 0x01]),
+    ([0x8F, 0x12, 0x01], [0x9D, 0x12, 0x01]),
+    ([0x9F, 0x12, 0x01], [0xA8, 0x12, 0x01]),
+    ([0xBA, 0x12, 0x01], [0xDE, 0x12, 0x01]),
+    ([0x15, 0x13, 0x01], [0x28, 0x13, 0x01]),
+    ([0x2A, 0x13, 0x01], [0x30, 0x13, 0x01]),
+    ([0x32, 0x13, 0x01], [0x33, 0x13, 0x01]),
+    ([0x35, 0x13, 0x01], [0x39, 0x13, 0x01]),
+    ([0x92, 0x13, 0x01], [0xB5, 0x13, 0x01]),
+    ([0x0E, 0x14, 0x01], [0x34, 0x14, 0x01]),
+    ([0x8F, 0x14, 0x01], [0xAF, 0x14, 0x01]),
+    ([0x8E, 0x15, 0x01], [0xAE, 0x15, 0x01]),
+    ([0x0E, 0x16, 0x01], [0x2F, 0x16, 0x01]),
+    ([0x8A, 0x16, 0x01], [0xAA, 0x16, 0x01]),
+    ([0xB8, 0x16, 0x01], [0xB8, 0x16, 0x01]),
+    ([0x00, 0x17, 0x01], [0x1A, 0x17, 0x01]),
+    ([0x40, 0x17, 0x01], [0x46, 0x17, 0x01]),
+    ([0x0A, 0x18, 0x01], [0x2B, 0x18, 0x01]),
+    ([0x0C, 0x19, 0x01], [0x13, 0x19, 0x01]),
+    ([0x15, 0x19, 0x01], [0x16, 0x19, 0x01]),
+    ([0x18, 0x19, 0x01], [0x2F, 0x19, 0x01]),
+    ([0xAE, 0x19, 0x01], [0xD0, 0x19, 0x01]),
+    ([0x00, 0x1A, 0x01], [0x00, 0x1A, 0x01]),
+    ([0x0B, 0x1A, 0x01], [0x32, 0x1A, 0x01]),
+    ([0x50, 0x1A, 0x01], [0x50, 0x1A, 0x01]),
+    ([0x5C, 0x1A, 0x01], [0x83, 0x1A, 0x01]),
+    ([0x0E, 0x1C, 0x01], [0x2E, 0x1C, 0x01]),
+    ([0x72, 0x1C, 0x01], [0x8F, 0x1C, 0x01]),
+    ([0x0C, 0x1D, 0x01], [0x30, 0x1D, 0x01]),
+    ([0x6C, 0x1D, 0x01], [0x89, 0x1D, 0x01]),
+    ([0xE0, 0x1E, 0x01], [0xF1, 0x1E, 0x01]),
+    ([0x12, 0x1F, 0x01], [0x33, 0x1F, 0x01]),
+    ([0x01, 0x61, 0x01], [0x1D, 0x61, 0x01]),
+    ([0x43, 0x6D, 0x01], [0x62, 0x6D, 0x01]),
+];
+
+/// Sorted list of codepoint ranges (inclusive) in the package's `ISC_VIRAMA` table.
+#[rustfmt::skip]
+pub static ISC_VIRAMA: [([u8; 3], [u8; 3]); 27] = [
+    ([0x4D, 0x09, 0x00], [0x4D, 0x09, 0x00]),
+    ([0xCD, 0x09, 0x00], [0xCD, 0x09, 0x00]),
+    ([0x4D, 0x0A, 0x00], [0x4D, 0x0A, 0x00]),
+    ([0xCD, 0x0A, 0x00], [0xCD, 0x0A, 0x00]),
+    ([0x4D, 0x0B, 0x00], [0x4D, 0x0B, 0x00]),
+    ([0xCD, 0x0B, 0x00], [0xCD, 0x0B, 0x00]),
+    ([0x4D, 0x0C, 0x00], [0x4D, 0x0C, 0x00]),
+    ([0xCD, 0x0C, 0x00], [0xCD, 0x0C, 0x00]),
+    ([0x4D, 0x0D, 0x00], [0x4D, 0x0D, 0x00]),
+    ([0xCA, 0x0D, 0x00], [0xCA, 0x0D, 0x00]),
+    ([0x44, 0x1B, 0x00], [0x44, 0x1B, 0x00]),
+    ([0x06, 0xA8, 0x00], [0x06, 0xA8, 0x00]),
+    ([0xC4, 0xA8, 0x00], [0xC4, 0xA8, 0x00]),
+    ([0xC0, 0xA9, 0x00], [0xC0, 0xA9, 0x00]),
+    ([0x46, 0x10, 0x01], [0x46, 0x10, 0x01]),
+    ([0xB9, 0x10, 0x01], [0xB9, 0x10, 0x01]),
+    ([0xC0, 0x11, 0x01], [0xC0, 0x11, 0x01]),
+    ([0x35, 0x12, 0x01], [0x35, 0x12, 0x01]),
+    ([0x4D, 0x13, 0x01], [0x4D, 0x13, 0x01]),
+    ([0x42, 0x14, 0x01], [0x42, 0x14, 0x01]),
+    ([0xC2, 0x14, 0x01], [0xC2, 0x14, 0x01]),
+    ([0xBF, 0x15, 0x01], [0xBF, 0x15, 0x01]),
+    ([0x3F, 0x16, 0x01], [0x3F, 0x16, 0x01]),
+    ([0xB6, 0x16, 0x01], [0xB6, 0x16, 0x01]),
+    ([0x39, 0x18, 0x01], [0x39, 0x18, 0x01]),
+    ([0xE0, 0x19, 0x01], [0xE0, 0x19, 0x01]),
+    ([0x3F, 0x1C, 0x01], [0x3F, 0x1C, 0x01]),
+];
+
+/// Sorted list of codepoint ranges (inclusive) in the package's `EMOJI_ZWJ` table.
+#[rustfmt::skip]
+pub static EMOJI_ZWJ: [([u8; 3], [u8; 3]); 156] = [
+    ([0xA9, 0x00, 0x00], [0xA9, 0x00, 0x00]),
+    ([0xAE, 0x00, 0x00], [0xAE, 0x00, 0x00]),
+    ([0x3C, 0x20, 0x00], [0x3C, 0x20, 0x00]),
+    ([0x49, 0x20, 0x00], [0x49, 0x20, 0x00]),
+    ([0x22, 0x21, 0x00], [0x22, 0x21, 0x00]),
+    ([0x39, 0x21, 0x00], [0x39, 0x21, 0x00]),
+    ([0x94, 0x21, 0x00], [0x99, 0x21, 0x00]),
+    ([0xA9, 0x21, 0x00], [0xAA, 0x21, 0x00]),
+    ([0x1A, 0x23, 0x00], [0x1B, 0x23, 0x00]),
+    ([0x28, 0x23, 0x00], [0x28, 0x23, 0x00]),
+    ([0xCF, 0x23, 0x00], [0xCF, 0x23, 0x00]),
+    ([0xE9, 0x23, 0x00], [0xF3, 0x23, 0x00]),
+    ([0xF8, 0x23, 0x00], [0xFA, 0x23, 0x00]),
+    ([0xC2, 0x24, 0x00], [0xC2, 0x24, 0x00]),
+    ([0xAA, 0x25, 0x00], [0xAB, 0x25, 0x00]),
+    ([0xB6, 0x25, 0x00], [0xB6, 0x25, 0x00]),
+    ([0xC0, 0x25, 0x00], [0xC0, 0x25, 0x00]),
+    ([0xFB, 0x25, 0x00], [0xFE, 0x25, 0x00]),
+    ([0x00, 0x26, 0x00], [0x04, 0x26, 0x00]),
+    ([0x0E, 0x26, 0x00], [0x0E, 0x26, 0x00]),
+    ([0x11, 0x26, 0x00], [0x11, 0x26, 0x00]),
+    ([0x14, 0x26, 0x00], [0x15, 0x26, 0x00]),
+    ([0x18, 0x26, 0x00], [0x18, 0x26, 0x00]),
+    ([0x1D, 0x26, 0x00], [0x1D, 0x26, 0x00]),
+    ([0x20, 0x26, 0x00], [0x20, 0x26, 0x00]),
+    ([0x22, 0x26, 0x00], [0x23, 0x26, 0x00]),
+    ([0x26, 0x26, 0x00], [0x26, 0x26, 0x00]),
+    ([0x2A, 0x26, 0x00], [0x2A, 0x26, 0x00]),
+    ([0x2E, 0x26, 0x00], [0x2F, 0x26, 0x00]),
+    ([0x38, 0x26, 0x00], [0x3A, 0x26, 0x00]),
+    ([0x40, 0x26, 0x00], [0x40, 0x26, 0x00]),
+    ([0x42, 0x26, 0x00], [0x42, 0x26, 0x00]),
+    ([0x48, 0x26, 0x00], [0x53, 0x26, 0x00]),
+    ([0x5F, 0x26, 0x00], [0x60, 0x26, 0x00]),
+    ([0x63, 0x26, 0x00], [0x63, 0x26, 0x00]),
+    ([0x65, 0x26, 0x00], [0x66, 0x26, 0x00]),
+    ([0x68, 0x26, 0x00], [0x68, 0x26, 0x00]),
+    ([0x7B, 0x26, 0x00], [0x7B, 0x26, 0x00]),
+    ([0x7E, 0x26, 0x00], [0x7F, 0x26, 0x00]),
+    ([0x92, 0x26, 0x00], [0x97, 0x26, 0x00]),
+    ([0x99, 0x26, 0x00], [0x99, 0x26, 0x00]),
+    ([0x9B, 0x26, 0x00], [0x9C, 0x26, 0x00]),
+    ([0xA0, 0x26, 0x00], [0xA1, 0x26, 0x00]),
+    ([0xA7, 0x26, 0x00], [0xA7, 0x26, 0x00]),
+    ([0xAA, 0x26, 0x00], [0xAB, 0x26, 0x00]),
+    ([0xB0, 0x26, 0x00], [0xB1, 0x26, 0x00]),
+    ([0xBD, 0x26, 0x00], [0xBE, 0x26, 0x00]),
+    ([0xC4, 0x26, 0x00], [0xC5, 0x26, 0x00]),
+    ([0xC8, 0x26, 0x00], [0xC8, 0x26, 0x00]),
+    ([0xCE, 0x26, 0x00], [0xCF, 0x26, 0x00]),
+    ([0xD1, 0x26, 0x00], [0xD1, 0x26, 0x00]),
+    ([0xD3, 0x26, 0x00], [0xD4, 0x26, 0x00]),
+    ([0xE9, 0x26, 0x00], [0xEA, 0x26, 0x00]),
+    ([0xF0, 0x26, 0x00], [0xF5, 0x26, 0x00]),
+    ([0xF7, 0x26, 0x00], [0xFA, 0x26, 0x00]),
+    ([0xFD, 0x26, 0x00], [0xFD, 0x26, 0x00]),
+    ([0x02, 0x27, 0x00], [0x02, 0x27, 0x00]),
+    ([0x05, 0x27, 0x00], [0x05, 0x27, 0x00]),
+    ([0x08, 0x27, 0x00], [0x0D, 0x27, 0x00]),
+    ([0x0F, 0x27, 0x00], [0x0F, 0x27, 0x00]),
+    ([0x12, 0x27, 0x00], [0x12, 0x27, 0x00]),
+    ([0x14, 0x27, 0x00], [0x14, 0x27, 0x00]),
+    ([0x16, 0x27, 0x00], [0x16, 0x27, 0x00]),
+    ([0x1D, 0x27, 0x00], [0x1D, 0x27, 0x00]),
+    ([0x21, 0x27, 0x00], [0x21, 0x27, 0x00]),
+    ([0x28, 0x27, 0x00], [0x28, 0x27, 0x00]),
+    ([0x33, 0x27, 0x00], [0x34, 0x27, 0x00]),
+    ([0x44, 0x27, 0x00], [0x44, 0x27, 0x00]),
+    ([0x47, 0x27, 0x00], [0x47, 0x27, 0x00]),
+    ([0x4C, 0x27, 0x00], [0x4C, 0x27, 0x00]),
+    ([0x4E, 0x27, 0x00], [0x4E, 0x27, 0x00]),
+    ([0x53, 0x27, 0x00], [0x55, 0x27, 0x00]),
+    ([0x57, 0x27, 0x00], [0x57, 0x27, 0x00]),
+    ([0x63, 0x27, 0x00], [0x64, 0x27, 0x00]),
+    ([0x95, 0x27, 0x00], [0x97, 0x27, 0x00]),
+    ([0xA1, 0x27, 0x00], [0xA1, 0x27, 0x00]),
+    ([0xB0, 0x27, 0x00], [0xB0, 0x27, 0x00]),
+    ([0xBF, 0x27, 0x00], [0xBF, 0x27, 0x00]),
+    ([0x34, 0x29, 0x00], [0x35, 0x29, 0x00]),
+    ([0x05, 0x2B, 0x00], [0x07, 0x2B, 0x00]),
+    ([0x1B, 0x2B, 0x00], [0x1C, 0x2B, 0x00]),
+    ([0x50, 0x2B, 0x00], [0x50, 0x2B, 0x00]),
+    ([0x55, 0x2B, 0x00], [0x55, 0x2B, 0x00]),
+    ([0x30, 0x30, 0x00], [0x30, 0x30, 0x00]),
+    ([0x3D, 0x30, 0x00], [0x3D, 0x30, 0x00]),
+    ([0x97, 0x32, 0x00], [0x97, 0x32, 0x00]),
+    ([0x99, 0x32, 0x00], [0x99, 0x32, 0x00]),
+    ([0x04, 0xF0, 0x01], [0x04, 0xF0, 0x01]),
+    ([0x2C, 0xF0, 0x01], [0x2F, 0xF0, 0x01]),
+    ([0x94, 0xF0, 0x01], [0x9F, 0xF0, 0x01]),
+    ([0xAF, 0xF0, 0x01], [0xB0, 0xF0, 0x01]),
+    ([0xC0, 0xF0, 0x01], [0xC0, 0xF0, 0x01]),
+    ([0xCF, 0xF0, 0x01], [0xD0, 0xF0, 0x01]),
+    ([0xF6, 0xF0, 0x01], [0xFF, 0xF0, 0x01]),
+    ([0x70, 0xF1, 0x01], [0x71, 0xF1, 0x01]),
+    ([0x7E, 0xF1, 0x01], [0x7F, 0xF1, 0x01]),
+    ([0x8E, 0xF1, 0x01], [0x8E, 0xF1, 0x01]),
+    ([0x91, 0xF1, 0x01], [0x9A, 0xF1, 0x01]),
+    ([0xAE, 0xF1, 0x01], [0xFF, 0xF1, 0x01]),
+    ([0x01, 0xF2, 0x01], [0x0F, 0xF2, 0x01]),
+    ([0x1A, 0xF2, 0x01], [0x1A, 0xF2, 0x01]),
+    ([0x2F, 0xF2, 0x01], [0x2F, 0xF2, 0x01]),
+    ([0x32, 0xF2, 0x01], [0x3A, 0xF2, 0x01]),
+    ([0x3C, 0xF2, 0x01], [0x3F, 0xF2, 0x01]),
+    ([0x49, 0xF2, 0x01], [0x5F, 0xF2, 0x01]),
+    ([0x66, 0xF2, 0x01], [0x21, 0xF3, 0x01]),
+    ([0x24, 0xF3, 0x01], [0x93, 0xF3, 0x01]),
+    ([0x96, 0xF3, 0x01], [0x97, 0xF3, 0x01]),
+    ([0x99, 0xF3, 0x01], [0x9B, 0xF3, 0x01]),
+    ([0x9E, 0xF3, 0x01], [0xF0, 0xF3, 0x01]),
+    ([0xF3, 0xF3, 0x01], [0xF5, 0xF3, 0x01]),
+    ([0xF7, 0xF3, 0x01], [0xFA, 0xF3, 0x01]),
+    ([0x00, 0xF4, 0x01], [0xFD, 0xF4, 0x01]),
+    ([0xFF, 0xF4, 0x01], [0x3D, 0xF5, 0x01]),
+    ([0x49, 0xF5, 0x01], [0x4E, 0xF5, 0x01]),
+    ([0x50, 0xF5, 0x01], [0x67, 0xF5, 0x01]),
+    ([0x6F, 0xF5, 0x01], [0x70, 0xF5, 0x01]),
+    ([0x73, 0xF5, 0x01], [0x7A, 0xF5, 0x01]),
+    ([0x87, 0xF5, 0x01], [0x87, 0xF5, 0x01]),
+    ([0x8A, 0xF5, 0x01], [0x8D, 0xF5, 0x01]),
+    ([0x90, 0xF5, 0x01], [0x90, 0xF5, 0x01]),
+    ([0x95, 0xF5, 0x01], [0x96, 0xF5, 0x01]),
+    ([0xA4, 0xF5, 0x01], [0xA5, 0xF5, 0x01]),
+    ([0xA8, 0xF5, 0x01], [0xA8, 0xF5, 0x01]),
+    ([0xB1, 0xF5, 0x01], [0xB2, 0xF5, 0x01]),
+    ([0xBC, 0xF5, 0x01], [0xBC, 0xF5, 0x01]),
+    ([0xC2, 0xF5, 0x01], [0xC4, 0xF5, 0x01]),
+    ([0xD1, 0xF5, 0x01], [0xD3, 0xF5, 0x01]),
+    ([0xDC, 0xF5, 0x01], [0xDE, 0xF5, 0x01]),
+    ([0xE1, 0xF5, 0x01], [0xE1, 0xF5, 0x01]),
+    ([0xE3, 0xF5, 0x01], [0xE3, 0xF5, 0x01]),
+    ([0xE8, 0xF5, 0x01], [0xE8, 0xF5, 0x01]),
+    ([0xEF, 0xF5, 0x01], [0xEF, 0xF5, 0x01]),
+    ([0xF3, 0xF5, 0x01], [0xF3, 0xF5, 0x01]),
+    ([0xFA, 0xF5, 0x01], [0x4F, 0xF6, 0x01]),
+    ([0x80, 0xF6, 0x01], [0xC5, 0xF6, 0x01]),
+    ([0xCB, 0xF6, 0x01], [0xD2, 0xF6, 0x01]),
+    ([0xD5, 0xF6, 0x01], [0xE5, 0xF6, 0x01]),
+    ([0xE9, 0xF6, 0x01], [0xE9, 0xF6, 0x01]),
+    ([0xEB, 0xF6, 0x01], [0xF0, 0xF6, 0x01]),
+    ([0xF3, 0xF6, 0x01], [0xFF, 0xF6, 0x01]),
+    ([0xDA, 0xF7, 0x01], [0xFF, 0xF7, 0x01]),
+    ([0x0C, 0xF8, 0x01], [0x0F, 0xF8, 0x01]),
+    ([0x48, 0xF8, 0x01], [0x4F, 0xF8, 0x01]),
+    ([0x5A, 0xF8, 0x01], [0x5F, 0xF8, 0x01]),
+    ([0x88, 0xF8, 0x01], [0x8F, 0xF8, 0x01]),
+    ([0xAE, 0xF8, 0x01], [0xAF, 0xF8, 0x01]),
+    ([0xBC, 0xF8, 0x01], [0xBF, 0xF8, 0x01]),
+    ([0xC2, 0xF8, 0x01], [0xCF, 0xF8, 0x01]),
+    ([0xD9, 0xF8, 0x01], [0xFF, 0xF8, 0x01]),
+    ([0x0C, 0xF9, 0x01], [0x3A, 0xF9, 0x01]),
+    ([0x3C, 0xF9, 0x01], [0x45, 0xF9, 0x01]),
+    ([0x47, 0xF9, 0x01], [0xFF, 0xF9, 0x01]),
+    ([0x58, 0xFA, 0x01], [0x5F, 0xFA, 0x01]),
+    ([0x6E, 0xFA, 0x01], [0xFF, 0xFA, 0x01]),
+    ([0x00, 0xFC, 0x01], [0xFD, 0xFF, 0x01]),
+];
+
+/// Sorted list of codepoint ranges (inclusive) in the package's `REGIONAL_INDICATOR` table.
+#[rustfmt::skip]
+pub static REGIONAL_INDICATOR: [([u8; 3], [u8; 3]); 1] = [
+    ([0xE6, 0xF1, 0x01], [0xFF, 0xF1, 0x01]),
+];
+
+/// Sorted list of codepoint ranges (inclusive) in the package's `FITZPATRICK` table.
+#[rustfmt::skip]
+pub static FITZPATRICK: [([u8; 3], [u8; 3]); 1] = [
+    ([0xFB, 0xF3, 0x01], [0xFF, 0xF3, 0x01]),
+];
@@ -0,0 +1,469 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::accumulator::WidthAccumulator;
+use crate::overrides::WidthOverrides;
+use crate::split::is_regional_indicator;
+use crate::tables;
+use crate::width_tables::{StateMachine, WidthTables};
+
+/// A set of choices for how to determine the displayed width of text, for when
+/// neither [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width) nor
+/// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk) matches how it is
+/// actually displayed.
+///
+/// [`WidthConfig::DEFAULT`] follows the
+/// [rules for determining width](crate#rules-for-determining-width), and each method
+/// changes one of them.
+///
+/// ```rust
+/// use unicode_width::WidthConfig;
+///
+/// let config = WidthConfig::DEFAULT.join_zwj_sequences(false).count_line_breaks(false);
+/// assert_eq!(config.width("👩‍🔬\r\n"), 4);
+/// assert_eq!(WidthConfig::DEFAULT.width("👩‍🔬\r\n"), 3);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WidthConfig<'t> {
+    ambiguous_wide: bool,
+    join_zwj_sequences: bool,
+    widen_emoji_presentation: bool,
+    narrow_text_presentation: bool,
+    unpaired_regional_indicator_width: usize,
+    count_line_breaks: bool,
+    unicode_version: (u8, u8, u8),
+    newer_char_width: usize,
+    emoji_version: EmojiVersion,
+    overrides: WidthOverrides<'t>,
+    tables: WidthTables<'t>,
+}
+
+impl WidthConfig<'static> {
+    /// The configuration used by [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width)
+    /// and [`UnicodeWidthChar::width`](crate::UnicodeWidthChar::width).
+    pub const DEFAULT: Self = Self {
+        ambiguous_wide: false,
+        join_zwj_sequences: true,
+        widen_emoji_presentation: true,
+        narrow_text_presentation: true,
+        unpaired_regional_indicator_width: 1,
+        count_line_breaks: true,
+        unicode_version: tables::UNICODE_VERSION,
+        newer_char_width: 1,
+        emoji_version: EmojiVersion::LATEST,
+        overrides: WidthOverrides::EMPTY,
+        tables: WidthTables::BUILTIN,
+    };
+
+    /// The configuration used by
+    /// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk) and
+    /// [`UnicodeWidthChar::width_cjk`](crate::UnicodeWidthChar::width_cjk).
+    #[cfg(feature = "cjk")]
+    pub const CJK: Self = Self::DEFAULT.ambiguous_wide(true);
+}
+
+impl<'t> WidthConfig<'t> {
+    /// Sets whether characters in the Ambiguous category are 2 columns wide,
+    /// as is usual in East Asian contexts, instead of 1.
+    ///
+    /// This also turns on the other rules that only apply in an East Asian context,
+    /// and turns off those that don't.
+    #[cfg(feature = "cjk")]
+    #[inline]
+    pub const fn ambiguous_wide(self, ambiguous_wide: bool) -> Self {
+        Self {
+            ambiguous_wide,
+            ..self
+        }
+    }
+
+    /// Sets whether emoji joined by `'\u{200D}'` ZERO WIDTH JOINER are shown
+    /// as a single emoji 2 columns wide, or each on its own.
+    ///
+    /// Other ligatures using `'\u{200D}'`, such as the Hebrew Alef-Lamed,
+    /// are not affected.
+    #[inline]
+    pub const fn join_zwj_sequences(self, join_zwj_sequences: bool) -> Self {
+        Self {
+            join_zwj_sequences,
+            ..self
+        }
+    }
+
+    /// Sets whether `'\u{FE0F}'` VARIATION SELECTOR-16 makes a character that is
+    /// otherwise 1 column wide, such as `'\u{2764}'`, 2 columns wide.
+    #[inline]
+    pub const fn widen_emoji_presentation(self, widen_emoji_presentation: bool) -> Self {
+        Self {
+            widen_emoji_presentation,
+            ..self
+        }
+    }
+
+    /// Sets whether `'\u{FE0E}'` VARIATION SELECTOR-15 makes an emoji that is
+    /// otherwise 2 columns wide, such as `'\u{231A}'`, 1 column wide.
+    #[inline]
+    pub const fn narrow_text_presentation(self, narrow_text_presentation: bool) -> Self {
+        Self {
+            narrow_text_presentation,
+            ..self
+        }
+    }
+
+    /// Sets the width of a regional indicator that isn't part of a flag,
+    /// which is 1 by default.
+    ///
+    /// Regional indicators pair up into flags from the start of each run of them,
+    /// so only the last one in a run of odd length is unpaired.
+    #[inline]
+    pub const fn unpaired_regional_indicator_width(self, width: usize) -> Self {
+        Self {
+            unpaired_regional_indicator_width: width,
+            ..self
+        }
+    }
+
+    /// Sets whether line breaks (`'\n'`, `'\r'`, and `"\r\n"`) are 1 column wide,
+    /// or take no space.
+    #[inline]
+    pub const fn count_line_breaks(self, count_line_breaks: bool) -> Self {
+        Self {
+            count_line_breaks,
+            ..self
+        }
+    }
+
+    /// Sets the version of Unicode that the text is displayed as of, for terminals
+    /// and fonts that don't know about the characters assigned since.
+    ///
+    /// Characters assigned after `version` are drawn on their own, as a placeholder
+    /// [`newer_char_width`](Self::newer_char_width) columns wide, and don't combine
+    /// with the characters around them. Other changes between Unicode versions, such as
+    /// to character properties or to the list of emoji sequences, are not taken into
+    /// account. By default, this is [`UNICODE_VERSION`](crate::UNICODE_VERSION),
+    /// so no characters are affected.
+    ///
+    /// ```rust
+    /// use unicode_width::WidthConfig;
+    ///
+    /// // U+1FAE9 FACE WITH BAGS UNDER EYES was assigned in Unicode 16.0
+    /// let config = WidthConfig::DEFAULT.unicode_version((12, 1, 0));
+    /// assert_eq!(config.width("\u{1FAE9}\u{1F600}"), 3);
+    /// assert_eq!(config.newer_char_width(2).width("\u{1FAE9}\u{1F600}"), 4);
+    /// ```
+    #[inline]
+    pub const fn unicode_version(self, version: (u8, u8, u8)) -> Self {
+        Self {
+            unicode_version: version,
+            ..self
+        }
+    }
+
+    /// Sets the width of a character assigned after the
+    /// [`unicode_version`](Self::unicode_version), which is 1 by default.
+    #[inline]
+    pub const fn newer_char_width(self, width: usize) -> Self {
+        Self {
+            newer_char_width: width,
+            ..self
+        }
+    }
+
+    /// Sets the version of the emoji data that the text is displayed as of,
+    /// for terminals that only draw emoji up to some release.
+    ///
+    /// Emoji introduced after `version` are drawn as text: if they are otherwise 2 columns
+    /// wide they are 1 column wide, `'\u{FE0F}'` VARIATION SELECTOR-16 doesn't widen them,
+    /// and they don't combine with the characters around them. Emoji ZWJ sequences
+    /// introduced after `version` are split into their components, even if those
+    /// components are older. By default, this is [`EmojiVersion::LATEST`], so no emoji
+    /// are affected.
+    ///
+    /// ```rust
+    /// use unicode_width::{EmojiVersion, WidthConfig};
+    ///
+    /// // U+1F97A FACE WITH PLEADING EYES was introduced in Emoji 11.0
+    /// let config = WidthConfig::DEFAULT.emoji_version(EmojiVersion::new(5, 0));
+    /// assert_eq!(config.width("\u{1F97A}\u{1F600}"), 3);
+    /// // PHOENIX was introduced in Emoji 15.1
+    /// assert_eq!(config.width("🐦\u{200D}🔥"), 4);
+    /// ```
+    #[inline]
+    pub const fn emoji_version(self, version: EmojiVersion) -> Self {
+        Self {
+            emoji_version: version,
+            ..self
+        }
+    }
+
+    /// Sets a table of widths that take precedence over all other rules.
+    ///
+    /// Overridden characters are drawn on their own with the width from the table,
+    /// and don't combine with the characters around them, so a string's width
+    /// is the sum of its overridden characters' widths and the widths of the runs
+    /// between them. By default, this is [`WidthOverrides::EMPTY`].
+    #[inline]
+    pub const fn overrides(self, overrides: WidthOverrides<'t>) -> Self {
+        Self { overrides, ..self }
+    }
+
+    /// Sets the tables that widths are looked up in, such as ones loaded with
+    /// [`WidthTables::from_bytes`]. By default, this is [`WidthTables::BUILTIN`].
+    ///
+    /// The other settings apply on top of the tables, but still find out when
+    /// characters and emoji were introduced from the data built into this crate.
+    #[inline]
+    pub const fn tables(self, tables: WidthTables<'t>) -> Self {
+        Self { tables, ..self }
+    }
+
+    /// Returns the displayed width of `c` in columns, or `None` if it is
+    /// a control character.
+    pub fn char_width(&self, c: char) -> Option<usize> {
+        if let Some(width) = self.overrides.get(c) {
+            return Some(width);
+        }
+        if self.is_newer(c) {
+            return Some(self.newer_char_width);
+        }
+        if self.is_newer_emoji(c) {
+            return StateMachine::new(self.tables)
+                .char_width(c)
+                .map(|width| width.min(1));
+        }
+        if self.unpaired_regional_indicator_width != 1 && is_regional_indicator(c) {
+            return Some(self.unpaired_regional_indicator_width);
+        }
+        self.machine().char_width(c)
+    }
+
+    /// Returns the displayed width of `s` in columns.
+    #[inline]
+    pub fn width(&self, s: &str) -> usize {
+        if self.join_zwj_sequences
+            && self.widen_emoji_presentation
+            && self.narrow_text_presentation
+            && self.unpaired_regional_indicator_width == 1
+            && self.count_line_breaks
+            && self.unicode_version >= tables::UNICODE_VERSION
+            && self.emoji_version >= EmojiVersion::LATEST
+            && self.overrides.is_empty()
+        {
+            self.sequence_width(s)
+        } else {
+            self.filtered_width(s)
+        }
+    }
+
+    /// Whether `c` was assigned after the Unicode version the text is displayed as of.
+    #[inline]
+    fn is_newer(&self, c: char) -> bool {
+        let (major, minor, _) = self.unicode_version;
+        self.unicode_version < tables::UNICODE_VERSION
+            && matches!(tables::age(c), Some(age) if age > (major, minor))
+    }
+
+    /// Whether `c` was introduced as an emoji after the emoji version the text
+    /// is displayed as of.
+    #[inline]
+    fn is_newer_emoji(&self, c: char) -> bool {
+        self.emoji_version < EmojiVersion::LATEST
+            && matches!(tables::emoji_version(c), Some(version) if EmojiVersion::from(version) > self.emoji_version)
+    }
+
+    /// Whether the emoji ZWJ sequence `sequence` is split into its components,
+    /// either because ZWJ sequences don't join or because it is too new.
+    fn splits_zwj_sequence(&self, sequence: &str) -> bool {
+        if !self.join_zwj_sequences {
+            return true;
+        }
+        if self.emoji_version >= EmojiVersion::LATEST {
+            return false;
+        }
+
+        if sequence.chars().any(|c| self.is_newer_emoji(c)) {
+            return true;
+        }
+        let skeleton = sequence
+            .chars()
+            .filter(|&c| c != '\u{FE0F}' && !tables::is_emoji_modifier(c));
+        let toned = sequence.chars().any(tables::is_emoji_modifier);
+        matches!(
+            tables::zwj_sequence_version(skeleton, toned),
+            Some(version) if EmojiVersion::from(version) > self.emoji_version
+        )
+    }
+
+    #[inline]
+    fn machine(&self) -> StateMachine<'t> {
+        #[cfg(feature = "cjk")]
+        if self.ambiguous_wide {
+            return StateMachine::new_cjk(self.tables);
+        }
+        StateMachine::new(self.tables)
+    }
+
+    /// Returns the width of `s` according to the tables alone.
+    #[inline]
+    fn sequence_width(&self, s: &str) -> usize {
+        self.machine().str_width(s)
+    }
+
+    /// Returns the width of `s` according to the tables, after taking out the
+    /// variation selectors that have no effect, splitting up the ZWJ sequences
+    /// that don't join, setting apart the overridden characters, the line breaks that
+    /// aren't counted and the characters and emoji that are too new, and changing
+    /// the width of unpaired regional indicators.
+    fn filtered_width(&self, s: &str) -> usize {
+        let mut width = 0;
+        // How many regional indicators the current run has, and how many runs had
+        // an unpaired one at the end
+        let mut regional_indicators = 0;
+        let mut unpaired = 0;
+        let mut acc = WidthAccumulator::with_state_machine(self.machine());
+        // Whether the ZWJ sequence that the current character is part of is split up
+        let mut split_zwj = !self.join_zwj_sequences;
+        let mut sequence_end = 0;
+        let mut chars = s.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            let next = chars.peek().map(|&(_, next)| next);
+            if i >= sequence_end && is_emoji(c) {
+                sequence_end = i + zwj_sequence_len(&s[i..]);
+                split_zwj = self.splits_zwj_sequence(&s[i..sequence_end]);
+            }
+            let overridden = self.overrides.get(c);
+            if is_regional_indicator(c) && overridden.is_none() {
+                regional_indicators += 1;
+            } else {
+                unpaired += regional_indicators % 2;
+                regional_indicators = 0;
+            }
+            if let Some(overridden) = overridden {
+                width += acc.width() + overridden;
+                acc = WidthAccumulator::with_state_machine(self.machine());
+                continue;
+            }
+            match c {
+                _ if self.is_newer(c) => {
+                    width += acc.width() + self.newer_char_width;
+                    acc = WidthAccumulator::with_state_machine(self.machine());
+                    continue;
+                }
+                _ if self.is_newer_emoji(c) => {
+                    width += acc.width() + self.char_width(c).unwrap_or(0);
+                    acc = WidthAccumulator::with_state_machine(self.machine());
+                    continue;
+                }
+                '\n' | '\r' if !self.count_line_breaks => {
+                    width += acc.width();
+                    acc = WidthAccumulator::with_state_machine(self.machine());
+                    continue;
+                }
+                '\u{FE0E}' if !self.narrow_text_presentation => continue,
+                // Leave the selector in if the sequence continues, so the rest still joins
+                '\u{FE0F}' if !self.widen_emoji_presentation && next != Some('\u{200D}') => {
+                    continue
+                }
+                '\u{200D}' if split_zwj && matches!(next, Some(next) if is_emoji(next)) => {
+                    acc.push(c);
+                    width += acc.width();
+                    acc = WidthAccumulator::with_state_machine(self.machine());
+                    continue;
+                }
+                _ => {}
+            }
+            acc.push(c);
+        }
+        unpaired += regional_indicators % 2;
+
+        // Unpaired regional indicators are 1 column wide according to the tables
+        (width + acc.width())
+            .wrapping_add(unpaired * self.unpaired_regional_indicator_width)
+            .wrapping_sub(unpaired)
+    }
+}
+
+impl Default for WidthConfig<'static> {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Whether `c` could be part of an emoji ZWJ sequence after a `'\u{200D}'`.
+///
+/// Splitting a string after a `'\u{200D}'` that doesn't join anything leaves its width
+/// unchanged, so this only needs to rule out the script ligatures, which use narrow letters.
+fn is_emoji(c: char) -> bool {
+    tables::single_char_width(c) == Some(2)
+        || tables::starts_emoji_presentation_seq(c)
+        || is_regional_indicator(c)
+}
+
+/// Returns the length in bytes of the emoji ZWJ sequence at the start of `s`: emoji,
+/// each followed by any variation selectors and emoji modifiers, joined by `'\u{200D}'`.
+fn zwj_sequence_len(s: &str) -> usize {
+    let mut len = 0;
+    let mut chars = s.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\u{FE0F}' => {}
+            _ if tables::is_emoji_modifier(c) => {}
+            '\u{200D}' if matches!(chars.peek(), Some(&(_, next)) if is_emoji(next)) => {}
+            _ if i == 0 || s[..i].ends_with('\u{200D}') => {}
+            _ => break,
+        }
+        len = i + c.len_utf8();
+    }
+    len
+}
+
+/// A version of the [emoji data](https://www.unicode.org/reports/tr51/#Emoji_Versions),
+/// for [`WidthConfig::emoji_version`].
+///
+/// Since Emoji 11.0, emoji versions have the same numbers as the Unicode versions
+/// that they come with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EmojiVersion {
+    major: u8,
+    minor: u8,
+}
+
+impl EmojiVersion {
+    /// The emoji version of [`UNICODE_VERSION`](crate::UNICODE_VERSION),
+    /// which this crate's tables are based on.
+    pub const LATEST: Self = Self::new(tables::UNICODE_VERSION.0, tables::UNICODE_VERSION.1);
+
+    /// Creates the emoji version `major.minor`, such as `EmojiVersion::new(12, 1)`
+    /// for Emoji 12.1.
+    #[inline]
+    pub const fn new(major: u8, minor: u8) -> Self {
+        Self { major, minor }
+    }
+
+    /// Returns the major version number.
+    #[inline]
+    pub const fn major(self) -> u8 {
+        self.major
+    }
+
+    /// Returns the minor version number.
+    #[inline]
+    pub const fn minor(self) -> u8 {
+        self.minor
+    }
+}
+
+impl From<(u8, u8)> for EmojiVersion {
+    #[inline]
+    fn from((major, minor): (u8, u8)) -> Self {
+        Self::new(major, minor)
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+
+use crate::accumulator::WidthAccumulator;
+use crate::width_tables::StateMachine;
+
+/// How control characters (`'\0'` to `'\x1F'`, `'\x7F'`, and `'\u{80}'` to `'\u{9F}'`)
+/// are shown, for [`width_with_controls`] and `escape_controls`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ControlPolicy {
+    /// Control characters are left as they are, and are 1 column wide
+    /// (except that `"\r\n"` is 1 column wide as a whole),
+    /// as in [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+    #[default]
+    Standard,
+    /// Caret notation: `^@` to `^_` for `'\0'` to `'\x1F'`, `^?` for `'\x7F'`,
+    /// and `M-^@` to `M-^_` for `'\u{80}'` to `'\u{9F}'`. Unlike `cat -v`, this
+    /// also escapes `'\t'` and `'\n'`, as `^I` and `^J`.
+    Caret,
+    /// Hexadecimal escapes, such as `\x01`.
+    Hex,
+    /// The code point in angle brackets, such as `<U+0001>`.
+    CodePoint,
+    /// The characters of the [Control Pictures] block, `'␀'` to `'␟'` for `'\0'` to `'\x1F'`
+    /// and `'␡'` for `'\x7F'`. The C1 controls, which have no pictures, are shown as in
+    /// [`CodePoint`](ControlPolicy::CodePoint).
+    ///
+    /// [Control Pictures]: https://www.unicode.org/charts/PDF/U2400.pdf
+    Pictures,
+}
+
+impl ControlPolicy {
+    /// Writes how `c` is shown into `buf` and returns it,
+    /// or returns `None` if `c` is shown as itself.
+    fn escape(self, c: char, buf: &mut [u8; 8]) -> Option<&str> {
+        if !c.is_control() {
+            return None;
+        }
+
+        let code = c as u8;
+        let len = match self {
+            ControlPolicy::Standard => return None,
+            ControlPolicy::Caret => match code {
+                0x00..=0x1F => put(buf, &[b'^', code + 0x40]),
+                0x7F => put(buf, b"^?"),
+                _ => put(buf, &[b'M', b'-', b'^', code - 0x40]),
+            },
+            ControlPolicy::Hex => put(buf, &[b'\\', b'x', hex_digit(code >> 4), hex_digit(code)]),
+            ControlPolicy::Pictures if code <= 0x1F || code == 0x7F => {
+                let picture = if code == 0x7F {
+                    '\u{2421}'
+                } else {
+                    char::from_u32(0x2400 + u32::from(code)).unwrap()
+                };
+                picture.encode_utf8(buf).len()
+            }
+            ControlPolicy::CodePoint | ControlPolicy::Pictures => put(
+                buf,
+                &[
+                    b'<',
+                    b'U',
+                    b'+',
+                    b'0',
+                    b'0',
+                    hex_digit(code >> 4),
+                    hex_digit(code),
+                    b'>',
+                ],
+            ),
+        };
+        core::str::from_utf8(&buf[..len]).ok()
+    }
+}
+
+fn put(buf: &mut [u8; 8], bytes: &[u8]) -> usize {
+    buf[..bytes.len()].copy_from_slice(bytes);
+    bytes.len()
+}
+
+fn hex_digit(nibble: u8) -> u8 {
+    b"0123456789ABCDEF"[usize::from(nibble & 0xF)]
+}
+
+/// Returns the displayed width of `s` in columns, with control characters shown
+/// according to `policy`.
+///
+/// This is the width of what `escape_controls` returns, so it always matches what is
+/// printed. With [`ControlPolicy::Standard`], it is the same as
+/// [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+///
+/// Characters in the Ambiguous category are treated as 1 column wide.
+///
+/// ```rust
+/// use unicode_width::{width_with_controls, ControlPolicy};
+///
+/// assert_eq!(width_with_controls("a\x01b", ControlPolicy::Standard), 3);
+/// assert_eq!(width_with_controls("a\x01b", ControlPolicy::Caret), 4);
+/// assert_eq!(width_with_controls("a\x01b", ControlPolicy::Hex), 6);
+/// assert_eq!(width_with_controls("a\x01b", ControlPolicy::CodePoint), 10);
+/// assert_eq!(width_with_controls("a\x01b", ControlPolicy::Pictures), 3);
+/// ```
+pub fn width_with_controls(s: &str, policy: ControlPolicy) -> usize {
+    width_with_controls_with(StateMachine::DEFAULT, s, policy)
+}
+
+/// Returns the displayed width of `s` in columns, with control characters shown
+/// according to `policy`.
+///
+/// This is [`width_with_controls`], but with characters in the Ambiguous category
+/// treated as 2 columns wide, as in
+/// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+#[cfg(feature = "cjk")]
+pub fn width_with_controls_cjk(s: &str, policy: ControlPolicy) -> usize {
+    width_with_controls_with(StateMachine::CJK, s, policy)
+}
+
+/// Returns `s` with its control characters replaced by how `policy` shows them.
+///
+/// `s` is returned unchanged if it has no control characters, or if `policy` is
+/// [`ControlPolicy::Standard`]. Its width is given by [`width_with_controls`].
+///
+/// ```rust
+/// use unicode_width::{escape_controls, ControlPolicy};
+///
+/// assert_eq!(escape_controls("a\x01\x7F\u{85}", ControlPolicy::Caret), "a^A^?M-^E");
+/// assert_eq!(escape_controls("a\x01\x7F\u{85}", ControlPolicy::Pictures), "a␁␡<U+0085>");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn escape_controls(s: &str, policy: ControlPolicy) -> Cow<'_, str> {
+    if policy == ControlPolicy::Standard || !s.contains(char::is_control) {
+        return Cow::Borrowed(s);
+    }
+
+    let mut escaped = String::with_capacity(s.len());
+    let mut buf = [0; 8];
+    for c in s.chars() {
+        match policy.escape(c, &mut buf) {
+            Some(shown) => escaped.push_str(shown),
+            None => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+pub(crate) fn width_with_controls_with(
+    machine: StateMachine<'_>,
+    s: &str,
+    policy: ControlPolicy,
+) -> usize {
+    if policy == ControlPolicy::Standard {
+        return machine.str_width(s);
+    }
+
+    let mut acc = WidthAccumulator::with_state_machine(machine);
+    let mut buf = [0; 8];
+    for c in s.chars() {
+        match policy.escape(c, &mut buf) {
+            Some(shown) => acc.push_str(shown),
+            None => acc.push(c),
+        }
+    }
+    acc.width()
+}
@@ -0,0 +1,151 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt::{self, Write};
+
+use crate::split::split_at_width_with;
+use crate::width_tables::{StateMachine, WidthTables};
+
+/// Formats a string with its width, fill and alignment counted in display columns
+/// instead of `char`s.
+///
+/// The standard library pads strings by the number of `char`s they contain, which
+/// misaligns text with wide characters, emoji or combining marks. Formatting a
+/// `DisplayWidth` instead pads the string to the requested width as measured by
+/// [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width). A precision is taken as the
+/// maximum number of columns to show, and the string is cut as by
+/// [`split_at_width`](crate::split_at_width).
+///
+/// The fill character is measured too. If it is wide, and the padding isn't a multiple
+/// of its width, the leftover columns are filled with spaces next to the string.
+/// A fill character with no width is replaced with spaces. Nothing is allocated.
+///
+/// ```rust
+/// use unicode_width::DisplayWidth;
+///
+/// assert_eq!(format!("[{:<6}]", DisplayWidth::new("中文")), "[中文  ]");
+/// assert_eq!(format!("[{:^7}]", DisplayWidth::new("👩‍🔬")), "[  👩‍🔬   ]");
+/// assert_eq!(format!("[{:>.3}]", DisplayWidth::new("中文")), "[中]");
+/// assert_eq!(format!("[{:\u{3000}>7}]", DisplayWidth::new("abc")), "[\u{3000}\u{3000}abc]");
+/// ```
+#[derive(Clone, Copy)]
+pub struct DisplayWidth<'a> {
+    machine: StateMachine<'a>,
+    s: &'a str,
+}
+
+impl<'a> DisplayWidth<'a> {
+    /// Wraps `s` to be padded according to
+    /// [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+    ///
+    /// Characters in the Ambiguous category are treated as 1 column wide.
+    #[inline]
+    pub fn new(s: &'a str) -> Self {
+        Self {
+            machine: StateMachine::DEFAULT,
+            s,
+        }
+    }
+
+    /// Wraps `s` to be padded according to
+    /// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+    ///
+    /// Characters in the Ambiguous category are treated as 2 columns wide.
+    #[cfg(feature = "cjk")]
+    #[inline]
+    pub fn new_cjk(s: &'a str) -> Self {
+        Self {
+            machine: StateMachine::CJK,
+            s,
+        }
+    }
+
+    /// Wraps `s` to be padded according to [`WidthTables::width`] with `tables`.
+    #[inline]
+    pub fn with_tables(s: &'a str, tables: WidthTables<'a>) -> Self {
+        Self {
+            machine: StateMachine::new(tables),
+            s,
+        }
+    }
+
+    /// Wraps `s` to be padded according to [`WidthTables::width_cjk`] with `tables`.
+    #[cfg(feature = "cjk")]
+    #[inline]
+    pub fn with_tables_cjk(s: &'a str, tables: WidthTables<'a>) -> Self {
+        Self {
+            machine: StateMachine::new_cjk(tables),
+            s,
+        }
+    }
+
+    /// Returns the wrapped string.
+    #[inline]
+    pub fn as_str(&self) -> &'a str {
+        self.s
+    }
+
+    /// Writes `cols` columns of padding, with any leftover spaces on the side of the string.
+    fn pad(&self, f: &mut fmt::Formatter<'_>, cols: usize, before_string: bool) -> fmt::Result {
+        let mut fill = f.fill();
+        let mut fill_width = self.machine.str_width(fill.encode_utf8(&mut [0; 4]));
+        if fill_width == 0 || fill.is_control() {
+            fill = ' ';
+            fill_width = 1;
+        }
+
+        let spaces = cols % fill_width;
+        if !before_string {
+            write_repeated(f, ' ', spaces)?;
+        }
+        write_repeated(f, fill, cols / fill_width)?;
+        if before_string {
+            write_repeated(f, ' ', spaces)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for DisplayWidth<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.machine.str_width(self.s);
+        let (s, width) = match f.precision() {
+            Some(max_width) if width > max_width => {
+                let (s, _, width) = split_at_width_with(self.machine, width, self.s, max_width);
+                (s, width)
+            }
+            _ => (self.s, width),
+        };
+
+        let padding = f.width().unwrap_or(0).saturating_sub(width);
+        let (before, after) = match f.align() {
+            None | Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        };
+
+        self.pad(f, before, true)?;
+        f.write_str(s)?;
+        self.pad(f, after, false)
+    }
+}
+
+impl fmt::Debug for DisplayWidth<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DisplayWidth").field(&self.s).finish()
+    }
+}
+
+fn write_repeated(f: &mut fmt::Formatter<'_>, c: char, count: usize) -> fmt::Result {
+    for _ in 0..count {
+        f.write_char(c)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,156 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The emoji properties of characters from
+//! [Unicode Technical Standard #51](https://www.unicode.org/reports/tr51/),
+//! for the same version of Unicode as [`UNICODE_VERSION`](crate::UNICODE_VERSION).
+//!
+//! These are the properties that this crate uses to determine the width of emoji,
+//! along with the other properties in `emoji-data.txt`. For example, a text input
+//! can find out whether to append `'\u{FE0F}'` VARIATION SELECTOR-16 to a character
+//! so that it is shown as an emoji:
+//!
+//! ```rust
+//! use unicode_width::emoji;
+//!
+//! fn needs_vs16(c: char) -> bool {
+//!     !emoji::is_emoji_presentation(c) && emoji::starts_emoji_presentation_seq(c)
+//! }
+//!
+//! assert!(needs_vs16('\u{2764}')); // HEAVY BLACK HEART
+//! assert!(!needs_vs16('😀'));
+//! assert!(!needs_vs16('a'));
+//! ```
+
+use crate::tables;
+
+/// Returns whether `c` has the `Emoji` property, which is the case for characters
+/// that are recommended for use as emoji, as well as digits, `'#'` and `'*'`,
+/// which start emoji keycap sequences.
+///
+/// ```rust
+/// use unicode_width::emoji::is_emoji;
+///
+/// assert!(is_emoji('😀'));
+/// assert!(is_emoji('\u{2764}'));
+/// assert!(is_emoji('#'));
+/// assert!(!is_emoji('a'));
+/// ```
+#[inline]
+pub fn is_emoji(c: char) -> bool {
+    tables::is_emoji(c)
+}
+
+/// Returns whether `c` has the `Emoji_Presentation` property, which is the case
+/// for characters that are shown as emoji by default, without `'\u{FE0F}'`.
+///
+/// ```rust
+/// use unicode_width::emoji::is_emoji_presentation;
+///
+/// assert!(is_emoji_presentation('😀'));
+/// assert!(!is_emoji_presentation('\u{2764}'));
+/// ```
+#[inline]
+pub fn is_emoji_presentation(c: char) -> bool {
+    tables::is_emoji_presentation(c)
+}
+
+/// Returns whether `c` has the `Emoji_Component` property, which is the case for
+/// characters that occur in emoji sequences without being emoji of their own
+/// there, such as emoji modifiers, regional indicators, `'\u{200D}'` ZERO WIDTH JOINER
+/// and `'\u{FE0F}'` VARIATION SELECTOR-16.
+///
+/// ```rust
+/// use unicode_width::emoji::is_emoji_component;
+///
+/// assert!(is_emoji_component('\u{1F3FB}'));
+/// assert!(is_emoji_component('\u{200D}'));
+/// assert!(!is_emoji_component('😀'));
+/// ```
+#[inline]
+pub fn is_emoji_component(c: char) -> bool {
+    tables::is_emoji_component(c)
+}
+
+/// Returns whether `c` has the `Extended_Pictographic` property, which is the case
+/// for emoji and other pictographic symbols, including unassigned code points
+/// reserved for future emoji. Grapheme clusters are built around these characters.
+///
+/// ```rust
+/// use unicode_width::emoji::is_extended_pictographic;
+///
+/// assert!(is_extended_pictographic('😀'));
+/// assert!(is_extended_pictographic('\u{1FAFF}'));
+/// assert!(!is_extended_pictographic('#'));
+/// ```
+#[inline]
+pub fn is_extended_pictographic(c: char) -> bool {
+    tables::is_extended_pictographic(c)
+}
+
+/// Returns whether `c` has the `Emoji_Modifier` property, which is the case for
+/// the five skin tone modifiers, `'\u{1F3FB}'` through `'\u{1F3FF}'`.
+///
+/// ```rust
+/// use unicode_width::emoji::is_emoji_modifier;
+///
+/// assert!(is_emoji_modifier('\u{1F3FD}'));
+/// assert!(!is_emoji_modifier('\u{200D}'));
+/// ```
+#[inline]
+pub fn is_emoji_modifier(c: char) -> bool {
+    tables::is_emoji_modifier(c)
+}
+
+/// Returns whether `c` has the `Emoji_Modifier_Base` property, which is the case
+/// for emoji whose skin tone can be changed by following them with an emoji modifier.
+///
+/// ```rust
+/// use unicode_width::emoji::is_emoji_modifier_base;
+///
+/// assert!(is_emoji_modifier_base('👋'));
+/// assert!(!is_emoji_modifier_base('😀'));
+/// ```
+#[inline]
+pub fn is_emoji_modifier_base(c: char) -> bool {
+    tables::is_emoji_modifier_base(c)
+}
+
+/// Returns whether `c` forms an
+/// [emoji presentation sequence](https://www.unicode.org/reports/tr51/#def_emoji_presentation_sequence)
+/// when followed by `'\u{FE0F}'` VARIATION SELECTOR-16.
+///
+/// ```rust
+/// use unicode_width::emoji::starts_emoji_presentation_seq;
+///
+/// assert!(starts_emoji_presentation_seq('\u{2764}'));
+/// assert!(starts_emoji_presentation_seq('#'));
+/// assert!(!starts_emoji_presentation_seq('a'));
+/// ```
+#[inline]
+pub fn starts_emoji_presentation_seq(c: char) -> bool {
+    tables::starts_emoji_presentation_seq(c)
+}
+
+/// Returns whether `c` is shown as an emoji by default, but forms a
+/// [text presentation sequence](https://www.unicode.org/reports/tr51/#def_text_presentation_sequence)
+/// when followed by `'\u{FE0E}'` VARIATION SELECTOR-15, and isn't ideographic.
+/// Such sequences are 1 column wide instead of 2.
+///
+/// ```rust
+/// use unicode_width::emoji::starts_non_ideographic_text_presentation_seq;
+///
+/// assert!(starts_non_ideographic_text_presentation_seq('\u{231A}')); // WATCH
+/// assert!(!starts_non_ideographic_text_presentation_seq('\u{2764}'));
+/// ```
+#[inline]
+pub fn starts_non_ideographic_text_presentation_seq(c: char) -> bool {
+    tables::starts_non_ideographic_text_presentation_seq(c)
+}
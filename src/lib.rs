@@ -27,8 +27,7 @@
 //!
 //! # `"cjk"` feature flag
 //!
-//! This crate has one Cargo feature flag, `"cjk"`
-//! (enabled by default).
+//! The `"cjk"` Cargo feature flag is enabled by default.
 //! It enables the [`UnicodeWidthChar::width_cjk`]
 //! and [`UnicodeWidthStr::width_cjk`],
 //! which perform an alternate width calculation
@@ -48,6 +47,12 @@
 //! assert_eq!(teststr.width_cjk(), 6);
 //! ```
 //!
+//! # `"alloc"` feature flag
+//!
+//! The `"alloc"` Cargo feature flag (disabled by default) enables the functions
+//! that need to build new strings, such as `truncate_end`, and the `wrap` module.
+//! The crate remains `#![no_std]`, but depends on the `alloc` crate.
+//!
 //! # Rules for determining width
 //!
 //! This crate currently uses the following rules to determine the width of a
@@ -176,9 +181,79 @@
 )]
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub use accumulator::WidthAccumulator;
+pub use ansi::width_ansi;
+#[cfg(feature = "cjk")]
+pub use ansi::width_ansi_cjk;
+#[cfg(feature = "alloc")]
+pub use ansi::{slice_ansi, truncate_ansi};
+#[cfg(all(feature = "alloc", feature = "cjk"))]
+pub use ansi::{slice_ansi_cjk, truncate_ansi_cjk};
+pub use class::{char_width_class, CharWidthClass};
+pub use column::{byte_at_column, column_at_byte, Rounding};
+#[cfg(feature = "cjk")]
+pub use column::{byte_at_column_cjk, column_at_byte_cjk};
+pub use config::{EmojiVersion, WidthConfig};
+#[cfg(feature = "alloc")]
+pub use controls::escape_controls;
+#[cfg(feature = "cjk")]
+pub use controls::width_with_controls_cjk;
+pub use controls::{width_with_controls, ControlPolicy};
+pub use display::DisplayWidth;
+pub use lines::{line_widths, max_line_width, LineWidths};
+#[cfg(feature = "cjk")]
+pub use lines::{line_widths_cjk, max_line_width_cjk};
+pub use overrides::WidthOverrides;
+#[cfg(feature = "alloc")]
+pub use overrides::{parse_width_overrides, ParseOverridesError};
+pub use profile::Profile;
+#[cfg(feature = "cjk")]
+pub use segments::width_segments_cjk;
+pub use segments::{width_segments, WidthSegments};
+pub use split::{rsplit_at_width, split_at_width};
+#[cfg(feature = "cjk")]
+pub use split::{rsplit_at_width_cjk, split_at_width_cjk};
 pub use tables::UNICODE_VERSION;
+#[cfg(feature = "alloc")]
+pub use tabs::expand_tabs;
+#[cfg(all(feature = "alloc", feature = "cjk"))]
+pub use tabs::expand_tabs_cjk;
+#[cfg(feature = "cjk")]
+pub use tabs::width_with_tabs_cjk;
+pub use tabs::{width_with_tabs, TabStops};
+#[cfg(feature = "alloc")]
+pub use truncate::{truncate_end, truncate_middle, truncate_start};
+#[cfg(all(feature = "alloc", feature = "cjk"))]
+pub use truncate::{truncate_end_cjk, truncate_middle_cjk, truncate_start_cjk};
+pub use uax11::{east_asian_width, EastAsianWidth};
+pub use width_tables::{TableError, WidthTables};
 
+mod accumulator;
+mod ansi;
+mod class;
+mod column;
+pub mod compat;
+mod config;
+mod controls;
+mod display;
+pub mod emoji;
+mod lines;
+mod overrides;
+pub mod presets;
+mod profile;
+mod segments;
+mod split;
 mod tables;
+mod tabs;
+#[cfg(feature = "alloc")]
+mod truncate;
+pub mod uax11;
+mod width_tables;
+#[cfg(feature = "alloc")]
+pub mod wrap;
 
 mod private {
     pub trait Sealed {}
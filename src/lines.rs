@@ -0,0 +1,134 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::iter::FusedIterator;
+
+use crate::width_tables::StateMachine;
+
+/// Returns an iterator over the displayed widths of the lines of `s`.
+///
+/// Lines end at `"\n"`, `"\r\n"`, `"\r"`, `'\u{2028}'` LINE SEPARATOR or
+/// `'\u{2029}'` PARAGRAPH SEPARATOR, which don't count towards the width.
+/// As with [`str::lines`], a line break at the very end of `s` doesn't start
+/// another line, so an empty string has no lines.
+///
+/// Characters in the Ambiguous category are treated as 1 column wide,
+/// as in [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+///
+/// ```rust
+/// use unicode_width::line_widths;
+///
+/// let widths: Vec<_> = line_widths("Hello,\r\n世界!\r\n").collect();
+/// assert_eq!(widths, [6, 5]);
+/// ```
+#[inline]
+pub fn line_widths(s: &str) -> LineWidths<'_> {
+    LineWidths::new(StateMachine::DEFAULT, s)
+}
+
+/// Returns an iterator over the displayed widths of the lines of `s`.
+///
+/// This is [`line_widths`], but with characters in the Ambiguous category
+/// treated as 2 columns wide, as in
+/// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+#[cfg(feature = "cjk")]
+#[inline]
+pub fn line_widths_cjk(s: &str) -> LineWidths<'_> {
+    LineWidths::new(StateMachine::CJK, s)
+}
+
+/// Returns the displayed width of the widest line of `s`, or 0 if it has no lines.
+///
+/// Lines are split as by [`line_widths`].
+///
+/// Characters in the Ambiguous category are treated as 1 column wide,
+/// as in [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+///
+/// ```rust
+/// use unicode_width::max_line_width;
+///
+/// assert_eq!(max_line_width("error: mismatched types\n  expected `中文`"), 23);
+/// ```
+#[inline]
+pub fn max_line_width(s: &str) -> usize {
+    line_widths(s).max().unwrap_or(0)
+}
+
+/// Returns the displayed width of the widest line of `s`, or 0 if it has no lines.
+///
+/// This is [`max_line_width`], but with characters in the Ambiguous category
+/// treated as 2 columns wide, as in
+/// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+#[cfg(feature = "cjk")]
+#[inline]
+pub fn max_line_width_cjk(s: &str) -> usize {
+    line_widths_cjk(s).max().unwrap_or(0)
+}
+
+/// An iterator over the displayed widths of the lines of a string.
+///
+/// This struct is created by [`line_widths`] or [`line_widths_cjk`].
+#[derive(Clone)]
+pub struct LineWidths<'a> {
+    machine: StateMachine<'a>,
+    /// The lines that haven't been visited yet.
+    rest: &'a str,
+}
+
+impl<'a> LineWidths<'a> {
+    #[inline]
+    pub(crate) fn new(machine: StateMachine<'a>, s: &'a str) -> Self {
+        Self { machine, rest: s }
+    }
+}
+
+impl Iterator for LineWidths<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let (line, rest) = match self.rest.find(['\n', '\r', '\u{2028}', '\u{2029}']) {
+            Some(i) => {
+                let (line, rest) = self.rest.split_at(i);
+                let c = rest.chars().next().unwrap();
+                let rest = &rest[c.len_utf8()..];
+                (
+                    line,
+                    if c == '\r' {
+                        rest.strip_prefix('\n').unwrap_or(rest)
+                    } else {
+                        rest
+                    },
+                )
+            }
+            None => (self.rest, ""),
+        };
+        self.rest = rest;
+        Some(self.machine.str_width(line))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::from(!self.rest.is_empty()), Some(self.rest.len()))
+    }
+}
+
+impl FusedIterator for LineWidths<'_> {}
+
+impl core::fmt::Debug for LineWidths<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LineWidths")
+            .field("rest", &self.rest)
+            .finish_non_exhaustive()
+    }
+}
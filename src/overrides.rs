@@ -0,0 +1,221 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::cmp::Ordering;
+use core::ops::RangeInclusive;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::fmt;
+
+/// A table of widths that take precedence over this crate's own, for characters
+/// that a particular font or terminal draws differently, such as Private Use Area icons.
+///
+/// The table is a slice of ranges of characters, each with its width, sorted
+/// in ascending order and not overlapping. It is used with
+/// [`WidthConfig::overrides`](crate::WidthConfig::overrides).
+///
+/// ```rust
+/// use unicode_width::{WidthConfig, WidthOverrides};
+///
+/// const OVERRIDES: WidthOverrides<'static> =
+///     WidthOverrides::new(&[('\u{2192}'..='\u{2193}', 2), ('\u{E000}'..='\u{F8FF}', 2)]);
+///
+/// let config = WidthConfig::DEFAULT.overrides(OVERRIDES);
+/// assert_eq!(config.char_width('\u{E0B0}'), Some(2));
+/// assert_eq!(config.width("a\u{2192}b"), 4);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WidthOverrides<'a> {
+    ranges: &'a [(RangeInclusive<char>, usize)],
+}
+
+impl<'a> WidthOverrides<'a> {
+    /// The table that overrides nothing.
+    pub const EMPTY: Self = Self { ranges: &[] };
+
+    /// Creates a table from ranges of characters and their widths.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a range is empty, or if the ranges are not sorted in ascending order
+    /// or overlap. In a `const` context, this is a compile error instead.
+    pub const fn new(ranges: &'a [(RangeInclusive<char>, usize)]) -> Self {
+        let mut i = 0;
+        while i < ranges.len() {
+            let start = *ranges[i].0.start();
+            assert!(
+                start <= *ranges[i].0.end(),
+                "empty range in width overrides"
+            );
+            if i > 0 {
+                assert!(
+                    *ranges[i - 1].0.end() < start,
+                    "width overrides are not sorted or overlap"
+                );
+            }
+            i += 1;
+        }
+        Self { ranges }
+    }
+
+    /// Returns the ranges of characters in the table and their widths.
+    #[inline]
+    pub const fn ranges(self) -> &'a [(RangeInclusive<char>, usize)] {
+        self.ranges
+    }
+
+    /// Returns whether the table overrides nothing.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns the width of `c` according to the table, or `None` if it isn't overridden.
+    #[inline]
+    pub fn get(self, c: char) -> Option<usize> {
+        self.ranges
+            .binary_search_by(|(range, _)| {
+                if c < *range.start() {
+                    Ordering::Greater
+                } else if c > *range.end() {
+                    Ordering::Less
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .ok()
+            .map(|i| self.ranges[i].1)
+    }
+}
+
+impl Default for WidthOverrides<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+/// Parses a table of widths for [`WidthOverrides::new`] from the format of
+/// the Unicode Character Database files.
+///
+/// Each line holds a character or a range of characters, as hexadecimal code points,
+/// and its width, separated by `;`. Anything after a `#` is a comment, and
+/// blank lines are ignored. The lines may come in any order.
+///
+/// ```text
+/// # Powerline symbols
+/// E0A0..E0A2 ; 1
+/// E0B0..E0B3 ; 1
+/// 2192       ; 2  # RIGHTWARDS ARROW
+/// ```
+///
+/// ```rust
+/// use unicode_width::{parse_width_overrides, WidthConfig, WidthOverrides};
+///
+/// let ranges = parse_width_overrides("2192 ; 2\nE0A0..E0A2 ; 1 # Powerline\n").unwrap();
+/// let config = WidthConfig::DEFAULT.overrides(WidthOverrides::new(&ranges));
+/// assert_eq!(config.width("\u{E0A0}\u{2192}"), 3);
+///
+/// assert_eq!(parse_width_overrides("2192\n").unwrap_err().line(), 1);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn parse_width_overrides(
+    text: &str,
+) -> Result<Vec<(RangeInclusive<char>, usize)>, ParseOverridesError> {
+    let mut ranges = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line_number = i + 1;
+        let error = |kind| ParseOverridesError {
+            line: line_number,
+            kind,
+        };
+
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let (chars, width) = line.split_once(';').ok_or(error(ErrorKind::Syntax))?;
+        let (start, end) = match chars.trim().split_once("..") {
+            Some((start, end)) => (start, end),
+            None => (chars.trim(), chars.trim()),
+        };
+        let start = parse_char(start).ok_or(error(ErrorKind::CodePoint))?;
+        let end = parse_char(end).ok_or(error(ErrorKind::CodePoint))?;
+        if start > end {
+            return Err(error(ErrorKind::CodePoint));
+        }
+        let width = width.trim().parse().map_err(|_| error(ErrorKind::Width))?;
+        ranges.push((start..=end, width, line_number));
+    }
+
+    ranges.sort_by_key(|(range, _, _)| *range.start());
+    for pair in ranges.windows(2) {
+        if pair[1].0.start() <= pair[0].0.end() {
+            return Err(ParseOverridesError {
+                line: pair[0].2.max(pair[1].2),
+                kind: ErrorKind::Overlap,
+            });
+        }
+    }
+    Ok(ranges
+        .into_iter()
+        .map(|(range, width, _)| (range, width))
+        .collect())
+}
+
+#[cfg(feature = "alloc")]
+fn parse_char(s: &str) -> Option<char> {
+    let s = s.trim();
+    if s.is_empty() || s.starts_with('+') {
+        return None;
+    }
+    u32::from_str_radix(s, 16).ok().and_then(char::from_u32)
+}
+
+/// The error returned by [`parse_width_overrides`] for a line it can't parse.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseOverridesError {
+    line: usize,
+    kind: ErrorKind,
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ErrorKind {
+    Syntax,
+    CodePoint,
+    Width,
+    Overlap,
+}
+
+#[cfg(feature = "alloc")]
+impl ParseOverridesError {
+    /// Returns the number of the line with the error, counting from 1.
+    #[inline]
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for ParseOverridesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self.kind {
+            ErrorKind::Syntax => "expected a code point or range, `;`, and a width",
+            ErrorKind::CodePoint => "invalid code point or range",
+            ErrorKind::Width => "invalid width",
+            ErrorKind::Overlap => "range overlaps another one",
+        };
+        write!(f, "line {}: {}", self.line, message)
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Ready-made [`WidthOverrides`] for fonts that put their own glyphs in the
+//! Private Use Area.
+//!
+//! Private Use Area characters have no assigned glyphs, so this crate treats them
+//! as 1 column wide, or as 2 columns wide in an East Asian context since they are in
+//! the Ambiguous category. Fonts that assign them glyphs often draw some of them wider.
+
+use crate::overrides::WidthOverrides;
+
+/// The widths of the icons that [Nerd Fonts](https://www.nerdfonts.com/) 3.3 patches into
+/// fonts, in the Private Use Area, as drawn by a terminal using a font that isn't a
+/// "Mono" variant.
+///
+/// The [glyph sets](https://github.com/ryanoasis/nerd-fonts/wiki/Glyph-Sets-and-Code-Points)
+/// of icons, such as Font Awesome, Devicons, Codicons and Material Design Icons,
+/// are 2 columns wide. The Powerline separators and symbols at `'\u{E0A0}'`–`'\u{E0D7}'`
+/// are 1 column wide, so that they line up with the cells they separate, even
+/// in an East Asian context. The glyphs that Nerd Fonts patches in outside the
+/// Private Use Area, such as box drawing characters, keep their usual widths.
+///
+/// ```rust
+/// use unicode_width::{presets, WidthConfig};
+///
+/// let config = WidthConfig::DEFAULT.overrides(presets::nerd_fonts());
+/// // U+E0A0 POWERLINE BRANCH, U+E0B0 POWERLINE RIGHT ARROW, U+F09B GITHUB
+/// assert_eq!(config.width("\u{E0A0} main \u{E0B0}"), 8);
+/// assert_eq!(config.width("\u{F09B} repo"), 7);
+/// ```
+#[inline]
+pub const fn nerd_fonts() -> WidthOverrides<'static> {
+    NERD_FONTS
+}
+
+const NERD_FONTS: WidthOverrides<'static> = WidthOverrides::new(&[
+    // Pomicons
+    ('\u{E000}'..='\u{E00A}', 2),
+    // Powerline symbols and Powerline Extra symbols
+    ('\u{E0A0}'..='\u{E0A3}', 1),
+    ('\u{E0B0}'..='\u{E0C8}', 1),
+    ('\u{E0CA}'..='\u{E0CA}', 1),
+    ('\u{E0CC}'..='\u{E0D7}', 1),
+    // Font Awesome Extension
+    ('\u{E200}'..='\u{E2A9}', 2),
+    // Weather Icons
+    ('\u{E300}'..='\u{E3E3}', 2),
+    // Seti-UI and Custom icons
+    ('\u{E5FA}'..='\u{E6B7}', 2),
+    // Devicons
+    ('\u{E700}'..='\u{E8EF}', 2),
+    // Codicons
+    ('\u{EA60}'..='\u{EC1E}', 2),
+    // Font Awesome
+    ('\u{ED00}'..='\u{F2FF}', 2),
+    // Font Logos
+    ('\u{F300}'..='\u{F381}', 2),
+    // Octicons
+    ('\u{F400}'..='\u{F533}', 2),
+    // Material Design Icons
+    ('\u{F0001}'..='\u{F1AF0}', 2),
+]);
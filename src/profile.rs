@@ -0,0 +1,113 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::config::WidthConfig;
+
+/// How wide a terminal emulator draws emoji sequences, for laying out text
+/// the way it will actually appear there.
+///
+/// Terminals differ in whether they draw [emoji ZWJ sequences] as a single emoji,
+/// whether `'\u{FE0F}'` VARIATION SELECTOR-16 widens a character such as `'\u{2764}'`,
+/// and whether `'\u{FE0E}'` VARIATION SELECTOR-15 narrows an emoji such as `'\u{231A}'`.
+/// Each profile describes the recent releases of a terminal in their default
+/// configuration, on top of the same tables as
+/// [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width). Older releases, and
+/// terminals configured otherwise, may behave differently; [`Profile::config`]
+/// can be used as a starting point in that case.
+///
+/// All profiles treat characters in the Ambiguous category as 1 column wide.
+/// Terminals that can be set to treat them as 2 columns wide can be matched with
+/// [`WidthConfig::ambiguous_wide`].
+///
+/// ```rust
+/// use unicode_width::Profile;
+///
+/// assert_eq!(Profile::Kitty.width("👩‍🔬 \u{2764}\u{FE0F}"), 5);
+/// assert_eq!(Profile::Xterm.width("👩‍🔬 \u{2764}\u{FE0F}"), 6);
+/// ```
+///
+/// [emoji ZWJ sequences]: https://www.unicode.org/reports/tr51/#def_emoji_zwj_sequence
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Profile {
+    /// [xterm](https://invisible-island.net/xterm/), which draws each character
+    /// on its own and ignores variation selectors.
+    Xterm,
+    /// Terminals built on [VTE](https://gitlab.gnome.org/GNOME/vte), such as
+    /// GNOME Terminal, which draw each character on its own and ignore
+    /// variation selectors.
+    Vte,
+    /// [kitty](https://sw.kovidgoyal.net/kitty/), which draws emoji sequences
+    /// as a single emoji and honors both variation selectors.
+    Kitty,
+    /// [WezTerm](https://wezfurlong.org/wezterm/), which draws emoji sequences
+    /// as a single emoji but, with its default `unicode_version` of 9,
+    /// ignores variation selectors.
+    WezTerm,
+    /// [foot](https://codeberg.org/dnkl/foot), which draws emoji sequences
+    /// as a single emoji and honors both variation selectors.
+    Foot,
+    /// [Alacritty](https://alacritty.org/), which draws each character
+    /// on its own and ignores variation selectors.
+    Alacritty,
+    /// [Windows Terminal](https://github.com/microsoft/terminal), which draws
+    /// emoji sequences as a single emoji and widens characters followed by
+    /// `'\u{FE0F}'`, but doesn't narrow those followed by `'\u{FE0E}'`.
+    WindowsTerminal,
+    /// The Windows console host, which draws each character on its own
+    /// and ignores variation selectors.
+    Conhost,
+    /// macOS Terminal, which draws emoji sequences as a single emoji
+    /// and honors both variation selectors.
+    AppleTerminal,
+    /// [tmux](https://github.com/tmux/tmux), which widens characters followed by
+    /// `'\u{FE0F}'`, but otherwise counts each character on its own.
+    Tmux,
+    /// [GNU Screen](https://www.gnu.org/software/screen/), which counts each
+    /// character on its own and ignores variation selectors.
+    Screen,
+}
+
+impl Profile {
+    /// Returns the [`WidthConfig`] matching this terminal.
+    ///
+    /// To use the profile with tables loaded at runtime, set them with
+    /// [`WidthConfig::tables`].
+    pub const fn config(self) -> WidthConfig<'static> {
+        let (join_zwj_sequences, widen_emoji_presentation, narrow_text_presentation) = match self {
+            Profile::Xterm
+            | Profile::Vte
+            | Profile::Alacritty
+            | Profile::Conhost
+            | Profile::Screen => (false, false, false),
+            Profile::Kitty | Profile::Foot | Profile::AppleTerminal => (true, true, true),
+            Profile::WezTerm => (true, false, false),
+            Profile::WindowsTerminal => (true, true, false),
+            Profile::Tmux => (false, true, false),
+        };
+        WidthConfig::DEFAULT
+            .join_zwj_sequences(join_zwj_sequences)
+            .widen_emoji_presentation(widen_emoji_presentation)
+            .narrow_text_presentation(narrow_text_presentation)
+    }
+
+    /// Returns the width of `s` in columns as drawn by this terminal.
+    #[inline]
+    pub fn width(self, s: &str) -> usize {
+        self.config().width(s)
+    }
+}
+
+impl From<Profile> for WidthConfig<'static> {
+    #[inline]
+    fn from(profile: Profile) -> Self {
+        profile.config()
+    }
+}
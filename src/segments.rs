@@ -0,0 +1,301 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use crate::split::{can_split, look_back};
+use crate::tables::{WidthInfo, WIDTH_INFO_STATES, WIDTH_INFO_STATES_LEN};
+use crate::width_tables::StateMachine;
+
+/// How many characters [`PassedOn`] works out what is passed on after at a time.
+const CHUNK_LEN: usize = 32;
+
+/// How many positions further ahead [`PassedOn`] keeps what is passed on at.
+const CHECKPOINTS_LEN: usize = 32;
+
+/// Returns an iterator over the smallest pieces of `s` whose width doesn't depend
+/// on what's around them, as byte ranges along with their widths.
+///
+/// Splitting `s` between any two pieces, and measuring each side on its own, gives the
+/// same total width as measuring `s` as a whole. The pieces are the ones between the
+/// split points that [`split_at_width`](crate::split_at_width) chooses from, so a piece
+/// can be an emoji sequence, a ligature, a flag, or a character along with the
+/// zero-width characters after it. The widths of the pieces add up to the width of `s`.
+///
+/// Characters in the Ambiguous category are treated as 1 column wide,
+/// as in [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+///
+/// ```rust
+/// use unicode_width::width_segments;
+///
+/// let segments: Vec<_> = width_segments("e\u{301}中👩‍🔬").collect();
+/// assert_eq!(segments, [(0..3, 1), (3..6, 2), (6..17, 2)]);
+/// ```
+#[inline]
+pub fn width_segments(s: &str) -> WidthSegments<'_> {
+    WidthSegments::new(StateMachine::DEFAULT, s)
+}
+
+/// Returns an iterator over the smallest pieces of `s` whose width doesn't depend
+/// on what's around them, as byte ranges along with their widths.
+///
+/// This is [`width_segments`], but with characters in the Ambiguous category
+/// treated as 2 columns wide, as in
+/// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+#[cfg(feature = "cjk")]
+#[inline]
+pub fn width_segments_cjk(s: &str) -> WidthSegments<'_> {
+    WidthSegments::new(StateMachine::CJK, s)
+}
+
+/// An iterator over the pieces of a string whose width doesn't depend on what's
+/// around them.
+///
+/// This struct is created by [`width_segments`] or [`width_segments_cjk`].
+#[derive(Clone)]
+pub struct WidthSegments<'a> {
+    machine: StateMachine<'a>,
+    s: &'a str,
+    /// Where the next piece starts.
+    start: usize,
+    passed_on: PassedOn,
+}
+
+impl<'a> WidthSegments<'a> {
+    pub(crate) fn new(machine: StateMachine<'a>, s: &'a str) -> Self {
+        Self {
+            machine,
+            s,
+            start: 0,
+            passed_on: PassedOn::new(),
+        }
+    }
+
+    /// Whether the piece starting at `start` can end at `mid`, right before `c`.
+    ///
+    /// This is checked against the rest of the string after `start` rather than
+    /// all of it, so that the widths of the pieces add up to the width of the string.
+    fn is_end(&mut self, mid: usize, c: char) -> bool {
+        let rest = &self.s[self.start..mid];
+        can_split(self.machine, rest, c, &mut None, || {
+            let next_info = self.passed_on.at(self.machine, self.s, mid);
+            let (joined_width, split_width, _) = look_back(self.machine, rest, next_info);
+            joined_width == split_width
+        })
+    }
+}
+
+impl Iterator for WidthSegments<'_> {
+    type Item = (Range<usize>, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.s.len() {
+            return None;
+        }
+
+        let mut chars = self.s[self.start..].chars();
+        let mut end = self.start;
+        while let Some(c) = chars.next() {
+            end += c.len_utf8();
+            match chars.clone().next() {
+                Some(next) if !self.is_end(end, next) => {}
+                _ => break,
+            }
+        }
+
+        let range = self.start..end;
+        self.start = end;
+        let width = self.machine.str_width(&self.s[range.clone()]);
+        Some((range, width))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let rest = &self.s[self.start..];
+        (usize::from(!rest.is_empty()), Some(rest.len()))
+    }
+}
+
+impl FusedIterator for WidthSegments<'_> {}
+
+impl core::fmt::Debug for WidthSegments<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("WidthSegments")
+            .field("rest", &&self.s[self.start..])
+            .finish_non_exhaustive()
+    }
+}
+
+/// Works out what the part of a string after each position passes on to the character
+/// before it, for positions that only ever move forward.
+///
+/// That can depend on everything up to the end of the string, as in runs of regional
+/// indicators or Kirat Rai vowel signs. So besides what is passed on at the positions
+/// in the next chunk of characters, this keeps it at a few positions further ahead,
+/// each about half as far as the one after it, so that nothing is gone over more than
+/// a logarithmic number of times.
+#[derive(Clone)]
+struct PassedOn {
+    /// What is passed on at consecutive positions, from `index` on.
+    chunk: [(usize, WidthInfo); CHUNK_LEN + 1],
+    chunk_len: usize,
+    index: usize,
+    /// What is passed on at positions after the chunk, nearest last.
+    checkpoints: [(usize, WidthInfo); CHECKPOINTS_LEN],
+    checkpoints_len: usize,
+}
+
+impl PassedOn {
+    fn new() -> Self {
+        Self {
+            chunk: [(0, WidthInfo::DEFAULT); CHUNK_LEN + 1],
+            chunk_len: 0,
+            index: 0,
+            checkpoints: [(0, WidthInfo::DEFAULT); CHECKPOINTS_LEN],
+            checkpoints_len: 0,
+        }
+    }
+
+    /// Returns what `s[pos..]` passes on to the character before it.
+    fn at(&mut self, machine: StateMachine<'_>, s: &str, pos: usize) -> WidthInfo {
+        loop {
+            while let Some(&(chunk_pos, info)) = self.chunk[..self.chunk_len].get(self.index) {
+                if chunk_pos == pos {
+                    return info;
+                } else if chunk_pos > pos {
+                    break;
+                }
+                self.index += 1;
+            }
+            self.fill(machine, s, pos);
+        }
+    }
+
+    /// Fills `chunk` with what is passed on at `pos` and the positions after it.
+    fn fill(&mut self, machine: StateMachine<'_>, s: &str, pos: usize) {
+        let end = chunk_end(s, pos);
+        let (end, mut info) = match settle(machine, &s[pos..end]) {
+            Some((i, info)) => (pos + i, info),
+            None => (end, self.far(machine, s, end)),
+        };
+
+        self.chunk[0] = (end, info);
+        self.chunk_len = 1;
+        for (i, c) in s[pos..end].char_indices().rev() {
+            info = machine.width_in_str(c, info).1;
+            self.chunk[self.chunk_len] = (pos + i, info);
+            self.chunk_len += 1;
+        }
+        self.chunk[..self.chunk_len].reverse();
+        self.index = 0;
+    }
+
+    /// Returns what `s[pos..]` passes on to the character before it,
+    /// from the nearest checkpoint after it.
+    fn far(&mut self, machine: StateMachine<'_>, s: &str, pos: usize) -> WidthInfo {
+        while matches!(
+            self.checkpoints[..self.checkpoints_len].last(),
+            Some(&(checkpoint, _)) if checkpoint < pos
+        ) {
+            self.checkpoints_len -= 1;
+        }
+        let (mut checkpoint, mut info) = match self.checkpoints[..self.checkpoints_len].last() {
+            Some(&checkpoint) => checkpoint,
+            None => {
+                let checkpoint = anchor(machine, s, pos);
+                self.checkpoints[0] = checkpoint;
+                self.checkpoints_len = 1;
+                checkpoint
+            }
+        };
+
+        // Leave checkpoints halfway along, to come back to from later positions
+        while checkpoint - pos > 4 * CHUNK_LEN && self.checkpoints_len < CHECKPOINTS_LEN {
+            let mut mid = pos + (checkpoint - pos) / 2;
+            while !s.is_char_boundary(mid) {
+                mid -= 1;
+            }
+            info = passed_on_before(machine, &s[mid..checkpoint], info);
+            checkpoint = mid;
+            self.checkpoints[self.checkpoints_len] = (checkpoint, info);
+            self.checkpoints_len += 1;
+        }
+        passed_on_before(machine, &s[pos..checkpoint], info)
+    }
+}
+
+/// Returns the end of the chunk of characters that starts at `pos`.
+fn chunk_end(s: &str, pos: usize) -> usize {
+    s[pos..]
+        .char_indices()
+        .nth(CHUNK_LEN)
+        .map_or(s.len(), |(i, _)| pos + i)
+}
+
+/// Returns what `s` passes on to the character before it,
+/// when followed by something that passed on `next_info`.
+fn passed_on_before(machine: StateMachine<'_>, s: &str, next_info: WidthInfo) -> WidthInfo {
+    s.chars().rev().fold(next_info, |next_info, c| {
+        machine.width_in_str(c, next_info).1
+    })
+}
+
+/// Returns the first position from `pos` on at which what is passed on doesn't depend
+/// on anything after it, along with what is passed on there.
+fn anchor(machine: StateMachine<'_>, s: &str, mut pos: usize) -> (usize, WidthInfo) {
+    while pos < s.len() {
+        let end = chunk_end(s, pos);
+        if let Some((i, info)) = settle(machine, &s[pos..end]) {
+            return (pos + i, info);
+        }
+        pos = end;
+    }
+    (s.len(), WidthInfo::DEFAULT)
+}
+
+/// Returns the last position in `chunk` at which what is passed on is the same
+/// whatever comes after `chunk`, along with what that is.
+fn settle(machine: StateMachine<'_>, chunk: &str) -> Option<(usize, WidthInfo)> {
+    let mut chars = chunk.char_indices().rev();
+    let (i, c) = chars.next()?;
+    if c <= '\u{A0}' && !machine.starts_emoji_presentation_seq(c) {
+        // Fast path: what these pass on never depends on what follows them.
+        return Some((i, machine.width_in_str(c, WidthInfo::DEFAULT).1));
+    }
+
+    // Follow everything that could be passed on, until only one thing is left
+    let mut infos = [WidthInfo::DEFAULT; WIDTH_INFO_STATES_LEN];
+    let mut len = 0;
+    for &next_info in WIDTH_INFO_STATES.iter() {
+        let info = machine.width_in_str(c, next_info).1;
+        if !infos[..len].contains(&info) {
+            infos[len] = info;
+            len += 1;
+        }
+    }
+    let mut i = i;
+    for (j, c) in chars {
+        if len == 1 {
+            break;
+        }
+        let mut kept = 0;
+        for k in 0..len {
+            let info = machine.width_in_str(c, infos[k]).1;
+            if !infos[..kept].contains(&info) {
+                infos[kept] = info;
+                kept += 1;
+            }
+        }
+        len = kept;
+        i = j;
+    }
+    (len == 1).then_some((i, infos[0]))
+}
@@ -0,0 +1,335 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::tables::{self, WidthInfo};
+use crate::width_tables::StateMachine;
+
+/// Splits `s` into its longest prefix that is at most `cols` columns wide and the rest,
+/// and returns both along with the width of the prefix.
+///
+/// The split never falls inside a sequence whose width is not the sum of its parts
+/// (such as emoji ZWJ sequences, `"\r\n"`, Khmer coeng signs or Lisu tone letters),
+/// between the two regional indicators of a flag, or before a zero-width character.
+/// So the widths of the two halves always add up to the width of `s`.
+///
+/// Characters in the Ambiguous category are treated as 1 column wide,
+/// as in [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+///
+/// ```rust
+/// use unicode_width::split_at_width;
+///
+/// assert_eq!(split_at_width("ｈｅｌｌｏ", 5), ("ｈｅ", "ｌｌｏ", 4));
+/// assert_eq!(split_at_width("🇮🇸🇳🇴", 3), ("🇮🇸", "🇳🇴", 2));
+/// assert_eq!(split_at_width("👩‍🔬!", 1), ("", "👩‍🔬!", 0));
+/// ```
+pub fn split_at_width(s: &str, cols: usize) -> (&str, &str, usize) {
+    split_at_width_with(StateMachine::DEFAULT, tables::str_width(s), s, cols)
+}
+
+/// Splits `s` into its longest prefix that is at most `cols` columns wide and the rest,
+/// and returns both along with the width of the prefix.
+///
+/// This is [`split_at_width`], but with characters in the Ambiguous category
+/// treated as 2 columns wide, as in
+/// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+#[cfg(feature = "cjk")]
+pub fn split_at_width_cjk(s: &str, cols: usize) -> (&str, &str, usize) {
+    split_at_width_with(StateMachine::CJK, tables::str_width_cjk(s), s, cols)
+}
+
+/// Splits `s` into the rest and its longest suffix that is at most `cols` columns wide,
+/// and returns both along with the width of the suffix.
+///
+/// The split follows the same rules as [`split_at_width`].
+///
+/// Characters in the Ambiguous category are treated as 1 column wide,
+/// as in [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+///
+/// ```rust
+/// use unicode_width::rsplit_at_width;
+///
+/// assert_eq!(rsplit_at_width("ｈｅｌｌｏ", 5), ("ｈｅｌ", "ｌｏ", 4));
+/// assert_eq!(rsplit_at_width("🇮🇸🇳🇴", 3), ("🇮🇸", "🇳🇴", 2));
+/// assert_eq!(rsplit_at_width("e\u{301}", 0), ("e\u{301}", "", 0));
+/// ```
+pub fn rsplit_at_width(s: &str, cols: usize) -> (&str, &str, usize) {
+    rsplit_at_width_with(StateMachine::DEFAULT, s, cols)
+}
+
+/// Splits `s` into the rest and its longest suffix that is at most `cols` columns wide,
+/// and returns both along with the width of the suffix.
+///
+/// This is [`rsplit_at_width`], but with characters in the Ambiguous category
+/// treated as 2 columns wide, as in
+/// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+#[cfg(feature = "cjk")]
+pub fn rsplit_at_width_cjk(s: &str, cols: usize) -> (&str, &str, usize) {
+    rsplit_at_width_with(StateMachine::CJK, s, cols)
+}
+
+pub(crate) fn split_at_width_with<'a>(
+    machine: StateMachine<'_>,
+    width: usize,
+    s: &'a str,
+    cols: usize,
+) -> (&'a str, &'a str, usize) {
+    // The first boundary from the right where the prefix fits is the longest prefix
+    let (mid, suffix_width) = Boundaries::new(machine, s)
+        .find(|&(_, suffix_width)| width - suffix_width <= cols)
+        .unwrap_or((0, width));
+    (&s[..mid], &s[mid..], width - suffix_width)
+}
+
+pub(crate) fn rsplit_at_width_with<'a>(
+    machine: StateMachine<'_>,
+    s: &'a str,
+    cols: usize,
+) -> (&'a str, &'a str, usize) {
+    // Suffix widths can shrink as the suffix grows, so look at all of them
+    let (mid, suffix_width) = Boundaries::new(machine, s)
+        .filter(|&(_, suffix_width)| suffix_width <= cols)
+        .last()
+        .unwrap_or((s.len(), 0));
+    (&s[..mid], &s[mid..], suffix_width)
+}
+
+/// How many characters [`Boundaries`] looks back before it starts keeping track of
+/// the widths that bound how far later look-backs have to go.
+const LOOK_BACK_LEN: usize = 32;
+
+/// How many of those widths [`Boundaries`] keeps track of.
+const KNOWN_LEN: usize = 4;
+
+/// An iterator over the byte indices at which a string can be split,
+/// from last to first, along with the width of the part after each.
+///
+/// `s.len()` and `0` are always boundaries. Any other index is one if splitting there
+/// leaves the width of the string unchanged, doesn't split a pair of regional indicators,
+/// and isn't right before a character that is zero-width on its own.
+#[derive(Clone)]
+pub(crate) struct Boundaries<'a> {
+    machine: StateMachine<'a>,
+    /// The part of the string that hasn't been visited yet.
+    rest: &'a str,
+    /// The width of the part of the string after `rest`,
+    /// or `None` if `rest` is the whole string and nothing has been returned.
+    suffix_width: Option<usize>,
+    /// What the part of the string after `rest` passes on to the last character of `rest`.
+    next_info: WidthInfo,
+    /// How many regional indicators `rest` ends with, if that has been counted.
+    regional_indicators: Option<usize>,
+    /// The width `rest` would have if its last character was passed on each of these
+    /// states, plus some unknown constant, with `next_info` first.
+    /// Sequences like runs of Kirat Rai vowel signs can make the width of all of `rest`
+    /// depend on what follows it, and these stop each look-back from going all the way
+    /// to the start of the string.
+    known: [(WidthInfo, usize); KNOWN_LEN],
+    known_len: usize,
+}
+
+impl<'a> Boundaries<'a> {
+    pub(crate) fn new(machine: StateMachine<'a>, s: &'a str) -> Self {
+        Self {
+            machine,
+            rest: s,
+            suffix_width: None,
+            next_info: WidthInfo::DEFAULT,
+            regional_indicators: None,
+            known: [(WidthInfo::DEFAULT, 0); KNOWN_LEN],
+            known_len: 0,
+        }
+    }
+
+    /// Whether the string can be split between `rest` and `c`,
+    /// the character right after it.
+    fn is_boundary(&mut self, c: char) -> bool {
+        let mut regional_indicators = self.regional_indicators;
+        let is_boundary = can_split(self.machine, self.rest, c, &mut regional_indicators, || {
+            self.has_same_width()
+        });
+        self.regional_indicators = regional_indicators;
+        is_boundary
+    }
+
+    /// Whether `rest` has the same width whether or not it's followed by the rest
+    /// of the string.
+    fn has_same_width(&mut self) -> bool {
+        if self.known_len > 0 {
+            return self.is_boundary_known();
+        }
+
+        let (joined_width, split_width, looked_back) =
+            look_back(self.machine, self.rest, self.next_info);
+
+        // Both widths are now known up to the width of what's before where they agree
+        if looked_back > LOOK_BACK_LEN {
+            self.known = [(self.next_info, joined_width); KNOWN_LEN];
+            self.known[1] = (WidthInfo::DEFAULT, split_width);
+            self.known_len = 2;
+        }
+        joined_width == split_width
+    }
+
+    /// Like the look-back in `has_same_width`, but stops as soon as what `rest` passes on
+    /// when split agrees with one of the `known` cases.
+    fn is_boundary_known(&mut self) -> bool {
+        let mut known = self.known;
+        let known = &mut known[..self.known_len];
+        let mut split_width: usize = 0;
+        let mut split_info = WidthInfo::DEFAULT;
+        let mut chars = self.rest.chars().rev();
+        let width = loop {
+            if let Some(&(_, width)) = known.iter().find(|&&(info, _)| info == split_info) {
+                break split_width.wrapping_add(width);
+            }
+            let Some(c) = chars.next() else {
+                // Nothing is left, so all the known cases have the same width
+                break split_width.wrapping_add(known[0].1);
+            };
+            for (info, width) in known.iter_mut() {
+                let (add, next_info) = self.machine.width_in_str(c, *info);
+                *info = next_info;
+                *width = width.wrapping_add_signed(-isize::from(add));
+            }
+            let (add, info) = self.machine.width_in_str(c, split_info);
+            split_info = info;
+            split_width = split_width.wrapping_add_signed(isize::from(add));
+        };
+
+        // Keep the split case in mind for the next look-back
+        if !self.known[..self.known_len]
+            .iter()
+            .any(|&(info, _)| info == WidthInfo::DEFAULT)
+        {
+            self.known.copy_within(1..KNOWN_LEN - 1, 2);
+            self.known[1] = (WidthInfo::DEFAULT, width);
+            self.known_len = (self.known_len + 1).min(KNOWN_LEN);
+        }
+        width == self.known[0].1
+    }
+
+    /// Updates `known` for `rest` losing its last character, `c`.
+    fn step_known(&mut self, c: char) {
+        let mut len = 0;
+        for i in 0..self.known_len {
+            let (info, width) = self.known[i];
+            let (add, info) = self.machine.width_in_str(c, info);
+            // Cases that pass on the same state have the same width from now on
+            if self.known[..len].iter().all(|&(other, _)| other != info) {
+                self.known[len] = (info, width.wrapping_add_signed(-isize::from(add)));
+                len += 1;
+            }
+        }
+        // With only one case left, the look-back only has to agree with `next_info`
+        self.known_len = if len > 1 { len } else { 0 };
+    }
+}
+
+impl Iterator for Boundaries<'_> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Some(mut suffix_width) = self.suffix_width else {
+            self.suffix_width = Some(0);
+            return Some((self.rest.len(), 0));
+        };
+
+        loop {
+            let c = self.rest.chars().next_back()?;
+            self.rest = &self.rest[..self.rest.len() - c.len_utf8()];
+            self.regional_indicators = match self.regional_indicators {
+                Some(count) if count > 0 => Some(count - 1),
+                _ => None,
+            };
+
+            let (add, info) = self.machine.width_in_str(c, self.next_info);
+            self.next_info = info;
+            suffix_width = suffix_width.wrapping_add_signed(isize::from(add));
+            self.suffix_width = Some(suffix_width);
+            if self.known_len > 0 {
+                self.step_known(c);
+            }
+
+            if self.is_boundary(c) {
+                return Some((self.rest.len(), suffix_width));
+            }
+        }
+    }
+}
+
+/// Whether a string can be split between `rest` and `c`, the character right after it.
+///
+/// `regional_indicators` caches how many regional indicators `rest` ends with, and
+/// `same_width` checks that `rest` has the same width whether or not it's followed by `c`.
+/// Both are only used if the other rules don't already decide.
+pub(crate) fn can_split(
+    machine: StateMachine<'_>,
+    rest: &str,
+    c: char,
+    regional_indicators: &mut Option<usize>,
+    same_width: impl FnOnce() -> bool,
+) -> bool {
+    if rest.is_empty() {
+        return true;
+    }
+
+    // Keep combining marks, joiners and variation selectors with what they apply to
+    if machine.width_in_str(c, WidthInfo::DEFAULT).0 == 0 {
+        return false;
+    }
+
+    // Regional indicators pair up from the start of the run
+    if is_regional_indicator(c) {
+        let count = regional_indicators.get_or_insert_with(|| {
+            rest.chars()
+                .rev()
+                .take_while(|&c| is_regional_indicator(c))
+                .count()
+        });
+        if *count % 2 == 1 {
+            return false;
+        }
+    }
+
+    same_width()
+}
+
+/// Returns the width of `rest` when followed by something that passed on `next_info`,
+/// and when followed by nothing, both up to the same unknown constant, by looking back
+/// until what each case passes on agrees again. Also returns how many characters that took.
+pub(crate) fn look_back(
+    machine: StateMachine<'_>,
+    rest: &str,
+    next_info: WidthInfo,
+) -> (usize, usize, usize) {
+    let mut joined_width: usize = 0;
+    let mut split_width: usize = 0;
+    let mut joined_info = next_info;
+    let mut split_info = WidthInfo::DEFAULT;
+    let mut chars = rest.chars().rev();
+    let mut looked_back = 0;
+    while joined_info != split_info {
+        let Some(c) = chars.next() else {
+            break;
+        };
+        let (joined_add, info) = machine.width_in_str(c, joined_info);
+        joined_info = info;
+        joined_width = joined_width.wrapping_add_signed(isize::from(joined_add));
+        let (split_add, info) = machine.width_in_str(c, split_info);
+        split_info = info;
+        split_width = split_width.wrapping_add_signed(isize::from(split_add));
+        looked_back += 1;
+    }
+    (joined_width, split_width, looked_back)
+}
+
+pub(crate) fn is_regional_indicator(c: char) -> bool {
+    matches!(c, '\u{1F1E6}'..='\u{1F1FF}')
+}
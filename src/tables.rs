@@ -12,14 +12,16 @@
 
 use core::cmp::Ordering;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-struct WidthInfo(u16);
+use crate::uax11::EastAsianWidth;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct WidthInfo(u16);
 
 const LIGATURE_TRANSPARENT_MASK: u16 = 0b0010_0000_0000_0000;
 
 impl WidthInfo {
     /// No special handling necessary
-    const DEFAULT: Self = Self(0);
+    pub const DEFAULT: Self = Self(0);
     const LINE_FEED: Self = Self(0b0000000000000001);
     const EMOJI_MODIFIER: Self = Self(0b0000000000000010);
     const REGIONAL_INDICATOR: Self = Self(0b0000000000000011);
@@ -160,10 +162,208 @@ impl WidthInfo {
     }
 }
 
+/// The number of distinct `WidthInfo`s the string width state machines can produce.
+pub const WIDTH_INFO_STATES_LEN: usize = 148;
+
+/// Every `WidthInfo` that `width_in_str` or `width_in_str_cjk` can return, in ascending order.
+/// This is each carried width state, plus every combination of variation selector bits
+/// on top of the ones that are transparent to emoji and text presentation.
+#[rustfmt::skip]
+pub static WIDTH_INFO_STATES: [WidthInfo; WIDTH_INFO_STATES_LEN] = [
+    WidthInfo(0b0000000000000000),
+    WidthInfo(0b0000000000000001),
+    WidthInfo(0b0000000000000010),
+    WidthInfo(0b0000000000000011),
+    WidthInfo(0b0000000000000100),
+    WidthInfo(0b0000000000000101),
+    WidthInfo(0b0000000000001001),
+    WidthInfo(0b0000000000001010),
+    WidthInfo(0b0000000000001011),
+    WidthInfo(0b0000000000010000),
+    WidthInfo(0b0000000000010001),
+    WidthInfo(0b0000000000010010),
+    WidthInfo(0b0000000000010011),
+    WidthInfo(0b0000000000011001),
+    WidthInfo(0b0000000000011010),
+    WidthInfo(0b0000000000011011),
+    WidthInfo(0b0000000000011100),
+    WidthInfo(0b0000000000011101),
+    WidthInfo(0b0000000000011110),
+    WidthInfo(0b0000000000100000),
+    WidthInfo(0b0000000000100001),
+    WidthInfo(0b0000001000000000),
+    WidthInfo(0b0001000000000110),
+    WidthInfo(0b0001000000000111),
+    WidthInfo(0b0011000011111111),
+    WidthInfo(0b0011001011111111),
+    WidthInfo(0b0011100000000000),
+    WidthInfo(0b0011100000000001),
+    WidthInfo(0b0011100000000011),
+    WidthInfo(0b0011100000000110),
+    WidthInfo(0b0011100011111111),
+    WidthInfo(0b0011101000000000),
+    WidthInfo(0b0011101000000001),
+    WidthInfo(0b0011101000000011),
+    WidthInfo(0b0011101000000110),
+    WidthInfo(0b0011101011111111),
+    WidthInfo(0b0011110000000000),
+    WidthInfo(0b0011110000000001),
+    WidthInfo(0b0011110000000010),
+    WidthInfo(0b0011110000000011),
+    WidthInfo(0b0011110000000100),
+    WidthInfo(0b0011110000000101),
+    WidthInfo(0b0011110000000110),
+    WidthInfo(0b0011110000000111),
+    WidthInfo(0b0011110011111111),
+    WidthInfo(0b0011111000000000),
+    WidthInfo(0b0011111000000001),
+    WidthInfo(0b0011111000000010),
+    WidthInfo(0b0011111000000011),
+    WidthInfo(0b0011111000000100),
+    WidthInfo(0b0011111000000101),
+    WidthInfo(0b0011111000000110),
+    WidthInfo(0b0011111000000111),
+    WidthInfo(0b0011111011111111),
+    WidthInfo(0b0100000000000000),
+    WidthInfo(0b0111000011111111),
+    WidthInfo(0b0111001011111111),
+    WidthInfo(0b0111100000000000),
+    WidthInfo(0b0111100000000001),
+    WidthInfo(0b0111100000000011),
+    WidthInfo(0b0111100000000110),
+    WidthInfo(0b0111100011111111),
+    WidthInfo(0b0111101000000000),
+    WidthInfo(0b0111101000000001),
+    WidthInfo(0b0111101000000011),
+    WidthInfo(0b0111101000000110),
+    WidthInfo(0b0111101011111111),
+    WidthInfo(0b0111110000000000),
+    WidthInfo(0b0111110000000001),
+    WidthInfo(0b0111110000000010),
+    WidthInfo(0b0111110000000011),
+    WidthInfo(0b0111110000000100),
+    WidthInfo(0b0111110000000101),
+    WidthInfo(0b0111110000000110),
+    WidthInfo(0b0111110000000111),
+    WidthInfo(0b0111110011111111),
+    WidthInfo(0b0111111000000000),
+    WidthInfo(0b0111111000000001),
+    WidthInfo(0b0111111000000010),
+    WidthInfo(0b0111111000000011),
+    WidthInfo(0b0111111000000100),
+    WidthInfo(0b0111111000000101),
+    WidthInfo(0b0111111000000110),
+    WidthInfo(0b0111111000000111),
+    WidthInfo(0b0111111011111111),
+    WidthInfo(0b1000000000000000),
+    WidthInfo(0b1001000000000110),
+    WidthInfo(0b1001000000000111),
+    WidthInfo(0b1011000011111111),
+    WidthInfo(0b1011001011111111),
+    WidthInfo(0b1011100000000000),
+    WidthInfo(0b1011100000000001),
+    WidthInfo(0b1011100000000011),
+    WidthInfo(0b1011100000000110),
+    WidthInfo(0b1011100011111111),
+    WidthInfo(0b1011101000000000),
+    WidthInfo(0b1011101000000001),
+    WidthInfo(0b1011101000000011),
+    WidthInfo(0b1011101000000110),
+    WidthInfo(0b1011101011111111),
+    WidthInfo(0b1011110000000000),
+    WidthInfo(0b1011110000000001),
+    WidthInfo(0b1011110000000010),
+    WidthInfo(0b1011110000000011),
+    WidthInfo(0b1011110000000100),
+    WidthInfo(0b1011110000000101),
+    WidthInfo(0b1011110000000110),
+    WidthInfo(0b1011110000000111),
+    WidthInfo(0b1011110011111111),
+    WidthInfo(0b1011111000000000),
+    WidthInfo(0b1011111000000001),
+    WidthInfo(0b1011111000000010),
+    WidthInfo(0b1011111000000011),
+    WidthInfo(0b1011111000000100),
+    WidthInfo(0b1011111000000101),
+    WidthInfo(0b1011111000000110),
+    WidthInfo(0b1011111000000111),
+    WidthInfo(0b1011111011111111),
+    WidthInfo(0b1111000011111111),
+    WidthInfo(0b1111001011111111),
+    WidthInfo(0b1111100000000000),
+    WidthInfo(0b1111100000000001),
+    WidthInfo(0b1111100000000011),
+    WidthInfo(0b1111100000000110),
+    WidthInfo(0b1111100011111111),
+    WidthInfo(0b1111101000000000),
+    WidthInfo(0b1111101000000001),
+    WidthInfo(0b1111101000000011),
+    WidthInfo(0b1111101000000110),
+    WidthInfo(0b1111101011111111),
+    WidthInfo(0b1111110000000000),
+    WidthInfo(0b1111110000000001),
+    WidthInfo(0b1111110000000010),
+    WidthInfo(0b1111110000000011),
+    WidthInfo(0b1111110000000100),
+    WidthInfo(0b1111110000000101),
+    WidthInfo(0b1111110000000110),
+    WidthInfo(0b1111110000000111),
+    WidthInfo(0b1111110011111111),
+    WidthInfo(0b1111111000000000),
+    WidthInfo(0b1111111000000001),
+    WidthInfo(0b1111111000000010),
+    WidthInfo(0b1111111000000011),
+    WidthInfo(0b1111111000000100),
+    WidthInfo(0b1111111000000101),
+    WidthInfo(0b1111111000000110),
+    WidthInfo(0b1111111000000111),
+    WidthInfo(0b1111111011111111),
+];
+
 /// The version of [Unicode](http://www.unicode.org/)
 /// that this version of unicode-width is based on.
 pub const UNICODE_VERSION: (u8, u8, u8) = (17, 0, 0);
 
+/// The tables that the width state machine looks characters up in.
+///
+/// [`Builtin`] uses the tables in this module, and [`WidthTables`](crate::WidthTables)
+/// can also use tables loaded at runtime.
+pub trait WidthLookup: Copy {
+    /// Returns the width of `c`, and the state it passes on, with ambiguous width
+    /// characters treated as narrow.
+    fn lookup_width(self, c: char) -> (u8, WidthInfo);
+
+    /// Returns the width of `c`, and the state it passes on, with ambiguous width
+    /// characters treated as wide.
+    #[cfg(feature = "cjk")]
+    fn lookup_width_cjk(self, c: char) -> (u8, WidthInfo);
+
+    /// Whether `c` forms an emoji presentation sequence when followed by `'\u{FE0F}'`.
+    fn starts_emoji_presentation_seq(self, c: char) -> bool;
+}
+
+/// The tables in this module.
+#[derive(Clone, Copy, Debug)]
+pub struct Builtin;
+
+impl WidthLookup for Builtin {
+    #[inline]
+    fn lookup_width(self, c: char) -> (u8, WidthInfo) {
+        lookup_width(c)
+    }
+
+    #[cfg(feature = "cjk")]
+    #[inline]
+    fn lookup_width_cjk(self, c: char) -> (u8, WidthInfo) {
+        lookup_width_cjk(c)
+    }
+
+    #[inline]
+    fn starts_emoji_presentation_seq(self, c: char) -> bool {
+        starts_emoji_presentation_seq(c)
+    }
+}
+
 /// Returns the [UAX #11](https://www.unicode.org/reports/tr11/) based width of `c` by
 /// consulting a multi-level lookup table.
 ///
@@ -195,25 +395,32 @@ fn lookup_width(c: char) -> (u8, WidthInfo) {
     if width < 3 {
         (width, WidthInfo::DEFAULT)
     } else {
-        match c {
-            '\u{A}' => (1, WidthInfo::LINE_FEED),
-            '\u{5DC}' => (1, WidthInfo::HEBREW_LETTER_LAMED),
-            '\u{622}'..='\u{882}' => (1, WidthInfo::JOINING_GROUP_ALEF),
-            '\u{1780}'..='\u{17AF}' => (1, WidthInfo::KHMER_COENG_ELIGIBLE_LETTER),
-            '\u{17D8}' => (3, WidthInfo::DEFAULT),
-            '\u{1A10}' => (1, WidthInfo::BUGINESE_LETTER_YA),
-            '\u{2D31}'..='\u{2D6F}' => (1, WidthInfo::TIFINAGH_CONSONANT),
-            '\u{A4FC}'..='\u{A4FD}' => (1, WidthInfo::LISU_TONE_LETTER_MYA_NA_JEU),
-            '\u{FE01}' => (0, WidthInfo::VARIATION_SELECTOR_1_2_OR_3),
-            '\u{FE0E}' => (0, WidthInfo::VARIATION_SELECTOR_15),
-            '\u{FE0F}' => (0, WidthInfo::VARIATION_SELECTOR_16),
-            '\u{10C03}' => (1, WidthInfo::OLD_TURKIC_LETTER_ORKHON_I),
-            '\u{16D67}' => (1, WidthInfo::KIRAT_RAI_VOWEL_SIGN_E),
-            '\u{16D68}' => (1, WidthInfo::KIRAT_RAI_VOWEL_SIGN_AI),
-            '\u{1F1E6}'..='\u{1F1FF}' => (1, WidthInfo::REGIONAL_INDICATOR),
-            '\u{1F3FB}'..='\u{1F3FF}' => (2, WidthInfo::EMOJI_MODIFIER),
-            _ => (2, WidthInfo::EMOJI_PRESENTATION),
-        }
+        special_width(c)
+    }
+}
+
+/// Returns the width of `c`, and the state it passes on, for a character that the lookup
+/// table marks as special with a width of 3.
+#[inline]
+pub fn special_width(c: char) -> (u8, WidthInfo) {
+    match c {
+        '\u{A}' => (1, WidthInfo::LINE_FEED),
+        '\u{5DC}' => (1, WidthInfo::HEBREW_LETTER_LAMED),
+        '\u{622}'..='\u{882}' => (1, WidthInfo::JOINING_GROUP_ALEF),
+        '\u{1780}'..='\u{17AF}' => (1, WidthInfo::KHMER_COENG_ELIGIBLE_LETTER),
+        '\u{17D8}' => (3, WidthInfo::DEFAULT),
+        '\u{1A10}' => (1, WidthInfo::BUGINESE_LETTER_YA),
+        '\u{2D31}'..='\u{2D6F}' => (1, WidthInfo::TIFINAGH_CONSONANT),
+        '\u{A4FC}'..='\u{A4FD}' => (1, WidthInfo::LISU_TONE_LETTER_MYA_NA_JEU),
+        '\u{FE01}' => (0, WidthInfo::VARIATION_SELECTOR_1_2_OR_3),
+        '\u{FE0E}' => (0, WidthInfo::VARIATION_SELECTOR_15),
+        '\u{FE0F}' => (0, WidthInfo::VARIATION_SELECTOR_16),
+        '\u{10C03}' => (1, WidthInfo::OLD_TURKIC_LETTER_ORKHON_I),
+        '\u{16D67}' => (1, WidthInfo::KIRAT_RAI_VOWEL_SIGN_E),
+        '\u{16D68}' => (1, WidthInfo::KIRAT_RAI_VOWEL_SIGN_AI),
+        '\u{1F1E6}'..='\u{1F1FF}' => (1, WidthInfo::REGIONAL_INDICATOR),
+        '\u{1F3FB}'..='\u{1F3FF}' => (2, WidthInfo::EMOJI_MODIFIER),
+        _ => (2, WidthInfo::EMOJI_PRESENTATION),
     }
 }
 
@@ -222,6 +429,12 @@ fn lookup_width(c: char) -> (u8, WidthInfo) {
 /// Ambiguous width characters are treated as narrow.
 #[inline]
 pub fn single_char_width(c: char) -> Option<usize> {
+    single_char_width_with(Builtin, c)
+}
+
+/// Like [`single_char_width`], but looks `c` up in `tables`.
+#[inline]
+pub fn single_char_width_with(tables: impl WidthLookup, c: char) -> Option<usize> {
     if c < '\u{7F}' {
         if c >= '\u{20}' {
             // U+0020 to U+007F (exclusive) are single-width ASCII codepoints
@@ -232,7 +445,7 @@ pub fn single_char_width(c: char) -> Option<usize> {
         }
     } else if c >= '\u{A0}' {
         // No characters >= U+00A0 are control codes, so we can consult the lookup tables
-        Some(lookup_width(c).0.into())
+        Some(tables.lookup_width(c).0.into())
     } else {
         // U+007F to U+00A0 (exclusive) are control codes
         None
@@ -242,9 +455,19 @@ pub fn single_char_width(c: char) -> Option<usize> {
 /// Returns the [UAX #11](https://www.unicode.org/reports/tr11/) based width of `c`.
 /// Ambiguous width characters are treated as narrow.
 #[inline]
-fn width_in_str(c: char, mut next_info: WidthInfo) -> (i8, WidthInfo) {
+pub fn width_in_str(c: char, next_info: WidthInfo) -> (i8, WidthInfo) {
+    width_in_str_with(Builtin, c, next_info)
+}
+
+/// Like [`width_in_str`], but looks `c` up in `tables`.
+#[inline]
+pub fn width_in_str_with(
+    tables: impl WidthLookup,
+    c: char,
+    mut next_info: WidthInfo,
+) -> (i8, WidthInfo) {
     if next_info.is_emoji_presentation() {
-        if starts_emoji_presentation_seq(c) {
+        if tables.starts_emoji_presentation_seq(c) {
             let width = if next_info.is_zwj_emoji_presentation() {
                 0
             } else {
@@ -365,7 +588,9 @@ fn width_in_str(c: char, mut next_info: WidthInfo) -> (i8, WidthInfo) {
                 (WidthInfo::ZWJ_EMOJI_PRESENTATION, '\u{20E3}') => {
                     return (0, WidthInfo::KEYCAP_ZWJ_EMOJI_PRESENTATION);
                 }
-                (WidthInfo::VS16_ZWJ_EMOJI_PRESENTATION, _) if starts_emoji_presentation_seq(c) => {
+                (WidthInfo::VS16_ZWJ_EMOJI_PRESENTATION, _)
+                    if tables.starts_emoji_presentation_seq(c) =>
+                {
                     return (0, WidthInfo::EMOJI_PRESENTATION)
                 }
                 (WidthInfo::VS16_KEYCAP_ZWJ_EMOJI_PRESENTATION, '0'..='9' | '#' | '*') => {
@@ -430,7 +655,7 @@ fn width_in_str(c: char, mut next_info: WidthInfo) -> (i8, WidthInfo) {
                     '\u{1F3F4}',
                 ) => return (0, WidthInfo::EMOJI_PRESENTATION),
                 (WidthInfo::ZWJ_EMOJI_PRESENTATION, _)
-                    if lookup_width(c).1 == WidthInfo::EMOJI_PRESENTATION =>
+                    if tables.lookup_width(c).1 == WidthInfo::EMOJI_PRESENTATION =>
                 {
                     return (0, WidthInfo::EMOJI_PRESENTATION)
                 }
@@ -456,18 +681,24 @@ fn width_in_str(c: char, mut next_info: WidthInfo) -> (i8, WidthInfo) {
             }
         }
 
-        let ret = lookup_width(c);
+        let ret = tables.lookup_width(c);
         (ret.0 as i8, ret.1)
     }
 }
 
 #[inline]
 pub fn str_width(s: &str) -> usize {
+    str_width_with(Builtin, s)
+}
+
+/// Like [`str_width`], but looks characters up in `tables`.
+#[inline]
+pub fn str_width_with(tables: impl WidthLookup, s: &str) -> usize {
     s.chars()
         .rfold(
             (0, WidthInfo::DEFAULT),
             |(sum, next_info), c| -> (usize, WidthInfo) {
-                let (add, info) = width_in_str(c, next_info);
+                let (add, info) = width_in_str_with(tables, c, next_info);
                 (sum.wrapping_add_signed(isize::from(add)), info)
             },
         )
@@ -506,25 +737,33 @@ fn lookup_width_cjk(c: char) -> (u8, WidthInfo) {
     if width < 3 {
         (width, WidthInfo::DEFAULT)
     } else {
-        match c {
-            '\u{A}' => (1, WidthInfo::LINE_FEED),
-            '\u{338}' => (0, WidthInfo::COMBINING_LONG_SOLIDUS_OVERLAY),
-            '\u{5DC}' => (1, WidthInfo::HEBREW_LETTER_LAMED),
-            '\u{622}'..='\u{882}' => (1, WidthInfo::JOINING_GROUP_ALEF),
-            '\u{1780}'..='\u{17AF}' => (1, WidthInfo::KHMER_COENG_ELIGIBLE_LETTER),
-            '\u{17D8}' => (3, WidthInfo::DEFAULT),
-            '\u{1A10}' => (1, WidthInfo::BUGINESE_LETTER_YA),
-            '\u{2D31}'..='\u{2D6F}' => (1, WidthInfo::TIFINAGH_CONSONANT),
-            '\u{A4FC}'..='\u{A4FD}' => (1, WidthInfo::LISU_TONE_LETTER_MYA_NA_JEU),
-            '\u{FE00}'..='\u{FE02}' => (0, WidthInfo::VARIATION_SELECTOR_1_2_OR_3),
-            '\u{FE0F}' => (0, WidthInfo::VARIATION_SELECTOR_16),
-            '\u{10C03}' => (1, WidthInfo::OLD_TURKIC_LETTER_ORKHON_I),
-            '\u{16D67}' => (1, WidthInfo::KIRAT_RAI_VOWEL_SIGN_E),
-            '\u{16D68}' => (1, WidthInfo::KIRAT_RAI_VOWEL_SIGN_AI),
-            '\u{1F1E6}'..='\u{1F1FF}' => (1, WidthInfo::REGIONAL_INDICATOR),
-            '\u{1F3FB}'..='\u{1F3FF}' => (2, WidthInfo::EMOJI_MODIFIER),
-            _ => (2, WidthInfo::EMOJI_PRESENTATION),
-        }
+        special_width_cjk(c)
+    }
+}
+
+/// Returns the width of `c`, and the state it passes on, for a character that the lookup
+/// table marks as special with a width of 3.
+#[cfg(feature = "cjk")]
+#[inline]
+pub fn special_width_cjk(c: char) -> (u8, WidthInfo) {
+    match c {
+        '\u{A}' => (1, WidthInfo::LINE_FEED),
+        '\u{338}' => (0, WidthInfo::COMBINING_LONG_SOLIDUS_OVERLAY),
+        '\u{5DC}' => (1, WidthInfo::HEBREW_LETTER_LAMED),
+        '\u{622}'..='\u{882}' => (1, WidthInfo::JOINING_GROUP_ALEF),
+        '\u{1780}'..='\u{17AF}' => (1, WidthInfo::KHMER_COENG_ELIGIBLE_LETTER),
+        '\u{17D8}' => (3, WidthInfo::DEFAULT),
+        '\u{1A10}' => (1, WidthInfo::BUGINESE_LETTER_YA),
+        '\u{2D31}'..='\u{2D6F}' => (1, WidthInfo::TIFINAGH_CONSONANT),
+        '\u{A4FC}'..='\u{A4FD}' => (1, WidthInfo::LISU_TONE_LETTER_MYA_NA_JEU),
+        '\u{FE00}'..='\u{FE02}' => (0, WidthInfo::VARIATION_SELECTOR_1_2_OR_3),
+        '\u{FE0F}' => (0, WidthInfo::VARIATION_SELECTOR_16),
+        '\u{10C03}' => (1, WidthInfo::OLD_TURKIC_LETTER_ORKHON_I),
+        '\u{16D67}' => (1, WidthInfo::KIRAT_RAI_VOWEL_SIGN_E),
+        '\u{16D68}' => (1, WidthInfo::KIRAT_RAI_VOWEL_SIGN_AI),
+        '\u{1F1E6}'..='\u{1F1FF}' => (1, WidthInfo::REGIONAL_INDICATOR),
+        '\u{1F3FB}'..='\u{1F3FF}' => (2, WidthInfo::EMOJI_MODIFIER),
+        _ => (2, WidthInfo::EMOJI_PRESENTATION),
     }
 }
 
@@ -534,6 +773,13 @@ fn lookup_width_cjk(c: char) -> (u8, WidthInfo) {
 #[cfg(feature = "cjk")]
 #[inline]
 pub fn single_char_width_cjk(c: char) -> Option<usize> {
+    single_char_width_with_cjk(Builtin, c)
+}
+
+/// Like [`single_char_width_cjk`], but looks `c` up in `tables`.
+#[cfg(feature = "cjk")]
+#[inline]
+pub fn single_char_width_with_cjk(tables: impl WidthLookup, c: char) -> Option<usize> {
     if c < '\u{7F}' {
         if c >= '\u{20}' {
             // U+0020 to U+007F (exclusive) are single-width ASCII codepoints
@@ -544,7 +790,7 @@ pub fn single_char_width_cjk(c: char) -> Option<usize> {
         }
     } else if c >= '\u{A0}' {
         // No characters >= U+00A0 are control codes, so we can consult the lookup tables
-        Some(lookup_width_cjk(c).0.into())
+        Some(tables.lookup_width_cjk(c).0.into())
     } else {
         // U+007F to U+00A0 (exclusive) are control codes
         None
@@ -555,9 +801,20 @@ pub fn single_char_width_cjk(c: char) -> Option<usize> {
 /// Ambiguous width characters are treated as wide.
 #[cfg(feature = "cjk")]
 #[inline]
-fn width_in_str_cjk(c: char, mut next_info: WidthInfo) -> (i8, WidthInfo) {
+pub fn width_in_str_cjk(c: char, next_info: WidthInfo) -> (i8, WidthInfo) {
+    width_in_str_with_cjk(Builtin, c, next_info)
+}
+
+/// Like [`width_in_str_cjk`], but looks `c` up in `tables`.
+#[cfg(feature = "cjk")]
+#[inline]
+pub fn width_in_str_with_cjk(
+    tables: impl WidthLookup,
+    c: char,
+    mut next_info: WidthInfo,
+) -> (i8, WidthInfo) {
     if next_info.is_emoji_presentation() {
-        if starts_emoji_presentation_seq(c) {
+        if tables.starts_emoji_presentation_seq(c) {
             let width = if next_info.is_zwj_emoji_presentation() {
                 0
             } else {
@@ -608,7 +865,7 @@ fn width_in_str_cjk(c: char, mut next_info: WidthInfo) -> (i8, WidthInfo) {
             match (next_info, c) {
                 (WidthInfo::COMBINING_LONG_SOLIDUS_OVERLAY, _) if is_solidus_transparent(c) => {
                     return (
-                        lookup_width_cjk(c).0 as i8,
+                        tables.lookup_width_cjk(c).0 as i8,
                         WidthInfo::COMBINING_LONG_SOLIDUS_OVERLAY,
                     );
                 }
@@ -685,7 +942,9 @@ fn width_in_str_cjk(c: char, mut next_info: WidthInfo) -> (i8, WidthInfo) {
                 (WidthInfo::ZWJ_EMOJI_PRESENTATION, '\u{20E3}') => {
                     return (0, WidthInfo::KEYCAP_ZWJ_EMOJI_PRESENTATION);
                 }
-                (WidthInfo::VS16_ZWJ_EMOJI_PRESENTATION, _) if starts_emoji_presentation_seq(c) => {
+                (WidthInfo::VS16_ZWJ_EMOJI_PRESENTATION, _)
+                    if tables.starts_emoji_presentation_seq(c) =>
+                {
                     return (0, WidthInfo::EMOJI_PRESENTATION)
                 }
                 (WidthInfo::VS16_KEYCAP_ZWJ_EMOJI_PRESENTATION, '0'..='9' | '#' | '*') => {
@@ -750,7 +1009,7 @@ fn width_in_str_cjk(c: char, mut next_info: WidthInfo) -> (i8, WidthInfo) {
                     '\u{1F3F4}',
                 ) => return (0, WidthInfo::EMOJI_PRESENTATION),
                 (WidthInfo::ZWJ_EMOJI_PRESENTATION, _)
-                    if lookup_width_cjk(c).1 == WidthInfo::EMOJI_PRESENTATION =>
+                    if tables.lookup_width_cjk(c).1 == WidthInfo::EMOJI_PRESENTATION =>
                 {
                     return (0, WidthInfo::EMOJI_PRESENTATION)
                 }
@@ -776,7 +1035,7 @@ fn width_in_str_cjk(c: char, mut next_info: WidthInfo) -> (i8, WidthInfo) {
             }
         }
 
-        let ret = lookup_width_cjk(c);
+        let ret = tables.lookup_width_cjk(c);
         (ret.0 as i8, ret.1)
     }
 }
@@ -784,11 +1043,18 @@ fn width_in_str_cjk(c: char, mut next_info: WidthInfo) -> (i8, WidthInfo) {
 #[cfg(feature = "cjk")]
 #[inline]
 pub fn str_width_cjk(s: &str) -> usize {
+    str_width_with_cjk(Builtin, s)
+}
+
+/// Like [`str_width_cjk`], but looks characters up in `tables`.
+#[cfg(feature = "cjk")]
+#[inline]
+pub fn str_width_with_cjk(tables: impl WidthLookup, s: &str) -> usize {
     s.chars()
         .rfold(
             (0, WidthInfo::DEFAULT),
             |(sum, next_info), c| -> (usize, WidthInfo) {
-                let (add, info) = width_in_str_cjk(c, next_info);
+                let (add, info) = width_in_str_with_cjk(tables, c, next_info);
                 (sum.wrapping_add_signed(isize::from(add)), info)
             },
         )
@@ -849,6 +1115,104 @@ fn is_solidus_transparent(c: char) -> bool {
             .is_ok()
 }
 
+/// Returns the `East_Asian_Width` of this character.
+pub fn east_asian_width(c: char) -> EastAsianWidth {
+    let cp: u32 = c.into();
+    EAST_ASIAN_WIDTHS
+        .binary_search_by(|&(lo, hi, _)| {
+            let lo = u32::from_le_bytes([lo[0], lo[1], lo[2], 0]);
+            let hi = u32::from_le_bytes([hi[0], hi[1], hi[2], 0]);
+            if cp < lo {
+                Ordering::Greater
+            } else if cp > hi {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+        .map_or(EastAsianWidth::Neutral, |i| EAST_ASIAN_WIDTHS[i].2)
+}
+
+/// Whether this character has width 0 only because it is prepended
+/// to the following character.
+pub fn is_prepended_zero_width(c: char) -> bool {
+    let cp: u32 = c.into();
+    PREPENDED_ZERO_WIDTHS
+        .binary_search_by(|&(lo, hi)| {
+            let lo = u32::from_le_bytes([lo[0], lo[1], lo[2], 0]);
+            let hi = u32::from_le_bytes([hi[0], hi[1], hi[2], 0]);
+            if cp < lo {
+                Ordering::Greater
+            } else if cp > hi {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// Returns the version of Unicode, as `(major, minor)`, in which this character
+/// was assigned, or `None` if it is unassigned.
+pub fn age(c: char) -> Option<(u8, u8)> {
+    let cp: u32 = c.into();
+    AGES.binary_search_by(|&(lo, hi, _)| {
+        let lo = u32::from_le_bytes([lo[0], lo[1], lo[2], 0]);
+        let hi = u32::from_le_bytes([hi[0], hi[1], hi[2], 0]);
+        if cp < lo {
+            Ordering::Greater
+        } else if cp > hi {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        }
+    })
+    .ok()
+    .map(|i| (AGES[i].2[0], AGES[i].2[1]))
+}
+
+/// Returns the emoji version, as `(major, minor)`, in which this character
+/// was introduced as an emoji, or `None` if it isn't an emoji on its own.
+pub fn emoji_version(c: char) -> Option<(u8, u8)> {
+    let cp: u32 = c.into();
+    EMOJI_VERSIONS
+        .binary_search_by(|&(lo, hi, _)| {
+            let lo = u32::from_le_bytes([lo[0], lo[1], lo[2], 0]);
+            let hi = u32::from_le_bytes([hi[0], hi[1], hi[2], 0]);
+            if cp < lo {
+                Ordering::Greater
+            } else if cp > hi {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|i| (EMOJI_VERSIONS[i].2[0], EMOJI_VERSIONS[i].2[1]))
+}
+
+/// Returns the emoji version, as `(major, minor)`, in which the emoji ZWJ sequence
+/// `skeleton` was introduced, or `None` if it isn't a recommended emoji ZWJ sequence.
+/// `skeleton` must have no variation selectors or emoji modifiers, and `toned`
+/// says whether the sequence had emoji modifiers.
+pub fn zwj_sequence_version(
+    skeleton: impl Iterator<Item = char> + Clone,
+    toned: bool,
+) -> Option<(u8, u8)> {
+    ZWJ_SEQUENCE_VERSIONS
+        .binary_search_by(|&(seq, _, _)| seq.chars().cmp(skeleton.clone()))
+        .ok()
+        .map(|i| {
+            let (_, untoned_version, toned_version) = ZWJ_SEQUENCE_VERSIONS[i];
+            let version = if toned {
+                toned_version
+            } else {
+                untoned_version
+            };
+            (version[0], version[1])
+        })
+}
+
 /// Whether this character forms an [emoji presentation sequence]
 /// (https://www.unicode.org/reports/tr51/#def_emoji_presentation_sequence)
 /// when followed by `'\u{FEOF}'`.
@@ -943,16 +1307,65 @@ pub fn is_emoji_modifier_base(c: char) -> bool {
     .is_ok()
 }
 
+/// Returns the bitset of the emoji properties of `c` in `EMOJI_PROPERTIES`,
+/// with bit `i` set if `c` has `EMOJI_PROPERTIES[i]` in `unicode.py`.
+fn emoji_properties(c: char) -> u8 {
+    let cp: u32 = c.into();
+    EMOJI_PROPERTIES
+        .binary_search_by(|&(lo, hi, _)| {
+            let lo = u32::from_le_bytes([lo[0], lo[1], lo[2], 0]);
+            let hi = u32::from_le_bytes([hi[0], hi[1], hi[2], 0]);
+            if cp < lo {
+                Ordering::Greater
+            } else if cp > hi {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+        .map_or(0, |i| EMOJI_PROPERTIES[i].2)
+}
+
+/// Returns `true` if `c` has the `Emoji` property.
+#[inline]
+pub fn is_emoji(c: char) -> bool {
+    emoji_properties(c) & 1 != 0
+}
+
+/// Returns `true` if `c` has the `Emoji_Presentation` property.
+#[inline]
+pub fn is_emoji_presentation(c: char) -> bool {
+    emoji_properties(c) & 2 != 0
+}
+
+/// Returns `true` if `c` has the `Emoji_Component` property.
+#[inline]
+pub fn is_emoji_component(c: char) -> bool {
+    emoji_properties(c) & 4 != 0
+}
+
+/// Returns `true` if `c` has the `Extended_Pictographic` property.
+#[inline]
+pub fn is_extended_pictographic(c: char) -> bool {
+    emoji_properties(c) & 8 != 0
+}
+
+/// Returns `true` if `c` has the `Emoji_Modifier` property.
+#[inline]
+pub fn is_emoji_modifier(c: char) -> bool {
+    emoji_properties(c) & 16 != 0
+}
+
 #[repr(align(32))]
-struct Align32<T>(T);
+pub struct Align32<T>(pub T);
 
 #[repr(align(64))]
-struct Align64<T>(T);
+pub struct Align64<T>(pub T);
 
 #[repr(align(128))]
-struct Align128<T>(T);
+pub struct Align128<T>(pub T);
 /// Autogenerated. 1 sub-table(s). Consult [`lookup_width`] for layout info.)
-static WIDTH_ROOT: Align128<[u8; 256]> = Align128([
+pub static WIDTH_ROOT: Align128<[u8; 256]> = Align128([
     0x00, 0x01, 0x02, 0x02, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
     0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x0E, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x0E,
     0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08,
@@ -972,7 +1385,7 @@ static WIDTH_ROOT: Align128<[u8; 256]> = Align128([
 ]);
 /// Autogenerated. 16 sub-table(s). Consult [`lookup_width`] for layout info.)
 #[cfg(feature = "cjk")]
-static WIDTH_ROOT_CJK: Align128<[u8; 256]> = Align128([
+pub static WIDTH_ROOT_CJK: Align128<[u8; 256]> = Align128([
     0x10, 0x11, 0x02, 0x02, 0x02, 0x03, 0x04, 0x12, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x13,
     0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x0E, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x0E,
     0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08,
@@ -992,11 +1405,11 @@ static WIDTH_ROOT_CJK: Align128<[u8; 256]> = Align128([
 ]);
 
 #[cfg(feature = "cjk")]
-const WIDTH_MIDDLE_LEN: usize = 20;
+pub const WIDTH_MIDDLE_LEN: usize = 20;
 #[cfg(not(feature = "cjk"))]
-const WIDTH_MIDDLE_LEN: usize = 16;
+pub const WIDTH_MIDDLE_LEN: usize = 16;
 /// Autogenerated. 4 sub-table(s). Consult [`lookup_width`] for layout info.
-static WIDTH_MIDDLE: Align64<[[u8; 64]; WIDTH_MIDDLE_LEN]> = Align64([
+pub static WIDTH_MIDDLE: Align64<[[u8; 64]; WIDTH_MIDDLE_LEN]> = Align64([
     [
         0x00, 0x01, 0x02, 0x02, 0x02, 0x02, 0x03, 0x02, 0x02, 0x04, 0x02, 0x05, 0x06, 0x07, 0x08,
         0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
@@ -1144,11 +1557,11 @@ static WIDTH_MIDDLE: Align64<[[u8; 64]; WIDTH_MIDDLE_LEN]> = Align64([
 ]);
 
 #[cfg(feature = "cjk")]
-const WIDTH_LEAVES_LEN: usize = 186;
+pub const WIDTH_LEAVES_LEN: usize = 186;
 #[cfg(not(feature = "cjk"))]
-const WIDTH_LEAVES_LEN: usize = 163;
+pub const WIDTH_LEAVES_LEN: usize = 163;
 /// Autogenerated. 186 sub-table(s). Consult [`lookup_width`] for layout info.
-static WIDTH_LEAVES: Align32<[[u8; 32]; WIDTH_LEAVES_LEN]> = Align32([
+pub static WIDTH_LEAVES: Align32<[[u8; 32]; WIDTH_LEAVES_LEN]> = Align32([
     [
         0x55, 0x55, 0x75, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
         0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
@@ -2397,6 +2810,3861 @@ static SOLIDUS_TRANSPARENT: [([u8; 3], [u8; 3]); 208] = [
     ([0x15, 0xED, 0x01], [0x15, 0xED, 0x01]),
 ];
 
+/// Sorted list of codepoint ranges (inclusive)
+/// with an `East_Asian_Width` other than `Neutral`, and their `East_Asian_Width`.
+static EAST_ASIAN_WIDTHS: [([u8; 3], [u8; 3], EastAsianWidth); 318] = [
+    (
+        [0x20, 0x00, 0x00],
+        [0x7E, 0x00, 0x00],
+        EastAsianWidth::Narrow,
+    ),
+    (
+        [0xA1, 0x00, 0x00],
+        [0xA1, 0x00, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xA2, 0x00, 0x00],
+        [0xA3, 0x00, 0x00],
+        EastAsianWidth::Narrow,
+    ),
+    (
+        [0xA4, 0x00, 0x00],
+        [0xA4, 0x00, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xA5, 0x00, 0x00],
+        [0xA6, 0x00, 0x00],
+        EastAsianWidth::Narrow,
+    ),
+    (
+        [0xA7, 0x00, 0x00],
+        [0xA8, 0x00, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xAA, 0x00, 0x00],
+        [0xAA, 0x00, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xAC, 0x00, 0x00],
+        [0xAC, 0x00, 0x00],
+        EastAsianWidth::Narrow,
+    ),
+    (
+        [0xAD, 0x00, 0x00],
+        [0xAE, 0x00, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xAF, 0x00, 0x00],
+        [0xAF, 0x00, 0x00],
+        EastAsianWidth::Narrow,
+    ),
+    (
+        [0xB0, 0x00, 0x00],
+        [0xB4, 0x00, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xB6, 0x00, 0x00],
+        [0xBA, 0x00, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xBC, 0x00, 0x00],
+        [0xBF, 0x00, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xC6, 0x00, 0x00],
+        [0xC6, 0x00, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xD0, 0x00, 0x00],
+        [0xD0, 0x00, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xD7, 0x00, 0x00],
+        [0xD8, 0x00, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xDE, 0x00, 0x00],
+        [0xE1, 0x00, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xE6, 0x00, 0x00],
+        [0xE6, 0x00, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xE8, 0x00, 0x00],
+        [0xEA, 0x00, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xEC, 0x00, 0x00],
+        [0xED, 0x00, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xF0, 0x00, 0x00],
+        [0xF0, 0x00, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xF2, 0x00, 0x00],
+        [0xF3, 0x00, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xF7, 0x00, 0x00],
+        [0xFA, 0x00, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xFC, 0x00, 0x00],
+        [0xFC, 0x00, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xFE, 0x00, 0x00],
+        [0xFE, 0x00, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x01, 0x01, 0x00],
+        [0x01, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x11, 0x01, 0x00],
+        [0x11, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x13, 0x01, 0x00],
+        [0x13, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x1B, 0x01, 0x00],
+        [0x1B, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x26, 0x01, 0x00],
+        [0x27, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x2B, 0x01, 0x00],
+        [0x2B, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x31, 0x01, 0x00],
+        [0x33, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x38, 0x01, 0x00],
+        [0x38, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x3F, 0x01, 0x00],
+        [0x42, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x44, 0x01, 0x00],
+        [0x44, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x48, 0x01, 0x00],
+        [0x4B, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x4D, 0x01, 0x00],
+        [0x4D, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x52, 0x01, 0x00],
+        [0x53, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x66, 0x01, 0x00],
+        [0x67, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x6B, 0x01, 0x00],
+        [0x6B, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xCE, 0x01, 0x00],
+        [0xCE, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xD0, 0x01, 0x00],
+        [0xD0, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xD2, 0x01, 0x00],
+        [0xD2, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xD4, 0x01, 0x00],
+        [0xD4, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xD6, 0x01, 0x00],
+        [0xD6, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xD8, 0x01, 0x00],
+        [0xD8, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xDA, 0x01, 0x00],
+        [0xDA, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xDC, 0x01, 0x00],
+        [0xDC, 0x01, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x51, 0x02, 0x00],
+        [0x51, 0x02, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x61, 0x02, 0x00],
+        [0x61, 0x02, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xC4, 0x02, 0x00],
+        [0xC4, 0x02, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xC7, 0x02, 0x00],
+        [0xC7, 0x02, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xC9, 0x02, 0x00],
+        [0xCB, 0x02, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xCD, 0x02, 0x00],
+        [0xCD, 0x02, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xD0, 0x02, 0x00],
+        [0xD0, 0x02, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xD8, 0x02, 0x00],
+        [0xDB, 0x02, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xDD, 0x02, 0x00],
+        [0xDD, 0x02, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xDF, 0x02, 0x00],
+        [0xDF, 0x02, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x00, 0x03, 0x00],
+        [0x6F, 0x03, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x91, 0x03, 0x00],
+        [0xA1, 0x03, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xA3, 0x03, 0x00],
+        [0xA9, 0x03, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xB1, 0x03, 0x00],
+        [0xC1, 0x03, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xC3, 0x03, 0x00],
+        [0xC9, 0x03, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x01, 0x04, 0x00],
+        [0x01, 0x04, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x10, 0x04, 0x00],
+        [0x4F, 0x04, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x51, 0x04, 0x00],
+        [0x51, 0x04, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0x00, 0x11, 0x00], [0x5F, 0x11, 0x00], EastAsianWidth::Wide),
+    (
+        [0x10, 0x20, 0x00],
+        [0x10, 0x20, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x13, 0x20, 0x00],
+        [0x16, 0x20, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x18, 0x20, 0x00],
+        [0x19, 0x20, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x1C, 0x20, 0x00],
+        [0x1D, 0x20, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x20, 0x20, 0x00],
+        [0x22, 0x20, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x24, 0x20, 0x00],
+        [0x27, 0x20, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x30, 0x20, 0x00],
+        [0x30, 0x20, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x32, 0x20, 0x00],
+        [0x33, 0x20, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x35, 0x20, 0x00],
+        [0x35, 0x20, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x3B, 0x20, 0x00],
+        [0x3B, 0x20, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x3E, 0x20, 0x00],
+        [0x3E, 0x20, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x74, 0x20, 0x00],
+        [0x74, 0x20, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x7F, 0x20, 0x00],
+        [0x7F, 0x20, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x81, 0x20, 0x00],
+        [0x84, 0x20, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xA9, 0x20, 0x00],
+        [0xA9, 0x20, 0x00],
+        EastAsianWidth::Halfwidth,
+    ),
+    (
+        [0xAC, 0x20, 0x00],
+        [0xAC, 0x20, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x03, 0x21, 0x00],
+        [0x03, 0x21, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x05, 0x21, 0x00],
+        [0x05, 0x21, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x09, 0x21, 0x00],
+        [0x09, 0x21, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x13, 0x21, 0x00],
+        [0x13, 0x21, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x16, 0x21, 0x00],
+        [0x16, 0x21, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x21, 0x21, 0x00],
+        [0x22, 0x21, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x26, 0x21, 0x00],
+        [0x26, 0x21, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x2B, 0x21, 0x00],
+        [0x2B, 0x21, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x53, 0x21, 0x00],
+        [0x54, 0x21, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x5B, 0x21, 0x00],
+        [0x5E, 0x21, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x60, 0x21, 0x00],
+        [0x6B, 0x21, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x70, 0x21, 0x00],
+        [0x79, 0x21, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x89, 0x21, 0x00],
+        [0x89, 0x21, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x90, 0x21, 0x00],
+        [0x99, 0x21, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xB8, 0x21, 0x00],
+        [0xB9, 0x21, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xD2, 0x21, 0x00],
+        [0xD2, 0x21, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xD4, 0x21, 0x00],
+        [0xD4, 0x21, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xE7, 0x21, 0x00],
+        [0xE7, 0x21, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x00, 0x22, 0x00],
+        [0x00, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x02, 0x22, 0x00],
+        [0x03, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x07, 0x22, 0x00],
+        [0x08, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x0B, 0x22, 0x00],
+        [0x0B, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x0F, 0x22, 0x00],
+        [0x0F, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x11, 0x22, 0x00],
+        [0x11, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x15, 0x22, 0x00],
+        [0x15, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x1A, 0x22, 0x00],
+        [0x1A, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x1D, 0x22, 0x00],
+        [0x20, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x23, 0x22, 0x00],
+        [0x23, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x25, 0x22, 0x00],
+        [0x25, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x27, 0x22, 0x00],
+        [0x2C, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x2E, 0x22, 0x00],
+        [0x2E, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x34, 0x22, 0x00],
+        [0x37, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x3C, 0x22, 0x00],
+        [0x3D, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x48, 0x22, 0x00],
+        [0x48, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x4C, 0x22, 0x00],
+        [0x4C, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x52, 0x22, 0x00],
+        [0x52, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x60, 0x22, 0x00],
+        [0x61, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x64, 0x22, 0x00],
+        [0x67, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x6A, 0x22, 0x00],
+        [0x6B, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x6E, 0x22, 0x00],
+        [0x6F, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x82, 0x22, 0x00],
+        [0x83, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x86, 0x22, 0x00],
+        [0x87, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x95, 0x22, 0x00],
+        [0x95, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x99, 0x22, 0x00],
+        [0x99, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xA5, 0x22, 0x00],
+        [0xA5, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xBF, 0x22, 0x00],
+        [0xBF, 0x22, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x12, 0x23, 0x00],
+        [0x12, 0x23, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0x1A, 0x23, 0x00], [0x1B, 0x23, 0x00], EastAsianWidth::Wide),
+    ([0x29, 0x23, 0x00], [0x2A, 0x23, 0x00], EastAsianWidth::Wide),
+    ([0xE9, 0x23, 0x00], [0xEC, 0x23, 0x00], EastAsianWidth::Wide),
+    ([0xF0, 0x23, 0x00], [0xF0, 0x23, 0x00], EastAsianWidth::Wide),
+    ([0xF3, 0x23, 0x00], [0xF3, 0x23, 0x00], EastAsianWidth::Wide),
+    (
+        [0x60, 0x24, 0x00],
+        [0xE9, 0x24, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xEB, 0x24, 0x00],
+        [0x4B, 0x25, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x50, 0x25, 0x00],
+        [0x73, 0x25, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x80, 0x25, 0x00],
+        [0x8F, 0x25, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x92, 0x25, 0x00],
+        [0x95, 0x25, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xA0, 0x25, 0x00],
+        [0xA1, 0x25, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xA3, 0x25, 0x00],
+        [0xA9, 0x25, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xB2, 0x25, 0x00],
+        [0xB3, 0x25, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xB6, 0x25, 0x00],
+        [0xB7, 0x25, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xBC, 0x25, 0x00],
+        [0xBD, 0x25, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xC0, 0x25, 0x00],
+        [0xC1, 0x25, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xC6, 0x25, 0x00],
+        [0xC8, 0x25, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xCB, 0x25, 0x00],
+        [0xCB, 0x25, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xCE, 0x25, 0x00],
+        [0xD1, 0x25, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xE2, 0x25, 0x00],
+        [0xE5, 0x25, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xEF, 0x25, 0x00],
+        [0xEF, 0x25, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0xFD, 0x25, 0x00], [0xFE, 0x25, 0x00], EastAsianWidth::Wide),
+    (
+        [0x05, 0x26, 0x00],
+        [0x06, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x09, 0x26, 0x00],
+        [0x09, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x0E, 0x26, 0x00],
+        [0x0F, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0x14, 0x26, 0x00], [0x15, 0x26, 0x00], EastAsianWidth::Wide),
+    (
+        [0x1C, 0x26, 0x00],
+        [0x1C, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x1E, 0x26, 0x00],
+        [0x1E, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0x30, 0x26, 0x00], [0x37, 0x26, 0x00], EastAsianWidth::Wide),
+    (
+        [0x40, 0x26, 0x00],
+        [0x40, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x42, 0x26, 0x00],
+        [0x42, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0x48, 0x26, 0x00], [0x53, 0x26, 0x00], EastAsianWidth::Wide),
+    (
+        [0x60, 0x26, 0x00],
+        [0x61, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x63, 0x26, 0x00],
+        [0x65, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x67, 0x26, 0x00],
+        [0x6A, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x6C, 0x26, 0x00],
+        [0x6D, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x6F, 0x26, 0x00],
+        [0x6F, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0x7F, 0x26, 0x00], [0x7F, 0x26, 0x00], EastAsianWidth::Wide),
+    ([0x8A, 0x26, 0x00], [0x8F, 0x26, 0x00], EastAsianWidth::Wide),
+    ([0x93, 0x26, 0x00], [0x93, 0x26, 0x00], EastAsianWidth::Wide),
+    (
+        [0x9E, 0x26, 0x00],
+        [0x9F, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0xA1, 0x26, 0x00], [0xA1, 0x26, 0x00], EastAsianWidth::Wide),
+    ([0xAA, 0x26, 0x00], [0xAB, 0x26, 0x00], EastAsianWidth::Wide),
+    ([0xBD, 0x26, 0x00], [0xBE, 0x26, 0x00], EastAsianWidth::Wide),
+    (
+        [0xBF, 0x26, 0x00],
+        [0xBF, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0xC4, 0x26, 0x00], [0xC5, 0x26, 0x00], EastAsianWidth::Wide),
+    (
+        [0xC6, 0x26, 0x00],
+        [0xCD, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0xCE, 0x26, 0x00], [0xCE, 0x26, 0x00], EastAsianWidth::Wide),
+    (
+        [0xCF, 0x26, 0x00],
+        [0xD3, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0xD4, 0x26, 0x00], [0xD4, 0x26, 0x00], EastAsianWidth::Wide),
+    (
+        [0xD5, 0x26, 0x00],
+        [0xE1, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xE3, 0x26, 0x00],
+        [0xE3, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0xE8, 0x26, 0x00],
+        [0xE9, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0xEA, 0x26, 0x00], [0xEA, 0x26, 0x00], EastAsianWidth::Wide),
+    (
+        [0xEB, 0x26, 0x00],
+        [0xF1, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0xF2, 0x26, 0x00], [0xF3, 0x26, 0x00], EastAsianWidth::Wide),
+    (
+        [0xF4, 0x26, 0x00],
+        [0xF4, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0xF5, 0x26, 0x00], [0xF5, 0x26, 0x00], EastAsianWidth::Wide),
+    (
+        [0xF6, 0x26, 0x00],
+        [0xF9, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0xFA, 0x26, 0x00], [0xFA, 0x26, 0x00], EastAsianWidth::Wide),
+    (
+        [0xFB, 0x26, 0x00],
+        [0xFC, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0xFD, 0x26, 0x00], [0xFD, 0x26, 0x00], EastAsianWidth::Wide),
+    (
+        [0xFE, 0x26, 0x00],
+        [0xFF, 0x26, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0x05, 0x27, 0x00], [0x05, 0x27, 0x00], EastAsianWidth::Wide),
+    ([0x0A, 0x27, 0x00], [0x0B, 0x27, 0x00], EastAsianWidth::Wide),
+    ([0x28, 0x27, 0x00], [0x28, 0x27, 0x00], EastAsianWidth::Wide),
+    (
+        [0x3D, 0x27, 0x00],
+        [0x3D, 0x27, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0x4C, 0x27, 0x00], [0x4C, 0x27, 0x00], EastAsianWidth::Wide),
+    ([0x4E, 0x27, 0x00], [0x4E, 0x27, 0x00], EastAsianWidth::Wide),
+    ([0x53, 0x27, 0x00], [0x55, 0x27, 0x00], EastAsianWidth::Wide),
+    ([0x57, 0x27, 0x00], [0x57, 0x27, 0x00], EastAsianWidth::Wide),
+    (
+        [0x76, 0x27, 0x00],
+        [0x7F, 0x27, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0x95, 0x27, 0x00], [0x97, 0x27, 0x00], EastAsianWidth::Wide),
+    ([0xB0, 0x27, 0x00], [0xB0, 0x27, 0x00], EastAsianWidth::Wide),
+    ([0xBF, 0x27, 0x00], [0xBF, 0x27, 0x00], EastAsianWidth::Wide),
+    (
+        [0xE6, 0x27, 0x00],
+        [0xED, 0x27, 0x00],
+        EastAsianWidth::Narrow,
+    ),
+    (
+        [0x85, 0x29, 0x00],
+        [0x86, 0x29, 0x00],
+        EastAsianWidth::Narrow,
+    ),
+    ([0x1B, 0x2B, 0x00], [0x1C, 0x2B, 0x00], EastAsianWidth::Wide),
+    ([0x50, 0x2B, 0x00], [0x50, 0x2B, 0x00], EastAsianWidth::Wide),
+    ([0x55, 0x2B, 0x00], [0x55, 0x2B, 0x00], EastAsianWidth::Wide),
+    (
+        [0x56, 0x2B, 0x00],
+        [0x59, 0x2B, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0x80, 0x2E, 0x00], [0x99, 0x2E, 0x00], EastAsianWidth::Wide),
+    ([0x9B, 0x2E, 0x00], [0xF3, 0x2E, 0x00], EastAsianWidth::Wide),
+    ([0x00, 0x2F, 0x00], [0xD5, 0x2F, 0x00], EastAsianWidth::Wide),
+    ([0xF0, 0x2F, 0x00], [0xFF, 0x2F, 0x00], EastAsianWidth::Wide),
+    (
+        [0x00, 0x30, 0x00],
+        [0x00, 0x30, 0x00],
+        EastAsianWidth::Fullwidth,
+    ),
+    ([0x01, 0x30, 0x00], [0x3E, 0x30, 0x00], EastAsianWidth::Wide),
+    ([0x41, 0x30, 0x00], [0x96, 0x30, 0x00], EastAsianWidth::Wide),
+    ([0x99, 0x30, 0x00], [0xFF, 0x30, 0x00], EastAsianWidth::Wide),
+    ([0x05, 0x31, 0x00], [0x2F, 0x31, 0x00], EastAsianWidth::Wide),
+    ([0x31, 0x31, 0x00], [0x8E, 0x31, 0x00], EastAsianWidth::Wide),
+    ([0x90, 0x31, 0x00], [0xE5, 0x31, 0x00], EastAsianWidth::Wide),
+    ([0xEF, 0x31, 0x00], [0x1E, 0x32, 0x00], EastAsianWidth::Wide),
+    ([0x20, 0x32, 0x00], [0x47, 0x32, 0x00], EastAsianWidth::Wide),
+    (
+        [0x48, 0x32, 0x00],
+        [0x4F, 0x32, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0x50, 0x32, 0x00], [0x8C, 0xA4, 0x00], EastAsianWidth::Wide),
+    ([0x90, 0xA4, 0x00], [0xC6, 0xA4, 0x00], EastAsianWidth::Wide),
+    ([0x60, 0xA9, 0x00], [0x7C, 0xA9, 0x00], EastAsianWidth::Wide),
+    ([0x00, 0xAC, 0x00], [0xA3, 0xD7, 0x00], EastAsianWidth::Wide),
+    (
+        [0x00, 0xE0, 0x00],
+        [0xFF, 0xF8, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0x00, 0xF9, 0x00], [0xFF, 0xFA, 0x00], EastAsianWidth::Wide),
+    (
+        [0x00, 0xFE, 0x00],
+        [0x0F, 0xFE, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0x10, 0xFE, 0x00], [0x19, 0xFE, 0x00], EastAsianWidth::Wide),
+    ([0x30, 0xFE, 0x00], [0x52, 0xFE, 0x00], EastAsianWidth::Wide),
+    ([0x54, 0xFE, 0x00], [0x66, 0xFE, 0x00], EastAsianWidth::Wide),
+    ([0x68, 0xFE, 0x00], [0x6B, 0xFE, 0x00], EastAsianWidth::Wide),
+    (
+        [0x01, 0xFF, 0x00],
+        [0x60, 0xFF, 0x00],
+        EastAsianWidth::Fullwidth,
+    ),
+    (
+        [0x61, 0xFF, 0x00],
+        [0xBE, 0xFF, 0x00],
+        EastAsianWidth::Halfwidth,
+    ),
+    (
+        [0xC2, 0xFF, 0x00],
+        [0xC7, 0xFF, 0x00],
+        EastAsianWidth::Halfwidth,
+    ),
+    (
+        [0xCA, 0xFF, 0x00],
+        [0xCF, 0xFF, 0x00],
+        EastAsianWidth::Halfwidth,
+    ),
+    (
+        [0xD2, 0xFF, 0x00],
+        [0xD7, 0xFF, 0x00],
+        EastAsianWidth::Halfwidth,
+    ),
+    (
+        [0xDA, 0xFF, 0x00],
+        [0xDC, 0xFF, 0x00],
+        EastAsianWidth::Halfwidth,
+    ),
+    (
+        [0xE0, 0xFF, 0x00],
+        [0xE6, 0xFF, 0x00],
+        EastAsianWidth::Fullwidth,
+    ),
+    (
+        [0xE8, 0xFF, 0x00],
+        [0xEE, 0xFF, 0x00],
+        EastAsianWidth::Halfwidth,
+    ),
+    (
+        [0xFD, 0xFF, 0x00],
+        [0xFD, 0xFF, 0x00],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0xE0, 0x6F, 0x01], [0xE4, 0x6F, 0x01], EastAsianWidth::Wide),
+    ([0xF0, 0x6F, 0x01], [0xF6, 0x6F, 0x01], EastAsianWidth::Wide),
+    ([0x00, 0x70, 0x01], [0xD5, 0x8C, 0x01], EastAsianWidth::Wide),
+    ([0xFF, 0x8C, 0x01], [0x1E, 0x8D, 0x01], EastAsianWidth::Wide),
+    ([0x80, 0x8D, 0x01], [0xF2, 0x8D, 0x01], EastAsianWidth::Wide),
+    ([0xF0, 0xAF, 0x01], [0xF3, 0xAF, 0x01], EastAsianWidth::Wide),
+    ([0xF5, 0xAF, 0x01], [0xFB, 0xAF, 0x01], EastAsianWidth::Wide),
+    ([0xFD, 0xAF, 0x01], [0xFE, 0xAF, 0x01], EastAsianWidth::Wide),
+    ([0x00, 0xB0, 0x01], [0x22, 0xB1, 0x01], EastAsianWidth::Wide),
+    ([0x32, 0xB1, 0x01], [0x32, 0xB1, 0x01], EastAsianWidth::Wide),
+    ([0x50, 0xB1, 0x01], [0x52, 0xB1, 0x01], EastAsianWidth::Wide),
+    ([0x55, 0xB1, 0x01], [0x55, 0xB1, 0x01], EastAsianWidth::Wide),
+    ([0x64, 0xB1, 0x01], [0x67, 0xB1, 0x01], EastAsianWidth::Wide),
+    ([0x70, 0xB1, 0x01], [0xFB, 0xB2, 0x01], EastAsianWidth::Wide),
+    ([0x00, 0xD3, 0x01], [0x56, 0xD3, 0x01], EastAsianWidth::Wide),
+    ([0x60, 0xD3, 0x01], [0x76, 0xD3, 0x01], EastAsianWidth::Wide),
+    ([0x04, 0xF0, 0x01], [0x04, 0xF0, 0x01], EastAsianWidth::Wide),
+    ([0xCF, 0xF0, 0x01], [0xCF, 0xF0, 0x01], EastAsianWidth::Wide),
+    (
+        [0x00, 0xF1, 0x01],
+        [0x0A, 0xF1, 0x01],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x10, 0xF1, 0x01],
+        [0x2D, 0xF1, 0x01],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x30, 0xF1, 0x01],
+        [0x69, 0xF1, 0x01],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x70, 0xF1, 0x01],
+        [0x8D, 0xF1, 0x01],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0x8E, 0xF1, 0x01], [0x8E, 0xF1, 0x01], EastAsianWidth::Wide),
+    (
+        [0x8F, 0xF1, 0x01],
+        [0x90, 0xF1, 0x01],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0x91, 0xF1, 0x01], [0x9A, 0xF1, 0x01], EastAsianWidth::Wide),
+    (
+        [0x9B, 0xF1, 0x01],
+        [0xAC, 0xF1, 0x01],
+        EastAsianWidth::Ambiguous,
+    ),
+    ([0x00, 0xF2, 0x01], [0x02, 0xF2, 0x01], EastAsianWidth::Wide),
+    ([0x10, 0xF2, 0x01], [0x3B, 0xF2, 0x01], EastAsianWidth::Wide),
+    ([0x40, 0xF2, 0x01], [0x48, 0xF2, 0x01], EastAsianWidth::Wide),
+    ([0x50, 0xF2, 0x01], [0x51, 0xF2, 0x01], EastAsianWidth::Wide),
+    ([0x60, 0xF2, 0x01], [0x65, 0xF2, 0x01], EastAsianWidth::Wide),
+    ([0x00, 0xF3, 0x01], [0x20, 0xF3, 0x01], EastAsianWidth::Wide),
+    ([0x2D, 0xF3, 0x01], [0x35, 0xF3, 0x01], EastAsianWidth::Wide),
+    ([0x37, 0xF3, 0x01], [0x7C, 0xF3, 0x01], EastAsianWidth::Wide),
+    ([0x7E, 0xF3, 0x01], [0x93, 0xF3, 0x01], EastAsianWidth::Wide),
+    ([0xA0, 0xF3, 0x01], [0xCA, 0xF3, 0x01], EastAsianWidth::Wide),
+    ([0xCF, 0xF3, 0x01], [0xD3, 0xF3, 0x01], EastAsianWidth::Wide),
+    ([0xE0, 0xF3, 0x01], [0xF0, 0xF3, 0x01], EastAsianWidth::Wide),
+    ([0xF4, 0xF3, 0x01], [0xF4, 0xF3, 0x01], EastAsianWidth::Wide),
+    ([0xF8, 0xF3, 0x01], [0x3E, 0xF4, 0x01], EastAsianWidth::Wide),
+    ([0x40, 0xF4, 0x01], [0x40, 0xF4, 0x01], EastAsianWidth::Wide),
+    ([0x42, 0xF4, 0x01], [0xFC, 0xF4, 0x01], EastAsianWidth::Wide),
+    ([0xFF, 0xF4, 0x01], [0x3D, 0xF5, 0x01], EastAsianWidth::Wide),
+    ([0x4B, 0xF5, 0x01], [0x4E, 0xF5, 0x01], EastAsianWidth::Wide),
+    ([0x50, 0xF5, 0x01], [0x67, 0xF5, 0x01], EastAsianWidth::Wide),
+    ([0x7A, 0xF5, 0x01], [0x7A, 0xF5, 0x01], EastAsianWidth::Wide),
+    ([0x95, 0xF5, 0x01], [0x96, 0xF5, 0x01], EastAsianWidth::Wide),
+    ([0xA4, 0xF5, 0x01], [0xA4, 0xF5, 0x01], EastAsianWidth::Wide),
+    ([0xFB, 0xF5, 0x01], [0x4F, 0xF6, 0x01], EastAsianWidth::Wide),
+    ([0x80, 0xF6, 0x01], [0xC5, 0xF6, 0x01], EastAsianWidth::Wide),
+    ([0xCC, 0xF6, 0x01], [0xCC, 0xF6, 0x01], EastAsianWidth::Wide),
+    ([0xD0, 0xF6, 0x01], [0xD2, 0xF6, 0x01], EastAsianWidth::Wide),
+    ([0xD5, 0xF6, 0x01], [0xD8, 0xF6, 0x01], EastAsianWidth::Wide),
+    ([0xDC, 0xF6, 0x01], [0xDF, 0xF6, 0x01], EastAsianWidth::Wide),
+    ([0xEB, 0xF6, 0x01], [0xEC, 0xF6, 0x01], EastAsianWidth::Wide),
+    ([0xF4, 0xF6, 0x01], [0xFC, 0xF6, 0x01], EastAsianWidth::Wide),
+    ([0xE0, 0xF7, 0x01], [0xEB, 0xF7, 0x01], EastAsianWidth::Wide),
+    ([0xF0, 0xF7, 0x01], [0xF0, 0xF7, 0x01], EastAsianWidth::Wide),
+    ([0x0C, 0xF9, 0x01], [0x3A, 0xF9, 0x01], EastAsianWidth::Wide),
+    ([0x3C, 0xF9, 0x01], [0x45, 0xF9, 0x01], EastAsianWidth::Wide),
+    ([0x47, 0xF9, 0x01], [0xFF, 0xF9, 0x01], EastAsianWidth::Wide),
+    ([0x70, 0xFA, 0x01], [0x7C, 0xFA, 0x01], EastAsianWidth::Wide),
+    ([0x80, 0xFA, 0x01], [0x8A, 0xFA, 0x01], EastAsianWidth::Wide),
+    ([0x8E, 0xFA, 0x01], [0xC6, 0xFA, 0x01], EastAsianWidth::Wide),
+    ([0xC8, 0xFA, 0x01], [0xC8, 0xFA, 0x01], EastAsianWidth::Wide),
+    ([0xCD, 0xFA, 0x01], [0xDC, 0xFA, 0x01], EastAsianWidth::Wide),
+    ([0xDF, 0xFA, 0x01], [0xEA, 0xFA, 0x01], EastAsianWidth::Wide),
+    ([0xEF, 0xFA, 0x01], [0xF8, 0xFA, 0x01], EastAsianWidth::Wide),
+    ([0x00, 0x00, 0x02], [0xFD, 0xFF, 0x02], EastAsianWidth::Wide),
+    ([0x00, 0x00, 0x03], [0xFD, 0xFF, 0x03], EastAsianWidth::Wide),
+    (
+        [0x00, 0x01, 0x0E],
+        [0xEF, 0x01, 0x0E],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x00, 0x00, 0x0F],
+        [0xFD, 0xFF, 0x0F],
+        EastAsianWidth::Ambiguous,
+    ),
+    (
+        [0x00, 0x00, 0x10],
+        [0xFD, 0xFF, 0x10],
+        EastAsianWidth::Ambiguous,
+    ),
+];
+
+/// Sorted list of codepoint ranges (inclusive) with any of the emoji properties
+/// in `EMOJI_PROPERTIES` in `unicode.py`, and the bitset of their properties.
+static EMOJI_PROPERTIES: [([u8; 3], [u8; 3], u8); 244] = [
+    ([0x23, 0x00, 0x00], [0x23, 0x00, 0x00], 0b0101),
+    ([0x2A, 0x00, 0x00], [0x2A, 0x00, 0x00], 0b0101),
+    ([0x30, 0x00, 0x00], [0x39, 0x00, 0x00], 0b0101),
+    ([0xA9, 0x00, 0x00], [0xA9, 0x00, 0x00], 0b1001),
+    ([0xAE, 0x00, 0x00], [0xAE, 0x00, 0x00], 0b1001),
+    ([0x0D, 0x20, 0x00], [0x0D, 0x20, 0x00], 0b0100),
+    ([0x3C, 0x20, 0x00], [0x3C, 0x20, 0x00], 0b1001),
+    ([0x49, 0x20, 0x00], [0x49, 0x20, 0x00], 0b1001),
+    ([0xE3, 0x20, 0x00], [0xE3, 0x20, 0x00], 0b0100),
+    ([0x22, 0x21, 0x00], [0x22, 0x21, 0x00], 0b1001),
+    ([0x39, 0x21, 0x00], [0x39, 0x21, 0x00], 0b1001),
+    ([0x94, 0x21, 0x00], [0x99, 0x21, 0x00], 0b1001),
+    ([0xA9, 0x21, 0x00], [0xAA, 0x21, 0x00], 0b1001),
+    ([0x1A, 0x23, 0x00], [0x1B, 0x23, 0x00], 0b1011),
+    ([0x28, 0x23, 0x00], [0x28, 0x23, 0x00], 0b1001),
+    ([0xCF, 0x23, 0x00], [0xCF, 0x23, 0x00], 0b1001),
+    ([0xE9, 0x23, 0x00], [0xEC, 0x23, 0x00], 0b1011),
+    ([0xED, 0x23, 0x00], [0xEF, 0x23, 0x00], 0b1001),
+    ([0xF0, 0x23, 0x00], [0xF0, 0x23, 0x00], 0b1011),
+    ([0xF1, 0x23, 0x00], [0xF2, 0x23, 0x00], 0b1001),
+    ([0xF3, 0x23, 0x00], [0xF3, 0x23, 0x00], 0b1011),
+    ([0xF8, 0x23, 0x00], [0xFA, 0x23, 0x00], 0b1001),
+    ([0xC2, 0x24, 0x00], [0xC2, 0x24, 0x00], 0b1001),
+    ([0xAA, 0x25, 0x00], [0xAB, 0x25, 0x00], 0b1001),
+    ([0xB6, 0x25, 0x00], [0xB6, 0x25, 0x00], 0b1001),
+    ([0xC0, 0x25, 0x00], [0xC0, 0x25, 0x00], 0b1001),
+    ([0xFB, 0x25, 0x00], [0xFC, 0x25, 0x00], 0b1001),
+    ([0xFD, 0x25, 0x00], [0xFE, 0x25, 0x00], 0b1011),
+    ([0x00, 0x26, 0x00], [0x04, 0x26, 0x00], 0b1001),
+    ([0x0E, 0x26, 0x00], [0x0E, 0x26, 0x00], 0b1001),
+    ([0x11, 0x26, 0x00], [0x11, 0x26, 0x00], 0b1001),
+    ([0x14, 0x26, 0x00], [0x15, 0x26, 0x00], 0b1011),
+    ([0x18, 0x26, 0x00], [0x18, 0x26, 0x00], 0b1001),
+    ([0x1D, 0x26, 0x00], [0x1D, 0x26, 0x00], 0b1001),
+    ([0x20, 0x26, 0x00], [0x20, 0x26, 0x00], 0b1001),
+    ([0x22, 0x26, 0x00], [0x23, 0x26, 0x00], 0b1001),
+    ([0x26, 0x26, 0x00], [0x26, 0x26, 0x00], 0b1001),
+    ([0x2A, 0x26, 0x00], [0x2A, 0x26, 0x00], 0b1001),
+    ([0x2E, 0x26, 0x00], [0x2F, 0x26, 0x00], 0b1001),
+    ([0x38, 0x26, 0x00], [0x3A, 0x26, 0x00], 0b1001),
+    ([0x40, 0x26, 0x00], [0x40, 0x26, 0x00], 0b1001),
+    ([0x42, 0x26, 0x00], [0x42, 0x26, 0x00], 0b1001),
+    ([0x48, 0x26, 0x00], [0x53, 0x26, 0x00], 0b1011),
+    ([0x5F, 0x26, 0x00], [0x60, 0x26, 0x00], 0b1001),
+    ([0x63, 0x26, 0x00], [0x63, 0x26, 0x00], 0b1001),
+    ([0x65, 0x26, 0x00], [0x66, 0x26, 0x00], 0b1001),
+    ([0x68, 0x26, 0x00], [0x68, 0x26, 0x00], 0b1001),
+    ([0x7B, 0x26, 0x00], [0x7B, 0x26, 0x00], 0b1001),
+    ([0x7E, 0x26, 0x00], [0x7E, 0x26, 0x00], 0b1001),
+    ([0x7F, 0x26, 0x00], [0x7F, 0x26, 0x00], 0b1011),
+    ([0x92, 0x26, 0x00], [0x92, 0x26, 0x00], 0b1001),
+    ([0x93, 0x26, 0x00], [0x93, 0x26, 0x00], 0b1011),
+    ([0x94, 0x26, 0x00], [0x97, 0x26, 0x00], 0b1001),
+    ([0x99, 0x26, 0x00], [0x99, 0x26, 0x00], 0b1001),
+    ([0x9B, 0x26, 0x00], [0x9C, 0x26, 0x00], 0b1001),
+    ([0xA0, 0x26, 0x00], [0xA0, 0x26, 0x00], 0b1001),
+    ([0xA1, 0x26, 0x00], [0xA1, 0x26, 0x00], 0b1011),
+    ([0xA7, 0x26, 0x00], [0xA7, 0x26, 0x00], 0b1001),
+    ([0xAA, 0x26, 0x00], [0xAB, 0x26, 0x00], 0b1011),
+    ([0xB0, 0x26, 0x00], [0xB1, 0x26, 0x00], 0b1001),
+    ([0xBD, 0x26, 0x00], [0xBE, 0x26, 0x00], 0b1011),
+    ([0xC4, 0x26, 0x00], [0xC5, 0x26, 0x00], 0b1011),
+    ([0xC8, 0x26, 0x00], [0xC8, 0x26, 0x00], 0b1001),
+    ([0xCE, 0x26, 0x00], [0xCE, 0x26, 0x00], 0b1011),
+    ([0xCF, 0x26, 0x00], [0xCF, 0x26, 0x00], 0b1001),
+    ([0xD1, 0x26, 0x00], [0xD1, 0x26, 0x00], 0b1001),
+    ([0xD3, 0x26, 0x00], [0xD3, 0x26, 0x00], 0b1001),
+    ([0xD4, 0x26, 0x00], [0xD4, 0x26, 0x00], 0b1011),
+    ([0xE9, 0x26, 0x00], [0xE9, 0x26, 0x00], 0b1001),
+    ([0xEA, 0x26, 0x00], [0xEA, 0x26, 0x00], 0b1011),
+    ([0xF0, 0x26, 0x00], [0xF1, 0x26, 0x00], 0b1001),
+    ([0xF2, 0x26, 0x00], [0xF3, 0x26, 0x00], 0b1011),
+    ([0xF4, 0x26, 0x00], [0xF4, 0x26, 0x00], 0b1001),
+    ([0xF5, 0x26, 0x00], [0xF5, 0x26, 0x00], 0b1011),
+    ([0xF7, 0x26, 0x00], [0xF9, 0x26, 0x00], 0b1001),
+    ([0xFA, 0x26, 0x00], [0xFA, 0x26, 0x00], 0b1011),
+    ([0xFD, 0x26, 0x00], [0xFD, 0x26, 0x00], 0b1011),
+    ([0x02, 0x27, 0x00], [0x02, 0x27, 0x00], 0b1001),
+    ([0x05, 0x27, 0x00], [0x05, 0x27, 0x00], 0b1011),
+    ([0x08, 0x27, 0x00], [0x09, 0x27, 0x00], 0b1001),
+    ([0x0A, 0x27, 0x00], [0x0B, 0x27, 0x00], 0b1011),
+    ([0x0C, 0x27, 0x00], [0x0D, 0x27, 0x00], 0b1001),
+    ([0x0F, 0x27, 0x00], [0x0F, 0x27, 0x00], 0b1001),
+    ([0x12, 0x27, 0x00], [0x12, 0x27, 0x00], 0b1001),
+    ([0x14, 0x27, 0x00], [0x14, 0x27, 0x00], 0b1001),
+    ([0x16, 0x27, 0x00], [0x16, 0x27, 0x00], 0b1001),
+    ([0x1D, 0x27, 0x00], [0x1D, 0x27, 0x00], 0b1001),
+    ([0x21, 0x27, 0x00], [0x21, 0x27, 0x00], 0b1001),
+    ([0x28, 0x27, 0x00], [0x28, 0x27, 0x00], 0b1011),
+    ([0x33, 0x27, 0x00], [0x34, 0x27, 0x00], 0b1001),
+    ([0x44, 0x27, 0x00], [0x44, 0x27, 0x00], 0b1001),
+    ([0x47, 0x27, 0x00], [0x47, 0x27, 0x00], 0b1001),
+    ([0x4C, 0x27, 0x00], [0x4C, 0x27, 0x00], 0b1011),
+    ([0x4E, 0x27, 0x00], [0x4E, 0x27, 0x00], 0b1011),
+    ([0x53, 0x27, 0x00], [0x55, 0x27, 0x00], 0b1011),
+    ([0x57, 0x27, 0x00], [0x57, 0x27, 0x00], 0b1011),
+    ([0x63, 0x27, 0x00], [0x64, 0x27, 0x00], 0b1001),
+    ([0x95, 0x27, 0x00], [0x97, 0x27, 0x00], 0b1011),
+    ([0xA1, 0x27, 0x00], [0xA1, 0x27, 0x00], 0b1001),
+    ([0xB0, 0x27, 0x00], [0xB0, 0x27, 0x00], 0b1011),
+    ([0xBF, 0x27, 0x00], [0xBF, 0x27, 0x00], 0b1011),
+    ([0x34, 0x29, 0x00], [0x35, 0x29, 0x00], 0b1001),
+    ([0x05, 0x2B, 0x00], [0x07, 0x2B, 0x00], 0b1001),
+    ([0x1B, 0x2B, 0x00], [0x1C, 0x2B, 0x00], 0b1011),
+    ([0x50, 0x2B, 0x00], [0x50, 0x2B, 0x00], 0b1011),
+    ([0x55, 0x2B, 0x00], [0x55, 0x2B, 0x00], 0b1011),
+    ([0x30, 0x30, 0x00], [0x30, 0x30, 0x00], 0b1001),
+    ([0x3D, 0x30, 0x00], [0x3D, 0x30, 0x00], 0b1001),
+    ([0x97, 0x32, 0x00], [0x97, 0x32, 0x00], 0b1001),
+    ([0x99, 0x32, 0x00], [0x99, 0x32, 0x00], 0b1001),
+    ([0x0F, 0xFE, 0x00], [0x0F, 0xFE, 0x00], 0b0100),
+    ([0x04, 0xF0, 0x01], [0x04, 0xF0, 0x01], 0b1011),
+    ([0x2C, 0xF0, 0x01], [0x2F, 0xF0, 0x01], 0b1000),
+    ([0x94, 0xF0, 0x01], [0x9F, 0xF0, 0x01], 0b1000),
+    ([0xAF, 0xF0, 0x01], [0xB0, 0xF0, 0x01], 0b1000),
+    ([0xC0, 0xF0, 0x01], [0xC0, 0xF0, 0x01], 0b1000),
+    ([0xCF, 0xF0, 0x01], [0xCF, 0xF0, 0x01], 0b1011),
+    ([0xD0, 0xF0, 0x01], [0xD0, 0xF0, 0x01], 0b1000),
+    ([0xF6, 0xF0, 0x01], [0xFF, 0xF0, 0x01], 0b1000),
+    ([0x70, 0xF1, 0x01], [0x71, 0xF1, 0x01], 0b1001),
+    ([0x7E, 0xF1, 0x01], [0x7F, 0xF1, 0x01], 0b1001),
+    ([0x8E, 0xF1, 0x01], [0x8E, 0xF1, 0x01], 0b1011),
+    ([0x91, 0xF1, 0x01], [0x9A, 0xF1, 0x01], 0b1011),
+    ([0xAE, 0xF1, 0x01], [0xE5, 0xF1, 0x01], 0b1000),
+    ([0xE6, 0xF1, 0x01], [0xFF, 0xF1, 0x01], 0b0111),
+    ([0x01, 0xF2, 0x01], [0x01, 0xF2, 0x01], 0b1011),
+    ([0x02, 0xF2, 0x01], [0x02, 0xF2, 0x01], 0b1001),
+    ([0x03, 0xF2, 0x01], [0x0F, 0xF2, 0x01], 0b1000),
+    ([0x1A, 0xF2, 0x01], [0x1A, 0xF2, 0x01], 0b1011),
+    ([0x2F, 0xF2, 0x01], [0x2F, 0xF2, 0x01], 0b1011),
+    ([0x32, 0xF2, 0x01], [0x36, 0xF2, 0x01], 0b1011),
+    ([0x37, 0xF2, 0x01], [0x37, 0xF2, 0x01], 0b1001),
+    ([0x38, 0xF2, 0x01], [0x3A, 0xF2, 0x01], 0b1011),
+    ([0x3C, 0xF2, 0x01], [0x3F, 0xF2, 0x01], 0b1000),
+    ([0x49, 0xF2, 0x01], [0x4F, 0xF2, 0x01], 0b1000),
+    ([0x50, 0xF2, 0x01], [0x51, 0xF2, 0x01], 0b1011),
+    ([0x52, 0xF2, 0x01], [0x5F, 0xF2, 0x01], 0b1000),
+    ([0x66, 0xF2, 0x01], [0xFF, 0xF2, 0x01], 0b1000),
+    ([0x00, 0xF3, 0x01], [0x20, 0xF3, 0x01], 0b1011),
+    ([0x21, 0xF3, 0x01], [0x21, 0xF3, 0x01], 0b1001),
+    ([0x24, 0xF3, 0x01], [0x2C, 0xF3, 0x01], 0b1001),
+    ([0x2D, 0xF3, 0x01], [0x35, 0xF3, 0x01], 0b1011),
+    ([0x36, 0xF3, 0x01], [0x36, 0xF3, 0x01], 0b1001),
+    ([0x37, 0xF3, 0x01], [0x7C, 0xF3, 0x01], 0b1011),
+    ([0x7D, 0xF3, 0x01], [0x7D, 0xF3, 0x01], 0b1001),
+    ([0x7E, 0xF3, 0x01], [0x93, 0xF3, 0x01], 0b1011),
+    ([0x96, 0xF3, 0x01], [0x97, 0xF3, 0x01], 0b1001),
+    ([0x99, 0xF3, 0x01], [0x9B, 0xF3, 0x01], 0b1001),
+    ([0x9E, 0xF3, 0x01], [0x9F, 0xF3, 0x01], 0b1001),
+    ([0xA0, 0xF3, 0x01], [0xCA, 0xF3, 0x01], 0b1011),
+    ([0xCB, 0xF3, 0x01], [0xCE, 0xF3, 0x01], 0b1001),
+    ([0xCF, 0xF3, 0x01], [0xD3, 0xF3, 0x01], 0b1011),
+    ([0xD4, 0xF3, 0x01], [0xDF, 0xF3, 0x01], 0b1001),
+    ([0xE0, 0xF3, 0x01], [0xF0, 0xF3, 0x01], 0b1011),
+    ([0xF3, 0xF3, 0x01], [0xF3, 0xF3, 0x01], 0b1001),
+    ([0xF4, 0xF3, 0x01], [0xF4, 0xF3, 0x01], 0b1011),
+    ([0xF5, 0xF3, 0x01], [0xF5, 0xF3, 0x01], 0b1001),
+    ([0xF7, 0xF3, 0x01], [0xF7, 0xF3, 0x01], 0b1001),
+    ([0xF8, 0xF3, 0x01], [0xFA, 0xF3, 0x01], 0b1011),
+    ([0xFB, 0xF3, 0x01], [0xFF, 0xF3, 0x01], 0b10111),
+    ([0x00, 0xF4, 0x01], [0x3E, 0xF4, 0x01], 0b1011),
+    ([0x3F, 0xF4, 0x01], [0x3F, 0xF4, 0x01], 0b1001),
+    ([0x40, 0xF4, 0x01], [0x40, 0xF4, 0x01], 0b1011),
+    ([0x41, 0xF4, 0x01], [0x41, 0xF4, 0x01], 0b1001),
+    ([0x42, 0xF4, 0x01], [0xFC, 0xF4, 0x01], 0b1011),
+    ([0xFD, 0xF4, 0x01], [0xFD, 0xF4, 0x01], 0b1001),
+    ([0xFF, 0xF4, 0x01], [0x3D, 0xF5, 0x01], 0b1011),
+    ([0x49, 0xF5, 0x01], [0x4A, 0xF5, 0x01], 0b1001),
+    ([0x4B, 0xF5, 0x01], [0x4E, 0xF5, 0x01], 0b1011),
+    ([0x50, 0xF5, 0x01], [0x67, 0xF5, 0x01], 0b1011),
+    ([0x6F, 0xF5, 0x01], [0x70, 0xF5, 0x01], 0b1001),
+    ([0x73, 0xF5, 0x01], [0x79, 0xF5, 0x01], 0b1001),
+    ([0x7A, 0xF5, 0x01], [0x7A, 0xF5, 0x01], 0b1011),
+    ([0x87, 0xF5, 0x01], [0x87, 0xF5, 0x01], 0b1001),
+    ([0x8A, 0xF5, 0x01], [0x8D, 0xF5, 0x01], 0b1001),
+    ([0x90, 0xF5, 0x01], [0x90, 0xF5, 0x01], 0b1001),
+    ([0x95, 0xF5, 0x01], [0x96, 0xF5, 0x01], 0b1011),
+    ([0xA4, 0xF5, 0x01], [0xA4, 0xF5, 0x01], 0b1011),
+    ([0xA5, 0xF5, 0x01], [0xA5, 0xF5, 0x01], 0b1001),
+    ([0xA8, 0xF5, 0x01], [0xA8, 0xF5, 0x01], 0b1001),
+    ([0xB1, 0xF5, 0x01], [0xB2, 0xF5, 0x01], 0b1001),
+    ([0xBC, 0xF5, 0x01], [0xBC, 0xF5, 0x01], 0b1001),
+    ([0xC2, 0xF5, 0x01], [0xC4, 0xF5, 0x01], 0b1001),
+    ([0xD1, 0xF5, 0x01], [0xD3, 0xF5, 0x01], 0b1001),
+    ([0xDC, 0xF5, 0x01], [0xDE, 0xF5, 0x01], 0b1001),
+    ([0xE1, 0xF5, 0x01], [0xE1, 0xF5, 0x01], 0b1001),
+    ([0xE3, 0xF5, 0x01], [0xE3, 0xF5, 0x01], 0b1001),
+    ([0xE8, 0xF5, 0x01], [0xE8, 0xF5, 0x01], 0b1001),
+    ([0xEF, 0xF5, 0x01], [0xEF, 0xF5, 0x01], 0b1001),
+    ([0xF3, 0xF5, 0x01], [0xF3, 0xF5, 0x01], 0b1001),
+    ([0xFA, 0xF5, 0x01], [0xFA, 0xF5, 0x01], 0b1001),
+    ([0xFB, 0xF5, 0x01], [0x4F, 0xF6, 0x01], 0b1011),
+    ([0x80, 0xF6, 0x01], [0xC5, 0xF6, 0x01], 0b1011),
+    ([0xCB, 0xF6, 0x01], [0xCB, 0xF6, 0x01], 0b1001),
+    ([0xCC, 0xF6, 0x01], [0xCC, 0xF6, 0x01], 0b1011),
+    ([0xCD, 0xF6, 0x01], [0xCF, 0xF6, 0x01], 0b1001),
+    ([0xD0, 0xF6, 0x01], [0xD2, 0xF6, 0x01], 0b1011),
+    ([0xD5, 0xF6, 0x01], [0xD8, 0xF6, 0x01], 0b1011),
+    ([0xD9, 0xF6, 0x01], [0xDB, 0xF6, 0x01], 0b1000),
+    ([0xDC, 0xF6, 0x01], [0xDF, 0xF6, 0x01], 0b1011),
+    ([0xE0, 0xF6, 0x01], [0xE5, 0xF6, 0x01], 0b1001),
+    ([0xE9, 0xF6, 0x01], [0xE9, 0xF6, 0x01], 0b1001),
+    ([0xEB, 0xF6, 0x01], [0xEC, 0xF6, 0x01], 0b1011),
+    ([0xED, 0xF6, 0x01], [0xEF, 0xF6, 0x01], 0b1000),
+    ([0xF0, 0xF6, 0x01], [0xF0, 0xF6, 0x01], 0b1001),
+    ([0xF3, 0xF6, 0x01], [0xF3, 0xF6, 0x01], 0b1001),
+    ([0xF4, 0xF6, 0x01], [0xFC, 0xF6, 0x01], 0b1011),
+    ([0xFD, 0xF6, 0x01], [0xFF, 0xF6, 0x01], 0b1000),
+    ([0xDA, 0xF7, 0x01], [0xDF, 0xF7, 0x01], 0b1000),
+    ([0xE0, 0xF7, 0x01], [0xEB, 0xF7, 0x01], 0b1011),
+    ([0xEC, 0xF7, 0x01], [0xEF, 0xF7, 0x01], 0b1000),
+    ([0xF0, 0xF7, 0x01], [0xF0, 0xF7, 0x01], 0b1011),
+    ([0xF1, 0xF7, 0x01], [0xFF, 0xF7, 0x01], 0b1000),
+    ([0x0C, 0xF8, 0x01], [0x0F, 0xF8, 0x01], 0b1000),
+    ([0x48, 0xF8, 0x01], [0x4F, 0xF8, 0x01], 0b1000),
+    ([0x5A, 0xF8, 0x01], [0x5F, 0xF8, 0x01], 0b1000),
+    ([0x88, 0xF8, 0x01], [0x8F, 0xF8, 0x01], 0b1000),
+    ([0xAE, 0xF8, 0x01], [0xAF, 0xF8, 0x01], 0b1000),
+    ([0xBC, 0xF8, 0x01], [0xBF, 0xF8, 0x01], 0b1000),
+    ([0xC2, 0xF8, 0x01], [0xCF, 0xF8, 0x01], 0b1000),
+    ([0xD9, 0xF8, 0x01], [0xFF, 0xF8, 0x01], 0b1000),
+    ([0x0C, 0xF9, 0x01], [0x3A, 0xF9, 0x01], 0b1011),
+    ([0x3C, 0xF9, 0x01], [0x45, 0xF9, 0x01], 0b1011),
+    ([0x47, 0xF9, 0x01], [0xAF, 0xF9, 0x01], 0b1011),
+    ([0xB0, 0xF9, 0x01], [0xB3, 0xF9, 0x01], 0b1111),
+    ([0xB4, 0xF9, 0x01], [0xFF, 0xF9, 0x01], 0b1011),
+    ([0x58, 0xFA, 0x01], [0x5F, 0xFA, 0x01], 0b1000),
+    ([0x6E, 0xFA, 0x01], [0x6F, 0xFA, 0x01], 0b1000),
+    ([0x70, 0xFA, 0x01], [0x7C, 0xFA, 0x01], 0b1011),
+    ([0x7D, 0xFA, 0x01], [0x7F, 0xFA, 0x01], 0b1000),
+    ([0x80, 0xFA, 0x01], [0x8A, 0xFA, 0x01], 0b1011),
+    ([0x8B, 0xFA, 0x01], [0x8D, 0xFA, 0x01], 0b1000),
+    ([0x8E, 0xFA, 0x01], [0xC6, 0xFA, 0x01], 0b1011),
+    ([0xC7, 0xFA, 0x01], [0xC7, 0xFA, 0x01], 0b1000),
+    ([0xC8, 0xFA, 0x01], [0xC8, 0xFA, 0x01], 0b1011),
+    ([0xC9, 0xFA, 0x01], [0xCC, 0xFA, 0x01], 0b1000),
+    ([0xCD, 0xFA, 0x01], [0xDC, 0xFA, 0x01], 0b1011),
+    ([0xDD, 0xFA, 0x01], [0xDE, 0xFA, 0x01], 0b1000),
+    ([0xDF, 0xFA, 0x01], [0xEA, 0xFA, 0x01], 0b1011),
+    ([0xEB, 0xFA, 0x01], [0xEE, 0xFA, 0x01], 0b1000),
+    ([0xEF, 0xFA, 0x01], [0xF8, 0xFA, 0x01], 0b1011),
+    ([0xF9, 0xFA, 0x01], [0xFF, 0xFA, 0x01], 0b1000),
+    ([0x00, 0xFC, 0x01], [0xFD, 0xFF, 0x01], 0b1000),
+    ([0x20, 0x00, 0x0E], [0x7F, 0x00, 0x0E], 0b0100),
+];
+
+/// Sorted list of codepoint ranges (inclusive)
+/// that have width 0 because they are prepended to the following character.
+static PREPENDED_ZERO_WIDTHS: [([u8; 3], [u8; 3]); 14] = [
+    ([0x05, 0x06, 0x00], [0x05, 0x06, 0x00]),
+    ([0x0F, 0x07, 0x00], [0x0F, 0x07, 0x00]),
+    ([0x90, 0x08, 0x00], [0x91, 0x08, 0x00]),
+    ([0xE2, 0x08, 0x00], [0xE2, 0x08, 0x00]),
+    ([0x4E, 0x0D, 0x00], [0x4E, 0x0D, 0x00]),
+    ([0xFA, 0xA8, 0x00], [0xFA, 0xA8, 0x00]),
+    ([0xC2, 0x11, 0x01], [0xC3, 0x11, 0x01]),
+    ([0xD1, 0x13, 0x01], [0xD1, 0x13, 0x01]),
+    ([0x3F, 0x19, 0x01], [0x3F, 0x19, 0x01]),
+    ([0x41, 0x19, 0x01], [0x41, 0x19, 0x01]),
+    ([0x3A, 0x1A, 0x01], [0x3A, 0x1A, 0x01]),
+    ([0x84, 0x1A, 0x01], [0x89, 0x1A, 0x01]),
+    ([0x46, 0x1D, 0x01], [0x46, 0x1D, 0x01]),
+    ([0x02, 0x1F, 0x01], [0x02, 0x1F, 0x01]),
+];
+
+/// Sorted list of assigned codepoint ranges (inclusive), each with the version
+/// of Unicode, as `[major, minor]`, in which it was assigned.
+static AGES: [([u8; 3], [u8; 3], [u8; 2]); 1788] = [
+    ([0x00, 0x00, 0x00], [0xF5, 0x01, 0x00], [1, 1]),
+    ([0xF6, 0x01, 0x00], [0xF9, 0x01, 0x00], [3, 0]),
+    ([0xFA, 0x01, 0x00], [0x17, 0x02, 0x00], [1, 1]),
+    ([0x18, 0x02, 0x00], [0x1F, 0x02, 0x00], [3, 0]),
+    ([0x20, 0x02, 0x00], [0x20, 0x02, 0x00], [3, 2]),
+    ([0x21, 0x02, 0x00], [0x21, 0x02, 0x00], [4, 0]),
+    ([0x22, 0x02, 0x00], [0x33, 0x02, 0x00], [3, 0]),
+    ([0x34, 0x02, 0x00], [0x36, 0x02, 0x00], [4, 0]),
+    ([0x37, 0x02, 0x00], [0x41, 0x02, 0x00], [4, 1]),
+    ([0x42, 0x02, 0x00], [0x4F, 0x02, 0x00], [5, 0]),
+    ([0x50, 0x02, 0x00], [0xA8, 0x02, 0x00], [1, 1]),
+    ([0xA9, 0x02, 0x00], [0xAD, 0x02, 0x00], [3, 0]),
+    ([0xAE, 0x02, 0x00], [0xAF, 0x02, 0x00], [4, 0]),
+    ([0xB0, 0x02, 0x00], [0xDE, 0x02, 0x00], [1, 1]),
+    ([0xDF, 0x02, 0x00], [0xDF, 0x02, 0x00], [3, 0]),
+    ([0xE0, 0x02, 0x00], [0xE9, 0x02, 0x00], [1, 1]),
+    ([0xEA, 0x02, 0x00], [0xEE, 0x02, 0x00], [3, 0]),
+    ([0xEF, 0x02, 0x00], [0xFF, 0x02, 0x00], [4, 0]),
+    ([0x00, 0x03, 0x00], [0x45, 0x03, 0x00], [1, 1]),
+    ([0x46, 0x03, 0x00], [0x4E, 0x03, 0x00], [3, 0]),
+    ([0x4F, 0x03, 0x00], [0x4F, 0x03, 0x00], [3, 2]),
+    ([0x50, 0x03, 0x00], [0x57, 0x03, 0x00], [4, 0]),
+    ([0x58, 0x03, 0x00], [0x5C, 0x03, 0x00], [4, 1]),
+    ([0x5D, 0x03, 0x00], [0x5F, 0x03, 0x00], [4, 0]),
+    ([0x60, 0x03, 0x00], [0x61, 0x03, 0x00], [1, 1]),
+    ([0x62, 0x03, 0x00], [0x62, 0x03, 0x00], [3, 0]),
+    ([0x63, 0x03, 0x00], [0x6F, 0x03, 0x00], [3, 2]),
+    ([0x70, 0x03, 0x00], [0x73, 0x03, 0x00], [5, 1]),
+    ([0x74, 0x03, 0x00], [0x75, 0x03, 0x00], [1, 1]),
+    ([0x76, 0x03, 0x00], [0x77, 0x03, 0x00], [5, 1]),
+    ([0x7A, 0x03, 0x00], [0x7A, 0x03, 0x00], [1, 1]),
+    ([0x7B, 0x03, 0x00], [0x7D, 0x03, 0x00], [5, 0]),
+    ([0x7E, 0x03, 0x00], [0x7E, 0x03, 0x00], [1, 1]),
+    ([0x7F, 0x03, 0x00], [0x7F, 0x03, 0x00], [7, 0]),
+    ([0x84, 0x03, 0x00], [0x8A, 0x03, 0x00], [1, 1]),
+    ([0x8C, 0x03, 0x00], [0x8C, 0x03, 0x00], [1, 1]),
+    ([0x8E, 0x03, 0x00], [0xA1, 0x03, 0x00], [1, 1]),
+    ([0xA3, 0x03, 0x00], [0xCE, 0x03, 0x00], [1, 1]),
+    ([0xCF, 0x03, 0x00], [0xCF, 0x03, 0x00], [5, 1]),
+    ([0xD0, 0x03, 0x00], [0xD6, 0x03, 0x00], [1, 1]),
+    ([0xD7, 0x03, 0x00], [0xD7, 0x03, 0x00], [3, 0]),
+    ([0xD8, 0x03, 0x00], [0xD9, 0x03, 0x00], [3, 2]),
+    ([0xDA, 0x03, 0x00], [0xDA, 0x03, 0x00], [1, 1]),
+    ([0xDB, 0x03, 0x00], [0xDB, 0x03, 0x00], [3, 0]),
+    ([0xDC, 0x03, 0x00], [0xDC, 0x03, 0x00], [1, 1]),
+    ([0xDD, 0x03, 0x00], [0xDD, 0x03, 0x00], [3, 0]),
+    ([0xDE, 0x03, 0x00], [0xDE, 0x03, 0x00], [1, 1]),
+    ([0xDF, 0x03, 0x00], [0xDF, 0x03, 0x00], [3, 0]),
+    ([0xE0, 0x03, 0x00], [0xE0, 0x03, 0x00], [1, 1]),
+    ([0xE1, 0x03, 0x00], [0xE1, 0x03, 0x00], [3, 0]),
+    ([0xE2, 0x03, 0x00], [0xF3, 0x03, 0x00], [1, 1]),
+    ([0xF4, 0x03, 0x00], [0xF5, 0x03, 0x00], [3, 1]),
+    ([0xF6, 0x03, 0x00], [0xF6, 0x03, 0x00], [3, 2]),
+    ([0xF7, 0x03, 0x00], [0xFB, 0x03, 0x00], [4, 0]),
+    ([0xFC, 0x03, 0x00], [0xFF, 0x03, 0x00], [4, 1]),
+    ([0x00, 0x04, 0x00], [0x00, 0x04, 0x00], [3, 0]),
+    ([0x01, 0x04, 0x00], [0x0C, 0x04, 0x00], [1, 1]),
+    ([0x0D, 0x04, 0x00], [0x0D, 0x04, 0x00], [3, 0]),
+    ([0x0E, 0x04, 0x00], [0x4F, 0x04, 0x00], [1, 1]),
+    ([0x50, 0x04, 0x00], [0x50, 0x04, 0x00], [3, 0]),
+    ([0x51, 0x04, 0x00], [0x5C, 0x04, 0x00], [1, 1]),
+    ([0x5D, 0x04, 0x00], [0x5D, 0x04, 0x00], [3, 0]),
+    ([0x5E, 0x04, 0x00], [0x86, 0x04, 0x00], [1, 1]),
+    ([0x87, 0x04, 0x00], [0x87, 0x04, 0x00], [5, 1]),
+    ([0x88, 0x04, 0x00], [0x89, 0x04, 0x00], [3, 0]),
+    ([0x8A, 0x04, 0x00], [0x8B, 0x04, 0x00], [3, 2]),
+    ([0x8C, 0x04, 0x00], [0x8F, 0x04, 0x00], [3, 0]),
+    ([0x90, 0x04, 0x00], [0xC4, 0x04, 0x00], [1, 1]),
+    ([0xC5, 0x04, 0x00], [0xC6, 0x04, 0x00], [3, 2]),
+    ([0xC7, 0x04, 0x00], [0xC8, 0x04, 0x00], [1, 1]),
+    ([0xC9, 0x04, 0x00], [0xCA, 0x04, 0x00], [3, 2]),
+    ([0xCB, 0x04, 0x00], [0xCC, 0x04, 0x00], [1, 1]),
+    ([0xCD, 0x04, 0x00], [0xCE, 0x04, 0x00], [3, 2]),
+    ([0xCF, 0x04, 0x00], [0xCF, 0x04, 0x00], [5, 0]),
+    ([0xD0, 0x04, 0x00], [0xEB, 0x04, 0x00], [1, 1]),
+    ([0xEC, 0x04, 0x00], [0xED, 0x04, 0x00], [3, 0]),
+    ([0xEE, 0x04, 0x00], [0xF5, 0x04, 0x00], [1, 1]),
+    ([0xF6, 0x04, 0x00], [0xF7, 0x04, 0x00], [4, 1]),
+    ([0xF8, 0x04, 0x00], [0xF9, 0x04, 0x00], [1, 1]),
+    ([0xFA, 0x04, 0x00], [0xFF, 0x04, 0x00], [5, 0]),
+    ([0x00, 0x05, 0x00], [0x0F, 0x05, 0x00], [3, 2]),
+    ([0x10, 0x05, 0x00], [0x13, 0x05, 0x00], [5, 0]),
+    ([0x14, 0x05, 0x00], [0x23, 0x05, 0x00], [5, 1]),
+    ([0x24, 0x05, 0x00], [0x25, 0x05, 0x00], [5, 2]),
+    ([0x26, 0x05, 0x00], [0x27, 0x05, 0x00], [6, 0]),
+    ([0x28, 0x05, 0x00], [0x2F, 0x05, 0x00], [7, 0]),
+    ([0x31, 0x05, 0x00], [0x56, 0x05, 0x00], [1, 1]),
+    ([0x59, 0x05, 0x00], [0x5F, 0x05, 0x00], [1, 1]),
+    ([0x60, 0x05, 0x00], [0x60, 0x05, 0x00], [11, 0]),
+    ([0x61, 0x05, 0x00], [0x87, 0x05, 0x00], [1, 1]),
+    ([0x88, 0x05, 0x00], [0x88, 0x05, 0x00], [11, 0]),
+    ([0x89, 0x05, 0x00], [0x89, 0x05, 0x00], [1, 1]),
+    ([0x8A, 0x05, 0x00], [0x8A, 0x05, 0x00], [3, 0]),
+    ([0x8D, 0x05, 0x00], [0x8E, 0x05, 0x00], [7, 0]),
+    ([0x8F, 0x05, 0x00], [0x8F, 0x05, 0x00], [6, 1]),
+    ([0x91, 0x05, 0x00], [0xA1, 0x05, 0x00], [2, 0]),
+    ([0xA2, 0x05, 0x00], [0xA2, 0x05, 0x00], [4, 1]),
+    ([0xA3, 0x05, 0x00], [0xAF, 0x05, 0x00], [2, 0]),
+    ([0xB0, 0x05, 0x00], [0xB9, 0x05, 0x00], [1, 1]),
+    ([0xBA, 0x05, 0x00], [0xBA, 0x05, 0x00], [5, 0]),
+    ([0xBB, 0x05, 0x00], [0xC3, 0x05, 0x00], [1, 1]),
+    ([0xC4, 0x05, 0x00], [0xC4, 0x05, 0x00], [2, 0]),
+    ([0xC5, 0x05, 0x00], [0xC7, 0x05, 0x00], [4, 1]),
+    ([0xD0, 0x05, 0x00], [0xEA, 0x05, 0x00], [1, 1]),
+    ([0xEF, 0x05, 0x00], [0xEF, 0x05, 0x00], [11, 0]),
+    ([0xF0, 0x05, 0x00], [0xF4, 0x05, 0x00], [1, 1]),
+    ([0x00, 0x06, 0x00], [0x03, 0x06, 0x00], [4, 0]),
+    ([0x04, 0x06, 0x00], [0x04, 0x06, 0x00], [6, 1]),
+    ([0x05, 0x06, 0x00], [0x05, 0x06, 0x00], [7, 0]),
+    ([0x06, 0x06, 0x00], [0x0A, 0x06, 0x00], [5, 1]),
+    ([0x0B, 0x06, 0x00], [0x0B, 0x06, 0x00], [4, 1]),
+    ([0x0C, 0x06, 0x00], [0x0C, 0x06, 0x00], [1, 1]),
+    ([0x0D, 0x06, 0x00], [0x15, 0x06, 0x00], [4, 0]),
+    ([0x16, 0x06, 0x00], [0x1A, 0x06, 0x00], [5, 1]),
+    ([0x1B, 0x06, 0x00], [0x1B, 0x06, 0x00], [1, 1]),
+    ([0x1C, 0x06, 0x00], [0x1C, 0x06, 0x00], [6, 3]),
+    ([0x1D, 0x06, 0x00], [0x1D, 0x06, 0x00], [14, 0]),
+    ([0x1E, 0x06, 0x00], [0x1E, 0x06, 0x00], [4, 1]),
+    ([0x1F, 0x06, 0x00], [0x1F, 0x06, 0x00], [1, 1]),
+    ([0x20, 0x06, 0x00], [0x20, 0x06, 0x00], [6, 0]),
+    ([0x21, 0x06, 0x00], [0x3A, 0x06, 0x00], [1, 1]),
+    ([0x3B, 0x06, 0x00], [0x3F, 0x06, 0x00], [5, 1]),
+    ([0x40, 0x06, 0x00], [0x52, 0x06, 0x00], [1, 1]),
+    ([0x53, 0x06, 0x00], [0x55, 0x06, 0x00], [3, 0]),
+    ([0x56, 0x06, 0x00], [0x58, 0x06, 0x00], [4, 0]),
+    ([0x59, 0x06, 0x00], [0x5E, 0x06, 0x00], [4, 1]),
+    ([0x5F, 0x06, 0x00], [0x5F, 0x06, 0x00], [6, 0]),
+    ([0x60, 0x06, 0x00], [0x6D, 0x06, 0x00], [1, 1]),
+    ([0x6E, 0x06, 0x00], [0x6F, 0x06, 0x00], [3, 2]),
+    ([0x70, 0x06, 0x00], [0xB7, 0x06, 0x00], [1, 1]),
+    ([0xB8, 0x06, 0x00], [0xB9, 0x06, 0x00], [3, 0]),
+    ([0xBA, 0x06, 0x00], [0xBE, 0x06, 0x00], [1, 1]),
+    ([0xBF, 0x06, 0x00], [0xBF, 0x06, 0x00], [3, 0]),
+    ([0xC0, 0x06, 0x00], [0xCE, 0x06, 0x00], [1, 1]),
+    ([0xCF, 0x06, 0x00], [0xCF, 0x06, 0x00], [3, 0]),
+    ([0xD0, 0x06, 0x00], [0xED, 0x06, 0x00], [1, 1]),
+    ([0xEE, 0x06, 0x00], [0xEF, 0x06, 0x00], [4, 0]),
+    ([0xF0, 0x06, 0x00], [0xF9, 0x06, 0x00], [1, 1]),
+    ([0xFA, 0x06, 0x00], [0xFE, 0x06, 0x00], [3, 0]),
+    ([0xFF, 0x06, 0x00], [0xFF, 0x06, 0x00], [4, 0]),
+    ([0x00, 0x07, 0x00], [0x0D, 0x07, 0x00], [3, 0]),
+    ([0x0F, 0x07, 0x00], [0x2C, 0x07, 0x00], [3, 0]),
+    ([0x2D, 0x07, 0x00], [0x2F, 0x07, 0x00], [4, 0]),
+    ([0x30, 0x07, 0x00], [0x4A, 0x07, 0x00], [3, 0]),
+    ([0x4D, 0x07, 0x00], [0x4F, 0x07, 0x00], [4, 0]),
+    ([0x50, 0x07, 0x00], [0x6D, 0x07, 0x00], [4, 1]),
+    ([0x6E, 0x07, 0x00], [0x7F, 0x07, 0x00], [5, 1]),
+    ([0x80, 0x07, 0x00], [0xB0, 0x07, 0x00], [3, 0]),
+    ([0xB1, 0x07, 0x00], [0xB1, 0x07, 0x00], [3, 2]),
+    ([0xC0, 0x07, 0x00], [0xFA, 0x07, 0x00], [5, 0]),
+    ([0xFD, 0x07, 0x00], [0xFF, 0x07, 0x00], [11, 0]),
+    ([0x00, 0x08, 0x00], [0x2D, 0x08, 0x00], [5, 2]),
+    ([0x30, 0x08, 0x00], [0x3E, 0x08, 0x00], [5, 2]),
+    ([0x40, 0x08, 0x00], [0x5B, 0x08, 0x00], [6, 0]),
+    ([0x5E, 0x08, 0x00], [0x5E, 0x08, 0x00], [6, 0]),
+    ([0x60, 0x08, 0x00], [0x6A, 0x08, 0x00], [10, 0]),
+    ([0x70, 0x08, 0x00], [0x8E, 0x08, 0x00], [14, 0]),
+    ([0x8F, 0x08, 0x00], [0x8F, 0x08, 0x00], [17, 0]),
+    ([0x90, 0x08, 0x00], [0x91, 0x08, 0x00], [14, 0]),
+    ([0x97, 0x08, 0x00], [0x97, 0x08, 0x00], [16, 0]),
+    ([0x98, 0x08, 0x00], [0x9F, 0x08, 0x00], [14, 0]),
+    ([0xA0, 0x08, 0x00], [0xA0, 0x08, 0x00], [6, 1]),
+    ([0xA1, 0x08, 0x00], [0xA1, 0x08, 0x00], [7, 0]),
+    ([0xA2, 0x08, 0x00], [0xAC, 0x08, 0x00], [6, 1]),
+    ([0xAD, 0x08, 0x00], [0xB2, 0x08, 0x00], [7, 0]),
+    ([0xB3, 0x08, 0x00], [0xB4, 0x08, 0x00], [8, 0]),
+    ([0xB5, 0x08, 0x00], [0xB5, 0x08, 0x00], [14, 0]),
+    ([0xB6, 0x08, 0x00], [0xBD, 0x08, 0x00], [9, 0]),
+    ([0xBE, 0x08, 0x00], [0xC7, 0x08, 0x00], [13, 0]),
+    ([0xC8, 0x08, 0x00], [0xD2, 0x08, 0x00], [14, 0]),
+    ([0xD3, 0x08, 0x00], [0xD3, 0x08, 0x00], [11, 0]),
+    ([0xD4, 0x08, 0x00], [0xE2, 0x08, 0x00], [9, 0]),
+    ([0xE3, 0x08, 0x00], [0xE3, 0x08, 0x00], [8, 0]),
+    ([0xE4, 0x08, 0x00], [0xFE, 0x08, 0x00], [6, 1]),
+    ([0xFF, 0x08, 0x00], [0xFF, 0x08, 0x00], [7, 0]),
+    ([0x00, 0x09, 0x00], [0x00, 0x09, 0x00], [5, 2]),
+    ([0x01, 0x09, 0x00], [0x03, 0x09, 0x00], [1, 1]),
+    ([0x04, 0x09, 0x00], [0x04, 0x09, 0x00], [4, 0]),
+    ([0x05, 0x09, 0x00], [0x39, 0x09, 0x00], [1, 1]),
+    ([0x3A, 0x09, 0x00], [0x3B, 0x09, 0x00], [6, 0]),
+    ([0x3C, 0x09, 0x00], [0x4D, 0x09, 0x00], [1, 1]),
+    ([0x4E, 0x09, 0x00], [0x4E, 0x09, 0x00], [5, 2]),
+    ([0x4F, 0x09, 0x00], [0x4F, 0x09, 0x00], [6, 0]),
+    ([0x50, 0x09, 0x00], [0x54, 0x09, 0x00], [1, 1]),
+    ([0x55, 0x09, 0x00], [0x55, 0x09, 0x00], [5, 2]),
+    ([0x56, 0x09, 0x00], [0x57, 0x09, 0x00], [6, 0]),
+    ([0x58, 0x09, 0x00], [0x70, 0x09, 0x00], [1, 1]),
+    ([0x71, 0x09, 0x00], [0x72, 0x09, 0x00], [5, 1]),
+    ([0x73, 0x09, 0x00], [0x77, 0x09, 0x00], [6, 0]),
+    ([0x78, 0x09, 0x00], [0x78, 0x09, 0x00], [7, 0]),
+    ([0x79, 0x09, 0x00], [0x7A, 0x09, 0x00], [5, 2]),
+    ([0x7B, 0x09, 0x00], [0x7C, 0x09, 0x00], [5, 0]),
+    ([0x7D, 0x09, 0x00], [0x7D, 0x09, 0x00], [4, 1]),
+    ([0x7E, 0x09, 0x00], [0x7F, 0x09, 0x00], [5, 0]),
+    ([0x80, 0x09, 0x00], [0x80, 0x09, 0x00], [7, 0]),
+    ([0x81, 0x09, 0x00], [0x83, 0x09, 0x00], [1, 1]),
+    ([0x85, 0x09, 0x00], [0x8C, 0x09, 0x00], [1, 1]),
+    ([0x8F, 0x09, 0x00], [0x90, 0x09, 0x00], [1, 1]),
+    ([0x93, 0x09, 0x00], [0xA8, 0x09, 0x00], [1, 1]),
+    ([0xAA, 0x09, 0x00], [0xB0, 0x09, 0x00], [1, 1]),
+    ([0xB2, 0x09, 0x00], [0xB2, 0x09, 0x00], [1, 1]),
+    ([0xB6, 0x09, 0x00], [0xB9, 0x09, 0x00], [1, 1]),
+    ([0xBC, 0x09, 0x00], [0xBC, 0x09, 0x00], [1, 1]),
+    ([0xBD, 0x09, 0x00], [0xBD, 0x09, 0x00], [4, 0]),
+    ([0xBE, 0x09, 0x00], [0xC4, 0x09, 0x00], [1, 1]),
+    ([0xC7, 0x09, 0x00], [0xC8, 0x09, 0x00], [1, 1]),
+    ([0xCB, 0x09, 0x00], [0xCD, 0x09, 0x00], [1, 1]),
+    ([0xCE, 0x09, 0x00], [0xCE, 0x09, 0x00], [4, 1]),
+    ([0xD7, 0x09, 0x00], [0xD7, 0x09, 0x00], [1, 1]),
+    ([0xDC, 0x09, 0x00], [0xDD, 0x09, 0x00], [1, 1]),
+    ([0xDF, 0x09, 0x00], [0xE3, 0x09, 0x00], [1, 1]),
+    ([0xE6, 0x09, 0x00], [0xFA, 0x09, 0x00], [1, 1]),
+    ([0xFB, 0x09, 0x00], [0xFB, 0x09, 0x00], [5, 2]),
+    ([0xFC, 0x09, 0x00], [0xFD, 0x09, 0x00], [10, 0]),
+    ([0xFE, 0x09, 0x00], [0xFE, 0x09, 0x00], [11, 0]),
+    ([0x01, 0x0A, 0x00], [0x01, 0x0A, 0x00], [4, 0]),
+    ([0x02, 0x0A, 0x00], [0x02, 0x0A, 0x00], [1, 1]),
+    ([0x03, 0x0A, 0x00], [0x03, 0x0A, 0x00], [4, 0]),
+    ([0x05, 0x0A, 0x00], [0x0A, 0x0A, 0x00], [1, 1]),
+    ([0x0F, 0x0A, 0x00], [0x10, 0x0A, 0x00], [1, 1]),
+    ([0x13, 0x0A, 0x00], [0x28, 0x0A, 0x00], [1, 1]),
+    ([0x2A, 0x0A, 0x00], [0x30, 0x0A, 0x00], [1, 1]),
+    ([0x32, 0x0A, 0x00], [0x33, 0x0A, 0x00], [1, 1]),
+    ([0x35, 0x0A, 0x00], [0x36, 0x0A, 0x00], [1, 1]),
+    ([0x38, 0x0A, 0x00], [0x39, 0x0A, 0x00], [1, 1]),
+    ([0x3C, 0x0A, 0x00], [0x3C, 0x0A, 0x00], [1, 1]),
+    ([0x3E, 0x0A, 0x00], [0x42, 0x0A, 0x00], [1, 1]),
+    ([0x47, 0x0A, 0x00], [0x48, 0x0A, 0x00], [1, 1]),
+    ([0x4B, 0x0A, 0x00], [0x4D, 0x0A, 0x00], [1, 1]),
+    ([0x51, 0x0A, 0x00], [0x51, 0x0A, 0x00], [5, 1]),
+    ([0x59, 0x0A, 0x00], [0x5C, 0x0A, 0x00], [1, 1]),
+    ([0x5E, 0x0A, 0x00], [0x5E, 0x0A, 0x00], [1, 1]),
+    ([0x66, 0x0A, 0x00], [0x74, 0x0A, 0x00], [1, 1]),
+    ([0x75, 0x0A, 0x00], [0x75, 0x0A, 0x00], [5, 1]),
+    ([0x76, 0x0A, 0x00], [0x76, 0x0A, 0x00], [11, 0]),
+    ([0x81, 0x0A, 0x00], [0x83, 0x0A, 0x00], [1, 1]),
+    ([0x85, 0x0A, 0x00], [0x8B, 0x0A, 0x00], [1, 1]),
+    ([0x8C, 0x0A, 0x00], [0x8C, 0x0A, 0x00], [4, 0]),
+    ([0x8D, 0x0A, 0x00], [0x8D, 0x0A, 0x00], [1, 1]),
+    ([0x8F, 0x0A, 0x00], [0x91, 0x0A, 0x00], [1, 1]),
+    ([0x93, 0x0A, 0x00], [0xA8, 0x0A, 0x00], [1, 1]),
+    ([0xAA, 0x0A, 0x00], [0xB0, 0x0A, 0x00], [1, 1]),
+    ([0xB2, 0x0A, 0x00], [0xB3, 0x0A, 0x00], [1, 1]),
+    ([0xB5, 0x0A, 0x00], [0xB9, 0x0A, 0x00], [1, 1]),
+    ([0xBC, 0x0A, 0x00], [0xC5, 0x0A, 0x00], [1, 1]),
+    ([0xC7, 0x0A, 0x00], [0xC9, 0x0A, 0x00], [1, 1]),
+    ([0xCB, 0x0A, 0x00], [0xCD, 0x0A, 0x00], [1, 1]),
+    ([0xD0, 0x0A, 0x00], [0xD0, 0x0A, 0x00], [1, 1]),
+    ([0xE0, 0x0A, 0x00], [0xE0, 0x0A, 0x00], [1, 1]),
+    ([0xE1, 0x0A, 0x00], [0xE3, 0x0A, 0x00], [4, 0]),
+    ([0xE6, 0x0A, 0x00], [0xEF, 0x0A, 0x00], [1, 1]),
+    ([0xF0, 0x0A, 0x00], [0xF0, 0x0A, 0x00], [6, 1]),
+    ([0xF1, 0x0A, 0x00], [0xF1, 0x0A, 0x00], [4, 0]),
+    ([0xF9, 0x0A, 0x00], [0xF9, 0x0A, 0x00], [8, 0]),
+    ([0xFA, 0x0A, 0x00], [0xFF, 0x0A, 0x00], [10, 0]),
+    ([0x01, 0x0B, 0x00], [0x03, 0x0B, 0x00], [1, 1]),
+    ([0x05, 0x0B, 0x00], [0x0C, 0x0B, 0x00], [1, 1]),
+    ([0x0F, 0x0B, 0x00], [0x10, 0x0B, 0x00], [1, 1]),
+    ([0x13, 0x0B, 0x00], [0x28, 0x0B, 0x00], [1, 1]),
+    ([0x2A, 0x0B, 0x00], [0x30, 0x0B, 0x00], [1, 1]),
+    ([0x32, 0x0B, 0x00], [0x33, 0x0B, 0x00], [1, 1]),
+    ([0x35, 0x0B, 0x00], [0x35, 0x0B, 0x00], [4, 0]),
+    ([0x36, 0x0B, 0x00], [0x39, 0x0B, 0x00], [1, 1]),
+    ([0x3C, 0x0B, 0x00], [0x43, 0x0B, 0x00], [1, 1]),
+    ([0x44, 0x0B, 0x00], [0x44, 0x0B, 0x00], [5, 1]),
+    ([0x47, 0x0B, 0x00], [0x48, 0x0B, 0x00], [1, 1]),
+    ([0x4B, 0x0B, 0x00], [0x4D, 0x0B, 0x00], [1, 1]),
+    ([0x55, 0x0B, 0x00], [0x55, 0x0B, 0x00], [13, 0]),
+    ([0x56, 0x0B, 0x00], [0x57, 0x0B, 0x00], [1, 1]),
+    ([0x5C, 0x0B, 0x00], [0x5D, 0x0B, 0x00], [1, 1]),
+    ([0x5F, 0x0B, 0x00], [0x61, 0x0B, 0x00], [1, 1]),
+    ([0x62, 0x0B, 0x00], [0x63, 0x0B, 0x00], [5, 1]),
+    ([0x66, 0x0B, 0x00], [0x70, 0x0B, 0x00], [1, 1]),
+    ([0x71, 0x0B, 0x00], [0x71, 0x0B, 0x00], [4, 0]),
+    ([0x72, 0x0B, 0x00], [0x77, 0x0B, 0x00], [6, 0]),
+    ([0x82, 0x0B, 0x00], [0x83, 0x0B, 0x00], [1, 1]),
+    ([0x85, 0x0B, 0x00], [0x8A, 0x0B, 0x00], [1, 1]),
+    ([0x8E, 0x0B, 0x00], [0x90, 0x0B, 0x00], [1, 1]),
+    ([0x92, 0x0B, 0x00], [0x95, 0x0B, 0x00], [1, 1]),
+    ([0x99, 0x0B, 0x00], [0x9A, 0x0B, 0x00], [1, 1]),
+    ([0x9C, 0x0B, 0x00], [0x9C, 0x0B, 0x00], [1, 1]),
+    ([0x9E, 0x0B, 0x00], [0x9F, 0x0B, 0x00], [1, 1]),
+    ([0xA3, 0x0B, 0x00], [0xA4, 0x0B, 0x00], [1, 1]),
+    ([0xA8, 0x0B, 0x00], [0xAA, 0x0B, 0x00], [1, 1]),
+    ([0xAE, 0x0B, 0x00], [0xB5, 0x0B, 0x00], [1, 1]),
+    ([0xB6, 0x0B, 0x00], [0xB6, 0x0B, 0x00], [4, 1]),
+    ([0xB7, 0x0B, 0x00], [0xB9, 0x0B, 0x00], [1, 1]),
+    ([0xBE, 0x0B, 0x00], [0xC2, 0x0B, 0x00], [1, 1]),
+    ([0xC6, 0x0B, 0x00], [0xC8, 0x0B, 0x00], [1, 1]),
+    ([0xCA, 0x0B, 0x00], [0xCD, 0x0B, 0x00], [1, 1]),
+    ([0xD0, 0x0B, 0x00], [0xD0, 0x0B, 0x00], [5, 1]),
+    ([0xD7, 0x0B, 0x00], [0xD7, 0x0B, 0x00], [1, 1]),
+    ([0xE6, 0x0B, 0x00], [0xE6, 0x0B, 0x00], [4, 1]),
+    ([0xE7, 0x0B, 0x00], [0xF2, 0x0B, 0x00], [1, 1]),
+    ([0xF3, 0x0B, 0x00], [0xFA, 0x0B, 0x00], [4, 0]),
+    ([0x00, 0x0C, 0x00], [0x00, 0x0C, 0x00], [7, 0]),
+    ([0x01, 0x0C, 0x00], [0x03, 0x0C, 0x00], [1, 1]),
+    ([0x04, 0x0C, 0x00], [0x04, 0x0C, 0x00], [11, 0]),
+    ([0x05, 0x0C, 0x00], [0x0C, 0x0C, 0x00], [1, 1]),
+    ([0x0E, 0x0C, 0x00], [0x10, 0x0C, 0x00], [1, 1]),
+    ([0x12, 0x0C, 0x00], [0x28, 0x0C, 0x00], [1, 1]),
+    ([0x2A, 0x0C, 0x00], [0x33, 0x0C, 0x00], [1, 1]),
+    ([0x34, 0x0C, 0x00], [0x34, 0x0C, 0x00], [7, 0]),
+    ([0x35, 0x0C, 0x00], [0x39, 0x0C, 0x00], [1, 1]),
+    ([0x3C, 0x0C, 0x00], [0x3C, 0x0C, 0x00], [14, 0]),
+    ([0x3D, 0x0C, 0x00], [0x3D, 0x0C, 0x00], [5, 1]),
+    ([0x3E, 0x0C, 0x00], [0x44, 0x0C, 0x00], [1, 1]),
+    ([0x46, 0x0C, 0x00], [0x48, 0x0C, 0x00], [1, 1]),
+    ([0x4A, 0x0C, 0x00], [0x4D, 0x0C, 0x00], [1, 1]),
+    ([0x55, 0x0C, 0x00], [0x56, 0x0C, 0x00], [1, 1]),
+    ([0x58, 0x0C, 0x00], [0x59, 0x0C, 0x00], [5, 1]),
+    ([0x5A, 0x0C, 0x00], [0x5A, 0x0C, 0x00], [8, 0]),
+    ([0x5C, 0x0C, 0x00], [0x5C, 0x0C, 0x00], [17, 0]),
+    ([0x5D, 0x0C, 0x00], [0x5D, 0x0C, 0x00], [14, 0]),
+    ([0x60, 0x0C, 0x00], [0x61, 0x0C, 0x00], [1, 1]),
+    ([0x62, 0x0C, 0x00], [0x63, 0x0C, 0x00], [5, 1]),
+    ([0x66, 0x0C, 0x00], [0x6F, 0x0C, 0x00], [1, 1]),
+    ([0x77, 0x0C, 0x00], [0x77, 0x0C, 0x00], [12, 0]),
+    ([0x78, 0x0C, 0x00], [0x7F, 0x0C, 0x00], [5, 1]),
+    ([0x80, 0x0C, 0x00], [0x80, 0x0C, 0x00], [9, 0]),
+    ([0x81, 0x0C, 0x00], [0x81, 0x0C, 0x00], [7, 0]),
+    ([0x82, 0x0C, 0x00], [0x83, 0x0C, 0x00], [1, 1]),
+    ([0x84, 0x0C, 0x00], [0x84, 0x0C, 0x00], [11, 0]),
+    ([0x85, 0x0C, 0x00], [0x8C, 0x0C, 0x00], [1, 1]),
+    ([0x8E, 0x0C, 0x00], [0x90, 0x0C, 0x00], [1, 1]),
+    ([0x92, 0x0C, 0x00], [0xA8, 0x0C, 0x00], [1, 1]),
+    ([0xAA, 0x0C, 0x00], [0xB3, 0x0C, 0x00], [1, 1]),
+    ([0xB5, 0x0C, 0x00], [0xB9, 0x0C, 0x00], [1, 1]),
+    ([0xBC, 0x0C, 0x00], [0xBD, 0x0C, 0x00], [4, 0]),
+    ([0xBE, 0x0C, 0x00], [0xC4, 0x0C, 0x00], [1, 1]),
+    ([0xC6, 0x0C, 0x00], [0xC8, 0x0C, 0x00], [1, 1]),
+    ([0xCA, 0x0C, 0x00], [0xCD, 0x0C, 0x00], [1, 1]),
+    ([0xD5, 0x0C, 0x00], [0xD6, 0x0C, 0x00], [1, 1]),
+    ([0xDC, 0x0C, 0x00], [0xDC, 0x0C, 0x00], [17, 0]),
+    ([0xDD, 0x0C, 0x00], [0xDD, 0x0C, 0x00], [14, 0]),
+    ([0xDE, 0x0C, 0x00], [0xDE, 0x0C, 0x00], [1, 1]),
+    ([0xE0, 0x0C, 0x00], [0xE1, 0x0C, 0x00], [1, 1]),
+    ([0xE2, 0x0C, 0x00], [0xE3, 0x0C, 0x00], [5, 0]),
+    ([0xE6, 0x0C, 0x00], [0xEF, 0x0C, 0x00], [1, 1]),
+    ([0xF1, 0x0C, 0x00], [0xF2, 0x0C, 0x00], [5, 0]),
+    ([0xF3, 0x0C, 0x00], [0xF3, 0x0C, 0x00], [15, 0]),
+    ([0x00, 0x0D, 0x00], [0x00, 0x0D, 0x00], [10, 0]),
+    ([0x01, 0x0D, 0x00], [0x01, 0x0D, 0x00], [7, 0]),
+    ([0x02, 0x0D, 0x00], [0x03, 0x0D, 0x00], [1, 1]),
+    ([0x04, 0x0D, 0x00], [0x04, 0x0D, 0x00], [13, 0]),
+    ([0x05, 0x0D, 0x00], [0x0C, 0x0D, 0x00], [1, 1]),
+    ([0x0E, 0x0D, 0x00], [0x10, 0x0D, 0x00], [1, 1]),
+    ([0x12, 0x0D, 0x00], [0x28, 0x0D, 0x00], [1, 1]),
+    ([0x29, 0x0D, 0x00], [0x29, 0x0D, 0x00], [6, 0]),
+    ([0x2A, 0x0D, 0x00], [0x39, 0x0D, 0x00], [1, 1]),
+    ([0x3A, 0x0D, 0x00], [0x3A, 0x0D, 0x00], [6, 0]),
+    ([0x3B, 0x0D, 0x00], [0x3C, 0x0D, 0x00], [10, 0]),
+    ([0x3D, 0x0D, 0x00], [0x3D, 0x0D, 0x00], [5, 1]),
+    ([0x3E, 0x0D, 0x00], [0x43, 0x0D, 0x00], [1, 1]),
+    ([0x44, 0x0D, 0x00], [0x44, 0x0D, 0x00], [5, 1]),
+    ([0x46, 0x0D, 0x00], [0x48, 0x0D, 0x00], [1, 1]),
+    ([0x4A, 0x0D, 0x00], [0x4D, 0x0D, 0x00], [1, 1]),
+    ([0x4E, 0x0D, 0x00], [0x4E, 0x0D, 0x00], [6, 0]),
+    ([0x4F, 0x0D, 0x00], [0x4F, 0x0D, 0x00], [9, 0]),
+    ([0x54, 0x0D, 0x00], [0x56, 0x0D, 0x00], [9, 0]),
+    ([0x57, 0x0D, 0x00], [0x57, 0x0D, 0x00], [1, 1]),
+    ([0x58, 0x0D, 0x00], [0x5E, 0x0D, 0x00], [9, 0]),
+    ([0x5F, 0x0D, 0x00], [0x5F, 0x0D, 0x00], [8, 0]),
+    ([0x60, 0x0D, 0x00], [0x61, 0x0D, 0x00], [1, 1]),
+    ([0x62, 0x0D, 0x00], [0x63, 0x0D, 0x00], [5, 1]),
+    ([0x66, 0x0D, 0x00], [0x6F, 0x0D, 0x00], [1, 1]),
+    ([0x70, 0x0D, 0x00], [0x75, 0x0D, 0x00], [5, 1]),
+    ([0x76, 0x0D, 0x00], [0x78, 0x0D, 0x00], [9, 0]),
+    ([0x79, 0x0D, 0x00], [0x7F, 0x0D, 0x00], [5, 1]),
+    ([0x81, 0x0D, 0x00], [0x81, 0x0D, 0x00], [13, 0]),
+    ([0x82, 0x0D, 0x00], [0x83, 0x0D, 0x00], [3, 0]),
+    ([0x85, 0x0D, 0x00], [0x96, 0x0D, 0x00], [3, 0]),
+    ([0x9A, 0x0D, 0x00], [0xB1, 0x0D, 0x00], [3, 0]),
+    ([0xB3, 0x0D, 0x00], [0xBB, 0x0D, 0x00], [3, 0]),
+    ([0xBD, 0x0D, 0x00], [0xBD, 0x0D, 0x00], [3, 0]),
+    ([0xC0, 0x0D, 0x00], [0xC6, 0x0D, 0x00], [3, 0]),
+    ([0xCA, 0x0D, 0x00], [0xCA, 0x0D, 0x00], [3, 0]),
+    ([0xCF, 0x0D, 0x00], [0xD4, 0x0D, 0x00], [3, 0]),
+    ([0xD6, 0x0D, 0x00], [0xD6, 0x0D, 0x00], [3, 0]),
+    ([0xD8, 0x0D, 0x00], [0xDF, 0x0D, 0x00], [3, 0]),
+    ([0xE6, 0x0D, 0x00], [0xEF, 0x0D, 0x00], [7, 0]),
+    ([0xF2, 0x0D, 0x00], [0xF4, 0x0D, 0x00], [3, 0]),
+    ([0x01, 0x0E, 0x00], [0x3A, 0x0E, 0x00], [1, 1]),
+    ([0x3F, 0x0E, 0x00], [0x5B, 0x0E, 0x00], [1, 1]),
+    ([0x81, 0x0E, 0x00], [0x82, 0x0E, 0x00], [1, 1]),
+    ([0x84, 0x0E, 0x00], [0x84, 0x0E, 0x00], [1, 1]),
+    ([0x86, 0x0E, 0x00], [0x86, 0x0E, 0x00], [12, 0]),
+    ([0x87, 0x0E, 0x00], [0x88, 0x0E, 0x00], [1, 1]),
+    ([0x89, 0x0E, 0x00], [0x89, 0x0E, 0x00], [12, 0]),
+    ([0x8A, 0x0E, 0x00], [0x8A, 0x0E, 0x00], [1, 1]),
+    ([0x8C, 0x0E, 0x00], [0x8C, 0x0E, 0x00], [12, 0]),
+    ([0x8D, 0x0E, 0x00], [0x8D, 0x0E, 0x00], [1, 1]),
+    ([0x8E, 0x0E, 0x00], [0x93, 0x0E, 0x00], [12, 0]),
+    ([0x94, 0x0E, 0x00], [0x97, 0x0E, 0x00], [1, 1]),
+    ([0x98, 0x0E, 0x00], [0x98, 0x0E, 0x00], [12, 0]),
+    ([0x99, 0x0E, 0x00], [0x9F, 0x0E, 0x00], [1, 1]),
+    ([0xA0, 0x0E, 0x00], [0xA0, 0x0E, 0x00], [12, 0]),
+    ([0xA1, 0x0E, 0x00], [0xA3, 0x0E, 0x00], [1, 1]),
+    ([0xA5, 0x0E, 0x00], [0xA5, 0x0E, 0x00], [1, 1]),
+    ([0xA7, 0x0E, 0x00], [0xA7, 0x0E, 0x00], [1, 1]),
+    ([0xA8, 0x0E, 0x00], [0xA9, 0x0E, 0x00], [12, 0]),
+    ([0xAA, 0x0E, 0x00], [0xAB, 0x0E, 0x00], [1, 1]),
+    ([0xAC, 0x0E, 0x00], [0xAC, 0x0E, 0x00], [12, 0]),
+    ([0xAD, 0x0E, 0x00], [0xB9, 0x0E, 0x00], [1, 1]),
+    ([0xBA, 0x0E, 0x00], [0xBA, 0x0E, 0x00], [12, 0]),
+    ([0xBB, 0x0E, 0x00], [0xBD, 0x0E, 0x00], [1, 1]),
+    ([0xC0, 0x0E, 0x00], [0xC4, 0x0E, 0x00], [1, 1]),
+    ([0xC6, 0x0E, 0x00], [0xC6, 0x0E, 0x00], [1, 1]),
+    ([0xC8, 0x0E, 0x00], [0xCD, 0x0E, 0x00], [1, 1]),
+    ([0xCE, 0x0E, 0x00], [0xCE, 0x0E, 0x00], [15, 0]),
+    ([0xD0, 0x0E, 0x00], [0xD9, 0x0E, 0x00], [1, 1]),
+    ([0xDC, 0x0E, 0x00], [0xDD, 0x0E, 0x00], [1, 1]),
+    ([0xDE, 0x0E, 0x00], [0xDF, 0x0E, 0x00], [6, 1]),
+    ([0x00, 0x0F, 0x00], [0x47, 0x0F, 0x00], [2, 0]),
+    ([0x49, 0x0F, 0x00], [0x69, 0x0F, 0x00], [2, 0]),
+    ([0x6A, 0x0F, 0x00], [0x6A, 0x0F, 0x00], [3, 0]),
+    ([0x6B, 0x0F, 0x00], [0x6C, 0x0F, 0x00], [5, 1]),
+    ([0x71, 0x0F, 0x00], [0x8B, 0x0F, 0x00], [2, 0]),
+    ([0x8C, 0x0F, 0x00], [0x8F, 0x0F, 0x00], [6, 0]),
+    ([0x90, 0x0F, 0x00], [0x95, 0x0F, 0x00], [2, 0]),
+    ([0x96, 0x0F, 0x00], [0x96, 0x0F, 0x00], [3, 0]),
+    ([0x97, 0x0F, 0x00], [0x97, 0x0F, 0x00], [2, 0]),
+    ([0x99, 0x0F, 0x00], [0xAD, 0x0F, 0x00], [2, 0]),
+    ([0xAE, 0x0F, 0x00], [0xB0, 0x0F, 0x00], [3, 0]),
+    ([0xB1, 0x0F, 0x00], [0xB7, 0x0F, 0x00], [2, 0]),
+    ([0xB8, 0x0F, 0x00], [0xB8, 0x0F, 0x00], [3, 0]),
+    ([0xB9, 0x0F, 0x00], [0xB9, 0x0F, 0x00], [2, 0]),
+    ([0xBA, 0x0F, 0x00], [0xBC, 0x0F, 0x00], [3, 0]),
+    ([0xBE, 0x0F, 0x00], [0xCC, 0x0F, 0x00], [3, 0]),
+    ([0xCE, 0x0F, 0x00], [0xCE, 0x0F, 0x00], [5, 1]),
+    ([0xCF, 0x0F, 0x00], [0xCF, 0x0F, 0x00], [3, 0]),
+    ([0xD0, 0x0F, 0x00], [0xD1, 0x0F, 0x00], [4, 1]),
+    ([0xD2, 0x0F, 0x00], [0xD4, 0x0F, 0x00], [5, 1]),
+    ([0xD5, 0x0F, 0x00], [0xD8, 0x0F, 0x00], [5, 2]),
+    ([0xD9, 0x0F, 0x00], [0xDA, 0x0F, 0x00], [6, 0]),
+    ([0x00, 0x10, 0x00], [0x21, 0x10, 0x00], [3, 0]),
+    ([0x22, 0x10, 0x00], [0x22, 0x10, 0x00], [5, 1]),
+    ([0x23, 0x10, 0x00], [0x27, 0x10, 0x00], [3, 0]),
+    ([0x28, 0x10, 0x00], [0x28, 0x10, 0x00], [5, 1]),
+    ([0x29, 0x10, 0x00], [0x2A, 0x10, 0x00], [3, 0]),
+    ([0x2B, 0x10, 0x00], [0x2B, 0x10, 0x00], [5, 1]),
+    ([0x2C, 0x10, 0x00], [0x32, 0x10, 0x00], [3, 0]),
+    ([0x33, 0x10, 0x00], [0x35, 0x10, 0x00], [5, 1]),
+    ([0x36, 0x10, 0x00], [0x39, 0x10, 0x00], [3, 0]),
+    ([0x3A, 0x10, 0x00], [0x3F, 0x10, 0x00], [5, 1]),
+    ([0x40, 0x10, 0x00], [0x59, 0x10, 0x00], [3, 0]),
+    ([0x5A, 0x10, 0x00], [0x99, 0x10, 0x00], [5, 1]),
+    ([0x9A, 0x10, 0x00], [0x9D, 0x10, 0x00], [5, 2]),
+    ([0x9E, 0x10, 0x00], [0x9F, 0x10, 0x00], [5, 1]),
+    ([0xA0, 0x10, 0x00], [0xC5, 0x10, 0x00], [1, 1]),
+    ([0xC7, 0x10, 0x00], [0xC7, 0x10, 0x00], [6, 1]),
+    ([0xCD, 0x10, 0x00], [0xCD, 0x10, 0x00], [6, 1]),
+    ([0xD0, 0x10, 0x00], [0xF6, 0x10, 0x00], [1, 1]),
+    ([0xF7, 0x10, 0x00], [0xF8, 0x10, 0x00], [3, 2]),
+    ([0xF9, 0x10, 0x00], [0xFA, 0x10, 0x00], [4, 1]),
+    ([0xFB, 0x10, 0x00], [0xFB, 0x10, 0x00], [1, 1]),
+    ([0xFC, 0x10, 0x00], [0xFC, 0x10, 0x00], [4, 1]),
+    ([0xFD, 0x10, 0x00], [0xFF, 0x10, 0x00], [6, 1]),
+    ([0x00, 0x11, 0x00], [0x59, 0x11, 0x00], [1, 1]),
+    ([0x5A, 0x11, 0x00], [0x5E, 0x11, 0x00], [5, 2]),
+    ([0x5F, 0x11, 0x00], [0xA2, 0x11, 0x00], [1, 1]),
+    ([0xA3, 0x11, 0x00], [0xA7, 0x11, 0x00], [5, 2]),
+    ([0xA8, 0x11, 0x00], [0xF9, 0x11, 0x00], [1, 1]),
+    ([0xFA, 0x11, 0x00], [0xFF, 0x11, 0x00], [5, 2]),
+    ([0x00, 0x12, 0x00], [0x06, 0x12, 0x00], [3, 0]),
+    ([0x07, 0x12, 0x00], [0x07, 0x12, 0x00], [4, 1]),
+    ([0x08, 0x12, 0x00], [0x46, 0x12, 0x00], [3, 0]),
+    ([0x47, 0x12, 0x00], [0x47, 0x12, 0x00], [4, 1]),
+    ([0x48, 0x12, 0x00], [0x48, 0x12, 0x00], [3, 0]),
+    ([0x4A, 0x12, 0x00], [0x4D, 0x12, 0x00], [3, 0]),
+    ([0x50, 0x12, 0x00], [0x56, 0x12, 0x00], [3, 0]),
+    ([0x58, 0x12, 0x00], [0x58, 0x12, 0x00], [3, 0]),
+    ([0x5A, 0x12, 0x00], [0x5D, 0x12, 0x00], [3, 0]),
+    ([0x60, 0x12, 0x00], [0x86, 0x12, 0x00], [3, 0]),
+    ([0x87, 0x12, 0x00], [0x87, 0x12, 0x00], [4, 1]),
+    ([0x88, 0x12, 0x00], [0x88, 0x12, 0x00], [3, 0]),
+    ([0x8A, 0x12, 0x00], [0x8D, 0x12, 0x00], [3, 0]),
+    ([0x90, 0x12, 0x00], [0xAE, 0x12, 0x00], [3, 0]),
+    ([0xAF, 0x12, 0x00], [0xAF, 0x12, 0x00], [4, 1]),
+    ([0xB0, 0x12, 0x00], [0xB0, 0x12, 0x00], [3, 0]),
+    ([0xB2, 0x12, 0x00], [0xB5, 0x12, 0x00], [3, 0]),
+    ([0xB8, 0x12, 0x00], [0xBE, 0x12, 0x00], [3, 0]),
+    ([0xC0, 0x12, 0x00], [0xC0, 0x12, 0x00], [3, 0]),
+    ([0xC2, 0x12, 0x00], [0xC5, 0x12, 0x00], [3, 0]),
+    ([0xC8, 0x12, 0x00], [0xCE, 0x12, 0x00], [3, 0]),
+    ([0xCF, 0x12, 0x00], [0xCF, 0x12, 0x00], [4, 1]),
+    ([0xD0, 0x12, 0x00], [0xD6, 0x12, 0x00], [3, 0]),
+    ([0xD8, 0x12, 0x00], [0xEE, 0x12, 0x00], [3, 0]),
+    ([0xEF, 0x12, 0x00], [0xEF, 0x12, 0x00], [4, 1]),
+    ([0xF0, 0x12, 0x00], [0x0E, 0x13, 0x00], [3, 0]),
+    ([0x0F, 0x13, 0x00], [0x0F, 0x13, 0x00], [4, 1]),
+    ([0x10, 0x13, 0x00], [0x10, 0x13, 0x00], [3, 0]),
+    ([0x12, 0x13, 0x00], [0x15, 0x13, 0x00], [3, 0]),
+    ([0x18, 0x13, 0x00], [0x1E, 0x13, 0x00], [3, 0]),
+    ([0x1F, 0x13, 0x00], [0x1F, 0x13, 0x00], [4, 1]),
+    ([0x20, 0x13, 0x00], [0x46, 0x13, 0x00], [3, 0]),
+    ([0x47, 0x13, 0x00], [0x47, 0x13, 0x00], [4, 1]),
+    ([0x48, 0x13, 0x00], [0x5A, 0x13, 0x00], [3, 0]),
+    ([0x5D, 0x13, 0x00], [0x5E, 0x13, 0x00], [6, 0]),
+    ([0x5F, 0x13, 0x00], [0x60, 0x13, 0x00], [4, 1]),
+    ([0x61, 0x13, 0x00], [0x7C, 0x13, 0x00], [3, 0]),
+    ([0x80, 0x13, 0x00], [0x99, 0x13, 0x00], [4, 1]),
+    ([0xA0, 0x13, 0x00], [0xF4, 0x13, 0x00], [3, 0]),
+    ([0xF5, 0x13, 0x00], [0xF5, 0x13, 0x00], [8, 0]),
+    ([0xF8, 0x13, 0x00], [0xFD, 0x13, 0x00], [8, 0]),
+    ([0x00, 0x14, 0x00], [0x00, 0x14, 0x00], [5, 2]),
+    ([0x01, 0x14, 0x00], [0x76, 0x16, 0x00], [3, 0]),
+    ([0x77, 0x16, 0x00], [0x7F, 0x16, 0x00], [5, 2]),
+    ([0x80, 0x16, 0x00], [0x9C, 0x16, 0x00], [3, 0]),
+    ([0xA0, 0x16, 0x00], [0xF0, 0x16, 0x00], [3, 0]),
+    ([0xF1, 0x16, 0x00], [0xF8, 0x16, 0x00], [7, 0]),
+    ([0x00, 0x17, 0x00], [0x0C, 0x17, 0x00], [3, 2]),
+    ([0x0D, 0x17, 0x00], [0x0D, 0x17, 0x00], [14, 0]),
+    ([0x0E, 0x17, 0x00], [0x14, 0x17, 0x00], [3, 2]),
+    ([0x15, 0x17, 0x00], [0x15, 0x17, 0x00], [14, 0]),
+    ([0x1F, 0x17, 0x00], [0x1F, 0x17, 0x00], [14, 0]),
+    ([0x20, 0x17, 0x00], [0x36, 0x17, 0x00], [3, 2]),
+    ([0x40, 0x17, 0x00], [0x53, 0x17, 0x00], [3, 2]),
+    ([0x60, 0x17, 0x00], [0x6C, 0x17, 0x00], [3, 2]),
+    ([0x6E, 0x17, 0x00], [0x70, 0x17, 0x00], [3, 2]),
+    ([0x72, 0x17, 0x00], [0x73, 0x17, 0x00], [3, 2]),
+    ([0x80, 0x17, 0x00], [0xDC, 0x17, 0x00], [3, 0]),
+    ([0xDD, 0x17, 0x00], [0xDD, 0x17, 0x00], [4, 0]),
+    ([0xE0, 0x17, 0x00], [0xE9, 0x17, 0x00], [3, 0]),
+    ([0xF0, 0x17, 0x00], [0xF9, 0x17, 0x00], [4, 0]),
+    ([0x00, 0x18, 0x00], [0x0E, 0x18, 0x00], [3, 0]),
+    ([0x0F, 0x18, 0x00], [0x0F, 0x18, 0x00], [14, 0]),
+    ([0x10, 0x18, 0x00], [0x19, 0x18, 0x00], [3, 0]),
+    ([0x20, 0x18, 0x00], [0x77, 0x18, 0x00], [3, 0]),
+    ([0x78, 0x18, 0x00], [0x78, 0x18, 0x00], [11, 0]),
+    ([0x80, 0x18, 0x00], [0xA9, 0x18, 0x00], [3, 0]),
+    ([0xAA, 0x18, 0x00], [0xAA, 0x18, 0x00], [5, 1]),
+    ([0xB0, 0x18, 0x00], [0xF5, 0x18, 0x00], [5, 2]),
+    ([0x00, 0x19, 0x00], [0x1C, 0x19, 0x00], [4, 0]),
+    ([0x1D, 0x19, 0x00], [0x1E, 0x19, 0x00], [7, 0]),
+    ([0x20, 0x19, 0x00], [0x2B, 0x19, 0x00], [4, 0]),
+    ([0x30, 0x19, 0x00], [0x3B, 0x19, 0x00], [4, 0]),
+    ([0x40, 0x19, 0x00], [0x40, 0x19, 0x00], [4, 0]),
+    ([0x44, 0x19, 0x00], [0x6D, 0x19, 0x00], [4, 0]),
+    ([0x70, 0x19, 0x00], [0x74, 0x19, 0x00], [4, 0]),
+    ([0x80, 0x19, 0x00], [0xA9, 0x19, 0x00], [4, 1]),
+    ([0xAA, 0x19, 0x00], [0xAB, 0x19, 0x00], [5, 2]),
+    ([0xB0, 0x19, 0x00], [0xC9, 0x19, 0x00], [4, 1]),
+    ([0xD0, 0x19, 0x00], [0xD9, 0x19, 0x00], [4, 1]),
+    ([0xDA, 0x19, 0x00], [0xDA, 0x19, 0x00], [5, 2]),
+    ([0xDE, 0x19, 0x00], [0xDF, 0x19, 0x00], [4, 1]),
+    ([0xE0, 0x19, 0x00], [0xFF, 0x19, 0x00], [4, 0]),
+    ([0x00, 0x1A, 0x00], [0x1B, 0x1A, 0x00], [4, 1]),
+    ([0x1E, 0x1A, 0x00], [0x1F, 0x1A, 0x00], [4, 1]),
+    ([0x20, 0x1A, 0x00], [0x5E, 0x1A, 0x00], [5, 2]),
+    ([0x60, 0x1A, 0x00], [0x7C, 0x1A, 0x00], [5, 2]),
+    ([0x7F, 0x1A, 0x00], [0x89, 0x1A, 0x00], [5, 2]),
+    ([0x90, 0x1A, 0x00], [0x99, 0x1A, 0x00], [5, 2]),
+    ([0xA0, 0x1A, 0x00], [0xAD, 0x1A, 0x00], [5, 2]),
+    ([0xB0, 0x1A, 0x00], [0xBE, 0x1A, 0x00], [7, 0]),
+    ([0xBF, 0x1A, 0x00], [0xC0, 0x1A, 0x00], [13, 0]),
+    ([0xC1, 0x1A, 0x00], [0xCE, 0x1A, 0x00], [14, 0]),
+    ([0xCF, 0x1A, 0x00], [0xDD, 0x1A, 0x00], [17, 0]),
+    ([0xE0, 0x1A, 0x00], [0xEB, 0x1A, 0x00], [17, 0]),
+    ([0x00, 0x1B, 0x00], [0x4B, 0x1B, 0x00], [5, 0]),
+    ([0x4C, 0x1B, 0x00], [0x4C, 0x1B, 0x00], [14, 0]),
+    ([0x4E, 0x1B, 0x00], [0x4F, 0x1B, 0x00], [16, 0]),
+    ([0x50, 0x1B, 0x00], [0x7C, 0x1B, 0x00], [5, 0]),
+    ([0x7D, 0x1B, 0x00], [0x7E, 0x1B, 0x00], [14, 0]),
+    ([0x7F, 0x1B, 0x00], [0x7F, 0x1B, 0x00], [16, 0]),
+    ([0x80, 0x1B, 0x00], [0xAA, 0x1B, 0x00], [5, 1]),
+    ([0xAB, 0x1B, 0x00], [0xAD, 0x1B, 0x00], [6, 1]),
+    ([0xAE, 0x1B, 0x00], [0xB9, 0x1B, 0x00], [5, 1]),
+    ([0xBA, 0x1B, 0x00], [0xBF, 0x1B, 0x00], [6, 1]),
+    ([0xC0, 0x1B, 0x00], [0xF3, 0x1B, 0x00], [6, 0]),
+    ([0xFC, 0x1B, 0x00], [0xFF, 0x1B, 0x00], [6, 0]),
+    ([0x00, 0x1C, 0x00], [0x37, 0x1C, 0x00], [5, 1]),
+    ([0x3B, 0x1C, 0x00], [0x49, 0x1C, 0x00], [5, 1]),
+    ([0x4D, 0x1C, 0x00], [0x7F, 0x1C, 0x00], [5, 1]),
+    ([0x80, 0x1C, 0x00], [0x88, 0x1C, 0x00], [9, 0]),
+    ([0x89, 0x1C, 0x00], [0x8A, 0x1C, 0x00], [16, 0]),
+    ([0x90, 0x1C, 0x00], [0xBA, 0x1C, 0x00], [11, 0]),
+    ([0xBD, 0x1C, 0x00], [0xBF, 0x1C, 0x00], [11, 0]),
+    ([0xC0, 0x1C, 0x00], [0xC7, 0x1C, 0x00], [6, 1]),
+    ([0xD0, 0x1C, 0x00], [0xF2, 0x1C, 0x00], [5, 2]),
+    ([0xF3, 0x1C, 0x00], [0xF6, 0x1C, 0x00], [6, 1]),
+    ([0xF7, 0x1C, 0x00], [0xF7, 0x1C, 0x00], [10, 0]),
+    ([0xF8, 0x1C, 0x00], [0xF9, 0x1C, 0x00], [7, 0]),
+    ([0xFA, 0x1C, 0x00], [0xFA, 0x1C, 0x00], [12, 0]),
+    ([0x00, 0x1D, 0x00], [0x6B, 0x1D, 0x00], [4, 0]),
+    ([0x6C, 0x1D, 0x00], [0xC3, 0x1D, 0x00], [4, 1]),
+    ([0xC4, 0x1D, 0x00], [0xCA, 0x1D, 0x00], [5, 0]),
+    ([0xCB, 0x1D, 0x00], [0xE6, 0x1D, 0x00], [5, 1]),
+    ([0xE7, 0x1D, 0x00], [0xF5, 0x1D, 0x00], [7, 0]),
+    ([0xF6, 0x1D, 0x00], [0xF9, 0x1D, 0x00], [10, 0]),
+    ([0xFA, 0x1D, 0x00], [0xFA, 0x1D, 0x00], [14, 0]),
+    ([0xFB, 0x1D, 0x00], [0xFB, 0x1D, 0x00], [9, 0]),
+    ([0xFC, 0x1D, 0x00], [0xFC, 0x1D, 0x00], [6, 0]),
+    ([0xFD, 0x1D, 0x00], [0xFD, 0x1D, 0x00], [5, 2]),
+    ([0xFE, 0x1D, 0x00], [0xFF, 0x1D, 0x00], [5, 0]),
+    ([0x00, 0x1E, 0x00], [0x9A, 0x1E, 0x00], [1, 1]),
+    ([0x9B, 0x1E, 0x00], [0x9B, 0x1E, 0x00], [2, 0]),
+    ([0x9C, 0x1E, 0x00], [0x9F, 0x1E, 0x00], [5, 1]),
+    ([0xA0, 0x1E, 0x00], [0xF9, 0x1E, 0x00], [1, 1]),
+    ([0xFA, 0x1E, 0x00], [0xFF, 0x1E, 0x00], [5, 1]),
+    ([0x00, 0x1F, 0x00], [0x15, 0x1F, 0x00], [1, 1]),
+    ([0x18, 0x1F, 0x00], [0x1D, 0x1F, 0x00], [1, 1]),
+    ([0x20, 0x1F, 0x00], [0x45, 0x1F, 0x00], [1, 1]),
+    ([0x48, 0x1F, 0x00], [0x4D, 0x1F, 0x00], [1, 1]),
+    ([0x50, 0x1F, 0x00], [0x57, 0x1F, 0x00], [1, 1]),
+    ([0x59, 0x1F, 0x00], [0x59, 0x1F, 0x00], [1, 1]),
+    ([0x5B, 0x1F, 0x00], [0x5B, 0x1F, 0x00], [1, 1]),
+    ([0x5D, 0x1F, 0x00], [0x5D, 0x1F, 0x00], [1, 1]),
+    ([0x5F, 0x1F, 0x00], [0x7D, 0x1F, 0x00], [1, 1]),
+    ([0x80, 0x1F, 0x00], [0xB4, 0x1F, 0x00], [1, 1]),
+    ([0xB6, 0x1F, 0x00], [0xC4, 0x1F, 0x00], [1, 1]),
+    ([0xC6, 0x1F, 0x00], [0xD3, 0x1F, 0x00], [1, 1]),
+    ([0xD6, 0x1F, 0x00], [0xDB, 0x1F, 0x00], [1, 1]),
+    ([0xDD, 0x1F, 0x00], [0xEF, 0x1F, 0x00], [1, 1]),
+    ([0xF2, 0x1F, 0x00], [0xF4, 0x1F, 0x00], [1, 1]),
+    ([0xF6, 0x1F, 0x00], [0xFE, 0x1F, 0x00], [1, 1]),
+    ([0x00, 0x20, 0x00], [0x2E, 0x20, 0x00], [1, 1]),
+    ([0x2F, 0x20, 0x00], [0x2F, 0x20, 0x00], [3, 0]),
+    ([0x30, 0x20, 0x00], [0x46, 0x20, 0x00], [1, 1]),
+    ([0x47, 0x20, 0x00], [0x47, 0x20, 0x00], [3, 2]),
+    ([0x48, 0x20, 0x00], [0x4D, 0x20, 0x00], [3, 0]),
+    ([0x4E, 0x20, 0x00], [0x52, 0x20, 0x00], [3, 2]),
+    ([0x53, 0x20, 0x00], [0x54, 0x20, 0x00], [4, 0]),
+    ([0x55, 0x20, 0x00], [0x56, 0x20, 0x00], [4, 1]),
+    ([0x57, 0x20, 0x00], [0x57, 0x20, 0x00], [3, 2]),
+    ([0x58, 0x20, 0x00], [0x5E, 0x20, 0x00], [4, 1]),
+    ([0x5F, 0x20, 0x00], [0x63, 0x20, 0x00], [3, 2]),
+    ([0x64, 0x20, 0x00], [0x64, 0x20, 0x00], [5, 1]),
+    ([0x66, 0x20, 0x00], [0x69, 0x20, 0x00], [6, 3]),
+    ([0x6A, 0x20, 0x00], [0x70, 0x20, 0x00], [1, 1]),
+    ([0x71, 0x20, 0x00], [0x71, 0x20, 0x00], [3, 2]),
+    ([0x74, 0x20, 0x00], [0x8E, 0x20, 0x00], [1, 1]),
+    ([0x90, 0x20, 0x00], [0x94, 0x20, 0x00], [4, 1]),
+    ([0x95, 0x20, 0x00], [0x9C, 0x20, 0x00], [6, 0]),
+    ([0xA0, 0x20, 0x00], [0xAA, 0x20, 0x00], [1, 1]),
+    ([0xAB, 0x20, 0x00], [0xAB, 0x20, 0x00], [2, 0]),
+    ([0xAC, 0x20, 0x00], [0xAC, 0x20, 0x00], [2, 1]),
+    ([0xAD, 0x20, 0x00], [0xAF, 0x20, 0x00], [3, 0]),
+    ([0xB0, 0x20, 0x00], [0xB1, 0x20, 0x00], [3, 2]),
+    ([0xB2, 0x20, 0x00], [0xB5, 0x20, 0x00], [4, 1]),
+    ([0xB6, 0x20, 0x00], [0xB8, 0x20, 0x00], [5, 2]),
+    ([0xB9, 0x20, 0x00], [0xB9, 0x20, 0x00], [6, 0]),
+    ([0xBA, 0x20, 0x00], [0xBA, 0x20, 0x00], [6, 2]),
+    ([0xBB, 0x20, 0x00], [0xBD, 0x20, 0x00], [7, 0]),
+    ([0xBE, 0x20, 0x00], [0xBE, 0x20, 0x00], [8, 0]),
+    ([0xBF, 0x20, 0x00], [0xBF, 0x20, 0x00], [10, 0]),
+    ([0xC0, 0x20, 0x00], [0xC0, 0x20, 0x00], [14, 0]),
+    ([0xC1, 0x20, 0x00], [0xC1, 0x20, 0x00], [17, 0]),
+    ([0xD0, 0x20, 0x00], [0xE1, 0x20, 0x00], [1, 1]),
+    ([0xE2, 0x20, 0x00], [0xE3, 0x20, 0x00], [3, 0]),
+    ([0xE4, 0x20, 0x00], [0xEA, 0x20, 0x00], [3, 2]),
+    ([0xEB, 0x20, 0x00], [0xEB, 0x20, 0x00], [4, 1]),
+    ([0xEC, 0x20, 0x00], [0xEF, 0x20, 0x00], [5, 0]),
+    ([0xF0, 0x20, 0x00], [0xF0, 0x20, 0x00], [5, 1]),
+    ([0x00, 0x21, 0x00], [0x38, 0x21, 0x00], [1, 1]),
+    ([0x39, 0x21, 0x00], [0x3A, 0x21, 0x00], [3, 0]),
+    ([0x3B, 0x21, 0x00], [0x3B, 0x21, 0x00], [4, 0]),
+    ([0x3C, 0x21, 0x00], [0x3C, 0x21, 0x00], [4, 1]),
+    ([0x3D, 0x21, 0x00], [0x4B, 0x21, 0x00], [3, 2]),
+    ([0x4C, 0x21, 0x00], [0x4C, 0x21, 0x00], [4, 1]),
+    ([0x4D, 0x21, 0x00], [0x4E, 0x21, 0x00], [5, 0]),
+    ([0x4F, 0x21, 0x00], [0x4F, 0x21, 0x00], [5, 1]),
+    ([0x50, 0x21, 0x00], [0x52, 0x21, 0x00], [5, 2]),
+    ([0x53, 0x21, 0x00], [0x82, 0x21, 0x00], [1, 1]),
+    ([0x83, 0x21, 0x00], [0x83, 0x21, 0x00], [3, 0]),
+    ([0x84, 0x21, 0x00], [0x84, 0x21, 0x00], [5, 0]),
+    ([0x85, 0x21, 0x00], [0x88, 0x21, 0x00], [5, 1]),
+    ([0x89, 0x21, 0x00], [0x89, 0x21, 0x00], [5, 2]),
+    ([0x8A, 0x21, 0x00], [0x8B, 0x21, 0x00], [8, 0]),
+    ([0x90, 0x21, 0x00], [0xEA, 0x21, 0x00], [1, 1]),
+    ([0xEB, 0x21, 0x00], [0xF3, 0x21, 0x00], [3, 0]),
+    ([0xF4, 0x21, 0x00], [0xFF, 0x21, 0x00], [3, 2]),
+    ([0x00, 0x22, 0x00], [0xF1, 0x22, 0x00], [1, 1]),
+    ([0xF2, 0x22, 0x00], [0xFF, 0x22, 0x00], [3, 2]),
+    ([0x00, 0x23, 0x00], [0x00, 0x23, 0x00], [1, 1]),
+    ([0x01, 0x23, 0x00], [0x01, 0x23, 0x00], [3, 0]),
+    ([0x02, 0x23, 0x00], [0x7A, 0x23, 0x00], [1, 1]),
+    ([0x7B, 0x23, 0x00], [0x7B, 0x23, 0x00], [3, 0]),
+    ([0x7C, 0x23, 0x00], [0x7C, 0x23, 0x00], [3, 2]),
+    ([0x7D, 0x23, 0x00], [0x9A, 0x23, 0x00], [3, 0]),
+    ([0x9B, 0x23, 0x00], [0xCE, 0x23, 0x00], [3, 2]),
+    ([0xCF, 0x23, 0x00], [0xD0, 0x23, 0x00], [4, 0]),
+    ([0xD1, 0x23, 0x00], [0xDB, 0x23, 0x00], [4, 1]),
+    ([0xDC, 0x23, 0x00], [0xE7, 0x23, 0x00], [5, 0]),
+    ([0xE8, 0x23, 0x00], [0xE8, 0x23, 0x00], [5, 2]),
+    ([0xE9, 0x23, 0x00], [0xF3, 0x23, 0x00], [6, 0]),
+    ([0xF4, 0x23, 0x00], [0xFA, 0x23, 0x00], [7, 0]),
+    ([0xFB, 0x23, 0x00], [0xFE, 0x23, 0x00], [9, 0]),
+    ([0xFF, 0x23, 0x00], [0xFF, 0x23, 0x00], [10, 0]),
+    ([0x00, 0x24, 0x00], [0x24, 0x24, 0x00], [1, 1]),
+    ([0x25, 0x24, 0x00], [0x26, 0x24, 0x00], [3, 0]),
+    ([0x27, 0x24, 0x00], [0x29, 0x24, 0x00], [16, 0]),
+    ([0x40, 0x24, 0x00], [0x4A, 0x24, 0x00], [1, 1]),
+    ([0x60, 0x24, 0x00], [0xEA, 0x24, 0x00], [1, 1]),
+    ([0xEB, 0x24, 0x00], [0xFE, 0x24, 0x00], [3, 2]),
+    ([0xFF, 0x24, 0x00], [0xFF, 0x24, 0x00], [4, 0]),
+    ([0x00, 0x25, 0x00], [0x95, 0x25, 0x00], [1, 1]),
+    ([0x96, 0x25, 0x00], [0x9F, 0x25, 0x00], [3, 2]),
+    ([0xA0, 0x25, 0x00], [0xEF, 0x25, 0x00], [1, 1]),
+    ([0xF0, 0x25, 0x00], [0xF7, 0x25, 0x00], [3, 0]),
+    ([0xF8, 0x25, 0x00], [0xFF, 0x25, 0x00], [3, 2]),
+    ([0x00, 0x26, 0x00], [0x13, 0x26, 0x00], [1, 1]),
+    ([0x14, 0x26, 0x00], [0x15, 0x26, 0x00], [4, 0]),
+    ([0x16, 0x26, 0x00], [0x17, 0x26, 0x00], [3, 2]),
+    ([0x18, 0x26, 0x00], [0x18, 0x26, 0x00], [4, 1]),
+    ([0x19, 0x26, 0x00], [0x19, 0x26, 0x00], [3, 0]),
+    ([0x1A, 0x26, 0x00], [0x6F, 0x26, 0x00], [1, 1]),
+    ([0x70, 0x26, 0x00], [0x71, 0x26, 0x00], [3, 0]),
+    ([0x72, 0x26, 0x00], [0x7D, 0x26, 0x00], [3, 2]),
+    ([0x7E, 0x26, 0x00], [0x7F, 0x26, 0x00], [4, 1]),
+    ([0x80, 0x26, 0x00], [0x89, 0x26, 0x00], [3, 2]),
+    ([0x8A, 0x26, 0x00], [0x91, 0x26, 0x00], [4, 0]),
+    ([0x92, 0x26, 0x00], [0x9C, 0x26, 0x00], [4, 1]),
+    ([0x9D, 0x26, 0x00], [0x9D, 0x26, 0x00], [5, 1]),
+    ([0x9E, 0x26, 0x00], [0x9F, 0x26, 0x00], [5, 2]),
+    ([0xA0, 0x26, 0x00], [0xA1, 0x26, 0x00], [4, 0]),
+    ([0xA2, 0x26, 0x00], [0xB1, 0x26, 0x00], [4, 1]),
+    ([0xB2, 0x26, 0x00], [0xB2, 0x26, 0x00], [5, 0]),
+    ([0xB3, 0x26, 0x00], [0xBC, 0x26, 0x00], [5, 1]),
+    ([0xBD, 0x26, 0x00], [0xBF, 0x26, 0x00], [5, 2]),
+    ([0xC0, 0x26, 0x00], [0xC3, 0x26, 0x00], [5, 1]),
+    ([0xC4, 0x26, 0x00], [0xCD, 0x26, 0x00], [5, 2]),
+    ([0xCE, 0x26, 0x00], [0xCE, 0x26, 0x00], [6, 0]),
+    ([0xCF, 0x26, 0x00], [0xE1, 0x26, 0x00], [5, 2]),
+    ([0xE2, 0x26, 0x00], [0xE2, 0x26, 0x00], [6, 0]),
+    ([0xE3, 0x26, 0x00], [0xE3, 0x26, 0x00], [5, 2]),
+    ([0xE4, 0x26, 0x00], [0xE7, 0x26, 0x00], [6, 0]),
+    ([0xE8, 0x26, 0x00], [0xFF, 0x26, 0x00], [5, 2]),
+    ([0x00, 0x27, 0x00], [0x00, 0x27, 0x00], [7, 0]),
+    ([0x01, 0x27, 0x00], [0x04, 0x27, 0x00], [1, 1]),
+    ([0x05, 0x27, 0x00], [0x05, 0x27, 0x00], [6, 0]),
+    ([0x06, 0x27, 0x00], [0x09, 0x27, 0x00], [1, 1]),
+    ([0x0A, 0x27, 0x00], [0x0B, 0x27, 0x00], [6, 0]),
+    ([0x0C, 0x27, 0x00], [0x27, 0x27, 0x00], [1, 1]),
+    ([0x28, 0x27, 0x00], [0x28, 0x27, 0x00], [6, 0]),
+    ([0x29, 0x27, 0x00], [0x4B, 0x27, 0x00], [1, 1]),
+    ([0x4C, 0x27, 0x00], [0x4C, 0x27, 0x00], [6, 0]),
+    ([0x4D, 0x27, 0x00], [0x4D, 0x27, 0x00], [1, 1]),
+    ([0x4E, 0x27, 0x00], [0x4E, 0x27, 0x00], [6, 0]),
+    ([0x4F, 0x27, 0x00], [0x52, 0x27, 0x00], [1, 1]),
+    ([0x53, 0x27, 0x00], [0x55, 0x27, 0x00], [6, 0]),
+    ([0x56, 0x27, 0x00], [0x56, 0x27, 0x00], [1, 1]),
+    ([0x57, 0x27, 0x00], [0x57, 0x27, 0x00], [5, 2]),
+    ([0x58, 0x27, 0x00], [0x5E, 0x27, 0x00], [1, 1]),
+    ([0x5F, 0x27, 0x00], [0x60, 0x27, 0x00], [6, 0]),
+    ([0x61, 0x27, 0x00], [0x67, 0x27, 0x00], [1, 1]),
+    ([0x68, 0x27, 0x00], [0x75, 0x27, 0x00], [3, 2]),
+    ([0x76, 0x27, 0x00], [0x94, 0x27, 0x00], [1, 1]),
+    ([0x95, 0x27, 0x00], [0x97, 0x27, 0x00], [6, 0]),
+    ([0x98, 0x27, 0x00], [0xAF, 0x27, 0x00], [1, 1]),
+    ([0xB0, 0x27, 0x00], [0xB0, 0x27, 0x00], [6, 0]),
+    ([0xB1, 0x27, 0x00], [0xBE, 0x27, 0x00], [1, 1]),
+    ([0xBF, 0x27, 0x00], [0xBF, 0x27, 0x00], [6, 0]),
+    ([0xC0, 0x27, 0x00], [0xC6, 0x27, 0x00], [4, 1]),
+    ([0xC7, 0x27, 0x00], [0xCA, 0x27, 0x00], [5, 0]),
+    ([0xCB, 0x27, 0x00], [0xCB, 0x27, 0x00], [6, 1]),
+    ([0xCC, 0x27, 0x00], [0xCC, 0x27, 0x00], [5, 1]),
+    ([0xCD, 0x27, 0x00], [0xCD, 0x27, 0x00], [6, 1]),
+    ([0xCE, 0x27, 0x00], [0xCF, 0x27, 0x00], [6, 0]),
+    ([0xD0, 0x27, 0x00], [0xEB, 0x27, 0x00], [3, 2]),
+    ([0xEC, 0x27, 0x00], [0xEF, 0x27, 0x00], [5, 1]),
+    ([0xF0, 0x27, 0x00], [0xFF, 0x27, 0x00], [3, 2]),
+    ([0x00, 0x28, 0x00], [0xFF, 0x28, 0x00], [3, 0]),
+    ([0x00, 0x29, 0x00], [0xFF, 0x2A, 0x00], [3, 2]),
+    ([0x00, 0x2B, 0x00], [0x0D, 0x2B, 0x00], [4, 0]),
+    ([0x0E, 0x2B, 0x00], [0x13, 0x2B, 0x00], [4, 1]),
+    ([0x14, 0x2B, 0x00], [0x1A, 0x2B, 0x00], [5, 0]),
+    ([0x1B, 0x2B, 0x00], [0x1F, 0x2B, 0x00], [5, 1]),
+    ([0x20, 0x2B, 0x00], [0x23, 0x2B, 0x00], [5, 0]),
+    ([0x24, 0x2B, 0x00], [0x4C, 0x2B, 0x00], [5, 1]),
+    ([0x4D, 0x2B, 0x00], [0x4F, 0x2B, 0x00], [7, 0]),
+    ([0x50, 0x2B, 0x00], [0x54, 0x2B, 0x00], [5, 1]),
+    ([0x55, 0x2B, 0x00], [0x59, 0x2B, 0x00], [5, 2]),
+    ([0x5A, 0x2B, 0x00], [0x73, 0x2B, 0x00], [7, 0]),
+    ([0x76, 0x2B, 0x00], [0x95, 0x2B, 0x00], [7, 0]),
+    ([0x96, 0x2B, 0x00], [0x96, 0x2B, 0x00], [17, 0]),
+    ([0x97, 0x2B, 0x00], [0x97, 0x2B, 0x00], [13, 0]),
+    ([0x98, 0x2B, 0x00], [0xB9, 0x2B, 0x00], [7, 0]),
+    ([0xBA, 0x2B, 0x00], [0xBC, 0x2B, 0x00], [11, 0]),
+    ([0xBD, 0x2B, 0x00], [0xC8, 0x2B, 0x00], [7, 0]),
+    ([0xC9, 0x2B, 0x00], [0xC9, 0x2B, 0x00], [12, 0]),
+    ([0xCA, 0x2B, 0x00], [0xD1, 0x2B, 0x00], [7, 0]),
+    ([0xD2, 0x2B, 0x00], [0xD2, 0x2B, 0x00], [10, 0]),
+    ([0xD3, 0x2B, 0x00], [0xEB, 0x2B, 0x00], [11, 0]),
+    ([0xEC, 0x2B, 0x00], [0xEF, 0x2B, 0x00], [8, 0]),
+    ([0xF0, 0x2B, 0x00], [0xFE, 0x2B, 0x00], [11, 0]),
+    ([0xFF, 0x2B, 0x00], [0xFF, 0x2B, 0x00], [12, 0]),
+    ([0x00, 0x2C, 0x00], [0x2E, 0x2C, 0x00], [4, 1]),
+    ([0x2F, 0x2C, 0x00], [0x2F, 0x2C, 0x00], [14, 0]),
+    ([0x30, 0x2C, 0x00], [0x5E, 0x2C, 0x00], [4, 1]),
+    ([0x5F, 0x2C, 0x00], [0x5F, 0x2C, 0x00], [14, 0]),
+    ([0x60, 0x2C, 0x00], [0x6C, 0x2C, 0x00], [5, 0]),
+    ([0x6D, 0x2C, 0x00], [0x6F, 0x2C, 0x00], [5, 1]),
+    ([0x70, 0x2C, 0x00], [0x70, 0x2C, 0x00], [5, 2]),
+    ([0x71, 0x2C, 0x00], [0x73, 0x2C, 0x00], [5, 1]),
+    ([0x74, 0x2C, 0x00], [0x77, 0x2C, 0x00], [5, 0]),
+    ([0x78, 0x2C, 0x00], [0x7D, 0x2C, 0x00], [5, 1]),
+    ([0x7E, 0x2C, 0x00], [0x7F, 0x2C, 0x00], [5, 2]),
+    ([0x80, 0x2C, 0x00], [0xEA, 0x2C, 0x00], [4, 1]),
+    ([0xEB, 0x2C, 0x00], [0xF1, 0x2C, 0x00], [5, 2]),
+    ([0xF2, 0x2C, 0x00], [0xF3, 0x2C, 0x00], [6, 1]),
+    ([0xF9, 0x2C, 0x00], [0x25, 0x2D, 0x00], [4, 1]),
+    ([0x27, 0x2D, 0x00], [0x27, 0x2D, 0x00], [6, 1]),
+    ([0x2D, 0x2D, 0x00], [0x2D, 0x2D, 0x00], [6, 1]),
+    ([0x30, 0x2D, 0x00], [0x65, 0x2D, 0x00], [4, 1]),
+    ([0x66, 0x2D, 0x00], [0x67, 0x2D, 0x00], [6, 1]),
+    ([0x6F, 0x2D, 0x00], [0x6F, 0x2D, 0x00], [4, 1]),
+    ([0x70, 0x2D, 0x00], [0x70, 0x2D, 0x00], [6, 0]),
+    ([0x7F, 0x2D, 0x00], [0x7F, 0x2D, 0x00], [6, 0]),
+    ([0x80, 0x2D, 0x00], [0x96, 0x2D, 0x00], [4, 1]),
+    ([0xA0, 0x2D, 0x00], [0xA6, 0x2D, 0x00], [4, 1]),
+    ([0xA8, 0x2D, 0x00], [0xAE, 0x2D, 0x00], [4, 1]),
+    ([0xB0, 0x2D, 0x00], [0xB6, 0x2D, 0x00], [4, 1]),
+    ([0xB8, 0x2D, 0x00], [0xBE, 0x2D, 0x00], [4, 1]),
+    ([0xC0, 0x2D, 0x00], [0xC6, 0x2D, 0x00], [4, 1]),
+    ([0xC8, 0x2D, 0x00], [0xCE, 0x2D, 0x00], [4, 1]),
+    ([0xD0, 0x2D, 0x00], [0xD6, 0x2D, 0x00], [4, 1]),
+    ([0xD8, 0x2D, 0x00], [0xDE, 0x2D, 0x00], [4, 1]),
+    ([0xE0, 0x2D, 0x00], [0xFF, 0x2D, 0x00], [5, 1]),
+    ([0x00, 0x2E, 0x00], [0x17, 0x2E, 0x00], [4, 1]),
+    ([0x18, 0x2E, 0x00], [0x1B, 0x2E, 0x00], [5, 1]),
+    ([0x1C, 0x2E, 0x00], [0x1D, 0x2E, 0x00], [4, 1]),
+    ([0x1E, 0x2E, 0x00], [0x30, 0x2E, 0x00], [5, 1]),
+    ([0x31, 0x2E, 0x00], [0x31, 0x2E, 0x00], [5, 2]),
+    ([0x32, 0x2E, 0x00], [0x3B, 0x2E, 0x00], [6, 1]),
+    ([0x3C, 0x2E, 0x00], [0x42, 0x2E, 0x00], [7, 0]),
+    ([0x43, 0x2E, 0x00], [0x44, 0x2E, 0x00], [9, 0]),
+    ([0x45, 0x2E, 0x00], [0x49, 0x2E, 0x00], [10, 0]),
+    ([0x4A, 0x2E, 0x00], [0x4E, 0x2E, 0x00], [11, 0]),
+    ([0x4F, 0x2E, 0x00], [0x4F, 0x2E, 0x00], [12, 0]),
+    ([0x50, 0x2E, 0x00], [0x52, 0x2E, 0x00], [13, 0]),
+    ([0x53, 0x2E, 0x00], [0x5D, 0x2E, 0x00], [14, 0]),
+    ([0x80, 0x2E, 0x00], [0x99, 0x2E, 0x00], [3, 0]),
+    ([0x9B, 0x2E, 0x00], [0xF3, 0x2E, 0x00], [3, 0]),
+    ([0x00, 0x2F, 0x00], [0xD5, 0x2F, 0x00], [3, 0]),
+    ([0xF0, 0x2F, 0x00], [0xFB, 0x2F, 0x00], [3, 0]),
+    ([0xFC, 0x2F, 0x00], [0xFF, 0x2F, 0x00], [15, 1]),
+    ([0x00, 0x30, 0x00], [0x37, 0x30, 0x00], [1, 1]),
+    ([0x38, 0x30, 0x00], [0x3A, 0x30, 0x00], [3, 0]),
+    ([0x3B, 0x30, 0x00], [0x3D, 0x30, 0x00], [3, 2]),
+    ([0x3E, 0x30, 0x00], [0x3E, 0x30, 0x00], [3, 0]),
+    ([0x3F, 0x30, 0x00], [0x3F, 0x30, 0x00], [1, 1]),
+    ([0x41, 0x30, 0x00], [0x94, 0x30, 0x00], [1, 1]),
+    ([0x95, 0x30, 0x00], [0x96, 0x30, 0x00], [3, 2]),
+    ([0x99, 0x30, 0x00], [0x9E, 0x30, 0x00], [1, 1]),
+    ([0x9F, 0x30, 0x00], [0xA0, 0x30, 0x00], [3, 2]),
+    ([0xA1, 0x30, 0x00], [0xFE, 0x30, 0x00], [1, 1]),
+    ([0xFF, 0x30, 0x00], [0xFF, 0x30, 0x00], [3, 2]),
+    ([0x05, 0x31, 0x00], [0x2C, 0x31, 0x00], [1, 1]),
+    ([0x2D, 0x31, 0x00], [0x2D, 0x31, 0x00], [5, 1]),
+    ([0x2E, 0x31, 0x00], [0x2E, 0x31, 0x00], [10, 0]),
+    ([0x2F, 0x31, 0x00], [0x2F, 0x31, 0x00], [11, 0]),
+    ([0x31, 0x31, 0x00], [0x8E, 0x31, 0x00], [1, 1]),
+    ([0x90, 0x31, 0x00], [0x9F, 0x31, 0x00], [1, 1]),
+    ([0xA0, 0x31, 0x00], [0xB7, 0x31, 0x00], [3, 0]),
+    ([0xB8, 0x31, 0x00], [0xBA, 0x31, 0x00], [6, 0]),
+    ([0xBB, 0x31, 0x00], [0xBF, 0x31, 0x00], [13, 0]),
+    ([0xC0, 0x31, 0x00], [0xCF, 0x31, 0x00], [4, 1]),
+    ([0xD0, 0x31, 0x00], [0xE3, 0x31, 0x00], [5, 1]),
+    ([0xE4, 0x31, 0x00], [0xE5, 0x31, 0x00], [16, 0]),
+    ([0xEF, 0x31, 0x00], [0xEF, 0x31, 0x00], [15, 1]),
+    ([0xF0, 0x31, 0x00], [0xFF, 0x31, 0x00], [3, 2]),
+    ([0x00, 0x32, 0x00], [0x1C, 0x32, 0x00], [1, 1]),
+    ([0x1D, 0x32, 0x00], [0x1E, 0x32, 0x00], [4, 0]),
+    ([0x20, 0x32, 0x00], [0x43, 0x32, 0x00], [1, 1]),
+    ([0x44, 0x32, 0x00], [0x4F, 0x32, 0x00], [5, 2]),
+    ([0x50, 0x32, 0x00], [0x50, 0x32, 0x00], [4, 0]),
+    ([0x51, 0x32, 0x00], [0x5F, 0x32, 0x00], [3, 2]),
+    ([0x60, 0x32, 0x00], [0x7B, 0x32, 0x00], [1, 1]),
+    ([0x7C, 0x32, 0x00], [0x7D, 0x32, 0x00], [4, 0]),
+    ([0x7E, 0x32, 0x00], [0x7E, 0x32, 0x00], [4, 1]),
+    ([0x7F, 0x32, 0x00], [0xB0, 0x32, 0x00], [1, 1]),
+    ([0xB1, 0x32, 0x00], [0xBF, 0x32, 0x00], [3, 2]),
+    ([0xC0, 0x32, 0x00], [0xCB, 0x32, 0x00], [1, 1]),
+    ([0xCC, 0x32, 0x00], [0xCF, 0x32, 0x00], [4, 0]),
+    ([0xD0, 0x32, 0x00], [0xFE, 0x32, 0x00], [1, 1]),
+    ([0xFF, 0x32, 0x00], [0xFF, 0x32, 0x00], [12, 1]),
+    ([0x00, 0x33, 0x00], [0x76, 0x33, 0x00], [1, 1]),
+    ([0x77, 0x33, 0x00], [0x7A, 0x33, 0x00], [4, 0]),
+    ([0x7B, 0x33, 0x00], [0xDD, 0x33, 0x00], [1, 1]),
+    ([0xDE, 0x33, 0x00], [0xDF, 0x33, 0x00], [4, 0]),
+    ([0xE0, 0x33, 0x00], [0xFE, 0x33, 0x00], [1, 1]),
+    ([0xFF, 0x33, 0x00], [0xFF, 0x33, 0x00], [4, 0]),
+    ([0x00, 0x34, 0x00], [0xB5, 0x4D, 0x00], [3, 0]),
+    ([0xB6, 0x4D, 0x00], [0xBF, 0x4D, 0x00], [13, 0]),
+    ([0xC0, 0x4D, 0x00], [0xFF, 0x4D, 0x00], [4, 0]),
+    ([0x00, 0x4E, 0x00], [0xA5, 0x9F, 0x00], [1, 1]),
+    ([0xA6, 0x9F, 0x00], [0xBB, 0x9F, 0x00], [4, 1]),
+    ([0xBC, 0x9F, 0x00], [0xC3, 0x9F, 0x00], [5, 1]),
+    ([0xC4, 0x9F, 0x00], [0xCB, 0x9F, 0x00], [5, 2]),
+    ([0xCC, 0x9F, 0x00], [0xCC, 0x9F, 0x00], [6, 1]),
+    ([0xCD, 0x9F, 0x00], [0xD5, 0x9F, 0x00], [8, 0]),
+    ([0xD6, 0x9F, 0x00], [0xEA, 0x9F, 0x00], [10, 0]),
+    ([0xEB, 0x9F, 0x00], [0xEF, 0x9F, 0x00], [11, 0]),
+    ([0xF0, 0x9F, 0x00], [0xFC, 0x9F, 0x00], [13, 0]),
+    ([0xFD, 0x9F, 0x00], [0xFF, 0x9F, 0x00], [14, 0]),
+    ([0x00, 0xA0, 0x00], [0x8C, 0xA4, 0x00], [3, 0]),
+    ([0x90, 0xA4, 0x00], [0xA1, 0xA4, 0x00], [3, 0]),
+    ([0xA2, 0xA4, 0x00], [0xA3, 0xA4, 0x00], [3, 2]),
+    ([0xA4, 0xA4, 0x00], [0xB3, 0xA4, 0x00], [3, 0]),
+    ([0xB4, 0xA4, 0x00], [0xB4, 0xA4, 0x00], [3, 2]),
+    ([0xB5, 0xA4, 0x00], [0xC0, 0xA4, 0x00], [3, 0]),
+    ([0xC1, 0xA4, 0x00], [0xC1, 0xA4, 0x00], [3, 2]),
+    ([0xC2, 0xA4, 0x00], [0xC4, 0xA4, 0x00], [3, 0]),
+    ([0xC5, 0xA4, 0x00], [0xC5, 0xA4, 0x00], [3, 2]),
+    ([0xC6, 0xA4, 0x00], [0xC6, 0xA4, 0x00], [3, 0]),
+    ([0xD0, 0xA4, 0x00], [0xFF, 0xA4, 0x00], [5, 2]),
+    ([0x00, 0xA5, 0x00], [0x2B, 0xA6, 0x00], [5, 1]),
+    ([0x40, 0xA6, 0x00], [0x5F, 0xA6, 0x00], [5, 1]),
+    ([0x60, 0xA6, 0x00], [0x61, 0xA6, 0x00], [6, 0]),
+    ([0x62, 0xA6, 0x00], [0x73, 0xA6, 0x00], [5, 1]),
+    ([0x74, 0xA6, 0x00], [0x7B, 0xA6, 0x00], [6, 1]),
+    ([0x7C, 0xA6, 0x00], [0x97, 0xA6, 0x00], [5, 1]),
+    ([0x98, 0xA6, 0x00], [0x9D, 0xA6, 0x00], [7, 0]),
+    ([0x9E, 0xA6, 0x00], [0x9E, 0xA6, 0x00], [8, 0]),
+    ([0x9F, 0xA6, 0x00], [0x9F, 0xA6, 0x00], [6, 1]),
+    ([0xA0, 0xA6, 0x00], [0xF7, 0xA6, 0x00], [5, 2]),
+    ([0x00, 0xA7, 0x00], [0x16, 0xA7, 0x00], [4, 1]),
+    ([0x17, 0xA7, 0x00], [0x1A, 0xA7, 0x00], [5, 0]),
+    ([0x1B, 0xA7, 0x00], [0x1F, 0xA7, 0x00], [5, 1]),
+    ([0x20, 0xA7, 0x00], [0x21, 0xA7, 0x00], [5, 0]),
+    ([0x22, 0xA7, 0x00], [0x8C, 0xA7, 0x00], [5, 1]),
+    ([0x8D, 0xA7, 0x00], [0x8E, 0xA7, 0x00], [6, 0]),
+    ([0x8F, 0xA7, 0x00], [0x8F, 0xA7, 0x00], [8, 0]),
+    ([0x90, 0xA7, 0x00], [0x91, 0xA7, 0x00], [6, 0]),
+    ([0x92, 0xA7, 0x00], [0x93, 0xA7, 0x00], [6, 1]),
+    ([0x94, 0xA7, 0x00], [0x9F, 0xA7, 0x00], [7, 0]),
+    ([0xA0, 0xA7, 0x00], [0xA9, 0xA7, 0x00], [6, 0]),
+    ([0xAA, 0xA7, 0x00], [0xAA, 0xA7, 0x00], [6, 1]),
+    ([0xAB, 0xA7, 0x00], [0xAD, 0xA7, 0x00], [7, 0]),
+    ([0xAE, 0xA7, 0x00], [0xAE, 0xA7, 0x00], [9, 0]),
+    ([0xAF, 0xA7, 0x00], [0xAF, 0xA7, 0x00], [11, 0]),
+    ([0xB0, 0xA7, 0x00], [0xB1, 0xA7, 0x00], [7, 0]),
+    ([0xB2, 0xA7, 0x00], [0xB7, 0xA7, 0x00], [8, 0]),
+    ([0xB8, 0xA7, 0x00], [0xB9, 0xA7, 0x00], [11, 0]),
+    ([0xBA, 0xA7, 0x00], [0xBF, 0xA7, 0x00], [12, 0]),
+    ([0xC0, 0xA7, 0x00], [0xC1, 0xA7, 0x00], [14, 0]),
+    ([0xC2, 0xA7, 0x00], [0xC6, 0xA7, 0x00], [12, 0]),
+    ([0xC7, 0xA7, 0x00], [0xCA, 0xA7, 0x00], [13, 0]),
+    ([0xCB, 0xA7, 0x00], [0xCD, 0xA7, 0x00], [16, 0]),
+    ([0xCE, 0xA7, 0x00], [0xCF, 0xA7, 0x00], [17, 0]),
+    ([0xD0, 0xA7, 0x00], [0xD1, 0xA7, 0x00], [14, 0]),
+    ([0xD2, 0xA7, 0x00], [0xD2, 0xA7, 0x00], [17, 0]),
+    ([0xD3, 0xA7, 0x00], [0xD3, 0xA7, 0x00], [14, 0]),
+    ([0xD4, 0xA7, 0x00], [0xD4, 0xA7, 0x00], [17, 0]),
+    ([0xD5, 0xA7, 0x00], [0xD9, 0xA7, 0x00], [14, 0]),
+    ([0xDA, 0xA7, 0x00], [0xDC, 0xA7, 0x00], [16, 0]),
+    ([0xF1, 0xA7, 0x00], [0xF1, 0xA7, 0x00], [17, 0]),
+    ([0xF2, 0xA7, 0x00], [0xF4, 0xA7, 0x00], [14, 0]),
+    ([0xF5, 0xA7, 0x00], [0xF6, 0xA7, 0x00], [13, 0]),
+    ([0xF7, 0xA7, 0x00], [0xF7, 0xA7, 0x00], [7, 0]),
+    ([0xF8, 0xA7, 0x00], [0xF9, 0xA7, 0x00], [6, 1]),
+    ([0xFA, 0xA7, 0x00], [0xFA, 0xA7, 0x00], [6, 0]),
+    ([0xFB, 0xA7, 0x00], [0xFF, 0xA7, 0x00], [5, 1]),
+    ([0x00, 0xA8, 0x00], [0x2B, 0xA8, 0x00], [4, 1]),
+    ([0x2C, 0xA8, 0x00], [0x2C, 0xA8, 0x00], [13, 0]),
+    ([0x30, 0xA8, 0x00], [0x39, 0xA8, 0x00], [5, 2]),
+    ([0x40, 0xA8, 0x00], [0x77, 0xA8, 0x00], [5, 0]),
+    ([0x80, 0xA8, 0x00], [0xC4, 0xA8, 0x00], [5, 1]),
+    ([0xC5, 0xA8, 0x00], [0xC5, 0xA8, 0x00], [9, 0]),
+    ([0xCE, 0xA8, 0x00], [0xD9, 0xA8, 0x00], [5, 1]),
+    ([0xE0, 0xA8, 0x00], [0xFB, 0xA8, 0x00], [5, 2]),
+    ([0xFC, 0xA8, 0x00], [0xFD, 0xA8, 0x00], [8, 0]),
+    ([0xFE, 0xA8, 0x00], [0xFF, 0xA8, 0x00], [11, 0]),
+    ([0x00, 0xA9, 0x00], [0x53, 0xA9, 0x00], [5, 1]),
+    ([0x5F, 0xA9, 0x00], [0x5F, 0xA9, 0x00], [5, 1]),
+    ([0x60, 0xA9, 0x00], [0x7C, 0xA9, 0x00], [5, 2]),
+    ([0x80, 0xA9, 0x00], [0xCD, 0xA9, 0x00], [5, 2]),
+    ([0xCF, 0xA9, 0x00], [0xD9, 0xA9, 0x00], [5, 2]),
+    ([0xDE, 0xA9, 0x00], [0xDF, 0xA9, 0x00], [5, 2]),
+    ([0xE0, 0xA9, 0x00], [0xFE, 0xA9, 0x00], [7, 0]),
+    ([0x00, 0xAA, 0x00], [0x36, 0xAA, 0x00], [5, 1]),
+    ([0x40, 0xAA, 0x00], [0x4D, 0xAA, 0x00], [5, 1]),
+    ([0x50, 0xAA, 0x00], [0x59, 0xAA, 0x00], [5, 1]),
+    ([0x5C, 0xAA, 0x00], [0x5F, 0xAA, 0x00], [5, 1]),
+    ([0x60, 0xAA, 0x00], [0x7B, 0xAA, 0x00], [5, 2]),
+    ([0x7C, 0xAA, 0x00], [0x7F, 0xAA, 0x00], [7, 0]),
+    ([0x80, 0xAA, 0x00], [0xC2, 0xAA, 0x00], [5, 2]),
+    ([0xDB, 0xAA, 0x00], [0xDF, 0xAA, 0x00], [5, 2]),
+    ([0xE0, 0xAA, 0x00], [0xF6, 0xAA, 0x00], [6, 1]),
+    ([0x01, 0xAB, 0x00], [0x06, 0xAB, 0x00], [6, 0]),
+    ([0x09, 0xAB, 0x00], [0x0E, 0xAB, 0x00], [6, 0]),
+    ([0x11, 0xAB, 0x00], [0x16, 0xAB, 0x00], [6, 0]),
+    ([0x20, 0xAB, 0x00], [0x26, 0xAB, 0x00], [6, 0]),
+    ([0x28, 0xAB, 0x00], [0x2E, 0xAB, 0x00], [6, 0]),
+    ([0x30, 0xAB, 0x00], [0x5F, 0xAB, 0x00], [7, 0]),
+    ([0x60, 0xAB, 0x00], [0x63, 0xAB, 0x00], [8, 0]),
+    ([0x64, 0xAB, 0x00], [0x65, 0xAB, 0x00], [7, 0]),
+    ([0x66, 0xAB, 0x00], [0x67, 0xAB, 0x00], [12, 0]),
+    ([0x68, 0xAB, 0x00], [0x6B, 0xAB, 0x00], [13, 0]),
+    ([0x70, 0xAB, 0x00], [0xBF, 0xAB, 0x00], [8, 0]),
+    ([0xC0, 0xAB, 0x00], [0xED, 0xAB, 0x00], [5, 2]),
+    ([0xF0, 0xAB, 0x00], [0xF9, 0xAB, 0x00], [5, 2]),
+    ([0x00, 0xAC, 0x00], [0xA3, 0xD7, 0x00], [2, 0]),
+    ([0xB0, 0xD7, 0x00], [0xC6, 0xD7, 0x00], [5, 2]),
+    ([0xCB, 0xD7, 0x00], [0xFB, 0xD7, 0x00], [5, 2]),
+    ([0x00, 0xD8, 0x00], [0xFF, 0xDF, 0x00], [2, 0]),
+    ([0x00, 0xE0, 0x00], [0x2D, 0xFA, 0x00], [1, 1]),
+    ([0x2E, 0xFA, 0x00], [0x2F, 0xFA, 0x00], [6, 1]),
+    ([0x30, 0xFA, 0x00], [0x6A, 0xFA, 0x00], [3, 2]),
+    ([0x6B, 0xFA, 0x00], [0x6D, 0xFA, 0x00], [5, 2]),
+    ([0x70, 0xFA, 0x00], [0xD9, 0xFA, 0x00], [4, 1]),
+    ([0x00, 0xFB, 0x00], [0x06, 0xFB, 0x00], [1, 1]),
+    ([0x13, 0xFB, 0x00], [0x17, 0xFB, 0x00], [1, 1]),
+    ([0x1D, 0xFB, 0x00], [0x1D, 0xFB, 0x00], [3, 0]),
+    ([0x1E, 0xFB, 0x00], [0x36, 0xFB, 0x00], [1, 1]),
+    ([0x38, 0xFB, 0x00], [0x3C, 0xFB, 0x00], [1, 1]),
+    ([0x3E, 0xFB, 0x00], [0x3E, 0xFB, 0x00], [1, 1]),
+    ([0x40, 0xFB, 0x00], [0x41, 0xFB, 0x00], [1, 1]),
+    ([0x43, 0xFB, 0x00], [0x44, 0xFB, 0x00], [1, 1]),
+    ([0x46, 0xFB, 0x00], [0xB1, 0xFB, 0x00], [1, 1]),
+    ([0xB2, 0xFB, 0x00], [0xC1, 0xFB, 0x00], [6, 0]),
+    ([0xC2, 0xFB, 0x00], [0xC2, 0xFB, 0x00], [14, 0]),
+    ([0xC3, 0xFB, 0x00], [0xD2, 0xFB, 0x00], [17, 0]),
+    ([0xD3, 0xFB, 0x00], [0x3F, 0xFD, 0x00], [1, 1]),
+    ([0x40, 0xFD, 0x00], [0x4F, 0xFD, 0x00], [14, 0]),
+    ([0x50, 0xFD, 0x00], [0x8F, 0xFD, 0x00], [1, 1]),
+    ([0x90, 0xFD, 0x00], [0x91, 0xFD, 0x00], [17, 0]),
+    ([0x92, 0xFD, 0x00], [0xC7, 0xFD, 0x00], [1, 1]),
+    ([0xC8, 0xFD, 0x00], [0xCE, 0xFD, 0x00], [17, 0]),
+    ([0xCF, 0xFD, 0x00], [0xCF, 0xFD, 0x00], [14, 0]),
+    ([0xD0, 0xFD, 0x00], [0xEF, 0xFD, 0x00], [3, 1]),
+    ([0xF0, 0xFD, 0x00], [0xFB, 0xFD, 0x00], [1, 1]),
+    ([0xFC, 0xFD, 0x00], [0xFC, 0xFD, 0x00], [3, 2]),
+    ([0xFD, 0xFD, 0x00], [0xFD, 0xFD, 0x00], [4, 0]),
+    ([0xFE, 0xFD, 0x00], [0xFF, 0xFD, 0x00], [14, 0]),
+    ([0x00, 0xFE, 0x00], [0x0F, 0xFE, 0x00], [3, 2]),
+    ([0x10, 0xFE, 0x00], [0x19, 0xFE, 0x00], [4, 1]),
+    ([0x20, 0xFE, 0x00], [0x23, 0xFE, 0x00], [1, 1]),
+    ([0x24, 0xFE, 0x00], [0x26, 0xFE, 0x00], [5, 1]),
+    ([0x27, 0xFE, 0x00], [0x2D, 0xFE, 0x00], [7, 0]),
+    ([0x2E, 0xFE, 0x00], [0x2F, 0xFE, 0x00], [8, 0]),
+    ([0x30, 0xFE, 0x00], [0x44, 0xFE, 0x00], [1, 1]),
+    ([0x45, 0xFE, 0x00], [0x46, 0xFE, 0x00], [3, 2]),
+    ([0x47, 0xFE, 0x00], [0x48, 0xFE, 0x00], [4, 0]),
+    ([0x49, 0xFE, 0x00], [0x52, 0xFE, 0x00], [1, 1]),
+    ([0x54, 0xFE, 0x00], [0x66, 0xFE, 0x00], [1, 1]),
+    ([0x68, 0xFE, 0x00], [0x6B, 0xFE, 0x00], [1, 1]),
+    ([0x70, 0xFE, 0x00], [0x72, 0xFE, 0x00], [1, 1]),
+    ([0x73, 0xFE, 0x00], [0x73, 0xFE, 0x00], [3, 2]),
+    ([0x74, 0xFE, 0x00], [0x74, 0xFE, 0x00], [1, 1]),
+    ([0x76, 0xFE, 0x00], [0xFC, 0xFE, 0x00], [1, 1]),
+    ([0xFF, 0xFE, 0x00], [0xFF, 0xFE, 0x00], [1, 1]),
+    ([0x01, 0xFF, 0x00], [0x5E, 0xFF, 0x00], [1, 1]),
+    ([0x5F, 0xFF, 0x00], [0x60, 0xFF, 0x00], [3, 2]),
+    ([0x61, 0xFF, 0x00], [0xBE, 0xFF, 0x00], [1, 1]),
+    ([0xC2, 0xFF, 0x00], [0xC7, 0xFF, 0x00], [1, 1]),
+    ([0xCA, 0xFF, 0x00], [0xCF, 0xFF, 0x00], [1, 1]),
+    ([0xD2, 0xFF, 0x00], [0xD7, 0xFF, 0x00], [1, 1]),
+    ([0xDA, 0xFF, 0x00], [0xDC, 0xFF, 0x00], [1, 1]),
+    ([0xE0, 0xFF, 0x00], [0xE6, 0xFF, 0x00], [1, 1]),
+    ([0xE8, 0xFF, 0x00], [0xEE, 0xFF, 0x00], [1, 1]),
+    ([0xF9, 0xFF, 0x00], [0xFB, 0xFF, 0x00], [3, 0]),
+    ([0xFC, 0xFF, 0x00], [0xFC, 0xFF, 0x00], [2, 1]),
+    ([0xFD, 0xFF, 0x00], [0xFF, 0xFF, 0x00], [1, 1]),
+    ([0x00, 0x00, 0x01], [0x0B, 0x00, 0x01], [4, 0]),
+    ([0x0D, 0x00, 0x01], [0x26, 0x00, 0x01], [4, 0]),
+    ([0x28, 0x00, 0x01], [0x3A, 0x00, 0x01], [4, 0]),
+    ([0x3C, 0x00, 0x01], [0x3D, 0x00, 0x01], [4, 0]),
+    ([0x3F, 0x00, 0x01], [0x4D, 0x00, 0x01], [4, 0]),
+    ([0x50, 0x00, 0x01], [0x5D, 0x00, 0x01], [4, 0]),
+    ([0x80, 0x00, 0x01], [0xFA, 0x00, 0x01], [4, 0]),
+    ([0x00, 0x01, 0x01], [0x02, 0x01, 0x01], [4, 0]),
+    ([0x07, 0x01, 0x01], [0x33, 0x01, 0x01], [4, 0]),
+    ([0x37, 0x01, 0x01], [0x3F, 0x01, 0x01], [4, 0]),
+    ([0x40, 0x01, 0x01], [0x8A, 0x01, 0x01], [4, 1]),
+    ([0x8B, 0x01, 0x01], [0x8C, 0x01, 0x01], [7, 0]),
+    ([0x8D, 0x01, 0x01], [0x8E, 0x01, 0x01], [9, 0]),
+    ([0x90, 0x01, 0x01], [0x9B, 0x01, 0x01], [5, 1]),
+    ([0x9C, 0x01, 0x01], [0x9C, 0x01, 0x01], [13, 0]),
+    ([0xA0, 0x01, 0x01], [0xA0, 0x01, 0x01], [7, 0]),
+    ([0xD0, 0x01, 0x01], [0xFD, 0x01, 0x01], [5, 1]),
+    ([0x80, 0x02, 0x01], [0x9C, 0x02, 0x01], [5, 1]),
+    ([0xA0, 0x02, 0x01], [0xD0, 0x02, 0x01], [5, 1]),
+    ([0xE0, 0x02, 0x01], [0xFB, 0x02, 0x01], [7, 0]),
+    ([0x00, 0x03, 0x01], [0x1E, 0x03, 0x01], [3, 1]),
+    ([0x1F, 0x03, 0x01], [0x1F, 0x03, 0x01], [7, 0]),
+    ([0x20, 0x03, 0x01], [0x23, 0x03, 0x01], [3, 1]),
+    ([0x2D, 0x03, 0x01], [0x2F, 0x03, 0x01], [10, 0]),
+    ([0x30, 0x03, 0x01], [0x4A, 0x03, 0x01], [3, 1]),
+    ([0x50, 0x03, 0x01], [0x7A, 0x03, 0x01], [7, 0]),
+    ([0x80, 0x03, 0x01], [0x9D, 0x03, 0x01], [4, 0]),
+    ([0x9F, 0x03, 0x01], [0x9F, 0x03, 0x01], [4, 0]),
+    ([0xA0, 0x03, 0x01], [0xC3, 0x03, 0x01], [4, 1]),
+    ([0xC8, 0x03, 0x01], [0xD5, 0x03, 0x01], [4, 1]),
+    ([0x00, 0x04, 0x01], [0x25, 0x04, 0x01], [3, 1]),
+    ([0x26, 0x04, 0x01], [0x27, 0x04, 0x01], [4, 0]),
+    ([0x28, 0x04, 0x01], [0x4D, 0x04, 0x01], [3, 1]),
+    ([0x4E, 0x04, 0x01], [0x9D, 0x04, 0x01], [4, 0]),
+    ([0xA0, 0x04, 0x01], [0xA9, 0x04, 0x01], [4, 0]),
+    ([0xB0, 0x04, 0x01], [0xD3, 0x04, 0x01], [9, 0]),
+    ([0xD8, 0x04, 0x01], [0xFB, 0x04, 0x01], [9, 0]),
+    ([0x00, 0x05, 0x01], [0x27, 0x05, 0x01], [7, 0]),
+    ([0x30, 0x05, 0x01], [0x63, 0x05, 0x01], [7, 0]),
+    ([0x6F, 0x05, 0x01], [0x6F, 0x05, 0x01], [7, 0]),
+    ([0x70, 0x05, 0x01], [0x7A, 0x05, 0x01], [14, 0]),
+    ([0x7C, 0x05, 0x01], [0x8A, 0x05, 0x01], [14, 0]),
+    ([0x8C, 0x05, 0x01], [0x92, 0x05, 0x01], [14, 0]),
+    ([0x94, 0x05, 0x01], [0x95, 0x05, 0x01], [14, 0]),
+    ([0x97, 0x05, 0x01], [0xA1, 0x05, 0x01], [14, 0]),
+    ([0xA3, 0x05, 0x01], [0xB1, 0x05, 0x01], [14, 0]),
+    ([0xB3, 0x05, 0x01], [0xB9, 0x05, 0x01], [14, 0]),
+    ([0xBB, 0x05, 0x01], [0xBC, 0x05, 0x01], [14, 0]),
+    ([0xC0, 0x05, 0x01], [0xF3, 0x05, 0x01], [16, 0]),
+    ([0x00, 0x06, 0x01], [0x36, 0x07, 0x01], [7, 0]),
+    ([0x40, 0x07, 0x01], [0x55, 0x07, 0x01], [7, 0]),
+    ([0x60, 0x07, 0x01], [0x67, 0x07, 0x01], [7, 0]),
+    ([0x80, 0x07, 0x01], [0x85, 0x07, 0x01], [14, 0]),
+    ([0x87, 0x07, 0x01], [0xB0, 0x07, 0x01], [14, 0]),
+    ([0xB2, 0x07, 0x01], [0xBA, 0x07, 0x01], [14, 0]),
+    ([0x00, 0x08, 0x01], [0x05, 0x08, 0x01], [4, 0]),
+    ([0x08, 0x08, 0x01], [0x08, 0x08, 0x01], [4, 0]),
+    ([0x0A, 0x08, 0x01], [0x35, 0x08, 0x01], [4, 0]),
+    ([0x37, 0x08, 0x01], [0x38, 0x08, 0x01], [4, 0]),
+    ([0x3C, 0x08, 0x01], [0x3C, 0x08, 0x01], [4, 0]),
+    ([0x3F, 0x08, 0x01], [0x3F, 0x08, 0x01], [4, 0]),
+    ([0x40, 0x08, 0x01], [0x55, 0x08, 0x01], [5, 2]),
+    ([0x57, 0x08, 0x01], [0x5F, 0x08, 0x01], [5, 2]),
+    ([0x60, 0x08, 0x01], [0x9E, 0x08, 0x01], [7, 0]),
+    ([0xA7, 0x08, 0x01], [0xAF, 0x08, 0x01], [7, 0]),
+    ([0xE0, 0x08, 0x01], [0xF2, 0x08, 0x01], [8, 0]),
+    ([0xF4, 0x08, 0x01], [0xF5, 0x08, 0x01], [8, 0]),
+    ([0xFB, 0x08, 0x01], [0xFF, 0x08, 0x01], [8, 0]),
+    ([0x00, 0x09, 0x01], [0x19, 0x09, 0x01], [5, 0]),
+    ([0x1A, 0x09, 0x01], [0x1B, 0x09, 0x01], [5, 2]),
+    ([0x1F, 0x09, 0x01], [0x1F, 0x09, 0x01], [5, 0]),
+    ([0x20, 0x09, 0x01], [0x39, 0x09, 0x01], [5, 1]),
+    ([0x3F, 0x09, 0x01], [0x3F, 0x09, 0x01], [5, 1]),
+    ([0x40, 0x09, 0x01], [0x59, 0x09, 0x01], [17, 0]),
+    ([0x80, 0x09, 0x01], [0xB7, 0x09, 0x01], [6, 1]),
+    ([0xBC, 0x09, 0x01], [0xBD, 0x09, 0x01], [8, 0]),
+    ([0xBE, 0x09, 0x01], [0xBF, 0x09, 0x01], [6, 1]),
+    ([0xC0, 0x09, 0x01], [0xCF, 0x09, 0x01], [8, 0]),
+    ([0xD2, 0x09, 0x01], [0xFF, 0x09, 0x01], [8, 0]),
+    ([0x00, 0x0A, 0x01], [0x03, 0x0A, 0x01], [4, 1]),
+    ([0x05, 0x0A, 0x01], [0x06, 0x0A, 0x01], [4, 1]),
+    ([0x0C, 0x0A, 0x01], [0x13, 0x0A, 0x01], [4, 1]),
+    ([0x15, 0x0A, 0x01], [0x17, 0x0A, 0x01], [4, 1]),
+    ([0x19, 0x0A, 0x01], [0x33, 0x0A, 0x01], [4, 1]),
+    ([0x34, 0x0A, 0x01], [0x35, 0x0A, 0x01], [11, 0]),
+    ([0x38, 0x0A, 0x01], [0x3A, 0x0A, 0x01], [4, 1]),
+    ([0x3F, 0x0A, 0x01], [0x47, 0x0A, 0x01], [4, 1]),
+    ([0x48, 0x0A, 0x01], [0x48, 0x0A, 0x01], [11, 0]),
+    ([0x50, 0x0A, 0x01], [0x58, 0x0A, 0x01], [4, 1]),
+    ([0x60, 0x0A, 0x01], [0x7F, 0x0A, 0x01], [5, 2]),
+    ([0x80, 0x0A, 0x01], [0x9F, 0x0A, 0x01], [7, 0]),
+    ([0xC0, 0x0A, 0x01], [0xE6, 0x0A, 0x01], [7, 0]),
+    ([0xEB, 0x0A, 0x01], [0xF6, 0x0A, 0x01], [7, 0]),
+    ([0x00, 0x0B, 0x01], [0x35, 0x0B, 0x01], [5, 2]),
+    ([0x39, 0x0B, 0x01], [0x55, 0x0B, 0x01], [5, 2]),
+    ([0x58, 0x0B, 0x01], [0x72, 0x0B, 0x01], [5, 2]),
+    ([0x78, 0x0B, 0x01], [0x7F, 0x0B, 0x01], [5, 2]),
+    ([0x80, 0x0B, 0x01], [0x91, 0x0B, 0x01], [7, 0]),
+    ([0x99, 0x0B, 0x01], [0x9C, 0x0B, 0x01], [7, 0]),
+    ([0xA9, 0x0B, 0x01], [0xAF, 0x0B, 0x01], [7, 0]),
+    ([0x00, 0x0C, 0x01], [0x48, 0x0C, 0x01], [5, 2]),
+    ([0x80, 0x0C, 0x01], [0xB2, 0x0C, 0x01], [8, 0]),
+    ([0xC0, 0x0C, 0x01], [0xF2, 0x0C, 0x01], [8, 0]),
+    ([0xFA, 0x0C, 0x01], [0xFF, 0x0C, 0x01], [8, 0]),
+    ([0x00, 0x0D, 0x01], [0x27, 0x0D, 0x01], [11, 0]),
+    ([0x30, 0x0D, 0x01], [0x39, 0x0D, 0x01], [11, 0]),
+    ([0x40, 0x0D, 0x01], [0x65, 0x0D, 0x01], [16, 0]),
+    ([0x69, 0x0D, 0x01], [0x85, 0x0D, 0x01], [16, 0]),
+    ([0x8E, 0x0D, 0x01], [0x8F, 0x0D, 0x01], [16, 0]),
+    ([0x60, 0x0E, 0x01], [0x7E, 0x0E, 0x01], [5, 2]),
+    ([0x80, 0x0E, 0x01], [0xA9, 0x0E, 0x01], [13, 0]),
+    ([0xAB, 0x0E, 0x01], [0xAD, 0x0E, 0x01], [13, 0]),
+    ([0xB0, 0x0E, 0x01], [0xB1, 0x0E, 0x01], [13, 0]),
+    ([0xC2, 0x0E, 0x01], [0xC4, 0x0E, 0x01], [16, 0]),
+    ([0xC5, 0x0E, 0x01], [0xC7, 0x0E, 0x01], [17, 0]),
+    ([0xD0, 0x0E, 0x01], [0xD8, 0x0E, 0x01], [17, 0]),
+    ([0xFA, 0x0E, 0x01], [0xFB, 0x0E, 0x01], [17, 0]),
+    ([0xFC, 0x0E, 0x01], [0xFC, 0x0E, 0x01], [16, 0]),
+    ([0xFD, 0x0E, 0x01], [0xFF, 0x0E, 0x01], [15, 0]),
+    ([0x00, 0x0F, 0x01], [0x27, 0x0F, 0x01], [11, 0]),
+    ([0x30, 0x0F, 0x01], [0x59, 0x0F, 0x01], [11, 0]),
+    ([0x70, 0x0F, 0x01], [0x89, 0x0F, 0x01], [14, 0]),
+    ([0xB0, 0x0F, 0x01], [0xCB, 0x0F, 0x01], [13, 0]),
+    ([0xE0, 0x0F, 0x01], [0xF6, 0x0F, 0x01], [12, 0]),
+    ([0x00, 0x10, 0x01], [0x4D, 0x10, 0x01], [6, 0]),
+    ([0x52, 0x10, 0x01], [0x6F, 0x10, 0x01], [6, 0]),
+    ([0x70, 0x10, 0x01], [0x75, 0x10, 0x01], [14, 0]),
+    ([0x7F, 0x10, 0x01], [0x7F, 0x10, 0x01], [7, 0]),
+    ([0x80, 0x10, 0x01], [0xC1, 0x10, 0x01], [5, 2]),
+    ([0xC2, 0x10, 0x01], [0xC2, 0x10, 0x01], [14, 0]),
+    ([0xCD, 0x10, 0x01], [0xCD, 0x10, 0x01], [11, 0]),
+    ([0xD0, 0x10, 0x01], [0xE8, 0x10, 0x01], [6, 1]),
+    ([0xF0, 0x10, 0x01], [0xF9, 0x10, 0x01], [6, 1]),
+    ([0x00, 0x11, 0x01], [0x34, 0x11, 0x01], [6, 1]),
+    ([0x36, 0x11, 0x01], [0x43, 0x11, 0x01], [6, 1]),
+    ([0x44, 0x11, 0x01], [0x46, 0x11, 0x01], [11, 0]),
+    ([0x47, 0x11, 0x01], [0x47, 0x11, 0x01], [13, 0]),
+    ([0x50, 0x11, 0x01], [0x76, 0x11, 0x01], [7, 0]),
+    ([0x80, 0x11, 0x01], [0xC8, 0x11, 0x01], [6, 1]),
+    ([0xC9, 0x11, 0x01], [0xCC, 0x11, 0x01], [8, 0]),
+    ([0xCD, 0x11, 0x01], [0xCD, 0x11, 0x01], [7, 0]),
+    ([0xCE, 0x11, 0x01], [0xCF, 0x11, 0x01], [13, 0]),
+    ([0xD0, 0x11, 0x01], [0xD9, 0x11, 0x01], [6, 1]),
+    ([0xDA, 0x11, 0x01], [0xDA, 0x11, 0x01], [7, 0]),
+    ([0xDB, 0x11, 0x01], [0xDF, 0x11, 0x01], [8, 0]),
+    ([0xE1, 0x11, 0x01], [0xF4, 0x11, 0x01], [7, 0]),
+    ([0x00, 0x12, 0x01], [0x11, 0x12, 0x01], [7, 0]),
+    ([0x13, 0x12, 0x01], [0x3D, 0x12, 0x01], [7, 0]),
+    ([0x3E, 0x12, 0x01], [0x3E, 0x12, 0x01], [9, 0]),
+    ([0x3F, 0x12, 0x01], [0x41, 0x12, 0x01], [15, 0]),
+    ([0x80, 0x12, 0x01], [0x86, 0x12, 0x01], [8, 0]),
+    ([0x88, 0x12, 0x01], [0x88, 0x12, 0x01], [8, 0]),
+    ([0x8A, 0x12, 0x01], [0x8D, 0x12, 0x01], [8, 0]),
+    ([0x8F, 0x12, 0x01], [0x9D, 0x12, 0x01], [8, 0]),
+    ([0x9F, 0x12, 0x01], [0xA9, 0x12, 0x01], [8, 0]),
+    ([0xB0, 0x12, 0x01], [0xEA, 0x12, 0x01], [7, 0]),
+    ([0xF0, 0x12, 0x01], [0xF9, 0x12, 0x01], [7, 0]),
+    ([0x00, 0x13, 0x01], [0x00, 0x13, 0x01], [8, 0]),
+    ([0x01, 0x13, 0x01], [0x03, 0x13, 0x01], [7, 0]),
+    ([0x05, 0x13, 0x01], [0x0C, 0x13, 0x01], [7, 0]),
+    ([0x0F, 0x13, 0x01], [0x10, 0x13, 0x01], [7, 0]),
+    ([0x13, 0x13, 0x01], [0x28, 0x13, 0x01], [7, 0]),
+    ([0x2A, 0x13, 0x01], [0x30, 0x13, 0x01], [7, 0]),
+    ([0x32, 0x13, 0x01], [0x33, 0x13, 0x01], [7, 0]),
+    ([0x35, 0x13, 0x01], [0x39, 0x13, 0x01], [7, 0]),
+    ([0x3B, 0x13, 0x01], [0x3B, 0x13, 0x01], [11, 0]),
+    ([0x3C, 0x13, 0x01], [0x44, 0x13, 0x01], [7, 0]),
+    ([0x47, 0x13, 0x01], [0x48, 0x13, 0x01], [7, 0]),
+    ([0x4B, 0x13, 0x01], [0x4D, 0x13, 0x01], [7, 0]),
+    ([0x50, 0x13, 0x01], [0x50, 0x13, 0x01], [8, 0]),
+    ([0x57, 0x13, 0x01], [0x57, 0x13, 0x01], [7, 0]),
+    ([0x5D, 0x13, 0x01], [0x63, 0x13, 0x01], [7, 0]),
+    ([0x66, 0x13, 0x01], [0x6C, 0x13, 0x01], [7, 0]),
+    ([0x70, 0x13, 0x01], [0x74, 0x13, 0x01], [7, 0]),
+    ([0x80, 0x13, 0x01], [0x89, 0x13, 0x01], [16, 0]),
+    ([0x8B, 0x13, 0x01], [0x8B, 0x13, 0x01], [16, 0]),
+    ([0x8E, 0x13, 0x01], [0x8E, 0x13, 0x01], [16, 0]),
+    ([0x90, 0x13, 0x01], [0xB5, 0x13, 0x01], [16, 0]),
+    ([0xB7, 0x13, 0x01], [0xC0, 0x13, 0x01], [16, 0]),
+    ([0xC2, 0x13, 0x01], [0xC2, 0x13, 0x01], [16, 0]),
+    ([0xC5, 0x13, 0x01], [0xC5, 0x13, 0x01], [16, 0]),
+    ([0xC7, 0x13, 0x01], [0xCA, 0x13, 0x01], [16, 0]),
+    ([0xCC, 0x13, 0x01], [0xD5, 0x13, 0x01], [16, 0]),
+    ([0xD7, 0x13, 0x01], [0xD8, 0x13, 0x01], [16, 0]),
+    ([0xE1, 0x13, 0x01], [0xE2, 0x13, 0x01], [16, 0]),
+    ([0x00, 0x14, 0x01], [0x59, 0x14, 0x01], [9, 0]),
+    ([0x5A, 0x14, 0x01], [0x5A, 0x14, 0x01], [13, 0]),
+    ([0x5B, 0x14, 0x01], [0x5B, 0x14, 0x01], [9, 0]),
+    ([0x5D, 0x14, 0x01], [0x5D, 0x14, 0x01], [9, 0]),
+    ([0x5E, 0x14, 0x01], [0x5E, 0x14, 0x01], [11, 0]),
+    ([0x5F, 0x14, 0x01], [0x5F, 0x14, 0x01], [12, 0]),
+    ([0x60, 0x14, 0x01], [0x61, 0x14, 0x01], [13, 0]),
+    ([0x80, 0x14, 0x01], [0xC7, 0x14, 0x01], [7, 0]),
+    ([0xD0, 0x14, 0x01], [0xD9, 0x14, 0x01], [7, 0]),
+    ([0x80, 0x15, 0x01], [0xB5, 0x15, 0x01], [7, 0]),
+    ([0xB8, 0x15, 0x01], [0xC9, 0x15, 0x01], [7, 0]),
+    ([0xCA, 0x15, 0x01], [0xDD, 0x15, 0x01], [8, 0]),
+    ([0x00, 0x16, 0x01], [0x44, 0x16, 0x01], [7, 0]),
+    ([0x50, 0x16, 0x01], [0x59, 0x16, 0x01], [7, 0]),
+    ([0x60, 0x16, 0x01], [0x6C, 0x16, 0x01], [9, 0]),
+    ([0x80, 0x16, 0x01], [0xB7, 0x16, 0x01], [6, 1]),
+    ([0xB8, 0x16, 0x01], [0xB8, 0x16, 0x01], [12, 0]),
+    ([0xB9, 0x16, 0x01], [0xB9, 0x16, 0x01], [14, 0]),
+    ([0xC0, 0x16, 0x01], [0xC9, 0x16, 0x01], [6, 1]),
+    ([0xD0, 0x16, 0x01], [0xE3, 0x16, 0x01], [16, 0]),
+    ([0x00, 0x17, 0x01], [0x19, 0x17, 0x01], [8, 0]),
+    ([0x1A, 0x17, 0x01], [0x1A, 0x17, 0x01], [11, 0]),
+    ([0x1D, 0x17, 0x01], [0x2B, 0x17, 0x01], [8, 0]),
+    ([0x30, 0x17, 0x01], [0x3F, 0x17, 0x01], [8, 0]),
+    ([0x40, 0x17, 0x01], [0x46, 0x17, 0x01], [14, 0]),
+    ([0x00, 0x18, 0x01], [0x3B, 0x18, 0x01], [11, 0]),
+    ([0xA0, 0x18, 0x01], [0xF2, 0x18, 0x01], [7, 0]),
+    ([0xFF, 0x18, 0x01], [0xFF, 0x18, 0x01], [7, 0]),
+    ([0x00, 0x19, 0x01], [0x06, 0x19, 0x01], [13, 0]),
+    ([0x09, 0x19, 0x01], [0x09, 0x19, 0x01], [13, 0]),
+    ([0x0C, 0x19, 0x01], [0x13, 0x19, 0x01], [13, 0]),
+    ([0x15, 0x19, 0x01], [0x16, 0x19, 0x01], [13, 0]),
+    ([0x18, 0x19, 0x01], [0x35, 0x19, 0x01], [13, 0]),
+    ([0x37, 0x19, 0x01], [0x38, 0x19, 0x01], [13, 0]),
+    ([0x3B, 0x19, 0x01], [0x46, 0x19, 0x01], [13, 0]),
+    ([0x50, 0x19, 0x01], [0x59, 0x19, 0x01], [13, 0]),
+    ([0xA0, 0x19, 0x01], [0xA7, 0x19, 0x01], [12, 0]),
+    ([0xAA, 0x19, 0x01], [0xD7, 0x19, 0x01], [12, 0]),
+    ([0xDA, 0x19, 0x01], [0xE4, 0x19, 0x01], [12, 0]),
+    ([0x00, 0x1A, 0x01], [0x47, 0x1A, 0x01], [10, 0]),
+    ([0x50, 0x1A, 0x01], [0x83, 0x1A, 0x01], [10, 0]),
+    ([0x84, 0x1A, 0x01], [0x85, 0x1A, 0x01], [12, 0]),
+    ([0x86, 0x1A, 0x01], [0x9C, 0x1A, 0x01], [10, 0]),
+    ([0x9D, 0x1A, 0x01], [0x9D, 0x1A, 0x01], [11, 0]),
+    ([0x9E, 0x1A, 0x01], [0xA2, 0x1A, 0x01], [10, 0]),
+    ([0xB0, 0x1A, 0x01], [0xBF, 0x1A, 0x01], [14, 0]),
+    ([0xC0, 0x1A, 0x01], [0xF8, 0x1A, 0x01], [7, 0]),
+    ([0x00, 0x1B, 0x01], [0x09, 0x1B, 0x01], [15, 0]),
+    ([0x60, 0x1B, 0x01], [0x67, 0x1B, 0x01], [17, 0]),
+    ([0xC0, 0x1B, 0x01], [0xE1, 0x1B, 0x01], [16, 0]),
+    ([0xF0, 0x1B, 0x01], [0xF9, 0x1B, 0x01], [16, 0]),
+    ([0x00, 0x1C, 0x01], [0x08, 0x1C, 0x01], [9, 0]),
+    ([0x0A, 0x1C, 0x01], [0x36, 0x1C, 0x01], [9, 0]),
+    ([0x38, 0x1C, 0x01], [0x45, 0x1C, 0x01], [9, 0]),
+    ([0x50, 0x1C, 0x01], [0x6C, 0x1C, 0x01], [9, 0]),
+    ([0x70, 0x1C, 0x01], [0x8F, 0x1C, 0x01], [9, 0]),
+    ([0x92, 0x1C, 0x01], [0xA7, 0x1C, 0x01], [9, 0]),
+    ([0xA9, 0x1C, 0x01], [0xB6, 0x1C, 0x01], [9, 0]),
+    ([0x00, 0x1D, 0x01], [0x06, 0x1D, 0x01], [10, 0]),
+    ([0x08, 0x1D, 0x01], [0x09, 0x1D, 0x01], [10, 0]),
+    ([0x0B, 0x1D, 0x01], [0x36, 0x1D, 0x01], [10, 0]),
+    ([0x3A, 0x1D, 0x01], [0x3A, 0x1D, 0x01], [10, 0]),
+    ([0x3C, 0x1D, 0x01], [0x3D, 0x1D, 0x01], [10, 0]),
+    ([0x3F, 0x1D, 0x01], [0x47, 0x1D, 0x01], [10, 0]),
+    ([0x50, 0x1D, 0x01], [0x59, 0x1D, 0x01], [10, 0]),
+    ([0x60, 0x1D, 0x01], [0x65, 0x1D, 0x01], [11, 0]),
+    ([0x67, 0x1D, 0x01], [0x68, 0x1D, 0x01], [11, 0]),
+    ([0x6A, 0x1D, 0x01], [0x8E, 0x1D, 0x01], [11, 0]),
+    ([0x90, 0x1D, 0x01], [0x91, 0x1D, 0x01], [11, 0]),
+    ([0x93, 0x1D, 0x01], [0x98, 0x1D, 0x01], [11, 0]),
+    ([0xA0, 0x1D, 0x01], [0xA9, 0x1D, 0x01], [11, 0]),
+    ([0xB0, 0x1D, 0x01], [0xDB, 0x1D, 0x01], [17, 0]),
+    ([0xE0, 0x1D, 0x01], [0xE9, 0x1D, 0x01], [17, 0]),
+    ([0xE0, 0x1E, 0x01], [0xF8, 0x1E, 0x01], [11, 0]),
+    ([0x00, 0x1F, 0x01], [0x10, 0x1F, 0x01], [15, 0]),
+    ([0x12, 0x1F, 0x01], [0x3A, 0x1F, 0x01], [15, 0]),
+    ([0x3E, 0x1F, 0x01], [0x59, 0x1F, 0x01], [15, 0]),
+    ([0x5A, 0x1F, 0x01], [0x5A, 0x1F, 0x01], [16, 0]),
+    ([0xB0, 0x1F, 0x01], [0xB0, 0x1F, 0x01], [13, 0]),
+    ([0xC0, 0x1F, 0x01], [0xF1, 0x1F, 0x01], [12, 0]),
+    ([0xFF, 0x1F, 0x01], [0xFF, 0x1F, 0x01], [12, 0]),
+    ([0x00, 0x20, 0x01], [0x6E, 0x23, 0x01], [5, 0]),
+    ([0x6F, 0x23, 0x01], [0x98, 0x23, 0x01], [7, 0]),
+    ([0x99, 0x23, 0x01], [0x99, 0x23, 0x01], [8, 0]),
+    ([0x00, 0x24, 0x01], [0x62, 0x24, 0x01], [5, 0]),
+    ([0x63, 0x24, 0x01], [0x6E, 0x24, 0x01], [7, 0]),
+    ([0x70, 0x24, 0x01], [0x73, 0x24, 0x01], [5, 0]),
+    ([0x74, 0x24, 0x01], [0x74, 0x24, 0x01], [7, 0]),
+    ([0x80, 0x24, 0x01], [0x43, 0x25, 0x01], [8, 0]),
+    ([0x90, 0x2F, 0x01], [0xF2, 0x2F, 0x01], [14, 0]),
+    ([0x00, 0x30, 0x01], [0x2E, 0x34, 0x01], [5, 2]),
+    ([0x2F, 0x34, 0x01], [0x2F, 0x34, 0x01], [15, 0]),
+    ([0x30, 0x34, 0x01], [0x38, 0x34, 0x01], [12, 0]),
+    ([0x39, 0x34, 0x01], [0x55, 0x34, 0x01], [15, 0]),
+    ([0x60, 0x34, 0x01], [0xFA, 0x43, 0x01], [16, 0]),
+    ([0x00, 0x44, 0x01], [0x46, 0x46, 0x01], [8, 0]),
+    ([0x00, 0x61, 0x01], [0x39, 0x61, 0x01], [16, 0]),
+    ([0x00, 0x68, 0x01], [0x38, 0x6A, 0x01], [6, 0]),
+    ([0x40, 0x6A, 0x01], [0x5E, 0x6A, 0x01], [7, 0]),
+    ([0x60, 0x6A, 0x01], [0x69, 0x6A, 0x01], [7, 0]),
+    ([0x6E, 0x6A, 0x01], [0x6F, 0x6A, 0x01], [7, 0]),
+    ([0x70, 0x6A, 0x01], [0xBE, 0x6A, 0x01], [14, 0]),
+    ([0xC0, 0x6A, 0x01], [0xC9, 0x6A, 0x01], [14, 0]),
+    ([0xD0, 0x6A, 0x01], [0xED, 0x6A, 0x01], [7, 0]),
+    ([0xF0, 0x6A, 0x01], [0xF5, 0x6A, 0x01], [7, 0]),
+    ([0x00, 0x6B, 0x01], [0x45, 0x6B, 0x01], [7, 0]),
+    ([0x50, 0x6B, 0x01], [0x59, 0x6B, 0x01], [7, 0]),
+    ([0x5B, 0x6B, 0x01], [0x61, 0x6B, 0x01], [7, 0]),
+    ([0x63, 0x6B, 0x01], [0x77, 0x6B, 0x01], [7, 0]),
+    ([0x7D, 0x6B, 0x01], [0x8F, 0x6B, 0x01], [7, 0]),
+    ([0x40, 0x6D, 0x01], [0x79, 0x6D, 0x01], [16, 0]),
+    ([0x40, 0x6E, 0x01], [0x9A, 0x6E, 0x01], [11, 0]),
+    ([0xA0, 0x6E, 0x01], [0xB8, 0x6E, 0x01], [17, 0]),
+    ([0xBB, 0x6E, 0x01], [0xD3, 0x6E, 0x01], [17, 0]),
+    ([0x00, 0x6F, 0x01], [0x44, 0x6F, 0x01], [6, 1]),
+    ([0x45, 0x6F, 0x01], [0x4A, 0x6F, 0x01], [12, 0]),
+    ([0x4F, 0x6F, 0x01], [0x4F, 0x6F, 0x01], [12, 0]),
+    ([0x50, 0x6F, 0x01], [0x7E, 0x6F, 0x01], [6, 1]),
+    ([0x7F, 0x6F, 0x01], [0x87, 0x6F, 0x01], [12, 0]),
+    ([0x8F, 0x6F, 0x01], [0x9F, 0x6F, 0x01], [6, 1]),
+    ([0xE0, 0x6F, 0x01], [0xE0, 0x6F, 0x01], [9, 0]),
+    ([0xE1, 0x6F, 0x01], [0xE1, 0x6F, 0x01], [10, 0]),
+    ([0xE2, 0x6F, 0x01], [0xE3, 0x6F, 0x01], [12, 0]),
+    ([0xE4, 0x6F, 0x01], [0xE4, 0x6F, 0x01], [13, 0]),
+    ([0xF0, 0x6F, 0x01], [0xF1, 0x6F, 0x01], [13, 0]),
+    ([0xF2, 0x6F, 0x01], [0xF6, 0x6F, 0x01], [17, 0]),
+    ([0x00, 0x70, 0x01], [0xEC, 0x87, 0x01], [9, 0]),
+    ([0xED, 0x87, 0x01], [0xF1, 0x87, 0x01], [11, 0]),
+    ([0xF2, 0x87, 0x01], [0xF7, 0x87, 0x01], [12, 0]),
+    ([0xF8, 0x87, 0x01], [0xFF, 0x87, 0x01], [17, 0]),
+    ([0x00, 0x88, 0x01], [0xF2, 0x8A, 0x01], [9, 0]),
+    ([0xF3, 0x8A, 0x01], [0xD5, 0x8C, 0x01], [13, 0]),
+    ([0xFF, 0x8C, 0x01], [0xFF, 0x8C, 0x01], [16, 0]),
+    ([0x00, 0x8D, 0x01], [0x08, 0x8D, 0x01], [13, 0]),
+    ([0x09, 0x8D, 0x01], [0x1E, 0x8D, 0x01], [17, 0]),
+    ([0x80, 0x8D, 0x01], [0xF2, 0x8D, 0x01], [17, 0]),
+    ([0xF0, 0xAF, 0x01], [0xF3, 0xAF, 0x01], [14, 0]),
+    ([0xF5, 0xAF, 0x01], [0xFB, 0xAF, 0x01], [14, 0]),
+    ([0xFD, 0xAF, 0x01], [0xFE, 0xAF, 0x01], [14, 0]),
+    ([0x00, 0xB0, 0x01], [0x01, 0xB0, 0x01], [6, 0]),
+    ([0x02, 0xB0, 0x01], [0x1E, 0xB1, 0x01], [10, 0]),
+    ([0x1F, 0xB1, 0x01], [0x22, 0xB1, 0x01], [14, 0]),
+    ([0x32, 0xB1, 0x01], [0x32, 0xB1, 0x01], [15, 0]),
+    ([0x50, 0xB1, 0x01], [0x52, 0xB1, 0x01], [12, 0]),
+    ([0x55, 0xB1, 0x01], [0x55, 0xB1, 0x01], [15, 0]),
+    ([0x64, 0xB1, 0x01], [0x67, 0xB1, 0x01], [12, 0]),
+    ([0x70, 0xB1, 0x01], [0xFB, 0xB2, 0x01], [10, 0]),
+    ([0x00, 0xBC, 0x01], [0x6A, 0xBC, 0x01], [7, 0]),
+    ([0x70, 0xBC, 0x01], [0x7C, 0xBC, 0x01], [7, 0]),
+    ([0x80, 0xBC, 0x01], [0x88, 0xBC, 0x01], [7, 0]),
+    ([0x90, 0xBC, 0x01], [0x99, 0xBC, 0x01], [7, 0]),
+    ([0x9C, 0xBC, 0x01], [0xA3, 0xBC, 0x01], [7, 0]),
+    ([0x00, 0xCC, 0x01], [0xF9, 0xCC, 0x01], [16, 0]),
+    ([0xFA, 0xCC, 0x01], [0xFC, 0xCC, 0x01], [17, 0]),
+    ([0x00, 0xCD, 0x01], [0xB3, 0xCE, 0x01], [16, 0]),
+    ([0xBA, 0xCE, 0x01], [0xD0, 0xCE, 0x01], [17, 0]),
+    ([0xE0, 0xCE, 0x01], [0xF0, 0xCE, 0x01], [17, 0]),
+    ([0x00, 0xCF, 0x01], [0x2D, 0xCF, 0x01], [14, 0]),
+    ([0x30, 0xCF, 0x01], [0x46, 0xCF, 0x01], [14, 0]),
+    ([0x50, 0xCF, 0x01], [0xC3, 0xCF, 0x01], [14, 0]),
+    ([0x00, 0xD0, 0x01], [0xF5, 0xD0, 0x01], [3, 1]),
+    ([0x00, 0xD1, 0x01], [0x26, 0xD1, 0x01], [3, 1]),
+    ([0x29, 0xD1, 0x01], [0x29, 0xD1, 0x01], [5, 1]),
+    ([0x2A, 0xD1, 0x01], [0xDD, 0xD1, 0x01], [3, 1]),
+    ([0xDE, 0xD1, 0x01], [0xE8, 0xD1, 0x01], [8, 0]),
+    ([0xE9, 0xD1, 0x01], [0xEA, 0xD1, 0x01], [14, 0]),
+    ([0x00, 0xD2, 0x01], [0x45, 0xD2, 0x01], [4, 1]),
+    ([0xC0, 0xD2, 0x01], [0xD3, 0xD2, 0x01], [15, 0]),
+    ([0xE0, 0xD2, 0x01], [0xF3, 0xD2, 0x01], [11, 0]),
+    ([0x00, 0xD3, 0x01], [0x56, 0xD3, 0x01], [4, 0]),
+    ([0x60, 0xD3, 0x01], [0x71, 0xD3, 0x01], [5, 0]),
+    ([0x72, 0xD3, 0x01], [0x78, 0xD3, 0x01], [11, 0]),
+    ([0x00, 0xD4, 0x01], [0x54, 0xD4, 0x01], [3, 1]),
+    ([0x56, 0xD4, 0x01], [0x9C, 0xD4, 0x01], [3, 1]),
+    ([0x9E, 0xD4, 0x01], [0x9F, 0xD4, 0x01], [3, 1]),
+    ([0xA2, 0xD4, 0x01], [0xA2, 0xD4, 0x01], [3, 1]),
+    ([0xA5, 0xD4, 0x01], [0xA6, 0xD4, 0x01], [3, 1]),
+    ([0xA9, 0xD4, 0x01], [0xAC, 0xD4, 0x01], [3, 1]),
+    ([0xAE, 0xD4, 0x01], [0xB9, 0xD4, 0x01], [3, 1]),
+    ([0xBB, 0xD4, 0x01], [0xBB, 0xD4, 0x01], [3, 1]),
+    ([0xBD, 0xD4, 0x01], [0xC0, 0xD4, 0x01], [3, 1]),
+    ([0xC1, 0xD4, 0x01], [0xC1, 0xD4, 0x01], [4, 0]),
+    ([0xC2, 0xD4, 0x01], [0xC3, 0xD4, 0x01], [3, 1]),
+    ([0xC5, 0xD4, 0x01], [0x05, 0xD5, 0x01], [3, 1]),
+    ([0x07, 0xD5, 0x01], [0x0A, 0xD5, 0x01], [3, 1]),
+    ([0x0D, 0xD5, 0x01], [0x14, 0xD5, 0x01], [3, 1]),
+    ([0x16, 0xD5, 0x01], [0x1C, 0xD5, 0x01], [3, 1]),
+    ([0x1E, 0xD5, 0x01], [0x39, 0xD5, 0x01], [3, 1]),
+    ([0x3B, 0xD5, 0x01], [0x3E, 0xD5, 0x01], [3, 1]),
+    ([0x40, 0xD5, 0x01], [0x44, 0xD5, 0x01], [3, 1]),
+    ([0x46, 0xD5, 0x01], [0x46, 0xD5, 0x01], [3, 1]),
+    ([0x4A, 0xD5, 0x01], [0x50, 0xD5, 0x01], [3, 1]),
+    ([0x52, 0xD5, 0x01], [0xA3, 0xD6, 0x01], [3, 1]),
+    ([0xA4, 0xD6, 0x01], [0xA5, 0xD6, 0x01], [4, 1]),
+    ([0xA8, 0xD6, 0x01], [0xC9, 0xD7, 0x01], [3, 1]),
+    ([0xCA, 0xD7, 0x01], [0xCB, 0xD7, 0x01], [5, 0]),
+    ([0xCE, 0xD7, 0x01], [0xFF, 0xD7, 0x01], [3, 1]),
+    ([0x00, 0xD8, 0x01], [0x8B, 0xDA, 0x01], [8, 0]),
+    ([0x9B, 0xDA, 0x01], [0x9F, 0xDA, 0x01], [8, 0]),
+    ([0xA1, 0xDA, 0x01], [0xAF, 0xDA, 0x01], [8, 0]),
+    ([0x00, 0xDF, 0x01], [0x1E, 0xDF, 0x01], [14, 0]),
+    ([0x25, 0xDF, 0x01], [0x2A, 0xDF, 0x01], [15, 0]),
+    ([0x00, 0xE0, 0x01], [0x06, 0xE0, 0x01], [9, 0]),
+    ([0x08, 0xE0, 0x01], [0x18, 0xE0, 0x01], [9, 0]),
+    ([0x1B, 0xE0, 0x01], [0x21, 0xE0, 0x01], [9, 0]),
+    ([0x23, 0xE0, 0x01], [0x24, 0xE0, 0x01], [9, 0]),
+    ([0x26, 0xE0, 0x01], [0x2A, 0xE0, 0x01], [9, 0]),
+    ([0x30, 0xE0, 0x01], [0x6D, 0xE0, 0x01], [15, 0]),
+    ([0x8F, 0xE0, 0x01], [0x8F, 0xE0, 0x01], [15, 0]),
+    ([0x00, 0xE1, 0x01], [0x2C, 0xE1, 0x01], [12, 0]),
+    ([0x30, 0xE1, 0x01], [0x3D, 0xE1, 0x01], [12, 0]),
+    ([0x40, 0xE1, 0x01], [0x49, 0xE1, 0x01], [12, 0]),
+    ([0x4E, 0xE1, 0x01], [0x4F, 0xE1, 0x01], [12, 0]),
+    ([0x90, 0xE2, 0x01], [0xAE, 0xE2, 0x01], [14, 0]),
+    ([0xC0, 0xE2, 0x01], [0xF9, 0xE2, 0x01], [12, 0]),
+    ([0xFF, 0xE2, 0x01], [0xFF, 0xE2, 0x01], [12, 0]),
+    ([0xD0, 0xE4, 0x01], [0xF9, 0xE4, 0x01], [15, 0]),
+    ([0xD0, 0xE5, 0x01], [0xFA, 0xE5, 0x01], [16, 0]),
+    ([0xFF, 0xE5, 0x01], [0xFF, 0xE5, 0x01], [16, 0]),
+    ([0xC0, 0xE6, 0x01], [0xDE, 0xE6, 0x01], [17, 0]),
+    ([0xE0, 0xE6, 0x01], [0xF5, 0xE6, 0x01], [17, 0]),
+    ([0xFE, 0xE6, 0x01], [0xFF, 0xE6, 0x01], [17, 0]),
+    ([0xE0, 0xE7, 0x01], [0xE6, 0xE7, 0x01], [14, 0]),
+    ([0xE8, 0xE7, 0x01], [0xEB, 0xE7, 0x01], [14, 0]),
+    ([0xED, 0xE7, 0x01], [0xEE, 0xE7, 0x01], [14, 0]),
+    ([0xF0, 0xE7, 0x01], [0xFE, 0xE7, 0x01], [14, 0]),
+    ([0x00, 0xE8, 0x01], [0xC4, 0xE8, 0x01], [7, 0]),
+    ([0xC7, 0xE8, 0x01], [0xD6, 0xE8, 0x01], [7, 0]),
+    ([0x00, 0xE9, 0x01], [0x4A, 0xE9, 0x01], [9, 0]),
+    ([0x4B, 0xE9, 0x01], [0x4B, 0xE9, 0x01], [12, 0]),
+    ([0x50, 0xE9, 0x01], [0x59, 0xE9, 0x01], [9, 0]),
+    ([0x5E, 0xE9, 0x01], [0x5F, 0xE9, 0x01], [9, 0]),
+    ([0x71, 0xEC, 0x01], [0xB4, 0xEC, 0x01], [11, 0]),
+    ([0x01, 0xED, 0x01], [0x3D, 0xED, 0x01], [12, 0]),
+    ([0x00, 0xEE, 0x01], [0x03, 0xEE, 0x01], [6, 1]),
+    ([0x05, 0xEE, 0x01], [0x1F, 0xEE, 0x01], [6, 1]),
+    ([0x21, 0xEE, 0x01], [0x22, 0xEE, 0x01], [6, 1]),
+    ([0x24, 0xEE, 0x01], [0x24, 0xEE, 0x01], [6, 1]),
+    ([0x27, 0xEE, 0x01], [0x27, 0xEE, 0x01], [6, 1]),
+    ([0x29, 0xEE, 0x01], [0x32, 0xEE, 0x01], [6, 1]),
+    ([0x34, 0xEE, 0x01], [0x37, 0xEE, 0x01], [6, 1]),
+    ([0x39, 0xEE, 0x01], [0x39, 0xEE, 0x01], [6, 1]),
+    ([0x3B, 0xEE, 0x01], [0x3B, 0xEE, 0x01], [6, 1]),
+    ([0x42, 0xEE, 0x01], [0x42, 0xEE, 0x01], [6, 1]),
+    ([0x47, 0xEE, 0x01], [0x47, 0xEE, 0x01], [6, 1]),
+    ([0x49, 0xEE, 0x01], [0x49, 0xEE, 0x01], [6, 1]),
+    ([0x4B, 0xEE, 0x01], [0x4B, 0xEE, 0x01], [6, 1]),
+    ([0x4D, 0xEE, 0x01], [0x4F, 0xEE, 0x01], [6, 1]),
+    ([0x51, 0xEE, 0x01], [0x52, 0xEE, 0x01], [6, 1]),
+    ([0x54, 0xEE, 0x01], [0x54, 0xEE, 0x01], [6, 1]),
+    ([0x57, 0xEE, 0x01], [0x57, 0xEE, 0x01], [6, 1]),
+    ([0x59, 0xEE, 0x01], [0x59, 0xEE, 0x01], [6, 1]),
+    ([0x5B, 0xEE, 0x01], [0x5B, 0xEE, 0x01], [6, 1]),
+    ([0x5D, 0xEE, 0x01], [0x5D, 0xEE, 0x01], [6, 1]),
+    ([0x5F, 0xEE, 0x01], [0x5F, 0xEE, 0x01], [6, 1]),
+    ([0x61, 0xEE, 0x01], [0x62, 0xEE, 0x01], [6, 1]),
+    ([0x64, 0xEE, 0x01], [0x64, 0xEE, 0x01], [6, 1]),
+    ([0x67, 0xEE, 0x01], [0x6A, 0xEE, 0x01], [6, 1]),
+    ([0x6C, 0xEE, 0x01], [0x72, 0xEE, 0x01], [6, 1]),
+    ([0x74, 0xEE, 0x01], [0x77, 0xEE, 0x01], [6, 1]),
+    ([0x79, 0xEE, 0x01], [0x7C, 0xEE, 0x01], [6, 1]),
+    ([0x7E, 0xEE, 0x01], [0x7E, 0xEE, 0x01], [6, 1]),
+    ([0x80, 0xEE, 0x01], [0x89, 0xEE, 0x01], [6, 1]),
+    ([0x8B, 0xEE, 0x01], [0x9B, 0xEE, 0x01], [6, 1]),
+    ([0xA1, 0xEE, 0x01], [0xA3, 0xEE, 0x01], [6, 1]),
+    ([0xA5, 0xEE, 0x01], [0xA9, 0xEE, 0x01], [6, 1]),
+    ([0xAB, 0xEE, 0x01], [0xBB, 0xEE, 0x01], [6, 1]),
+    ([0xF0, 0xEE, 0x01], [0xF1, 0xEE, 0x01], [6, 1]),
+    ([0x00, 0xF0, 0x01], [0x2B, 0xF0, 0x01], [5, 1]),
+    ([0x30, 0xF0, 0x01], [0x93, 0xF0, 0x01], [5, 1]),
+    ([0xA0, 0xF0, 0x01], [0xAE, 0xF0, 0x01], [6, 0]),
+    ([0xB1, 0xF0, 0x01], [0xBE, 0xF0, 0x01], [6, 0]),
+    ([0xBF, 0xF0, 0x01], [0xBF, 0xF0, 0x01], [7, 0]),
+    ([0xC1, 0xF0, 0x01], [0xCF, 0xF0, 0x01], [6, 0]),
+    ([0xD1, 0xF0, 0x01], [0xDF, 0xF0, 0x01], [6, 0]),
+    ([0xE0, 0xF0, 0x01], [0xF5, 0xF0, 0x01], [7, 0]),
+    ([0x00, 0xF1, 0x01], [0x0A, 0xF1, 0x01], [5, 2]),
+    ([0x0B, 0xF1, 0x01], [0x0C, 0xF1, 0x01], [7, 0]),
+    ([0x0D, 0xF1, 0x01], [0x0F, 0xF1, 0x01], [13, 0]),
+    ([0x10, 0xF1, 0x01], [0x2E, 0xF1, 0x01], [5, 2]),
+    ([0x2F, 0xF1, 0x01], [0x2F, 0xF1, 0x01], [11, 0]),
+    ([0x30, 0xF1, 0x01], [0x30, 0xF1, 0x01], [6, 0]),
+    ([0x31, 0xF1, 0x01], [0x31, 0xF1, 0x01], [5, 2]),
+    ([0x32, 0xF1, 0x01], [0x3C, 0xF1, 0x01], [6, 0]),
+    ([0x3D, 0xF1, 0x01], [0x3D, 0xF1, 0x01], [5, 2]),
+    ([0x3E, 0xF1, 0x01], [0x3E, 0xF1, 0x01], [6, 0]),
+    ([0x3F, 0xF1, 0x01], [0x3F, 0xF1, 0x01], [5, 2]),
+    ([0x40, 0xF1, 0x01], [0x41, 0xF1, 0x01], [6, 0]),
+    ([0x42, 0xF1, 0x01], [0x42, 0xF1, 0x01], [5, 2]),
+    ([0x43, 0xF1, 0x01], [0x45, 0xF1, 0x01], [6, 0]),
+    ([0x46, 0xF1, 0x01], [0x46, 0xF1, 0x01], [5, 2]),
+    ([0x47, 0xF1, 0x01], [0x49, 0xF1, 0x01], [6, 0]),
+    ([0x4A, 0xF1, 0x01], [0x4E, 0xF1, 0x01], [5, 2]),
+    ([0x4F, 0xF1, 0x01], [0x56, 0xF1, 0x01], [6, 0]),
+    ([0x57, 0xF1, 0x01], [0x57, 0xF1, 0x01], [5, 2]),
+    ([0x58, 0xF1, 0x01], [0x5E, 0xF1, 0x01], [6, 0]),
+    ([0x5F, 0xF1, 0x01], [0x5F, 0xF1, 0x01], [5, 2]),
+    ([0x60, 0xF1, 0x01], [0x69, 0xF1, 0x01], [6, 0]),
+    ([0x6A, 0xF1, 0x01], [0x6B, 0xF1, 0x01], [6, 1]),
+    ([0x6C, 0xF1, 0x01], [0x6C, 0xF1, 0x01], [12, 0]),
+    ([0x6D, 0xF1, 0x01], [0x6F, 0xF1, 0x01], [13, 0]),
+    ([0x70, 0xF1, 0x01], [0x78, 0xF1, 0x01], [6, 0]),
+    ([0x79, 0xF1, 0x01], [0x79, 0xF1, 0x01], [5, 2]),
+    ([0x7A, 0xF1, 0x01], [0x7A, 0xF1, 0x01], [6, 0]),
+    ([0x7B, 0xF1, 0x01], [0x7C, 0xF1, 0x01], [5, 2]),
+    ([0x7D, 0xF1, 0x01], [0x7E, 0xF1, 0x01], [6, 0]),
+    ([0x7F, 0xF1, 0x01], [0x7F, 0xF1, 0x01], [5, 2]),
+    ([0x80, 0xF1, 0x01], [0x89, 0xF1, 0x01], [6, 0]),
+    ([0x8A, 0xF1, 0x01], [0x8D, 0xF1, 0x01], [5, 2]),
+    ([0x8E, 0xF1, 0x01], [0x8F, 0xF1, 0x01], [6, 0]),
+    ([0x90, 0xF1, 0x01], [0x90, 0xF1, 0x01], [5, 2]),
+    ([0x91, 0xF1, 0x01], [0x9A, 0xF1, 0x01], [6, 0]),
+    ([0x9B, 0xF1, 0x01], [0xAC, 0xF1, 0x01], [9, 0]),
+    ([0xAD, 0xF1, 0x01], [0xAD, 0xF1, 0x01], [13, 0]),
+    ([0xE6, 0xF1, 0x01], [0xFF, 0xF1, 0x01], [6, 0]),
+    ([0x00, 0xF2, 0x01], [0x00, 0xF2, 0x01], [5, 2]),
+    ([0x01, 0xF2, 0x01], [0x02, 0xF2, 0x01], [6, 0]),
+    ([0x10, 0xF2, 0x01], [0x31, 0xF2, 0x01], [5, 2]),
+    ([0x32, 0xF2, 0x01], [0x3A, 0xF2, 0x01], [6, 0]),
+    ([0x3B, 0xF2, 0x01], [0x3B, 0xF2, 0x01], [9, 0]),
+    ([0x40, 0xF2, 0x01], [0x48, 0xF2, 0x01], [5, 2]),
+    ([0x50, 0xF2, 0x01], [0x51, 0xF2, 0x01], [6, 0]),
+    ([0x60, 0xF2, 0x01], [0x65, 0xF2, 0x01], [10, 0]),
+    ([0x00, 0xF3, 0x01], [0x20, 0xF3, 0x01], [6, 0]),
+    ([0x21, 0xF3, 0x01], [0x2C, 0xF3, 0x01], [7, 0]),
+    ([0x2D, 0xF3, 0x01], [0x2F, 0xF3, 0x01], [8, 0]),
+    ([0x30, 0xF3, 0x01], [0x35, 0xF3, 0x01], [6, 0]),
+    ([0x36, 0xF3, 0x01], [0x36, 0xF3, 0x01], [7, 0]),
+    ([0x37, 0xF3, 0x01], [0x7C, 0xF3, 0x01], [6, 0]),
+    ([0x7D, 0xF3, 0x01], [0x7D, 0xF3, 0x01], [7, 0]),
+    ([0x7E, 0xF3, 0x01], [0x7F, 0xF3, 0x01], [8, 0]),
+    ([0x80, 0xF3, 0x01], [0x93, 0xF3, 0x01], [6, 0]),
+    ([0x94, 0xF3, 0x01], [0x9F, 0xF3, 0x01], [7, 0]),
+    ([0xA0, 0xF3, 0x01], [0xC4, 0xF3, 0x01], [6, 0]),
+    ([0xC5, 0xF3, 0x01], [0xC5, 0xF3, 0x01], [7, 0]),
+    ([0xC6, 0xF3, 0x01], [0xCA, 0xF3, 0x01], [6, 0]),
+    ([0xCB, 0xF3, 0x01], [0xCE, 0xF3, 0x01], [7, 0]),
+    ([0xCF, 0xF3, 0x01], [0xD3, 0xF3, 0x01], [8, 0]),
+    ([0xD4, 0xF3, 0x01], [0xDF, 0xF3, 0x01], [7, 0]),
+    ([0xE0, 0xF3, 0x01], [0xF0, 0xF3, 0x01], [6, 0]),
+    ([0xF1, 0xF3, 0x01], [0xF7, 0xF3, 0x01], [7, 0]),
+    ([0xF8, 0xF3, 0x01], [0xFF, 0xF3, 0x01], [8, 0]),
+    ([0x00, 0xF4, 0x01], [0x3E, 0xF4, 0x01], [6, 0]),
+    ([0x3F, 0xF4, 0x01], [0x3F, 0xF4, 0x01], [7, 0]),
+    ([0x40, 0xF4, 0x01], [0x40, 0xF4, 0x01], [6, 0]),
+    ([0x41, 0xF4, 0x01], [0x41, 0xF4, 0x01], [7, 0]),
+    ([0x42, 0xF4, 0x01], [0xF7, 0xF4, 0x01], [6, 0]),
+    ([0xF8, 0xF4, 0x01], [0xF8, 0xF4, 0x01], [7, 0]),
+    ([0xF9, 0xF4, 0x01], [0xFC, 0xF4, 0x01], [6, 0]),
+    ([0xFD, 0xF4, 0x01], [0xFE, 0xF4, 0x01], [7, 0]),
+    ([0xFF, 0xF4, 0x01], [0xFF, 0xF4, 0x01], [8, 0]),
+    ([0x00, 0xF5, 0x01], [0x3D, 0xF5, 0x01], [6, 0]),
+    ([0x3E, 0xF5, 0x01], [0x3F, 0xF5, 0x01], [7, 0]),
+    ([0x40, 0xF5, 0x01], [0x43, 0xF5, 0x01], [6, 1]),
+    ([0x44, 0xF5, 0x01], [0x4A, 0xF5, 0x01], [7, 0]),
+    ([0x4B, 0xF5, 0x01], [0x4F, 0xF5, 0x01], [8, 0]),
+    ([0x50, 0xF5, 0x01], [0x67, 0xF5, 0x01], [6, 0]),
+    ([0x68, 0xF5, 0x01], [0x79, 0xF5, 0x01], [7, 0]),
+    ([0x7A, 0xF5, 0x01], [0x7A, 0xF5, 0x01], [9, 0]),
+    ([0x7B, 0xF5, 0x01], [0xA3, 0xF5, 0x01], [7, 0]),
+    ([0xA4, 0xF5, 0x01], [0xA4, 0xF5, 0x01], [9, 0]),
+    ([0xA5, 0xF5, 0x01], [0xFA, 0xF5, 0x01], [7, 0]),
+    ([0xFB, 0xF5, 0x01], [0xFF, 0xF5, 0x01], [6, 0]),
+    ([0x00, 0xF6, 0x01], [0x00, 0xF6, 0x01], [6, 1]),
+    ([0x01, 0xF6, 0x01], [0x10, 0xF6, 0x01], [6, 0]),
+    ([0x11, 0xF6, 0x01], [0x11, 0xF6, 0x01], [6, 1]),
+    ([0x12, 0xF6, 0x01], [0x14, 0xF6, 0x01], [6, 0]),
+    ([0x15, 0xF6, 0x01], [0x15, 0xF6, 0x01], [6, 1]),
+    ([0x16, 0xF6, 0x01], [0x16, 0xF6, 0x01], [6, 0]),
+    ([0x17, 0xF6, 0x01], [0x17, 0xF6, 0x01], [6, 1]),
+    ([0x18, 0xF6, 0x01], [0x18, 0xF6, 0x01], [6, 0]),
+    ([0x19, 0xF6, 0x01], [0x19, 0xF6, 0x01], [6, 1]),
+    ([0x1A, 0xF6, 0x01], [0x1A, 0xF6, 0x01], [6, 0]),
+    ([0x1B, 0xF6, 0x01], [0x1B, 0xF6, 0x01], [6, 1]),
+    ([0x1C, 0xF6, 0x01], [0x1E, 0xF6, 0x01], [6, 0]),
+    ([0x1F, 0xF6, 0x01], [0x1F, 0xF6, 0x01], [6, 1]),
+    ([0x20, 0xF6, 0x01], [0x25, 0xF6, 0x01], [6, 0]),
+    ([0x26, 0xF6, 0x01], [0x27, 0xF6, 0x01], [6, 1]),
+    ([0x28, 0xF6, 0x01], [0x2B, 0xF6, 0x01], [6, 0]),
+    ([0x2C, 0xF6, 0x01], [0x2C, 0xF6, 0x01], [6, 1]),
+    ([0x2D, 0xF6, 0x01], [0x2D, 0xF6, 0x01], [6, 0]),
+    ([0x2E, 0xF6, 0x01], [0x2F, 0xF6, 0x01], [6, 1]),
+    ([0x30, 0xF6, 0x01], [0x33, 0xF6, 0x01], [6, 0]),
+    ([0x34, 0xF6, 0x01], [0x34, 0xF6, 0x01], [6, 1]),
+    ([0x35, 0xF6, 0x01], [0x40, 0xF6, 0x01], [6, 0]),
+    ([0x41, 0xF6, 0x01], [0x42, 0xF6, 0x01], [7, 0]),
+    ([0x43, 0xF6, 0x01], [0x44, 0xF6, 0x01], [8, 0]),
+    ([0x45, 0xF6, 0x01], [0x4F, 0xF6, 0x01], [6, 0]),
+    ([0x50, 0xF6, 0x01], [0x7F, 0xF6, 0x01], [7, 0]),
+    ([0x80, 0xF6, 0x01], [0xC5, 0xF6, 0x01], [6, 0]),
+    ([0xC6, 0xF6, 0x01], [0xCF, 0xF6, 0x01], [7, 0]),
+    ([0xD0, 0xF6, 0x01], [0xD0, 0xF6, 0x01], [8, 0]),
+    ([0xD1, 0xF6, 0x01], [0xD2, 0xF6, 0x01], [9, 0]),
+    ([0xD3, 0xF6, 0x01], [0xD4, 0xF6, 0x01], [10, 0]),
+    ([0xD5, 0xF6, 0x01], [0xD5, 0xF6, 0x01], [12, 0]),
+    ([0xD6, 0xF6, 0x01], [0xD7, 0xF6, 0x01], [13, 0]),
+    ([0xD8, 0xF6, 0x01], [0xD8, 0xF6, 0x01], [17, 0]),
+    ([0xDC, 0xF6, 0x01], [0xDC, 0xF6, 0x01], [15, 0]),
+    ([0xDD, 0xF6, 0x01], [0xDF, 0xF6, 0x01], [14, 0]),
+    ([0xE0, 0xF6, 0x01], [0xEC, 0xF6, 0x01], [7, 0]),
+    ([0xF0, 0xF6, 0x01], [0xF3, 0xF6, 0x01], [7, 0]),
+    ([0xF4, 0xF6, 0x01], [0xF6, 0xF6, 0x01], [9, 0]),
+    ([0xF7, 0xF6, 0x01], [0xF8, 0xF6, 0x01], [10, 0]),
+    ([0xF9, 0xF6, 0x01], [0xF9, 0xF6, 0x01], [11, 0]),
+    ([0xFA, 0xF6, 0x01], [0xFA, 0xF6, 0x01], [12, 0]),
+    ([0xFB, 0xF6, 0x01], [0xFC, 0xF6, 0x01], [13, 0]),
+    ([0x00, 0xF7, 0x01], [0x73, 0xF7, 0x01], [6, 0]),
+    ([0x74, 0xF7, 0x01], [0x76, 0xF7, 0x01], [15, 0]),
+    ([0x77, 0xF7, 0x01], [0x7A, 0xF7, 0x01], [17, 0]),
+    ([0x7B, 0xF7, 0x01], [0x7F, 0xF7, 0x01], [15, 0]),
+    ([0x80, 0xF7, 0x01], [0xD4, 0xF7, 0x01], [7, 0]),
+    ([0xD5, 0xF7, 0x01], [0xD8, 0xF7, 0x01], [11, 0]),
+    ([0xD9, 0xF7, 0x01], [0xD9, 0xF7, 0x01], [15, 0]),
+    ([0xE0, 0xF7, 0x01], [0xEB, 0xF7, 0x01], [12, 0]),
+    ([0xF0, 0xF7, 0x01], [0xF0, 0xF7, 0x01], [14, 0]),
+    ([0x00, 0xF8, 0x01], [0x0B, 0xF8, 0x01], [7, 0]),
+    ([0x10, 0xF8, 0x01], [0x47, 0xF8, 0x01], [7, 0]),
+    ([0x50, 0xF8, 0x01], [0x59, 0xF8, 0x01], [7, 0]),
+    ([0x60, 0xF8, 0x01], [0x87, 0xF8, 0x01], [7, 0]),
+    ([0x90, 0xF8, 0x01], [0xAD, 0xF8, 0x01], [7, 0]),
+    ([0xB0, 0xF8, 0x01], [0xB1, 0xF8, 0x01], [13, 0]),
+    ([0xB2, 0xF8, 0x01], [0xBB, 0xF8, 0x01], [16, 0]),
+    ([0xC0, 0xF8, 0x01], [0xC1, 0xF8, 0x01], [16, 0]),
+    ([0xD0, 0xF8, 0x01], [0xD8, 0xF8, 0x01], [17, 0]),
+    ([0x00, 0xF9, 0x01], [0x0B, 0xF9, 0x01], [10, 0]),
+    ([0x0C, 0xF9, 0x01], [0x0C, 0xF9, 0x01], [13, 0]),
+    ([0x0D, 0xF9, 0x01], [0x0F, 0xF9, 0x01], [12, 0]),
+    ([0x10, 0xF9, 0x01], [0x18, 0xF9, 0x01], [8, 0]),
+    ([0x19, 0xF9, 0x01], [0x1E, 0xF9, 0x01], [9, 0]),
+    ([0x1F, 0xF9, 0x01], [0x1F, 0xF9, 0x01], [10, 0]),
+    ([0x20, 0xF9, 0x01], [0x27, 0xF9, 0x01], [9, 0]),
+    ([0x28, 0xF9, 0x01], [0x2F, 0xF9, 0x01], [10, 0]),
+    ([0x30, 0xF9, 0x01], [0x30, 0xF9, 0x01], [9, 0]),
+    ([0x31, 0xF9, 0x01], [0x32, 0xF9, 0x01], [10, 0]),
+    ([0x33, 0xF9, 0x01], [0x3E, 0xF9, 0x01], [9, 0]),
+    ([0x3F, 0xF9, 0x01], [0x3F, 0xF9, 0x01], [12, 0]),
+    ([0x40, 0xF9, 0x01], [0x4B, 0xF9, 0x01], [9, 0]),
+    ([0x4C, 0xF9, 0x01], [0x4C, 0xF9, 0x01], [10, 0]),
+    ([0x4D, 0xF9, 0x01], [0x4F, 0xF9, 0x01], [11, 0]),
+    ([0x50, 0xF9, 0x01], [0x5E, 0xF9, 0x01], [9, 0]),
+    ([0x5F, 0xF9, 0x01], [0x6B, 0xF9, 0x01], [10, 0]),
+    ([0x6C, 0xF9, 0x01], [0x70, 0xF9, 0x01], [11, 0]),
+    ([0x71, 0xF9, 0x01], [0x71, 0xF9, 0x01], [12, 0]),
+    ([0x72, 0xF9, 0x01], [0x72, 0xF9, 0x01], [13, 0]),
+    ([0x73, 0xF9, 0x01], [0x76, 0xF9, 0x01], [11, 0]),
+    ([0x77, 0xF9, 0x01], [0x78, 0xF9, 0x01], [13, 0]),
+    ([0x79, 0xF9, 0x01], [0x79, 0xF9, 0x01], [14, 0]),
+    ([0x7A, 0xF9, 0x01], [0x7A, 0xF9, 0x01], [11, 0]),
+    ([0x7B, 0xF9, 0x01], [0x7B, 0xF9, 0x01], [12, 0]),
+    ([0x7C, 0xF9, 0x01], [0x7F, 0xF9, 0x01], [11, 0]),
+    ([0x80, 0xF9, 0x01], [0x84, 0xF9, 0x01], [8, 0]),
+    ([0x85, 0xF9, 0x01], [0x91, 0xF9, 0x01], [9, 0]),
+    ([0x92, 0xF9, 0x01], [0x97, 0xF9, 0x01], [10, 0]),
+    ([0x98, 0xF9, 0x01], [0xA2, 0xF9, 0x01], [11, 0]),
+    ([0xA3, 0xF9, 0x01], [0xA4, 0xF9, 0x01], [13, 0]),
+    ([0xA5, 0xF9, 0x01], [0xAA, 0xF9, 0x01], [12, 0]),
+    ([0xAB, 0xF9, 0x01], [0xAD, 0xF9, 0x01], [13, 0]),
+    ([0xAE, 0xF9, 0x01], [0xAF, 0xF9, 0x01], [12, 0]),
+    ([0xB0, 0xF9, 0x01], [0xB9, 0xF9, 0x01], [11, 0]),
+    ([0xBA, 0xF9, 0x01], [0xBF, 0xF9, 0x01], [12, 0]),
+    ([0xC0, 0xF9, 0x01], [0xC0, 0xF9, 0x01], [8, 0]),
+    ([0xC1, 0xF9, 0x01], [0xC2, 0xF9, 0x01], [11, 0]),
+    ([0xC3, 0xF9, 0x01], [0xCA, 0xF9, 0x01], [12, 0]),
+    ([0xCB, 0xF9, 0x01], [0xCB, 0xF9, 0x01], [13, 0]),
+    ([0xCC, 0xF9, 0x01], [0xCC, 0xF9, 0x01], [14, 0]),
+    ([0xCD, 0xF9, 0x01], [0xCF, 0xF9, 0x01], [12, 0]),
+    ([0xD0, 0xF9, 0x01], [0xE6, 0xF9, 0x01], [10, 0]),
+    ([0xE7, 0xF9, 0x01], [0xFF, 0xF9, 0x01], [11, 0]),
+    ([0x00, 0xFA, 0x01], [0x53, 0xFA, 0x01], [12, 0]),
+    ([0x54, 0xFA, 0x01], [0x57, 0xFA, 0x01], [17, 0]),
+    ([0x60, 0xFA, 0x01], [0x6D, 0xFA, 0x01], [11, 0]),
+    ([0x70, 0xFA, 0x01], [0x73, 0xFA, 0x01], [12, 0]),
+    ([0x74, 0xFA, 0x01], [0x74, 0xFA, 0x01], [13, 0]),
+    ([0x75, 0xFA, 0x01], [0x77, 0xFA, 0x01], [15, 0]),
+    ([0x78, 0xFA, 0x01], [0x7A, 0xFA, 0x01], [12, 0]),
+    ([0x7B, 0xFA, 0x01], [0x7C, 0xFA, 0x01], [14, 0]),
+    ([0x80, 0xFA, 0x01], [0x82, 0xFA, 0x01], [12, 0]),
+    ([0x83, 0xFA, 0x01], [0x86, 0xFA, 0x01], [13, 0]),
+    ([0x87, 0xFA, 0x01], [0x88, 0xFA, 0x01], [15, 0]),
+    ([0x89, 0xFA, 0x01], [0x89, 0xFA, 0x01], [16, 0]),
+    ([0x8A, 0xFA, 0x01], [0x8A, 0xFA, 0x01], [17, 0]),
+    ([0x8E, 0xFA, 0x01], [0x8E, 0xFA, 0x01], [17, 0]),
+    ([0x8F, 0xFA, 0x01], [0x8F, 0xFA, 0x01], [16, 0]),
+    ([0x90, 0xFA, 0x01], [0x95, 0xFA, 0x01], [12, 0]),
+    ([0x96, 0xFA, 0x01], [0xA8, 0xFA, 0x01], [13, 0]),
+    ([0xA9, 0xFA, 0x01], [0xAC, 0xFA, 0x01], [14, 0]),
+    ([0xAD, 0xFA, 0x01], [0xAF, 0xFA, 0x01], [15, 0]),
+    ([0xB0, 0xFA, 0x01], [0xB6, 0xFA, 0x01], [13, 0]),
+    ([0xB7, 0xFA, 0x01], [0xBA, 0xFA, 0x01], [14, 0]),
+    ([0xBB, 0xFA, 0x01], [0xBD, 0xFA, 0x01], [15, 0]),
+    ([0xBE, 0xFA, 0x01], [0xBE, 0xFA, 0x01], [16, 0]),
+    ([0xBF, 0xFA, 0x01], [0xBF, 0xFA, 0x01], [15, 0]),
+    ([0xC0, 0xFA, 0x01], [0xC2, 0xFA, 0x01], [13, 0]),
+    ([0xC3, 0xFA, 0x01], [0xC5, 0xFA, 0x01], [14, 0]),
+    ([0xC6, 0xFA, 0x01], [0xC6, 0xFA, 0x01], [16, 0]),
+    ([0xC8, 0xFA, 0x01], [0xC8, 0xFA, 0x01], [17, 0]),
+    ([0xCD, 0xFA, 0x01], [0xCD, 0xFA, 0x01], [17, 0]),
+    ([0xCE, 0xFA, 0x01], [0xCF, 0xFA, 0x01], [15, 0]),
+    ([0xD0, 0xFA, 0x01], [0xD6, 0xFA, 0x01], [13, 0]),
+    ([0xD7, 0xFA, 0x01], [0xD9, 0xFA, 0x01], [14, 0]),
+    ([0xDA, 0xFA, 0x01], [0xDB, 0xFA, 0x01], [15, 0]),
+    ([0xDC, 0xFA, 0x01], [0xDC, 0xFA, 0x01], [16, 0]),
+    ([0xDF, 0xFA, 0x01], [0xDF, 0xFA, 0x01], [16, 0]),
+    ([0xE0, 0xFA, 0x01], [0xE7, 0xFA, 0x01], [14, 0]),
+    ([0xE8, 0xFA, 0x01], [0xE8, 0xFA, 0x01], [15, 0]),
+    ([0xE9, 0xFA, 0x01], [0xE9, 0xFA, 0x01], [16, 0]),
+    ([0xEA, 0xFA, 0x01], [0xEA, 0xFA, 0x01], [17, 0]),
+    ([0xEF, 0xFA, 0x01], [0xEF, 0xFA, 0x01], [17, 0]),
+    ([0xF0, 0xFA, 0x01], [0xF6, 0xFA, 0x01], [14, 0]),
+    ([0xF7, 0xFA, 0x01], [0xF8, 0xFA, 0x01], [15, 0]),
+    ([0x00, 0xFB, 0x01], [0x92, 0xFB, 0x01], [13, 0]),
+    ([0x94, 0xFB, 0x01], [0xCA, 0xFB, 0x01], [13, 0]),
+    ([0xCB, 0xFB, 0x01], [0xEF, 0xFB, 0x01], [16, 0]),
+    ([0xF0, 0xFB, 0x01], [0xF9, 0xFB, 0x01], [13, 0]),
+    ([0xFA, 0xFB, 0x01], [0xFA, 0xFB, 0x01], [17, 0]),
+    ([0xFE, 0xFF, 0x01], [0xFF, 0xFF, 0x01], [2, 0]),
+    ([0x00, 0x00, 0x02], [0xD6, 0xA6, 0x02], [3, 1]),
+    ([0xD7, 0xA6, 0x02], [0xDD, 0xA6, 0x02], [13, 0]),
+    ([0xDE, 0xA6, 0x02], [0xDF, 0xA6, 0x02], [14, 0]),
+    ([0x00, 0xA7, 0x02], [0x34, 0xB7, 0x02], [5, 2]),
+    ([0x35, 0xB7, 0x02], [0x38, 0xB7, 0x02], [14, 0]),
+    ([0x39, 0xB7, 0x02], [0x39, 0xB7, 0x02], [15, 0]),
+    ([0x3A, 0xB7, 0x02], [0x3F, 0xB7, 0x02], [17, 0]),
+    ([0x40, 0xB7, 0x02], [0x1D, 0xB8, 0x02], [6, 0]),
+    ([0x20, 0xB8, 0x02], [0xA1, 0xCE, 0x02], [8, 0]),
+    ([0xA2, 0xCE, 0x02], [0xAD, 0xCE, 0x02], [17, 0]),
+    ([0xB0, 0xCE, 0x02], [0xE0, 0xEB, 0x02], [10, 0]),
+    ([0xF0, 0xEB, 0x02], [0x5D, 0xEE, 0x02], [15, 1]),
+    ([0x00, 0xF8, 0x02], [0x1D, 0xFA, 0x02], [3, 1]),
+    ([0xFE, 0xFF, 0x02], [0xFF, 0xFF, 0x02], [2, 0]),
+    ([0x00, 0x00, 0x03], [0x4A, 0x13, 0x03], [13, 0]),
+    ([0x50, 0x13, 0x03], [0xAF, 0x23, 0x03], [15, 0]),
+    ([0xB0, 0x23, 0x03], [0x79, 0x34, 0x03], [17, 0]),
+    ([0xFE, 0xFF, 0x03], [0xFF, 0xFF, 0x03], [2, 0]),
+    ([0xFE, 0xFF, 0x04], [0xFF, 0xFF, 0x04], [2, 0]),
+    ([0xFE, 0xFF, 0x05], [0xFF, 0xFF, 0x05], [2, 0]),
+    ([0xFE, 0xFF, 0x06], [0xFF, 0xFF, 0x06], [2, 0]),
+    ([0xFE, 0xFF, 0x07], [0xFF, 0xFF, 0x07], [2, 0]),
+    ([0xFE, 0xFF, 0x08], [0xFF, 0xFF, 0x08], [2, 0]),
+    ([0xFE, 0xFF, 0x09], [0xFF, 0xFF, 0x09], [2, 0]),
+    ([0xFE, 0xFF, 0x0A], [0xFF, 0xFF, 0x0A], [2, 0]),
+    ([0xFE, 0xFF, 0x0B], [0xFF, 0xFF, 0x0B], [2, 0]),
+    ([0xFE, 0xFF, 0x0C], [0xFF, 0xFF, 0x0C], [2, 0]),
+    ([0xFE, 0xFF, 0x0D], [0xFF, 0xFF, 0x0D], [2, 0]),
+    ([0x01, 0x00, 0x0E], [0x01, 0x00, 0x0E], [3, 1]),
+    ([0x20, 0x00, 0x0E], [0x7F, 0x00, 0x0E], [3, 1]),
+    ([0x00, 0x01, 0x0E], [0xEF, 0x01, 0x0E], [4, 0]),
+    ([0xFE, 0xFF, 0x0E], [0xFF, 0xFF, 0x10], [2, 0]),
+];
+
+/// Sorted list of codepoint ranges (inclusive) of emoji, each with the emoji version,
+/// as `[major, minor]`, in which it was introduced.
+static EMOJI_VERSIONS: [([u8; 3], [u8; 3], [u8; 2]); 414] = [
+    ([0xA9, 0x00, 0x00], [0xA9, 0x00, 0x00], [0, 6]),
+    ([0xAE, 0x00, 0x00], [0xAE, 0x00, 0x00], [0, 6]),
+    ([0x3C, 0x20, 0x00], [0x3C, 0x20, 0x00], [0, 6]),
+    ([0x49, 0x20, 0x00], [0x49, 0x20, 0x00], [0, 6]),
+    ([0x22, 0x21, 0x00], [0x22, 0x21, 0x00], [0, 6]),
+    ([0x39, 0x21, 0x00], [0x39, 0x21, 0x00], [0, 6]),
+    ([0x94, 0x21, 0x00], [0x99, 0x21, 0x00], [0, 6]),
+    ([0xA9, 0x21, 0x00], [0xAA, 0x21, 0x00], [0, 6]),
+    ([0x1A, 0x23, 0x00], [0x1B, 0x23, 0x00], [0, 6]),
+    ([0x28, 0x23, 0x00], [0x28, 0x23, 0x00], [1, 0]),
+    ([0xCF, 0x23, 0x00], [0xCF, 0x23, 0x00], [1, 0]),
+    ([0xE9, 0x23, 0x00], [0xEC, 0x23, 0x00], [0, 6]),
+    ([0xED, 0x23, 0x00], [0xEE, 0x23, 0x00], [0, 7]),
+    ([0xEF, 0x23, 0x00], [0xEF, 0x23, 0x00], [1, 0]),
+    ([0xF0, 0x23, 0x00], [0xF0, 0x23, 0x00], [0, 6]),
+    ([0xF1, 0x23, 0x00], [0xF2, 0x23, 0x00], [1, 0]),
+    ([0xF3, 0x23, 0x00], [0xF3, 0x23, 0x00], [0, 6]),
+    ([0xF8, 0x23, 0x00], [0xFA, 0x23, 0x00], [0, 7]),
+    ([0xC2, 0x24, 0x00], [0xC2, 0x24, 0x00], [0, 6]),
+    ([0xAA, 0x25, 0x00], [0xAB, 0x25, 0x00], [0, 6]),
+    ([0xB6, 0x25, 0x00], [0xB6, 0x25, 0x00], [0, 6]),
+    ([0xC0, 0x25, 0x00], [0xC0, 0x25, 0x00], [0, 6]),
+    ([0xFB, 0x25, 0x00], [0xFE, 0x25, 0x00], [0, 6]),
+    ([0x00, 0x26, 0x00], [0x01, 0x26, 0x00], [0, 6]),
+    ([0x02, 0x26, 0x00], [0x03, 0x26, 0x00], [0, 7]),
+    ([0x04, 0x26, 0x00], [0x04, 0x26, 0x00], [1, 0]),
+    ([0x0E, 0x26, 0x00], [0x0E, 0x26, 0x00], [0, 6]),
+    ([0x11, 0x26, 0x00], [0x11, 0x26, 0x00], [0, 6]),
+    ([0x14, 0x26, 0x00], [0x15, 0x26, 0x00], [0, 6]),
+    ([0x18, 0x26, 0x00], [0x18, 0x26, 0x00], [1, 0]),
+    ([0x1D, 0x26, 0x00], [0x1D, 0x26, 0x00], [0, 6]),
+    ([0x20, 0x26, 0x00], [0x20, 0x26, 0x00], [1, 0]),
+    ([0x22, 0x26, 0x00], [0x23, 0x26, 0x00], [1, 0]),
+    ([0x26, 0x26, 0x00], [0x26, 0x26, 0x00], [1, 0]),
+    ([0x2A, 0x26, 0x00], [0x2A, 0x26, 0x00], [0, 7]),
+    ([0x2E, 0x26, 0x00], [0x2E, 0x26, 0x00], [1, 0]),
+    ([0x2F, 0x26, 0x00], [0x2F, 0x26, 0x00], [0, 7]),
+    ([0x38, 0x26, 0x00], [0x39, 0x26, 0x00], [0, 7]),
+    ([0x3A, 0x26, 0x00], [0x3A, 0x26, 0x00], [0, 6]),
+    ([0x40, 0x26, 0x00], [0x40, 0x26, 0x00], [4, 0]),
+    ([0x42, 0x26, 0x00], [0x42, 0x26, 0x00], [4, 0]),
+    ([0x48, 0x26, 0x00], [0x53, 0x26, 0x00], [0, 6]),
+    ([0x5F, 0x26, 0x00], [0x5F, 0x26, 0x00], [11, 0]),
+    ([0x60, 0x26, 0x00], [0x60, 0x26, 0x00], [0, 6]),
+    ([0x63, 0x26, 0x00], [0x63, 0x26, 0x00], [0, 6]),
+    ([0x65, 0x26, 0x00], [0x66, 0x26, 0x00], [0, 6]),
+    ([0x68, 0x26, 0x00], [0x68, 0x26, 0x00], [0, 6]),
+    ([0x7B, 0x26, 0x00], [0x7B, 0x26, 0x00], [0, 6]),
+    ([0x7E, 0x26, 0x00], [0x7E, 0x26, 0x00], [11, 0]),
+    ([0x7F, 0x26, 0x00], [0x7F, 0x26, 0x00], [0, 6]),
+    ([0x92, 0x26, 0x00], [0x92, 0x26, 0x00], [1, 0]),
+    ([0x93, 0x26, 0x00], [0x93, 0x26, 0x00], [0, 6]),
+    ([0x94, 0x26, 0x00], [0x94, 0x26, 0x00], [1, 0]),
+    ([0x95, 0x26, 0x00], [0x95, 0x26, 0x00], [4, 0]),
+    ([0x96, 0x26, 0x00], [0x97, 0x26, 0x00], [1, 0]),
+    ([0x99, 0x26, 0x00], [0x99, 0x26, 0x00], [1, 0]),
+    ([0x9B, 0x26, 0x00], [0x9C, 0x26, 0x00], [1, 0]),
+    ([0xA0, 0x26, 0x00], [0xA1, 0x26, 0x00], [0, 6]),
+    ([0xA7, 0x26, 0x00], [0xA7, 0x26, 0x00], [13, 0]),
+    ([0xAA, 0x26, 0x00], [0xAB, 0x26, 0x00], [0, 6]),
+    ([0xB0, 0x26, 0x00], [0xB1, 0x26, 0x00], [1, 0]),
+    ([0xBD, 0x26, 0x00], [0xBE, 0x26, 0x00], [0, 6]),
+    ([0xC4, 0x26, 0x00], [0xC5, 0x26, 0x00], [0, 6]),
+    ([0xC8, 0x26, 0x00], [0xC8, 0x26, 0x00], [0, 7]),
+    ([0xCE, 0x26, 0x00], [0xCE, 0x26, 0x00], [0, 6]),
+    ([0xCF, 0x26, 0x00], [0xCF, 0x26, 0x00], [0, 7]),
+    ([0xD1, 0x26, 0x00], [0xD1, 0x26, 0x00], [0, 7]),
+    ([0xD3, 0x26, 0x00], [0xD3, 0x26, 0x00], [0, 7]),
+    ([0xD4, 0x26, 0x00], [0xD4, 0x26, 0x00], [0, 6]),
+    ([0xE9, 0x26, 0x00], [0xE9, 0x26, 0x00], [0, 7]),
+    ([0xEA, 0x26, 0x00], [0xEA, 0x26, 0x00], [0, 6]),
+    ([0xF0, 0x26, 0x00], [0xF1, 0x26, 0x00], [0, 7]),
+    ([0xF2, 0x26, 0x00], [0xF3, 0x26, 0x00], [0, 6]),
+    ([0xF4, 0x26, 0x00], [0xF4, 0x26, 0x00], [0, 7]),
+    ([0xF5, 0x26, 0x00], [0xF5, 0x26, 0x00], [0, 6]),
+    ([0xF7, 0x26, 0x00], [0xF9, 0x26, 0x00], [0, 7]),
+    ([0xFA, 0x26, 0x00], [0xFA, 0x26, 0x00], [0, 6]),
+    ([0xFD, 0x26, 0x00], [0xFD, 0x26, 0x00], [0, 6]),
+    ([0x02, 0x27, 0x00], [0x02, 0x27, 0x00], [0, 6]),
+    ([0x05, 0x27, 0x00], [0x05, 0x27, 0x00], [0, 6]),
+    ([0x08, 0x27, 0x00], [0x0C, 0x27, 0x00], [0, 6]),
+    ([0x0D, 0x27, 0x00], [0x0D, 0x27, 0x00], [0, 7]),
+    ([0x0F, 0x27, 0x00], [0x0F, 0x27, 0x00], [0, 6]),
+    ([0x12, 0x27, 0x00], [0x12, 0x27, 0x00], [0, 6]),
+    ([0x14, 0x27, 0x00], [0x14, 0x27, 0x00], [0, 6]),
+    ([0x16, 0x27, 0x00], [0x16, 0x27, 0x00], [0, 6]),
+    ([0x1D, 0x27, 0x00], [0x1D, 0x27, 0x00], [0, 7]),
+    ([0x21, 0x27, 0x00], [0x21, 0x27, 0x00], [0, 7]),
+    ([0x28, 0x27, 0x00], [0x28, 0x27, 0x00], [0, 6]),
+    ([0x33, 0x27, 0x00], [0x34, 0x27, 0x00], [0, 6]),
+    ([0x44, 0x27, 0x00], [0x44, 0x27, 0x00], [0, 6]),
+    ([0x47, 0x27, 0x00], [0x47, 0x27, 0x00], [0, 6]),
+    ([0x4C, 0x27, 0x00], [0x4C, 0x27, 0x00], [0, 6]),
+    ([0x4E, 0x27, 0x00], [0x4E, 0x27, 0x00], [0, 6]),
+    ([0x53, 0x27, 0x00], [0x55, 0x27, 0x00], [0, 6]),
+    ([0x57, 0x27, 0x00], [0x57, 0x27, 0x00], [0, 6]),
+    ([0x63, 0x27, 0x00], [0x63, 0x27, 0x00], [1, 0]),
+    ([0x64, 0x27, 0x00], [0x64, 0x27, 0x00], [0, 6]),
+    ([0x95, 0x27, 0x00], [0x97, 0x27, 0x00], [0, 6]),
+    ([0xA1, 0x27, 0x00], [0xA1, 0x27, 0x00], [0, 6]),
+    ([0xB0, 0x27, 0x00], [0xB0, 0x27, 0x00], [0, 6]),
+    ([0xBF, 0x27, 0x00], [0xBF, 0x27, 0x00], [1, 0]),
+    ([0x34, 0x29, 0x00], [0x35, 0x29, 0x00], [0, 6]),
+    ([0x05, 0x2B, 0x00], [0x07, 0x2B, 0x00], [0, 6]),
+    ([0x1B, 0x2B, 0x00], [0x1C, 0x2B, 0x00], [0, 6]),
+    ([0x50, 0x2B, 0x00], [0x50, 0x2B, 0x00], [0, 6]),
+    ([0x55, 0x2B, 0x00], [0x55, 0x2B, 0x00], [0, 6]),
+    ([0x30, 0x30, 0x00], [0x30, 0x30, 0x00], [0, 6]),
+    ([0x3D, 0x30, 0x00], [0x3D, 0x30, 0x00], [0, 6]),
+    ([0x97, 0x32, 0x00], [0x97, 0x32, 0x00], [0, 6]),
+    ([0x99, 0x32, 0x00], [0x99, 0x32, 0x00], [0, 6]),
+    ([0x04, 0xF0, 0x01], [0x04, 0xF0, 0x01], [0, 6]),
+    ([0xCF, 0xF0, 0x01], [0xCF, 0xF0, 0x01], [0, 6]),
+    ([0x70, 0xF1, 0x01], [0x71, 0xF1, 0x01], [0, 6]),
+    ([0x7E, 0xF1, 0x01], [0x7F, 0xF1, 0x01], [0, 6]),
+    ([0x8E, 0xF1, 0x01], [0x8E, 0xF1, 0x01], [0, 6]),
+    ([0x91, 0xF1, 0x01], [0x9A, 0xF1, 0x01], [0, 6]),
+    ([0x01, 0xF2, 0x01], [0x02, 0xF2, 0x01], [0, 6]),
+    ([0x1A, 0xF2, 0x01], [0x1A, 0xF2, 0x01], [0, 6]),
+    ([0x2F, 0xF2, 0x01], [0x2F, 0xF2, 0x01], [0, 6]),
+    ([0x32, 0xF2, 0x01], [0x3A, 0xF2, 0x01], [0, 6]),
+    ([0x50, 0xF2, 0x01], [0x51, 0xF2, 0x01], [0, 6]),
+    ([0x00, 0xF3, 0x01], [0x0C, 0xF3, 0x01], [0, 6]),
+    ([0x0D, 0xF3, 0x01], [0x0E, 0xF3, 0x01], [0, 7]),
+    ([0x0F, 0xF3, 0x01], [0x0F, 0xF3, 0x01], [0, 6]),
+    ([0x10, 0xF3, 0x01], [0x10, 0xF3, 0x01], [1, 0]),
+    ([0x11, 0xF3, 0x01], [0x11, 0xF3, 0x01], [0, 6]),
+    ([0x12, 0xF3, 0x01], [0x12, 0xF3, 0x01], [1, 0]),
+    ([0x13, 0xF3, 0x01], [0x15, 0xF3, 0x01], [0, 6]),
+    ([0x16, 0xF3, 0x01], [0x18, 0xF3, 0x01], [1, 0]),
+    ([0x19, 0xF3, 0x01], [0x19, 0xF3, 0x01], [0, 6]),
+    ([0x1A, 0xF3, 0x01], [0x1A, 0xF3, 0x01], [1, 0]),
+    ([0x1B, 0xF3, 0x01], [0x1B, 0xF3, 0x01], [0, 6]),
+    ([0x1C, 0xF3, 0x01], [0x1C, 0xF3, 0x01], [0, 7]),
+    ([0x1D, 0xF3, 0x01], [0x1E, 0xF3, 0x01], [1, 0]),
+    ([0x1F, 0xF3, 0x01], [0x20, 0xF3, 0x01], [0, 6]),
+    ([0x21, 0xF3, 0x01], [0x21, 0xF3, 0x01], [0, 7]),
+    ([0x24, 0xF3, 0x01], [0x2C, 0xF3, 0x01], [0, 7]),
+    ([0x2D, 0xF3, 0x01], [0x2F, 0xF3, 0x01], [1, 0]),
+    ([0x30, 0xF3, 0x01], [0x31, 0xF3, 0x01], [0, 6]),
+    ([0x32, 0xF3, 0x01], [0x33, 0xF3, 0x01], [1, 0]),
+    ([0x34, 0xF3, 0x01], [0x35, 0xF3, 0x01], [0, 6]),
+    ([0x36, 0xF3, 0x01], [0x36, 0xF3, 0x01], [0, 7]),
+    ([0x37, 0xF3, 0x01], [0x4A, 0xF3, 0x01], [0, 6]),
+    ([0x4B, 0xF3, 0x01], [0x4B, 0xF3, 0x01], [1, 0]),
+    ([0x4C, 0xF3, 0x01], [0x4F, 0xF3, 0x01], [0, 6]),
+    ([0x50, 0xF3, 0x01], [0x50, 0xF3, 0x01], [1, 0]),
+    ([0x51, 0xF3, 0x01], [0x7B, 0xF3, 0x01], [0, 6]),
+    ([0x7C, 0xF3, 0x01], [0x7C, 0xF3, 0x01], [1, 0]),
+    ([0x7D, 0xF3, 0x01], [0x7D, 0xF3, 0x01], [0, 7]),
+    ([0x7E, 0xF3, 0x01], [0x7F, 0xF3, 0x01], [1, 0]),
+    ([0x80, 0xF3, 0x01], [0x93, 0xF3, 0x01], [0, 6]),
+    ([0x96, 0xF3, 0x01], [0x97, 0xF3, 0x01], [0, 7]),
+    ([0x99, 0xF3, 0x01], [0x9B, 0xF3, 0x01], [0, 7]),
+    ([0x9E, 0xF3, 0x01], [0x9F, 0xF3, 0x01], [0, 7]),
+    ([0xA0, 0xF3, 0x01], [0xC4, 0xF3, 0x01], [0, 6]),
+    ([0xC5, 0xF3, 0x01], [0xC5, 0xF3, 0x01], [1, 0]),
+    ([0xC6, 0xF3, 0x01], [0xC6, 0xF3, 0x01], [0, 6]),
+    ([0xC7, 0xF3, 0x01], [0xC7, 0xF3, 0x01], [1, 0]),
+    ([0xC8, 0xF3, 0x01], [0xC8, 0xF3, 0x01], [0, 6]),
+    ([0xC9, 0xF3, 0x01], [0xC9, 0xF3, 0x01], [1, 0]),
+    ([0xCA, 0xF3, 0x01], [0xCA, 0xF3, 0x01], [0, 6]),
+    ([0xCB, 0xF3, 0x01], [0xCE, 0xF3, 0x01], [0, 7]),
+    ([0xCF, 0xF3, 0x01], [0xD3, 0xF3, 0x01], [1, 0]),
+    ([0xD4, 0xF3, 0x01], [0xDF, 0xF3, 0x01], [0, 7]),
+    ([0xE0, 0xF3, 0x01], [0xE3, 0xF3, 0x01], [0, 6]),
+    ([0xE4, 0xF3, 0x01], [0xE4, 0xF3, 0x01], [1, 0]),
+    ([0xE5, 0xF3, 0x01], [0xF0, 0xF3, 0x01], [0, 6]),
+    ([0xF3, 0xF3, 0x01], [0xF3, 0xF3, 0x01], [0, 7]),
+    ([0xF4, 0xF3, 0x01], [0xF4, 0xF3, 0x01], [1, 0]),
+    ([0xF5, 0xF3, 0x01], [0xF5, 0xF3, 0x01], [0, 7]),
+    ([0xF7, 0xF3, 0x01], [0xF7, 0xF3, 0x01], [0, 7]),
+    ([0xF8, 0xF3, 0x01], [0x07, 0xF4, 0x01], [1, 0]),
+    ([0x08, 0xF4, 0x01], [0x08, 0xF4, 0x01], [0, 7]),
+    ([0x09, 0xF4, 0x01], [0x0B, 0xF4, 0x01], [1, 0]),
+    ([0x0C, 0xF4, 0x01], [0x0E, 0xF4, 0x01], [0, 6]),
+    ([0x0F, 0xF4, 0x01], [0x10, 0xF4, 0x01], [1, 0]),
+    ([0x11, 0xF4, 0x01], [0x12, 0xF4, 0x01], [0, 6]),
+    ([0x13, 0xF4, 0x01], [0x13, 0xF4, 0x01], [1, 0]),
+    ([0x14, 0xF4, 0x01], [0x14, 0xF4, 0x01], [0, 6]),
+    ([0x15, 0xF4, 0x01], [0x15, 0xF4, 0x01], [0, 7]),
+    ([0x16, 0xF4, 0x01], [0x16, 0xF4, 0x01], [1, 0]),
+    ([0x17, 0xF4, 0x01], [0x29, 0xF4, 0x01], [0, 6]),
+    ([0x2A, 0xF4, 0x01], [0x2A, 0xF4, 0x01], [1, 0]),
+    ([0x2B, 0xF4, 0x01], [0x3E, 0xF4, 0x01], [0, 6]),
+    ([0x3F, 0xF4, 0x01], [0x3F, 0xF4, 0x01], [0, 7]),
+    ([0x40, 0xF4, 0x01], [0x40, 0xF4, 0x01], [0, 6]),
+    ([0x41, 0xF4, 0x01], [0x41, 0xF4, 0x01], [0, 7]),
+    ([0x42, 0xF4, 0x01], [0x64, 0xF4, 0x01], [0, 6]),
+    ([0x65, 0xF4, 0x01], [0x65, 0xF4, 0x01], [1, 0]),
+    ([0x66, 0xF4, 0x01], [0x6B, 0xF4, 0x01], [0, 6]),
+    ([0x6C, 0xF4, 0x01], [0x6D, 0xF4, 0x01], [1, 0]),
+    ([0x6E, 0xF4, 0x01], [0xAC, 0xF4, 0x01], [0, 6]),
+    ([0xAD, 0xF4, 0x01], [0xAD, 0xF4, 0x01], [1, 0]),
+    ([0xAE, 0xF4, 0x01], [0xB5, 0xF4, 0x01], [0, 6]),
+    ([0xB6, 0xF4, 0x01], [0xB7, 0xF4, 0x01], [1, 0]),
+    ([0xB8, 0xF4, 0x01], [0xEB, 0xF4, 0x01], [0, 6]),
+    ([0xEC, 0xF4, 0x01], [0xED, 0xF4, 0x01], [0, 7]),
+    ([0xEE, 0xF4, 0x01], [0xEE, 0xF4, 0x01], [0, 6]),
+    ([0xEF, 0xF4, 0x01], [0xEF, 0xF4, 0x01], [1, 0]),
+    ([0xF0, 0xF4, 0x01], [0xF4, 0xF4, 0x01], [0, 6]),
+    ([0xF5, 0xF4, 0x01], [0xF5, 0xF4, 0x01], [1, 0]),
+    ([0xF6, 0xF4, 0x01], [0xF7, 0xF4, 0x01], [0, 6]),
+    ([0xF8, 0xF4, 0x01], [0xF8, 0xF4, 0x01], [1, 0]),
+    ([0xF9, 0xF4, 0x01], [0xFC, 0xF4, 0x01], [0, 6]),
+    ([0xFD, 0xF4, 0x01], [0xFD, 0xF4, 0x01], [0, 7]),
+    ([0xFF, 0xF4, 0x01], [0x02, 0xF5, 0x01], [1, 0]),
+    ([0x03, 0xF5, 0x01], [0x03, 0xF5, 0x01], [0, 6]),
+    ([0x04, 0xF5, 0x01], [0x07, 0xF5, 0x01], [1, 0]),
+    ([0x08, 0xF5, 0x01], [0x08, 0xF5, 0x01], [0, 7]),
+    ([0x09, 0xF5, 0x01], [0x09, 0xF5, 0x01], [1, 0]),
+    ([0x0A, 0xF5, 0x01], [0x14, 0xF5, 0x01], [0, 6]),
+    ([0x15, 0xF5, 0x01], [0x15, 0xF5, 0x01], [1, 0]),
+    ([0x16, 0xF5, 0x01], [0x2B, 0xF5, 0x01], [0, 6]),
+    ([0x2C, 0xF5, 0x01], [0x2D, 0xF5, 0x01], [1, 0]),
+    ([0x2E, 0xF5, 0x01], [0x3D, 0xF5, 0x01], [0, 6]),
+    ([0x49, 0xF5, 0x01], [0x4A, 0xF5, 0x01], [0, 7]),
+    ([0x4B, 0xF5, 0x01], [0x4E, 0xF5, 0x01], [1, 0]),
+    ([0x50, 0xF5, 0x01], [0x5B, 0xF5, 0x01], [0, 6]),
+    ([0x5C, 0xF5, 0x01], [0x67, 0xF5, 0x01], [0, 7]),
+    ([0x6F, 0xF5, 0x01], [0x70, 0xF5, 0x01], [0, 7]),
+    ([0x73, 0xF5, 0x01], [0x79, 0xF5, 0x01], [0, 7]),
+    ([0x7A, 0xF5, 0x01], [0x7A, 0xF5, 0x01], [3, 0]),
+    ([0x87, 0xF5, 0x01], [0x87, 0xF5, 0x01], [0, 7]),
+    ([0x8A, 0xF5, 0x01], [0x8D, 0xF5, 0x01], [0, 7]),
+    ([0x90, 0xF5, 0x01], [0x90, 0xF5, 0x01], [0, 7]),
+    ([0x95, 0xF5, 0x01], [0x96, 0xF5, 0x01], [1, 0]),
+    ([0xA4, 0xF5, 0x01], [0xA4, 0xF5, 0x01], [3, 0]),
+    ([0xA5, 0xF5, 0x01], [0xA5, 0xF5, 0x01], [0, 7]),
+    ([0xA8, 0xF5, 0x01], [0xA8, 0xF5, 0x01], [0, 7]),
+    ([0xB1, 0xF5, 0x01], [0xB2, 0xF5, 0x01], [0, 7]),
+    ([0xBC, 0xF5, 0x01], [0xBC, 0xF5, 0x01], [0, 7]),
+    ([0xC2, 0xF5, 0x01], [0xC4, 0xF5, 0x01], [0, 7]),
+    ([0xD1, 0xF5, 0x01], [0xD3, 0xF5, 0x01], [0, 7]),
+    ([0xDC, 0xF5, 0x01], [0xDE, 0xF5, 0x01], [0, 7]),
+    ([0xE1, 0xF5, 0x01], [0xE1, 0xF5, 0x01], [0, 7]),
+    ([0xE3, 0xF5, 0x01], [0xE3, 0xF5, 0x01], [0, 7]),
+    ([0xE8, 0xF5, 0x01], [0xE8, 0xF5, 0x01], [2, 0]),
+    ([0xEF, 0xF5, 0x01], [0xEF, 0xF5, 0x01], [0, 7]),
+    ([0xF3, 0xF5, 0x01], [0xF3, 0xF5, 0x01], [0, 7]),
+    ([0xFA, 0xF5, 0x01], [0xFA, 0xF5, 0x01], [0, 7]),
+    ([0xFB, 0xF5, 0x01], [0xFF, 0xF5, 0x01], [0, 6]),
+    ([0x00, 0xF6, 0x01], [0x00, 0xF6, 0x01], [1, 0]),
+    ([0x01, 0xF6, 0x01], [0x06, 0xF6, 0x01], [0, 6]),
+    ([0x07, 0xF6, 0x01], [0x08, 0xF6, 0x01], [1, 0]),
+    ([0x09, 0xF6, 0x01], [0x0D, 0xF6, 0x01], [0, 6]),
+    ([0x0E, 0xF6, 0x01], [0x0E, 0xF6, 0x01], [1, 0]),
+    ([0x0F, 0xF6, 0x01], [0x0F, 0xF6, 0x01], [0, 6]),
+    ([0x10, 0xF6, 0x01], [0x10, 0xF6, 0x01], [0, 7]),
+    ([0x11, 0xF6, 0x01], [0x11, 0xF6, 0x01], [1, 0]),
+    ([0x12, 0xF6, 0x01], [0x14, 0xF6, 0x01], [0, 6]),
+    ([0x15, 0xF6, 0x01], [0x15, 0xF6, 0x01], [1, 0]),
+    ([0x16, 0xF6, 0x01], [0x16, 0xF6, 0x01], [0, 6]),
+    ([0x17, 0xF6, 0x01], [0x17, 0xF6, 0x01], [1, 0]),
+    ([0x18, 0xF6, 0x01], [0x18, 0xF6, 0x01], [0, 6]),
+    ([0x19, 0xF6, 0x01], [0x19, 0xF6, 0x01], [1, 0]),
+    ([0x1A, 0xF6, 0x01], [0x1A, 0xF6, 0x01], [0, 6]),
+    ([0x1B, 0xF6, 0x01], [0x1B, 0xF6, 0x01], [1, 0]),
+    ([0x1C, 0xF6, 0x01], [0x1E, 0xF6, 0x01], [0, 6]),
+    ([0x1F, 0xF6, 0x01], [0x1F, 0xF6, 0x01], [1, 0]),
+    ([0x20, 0xF6, 0x01], [0x25, 0xF6, 0x01], [0, 6]),
+    ([0x26, 0xF6, 0x01], [0x27, 0xF6, 0x01], [1, 0]),
+    ([0x28, 0xF6, 0x01], [0x2B, 0xF6, 0x01], [0, 6]),
+    ([0x2C, 0xF6, 0x01], [0x2C, 0xF6, 0x01], [1, 0]),
+    ([0x2D, 0xF6, 0x01], [0x2D, 0xF6, 0x01], [0, 6]),
+    ([0x2E, 0xF6, 0x01], [0x2F, 0xF6, 0x01], [1, 0]),
+    ([0x30, 0xF6, 0x01], [0x33, 0xF6, 0x01], [0, 6]),
+    ([0x34, 0xF6, 0x01], [0x34, 0xF6, 0x01], [1, 0]),
+    ([0x35, 0xF6, 0x01], [0x35, 0xF6, 0x01], [0, 6]),
+    ([0x36, 0xF6, 0x01], [0x36, 0xF6, 0x01], [1, 0]),
+    ([0x37, 0xF6, 0x01], [0x40, 0xF6, 0x01], [0, 6]),
+    ([0x41, 0xF6, 0x01], [0x44, 0xF6, 0x01], [1, 0]),
+    ([0x45, 0xF6, 0x01], [0x4F, 0xF6, 0x01], [0, 6]),
+    ([0x80, 0xF6, 0x01], [0x80, 0xF6, 0x01], [0, 6]),
+    ([0x81, 0xF6, 0x01], [0x82, 0xF6, 0x01], [1, 0]),
+    ([0x83, 0xF6, 0x01], [0x85, 0xF6, 0x01], [0, 6]),
+    ([0x86, 0xF6, 0x01], [0x86, 0xF6, 0x01], [1, 0]),
+    ([0x87, 0xF6, 0x01], [0x87, 0xF6, 0x01], [0, 6]),
+    ([0x88, 0xF6, 0x01], [0x88, 0xF6, 0x01], [1, 0]),
+    ([0x89, 0xF6, 0x01], [0x89, 0xF6, 0x01], [0, 6]),
+    ([0x8A, 0xF6, 0x01], [0x8B, 0xF6, 0x01], [1, 0]),
+    ([0x8C, 0xF6, 0x01], [0x8C, 0xF6, 0x01], [0, 6]),
+    ([0x8D, 0xF6, 0x01], [0x8D, 0xF6, 0x01], [0, 7]),
+    ([0x8E, 0xF6, 0x01], [0x8E, 0xF6, 0x01], [1, 0]),
+    ([0x8F, 0xF6, 0x01], [0x8F, 0xF6, 0x01], [0, 6]),
+    ([0x90, 0xF6, 0x01], [0x90, 0xF6, 0x01], [1, 0]),
+    ([0x91, 0xF6, 0x01], [0x93, 0xF6, 0x01], [0, 6]),
+    ([0x94, 0xF6, 0x01], [0x94, 0xF6, 0x01], [0, 7]),
+    ([0x95, 0xF6, 0x01], [0x95, 0xF6, 0x01], [0, 6]),
+    ([0x96, 0xF6, 0x01], [0x96, 0xF6, 0x01], [1, 0]),
+    ([0x97, 0xF6, 0x01], [0x97, 0xF6, 0x01], [0, 6]),
+    ([0x98, 0xF6, 0x01], [0x98, 0xF6, 0x01], [0, 7]),
+    ([0x99, 0xF6, 0x01], [0x9A, 0xF6, 0x01], [0, 6]),
+    ([0x9B, 0xF6, 0x01], [0xA1, 0xF6, 0x01], [1, 0]),
+    ([0xA2, 0xF6, 0x01], [0xA2, 0xF6, 0x01], [0, 6]),
+    ([0xA3, 0xF6, 0x01], [0xA3, 0xF6, 0x01], [1, 0]),
+    ([0xA4, 0xF6, 0x01], [0xA5, 0xF6, 0x01], [0, 6]),
+    ([0xA6, 0xF6, 0x01], [0xA6, 0xF6, 0x01], [1, 0]),
+    ([0xA7, 0xF6, 0x01], [0xAD, 0xF6, 0x01], [0, 6]),
+    ([0xAE, 0xF6, 0x01], [0xB1, 0xF6, 0x01], [1, 0]),
+    ([0xB2, 0xF6, 0x01], [0xB2, 0xF6, 0x01], [0, 6]),
+    ([0xB3, 0xF6, 0x01], [0xB5, 0xF6, 0x01], [1, 0]),
+    ([0xB6, 0xF6, 0x01], [0xB6, 0xF6, 0x01], [0, 6]),
+    ([0xB7, 0xF6, 0x01], [0xB8, 0xF6, 0x01], [1, 0]),
+    ([0xB9, 0xF6, 0x01], [0xBE, 0xF6, 0x01], [0, 6]),
+    ([0xBF, 0xF6, 0x01], [0xBF, 0xF6, 0x01], [1, 0]),
+    ([0xC0, 0xF6, 0x01], [0xC0, 0xF6, 0x01], [0, 6]),
+    ([0xC1, 0xF6, 0x01], [0xC5, 0xF6, 0x01], [1, 0]),
+    ([0xCB, 0xF6, 0x01], [0xCB, 0xF6, 0x01], [0, 7]),
+    ([0xCC, 0xF6, 0x01], [0xCC, 0xF6, 0x01], [1, 0]),
+    ([0xCD, 0xF6, 0x01], [0xCF, 0xF6, 0x01], [0, 7]),
+    ([0xD0, 0xF6, 0x01], [0xD0, 0xF6, 0x01], [1, 0]),
+    ([0xD1, 0xF6, 0x01], [0xD2, 0xF6, 0x01], [3, 0]),
+    ([0xD5, 0xF6, 0x01], [0xD5, 0xF6, 0x01], [12, 0]),
+    ([0xD6, 0xF6, 0x01], [0xD7, 0xF6, 0x01], [13, 0]),
+    ([0xD8, 0xF6, 0x01], [0xD8, 0xF6, 0x01], [17, 0]),
+    ([0xDC, 0xF6, 0x01], [0xDC, 0xF6, 0x01], [15, 0]),
+    ([0xDD, 0xF6, 0x01], [0xDF, 0xF6, 0x01], [14, 0]),
+    ([0xE0, 0xF6, 0x01], [0xE5, 0xF6, 0x01], [0, 7]),
+    ([0xE9, 0xF6, 0x01], [0xE9, 0xF6, 0x01], [0, 7]),
+    ([0xEB, 0xF6, 0x01], [0xEC, 0xF6, 0x01], [1, 0]),
+    ([0xF0, 0xF6, 0x01], [0xF0, 0xF6, 0x01], [0, 7]),
+    ([0xF3, 0xF6, 0x01], [0xF3, 0xF6, 0x01], [0, 7]),
+    ([0xF4, 0xF6, 0x01], [0xF6, 0xF6, 0x01], [3, 0]),
+    ([0xF7, 0xF6, 0x01], [0xF8, 0xF6, 0x01], [5, 0]),
+    ([0xF9, 0xF6, 0x01], [0xF9, 0xF6, 0x01], [11, 0]),
+    ([0xFA, 0xF6, 0x01], [0xFA, 0xF6, 0x01], [12, 0]),
+    ([0xFB, 0xF6, 0x01], [0xFC, 0xF6, 0x01], [13, 0]),
+    ([0xE0, 0xF7, 0x01], [0xEB, 0xF7, 0x01], [12, 0]),
+    ([0xF0, 0xF7, 0x01], [0xF0, 0xF7, 0x01], [14, 0]),
+    ([0x0C, 0xF9, 0x01], [0x0C, 0xF9, 0x01], [13, 0]),
+    ([0x0D, 0xF9, 0x01], [0x0F, 0xF9, 0x01], [12, 0]),
+    ([0x10, 0xF9, 0x01], [0x18, 0xF9, 0x01], [1, 0]),
+    ([0x19, 0xF9, 0x01], [0x1E, 0xF9, 0x01], [3, 0]),
+    ([0x1F, 0xF9, 0x01], [0x1F, 0xF9, 0x01], [5, 0]),
+    ([0x20, 0xF9, 0x01], [0x27, 0xF9, 0x01], [3, 0]),
+    ([0x28, 0xF9, 0x01], [0x2F, 0xF9, 0x01], [5, 0]),
+    ([0x30, 0xF9, 0x01], [0x30, 0xF9, 0x01], [3, 0]),
+    ([0x31, 0xF9, 0x01], [0x32, 0xF9, 0x01], [5, 0]),
+    ([0x33, 0xF9, 0x01], [0x3A, 0xF9, 0x01], [3, 0]),
+    ([0x3C, 0xF9, 0x01], [0x3E, 0xF9, 0x01], [3, 0]),
+    ([0x3F, 0xF9, 0x01], [0x3F, 0xF9, 0x01], [12, 0]),
+    ([0x40, 0xF9, 0x01], [0x45, 0xF9, 0x01], [3, 0]),
+    ([0x47, 0xF9, 0x01], [0x4B, 0xF9, 0x01], [3, 0]),
+    ([0x4C, 0xF9, 0x01], [0x4C, 0xF9, 0x01], [5, 0]),
+    ([0x4D, 0xF9, 0x01], [0x4F, 0xF9, 0x01], [11, 0]),
+    ([0x50, 0xF9, 0x01], [0x5E, 0xF9, 0x01], [3, 0]),
+    ([0x5F, 0xF9, 0x01], [0x6B, 0xF9, 0x01], [5, 0]),
+    ([0x6C, 0xF9, 0x01], [0x70, 0xF9, 0x01], [11, 0]),
+    ([0x71, 0xF9, 0x01], [0x71, 0xF9, 0x01], [12, 0]),
+    ([0x72, 0xF9, 0x01], [0x72, 0xF9, 0x01], [13, 0]),
+    ([0x73, 0xF9, 0x01], [0x76, 0xF9, 0x01], [11, 0]),
+    ([0x77, 0xF9, 0x01], [0x78, 0xF9, 0x01], [13, 0]),
+    ([0x79, 0xF9, 0x01], [0x79, 0xF9, 0x01], [14, 0]),
+    ([0x7A, 0xF9, 0x01], [0x7A, 0xF9, 0x01], [11, 0]),
+    ([0x7B, 0xF9, 0x01], [0x7B, 0xF9, 0x01], [12, 0]),
+    ([0x7C, 0xF9, 0x01], [0x7F, 0xF9, 0x01], [11, 0]),
+    ([0x80, 0xF9, 0x01], [0x84, 0xF9, 0x01], [1, 0]),
+    ([0x85, 0xF9, 0x01], [0x91, 0xF9, 0x01], [3, 0]),
+    ([0x92, 0xF9, 0x01], [0x97, 0xF9, 0x01], [5, 0]),
+    ([0x98, 0xF9, 0x01], [0xA2, 0xF9, 0x01], [11, 0]),
+    ([0xA3, 0xF9, 0x01], [0xA4, 0xF9, 0x01], [13, 0]),
+    ([0xA5, 0xF9, 0x01], [0xAA, 0xF9, 0x01], [12, 0]),
+    ([0xAB, 0xF9, 0x01], [0xAD, 0xF9, 0x01], [13, 0]),
+    ([0xAE, 0xF9, 0x01], [0xAF, 0xF9, 0x01], [12, 0]),
+    ([0xB0, 0xF9, 0x01], [0xB9, 0xF9, 0x01], [11, 0]),
+    ([0xBA, 0xF9, 0x01], [0xBF, 0xF9, 0x01], [12, 0]),
+    ([0xC0, 0xF9, 0x01], [0xC0, 0xF9, 0x01], [1, 0]),
+    ([0xC1, 0xF9, 0x01], [0xC2, 0xF9, 0x01], [11, 0]),
+    ([0xC3, 0xF9, 0x01], [0xCA, 0xF9, 0x01], [12, 0]),
+    ([0xCB, 0xF9, 0x01], [0xCB, 0xF9, 0x01], [13, 0]),
+    ([0xCC, 0xF9, 0x01], [0xCC, 0xF9, 0x01], [14, 0]),
+    ([0xCD, 0xF9, 0x01], [0xCF, 0xF9, 0x01], [12, 0]),
+    ([0xD0, 0xF9, 0x01], [0xE6, 0xF9, 0x01], [5, 0]),
+    ([0xE7, 0xF9, 0x01], [0xFF, 0xF9, 0x01], [11, 0]),
+    ([0x70, 0xFA, 0x01], [0x73, 0xFA, 0x01], [12, 0]),
+    ([0x74, 0xFA, 0x01], [0x74, 0xFA, 0x01], [13, 0]),
+    ([0x75, 0xFA, 0x01], [0x77, 0xFA, 0x01], [15, 0]),
+    ([0x78, 0xFA, 0x01], [0x7A, 0xFA, 0x01], [12, 0]),
+    ([0x7B, 0xFA, 0x01], [0x7C, 0xFA, 0x01], [14, 0]),
+    ([0x80, 0xFA, 0x01], [0x82, 0xFA, 0x01], [12, 0]),
+    ([0x83, 0xFA, 0x01], [0x86, 0xFA, 0x01], [13, 0]),
+    ([0x87, 0xFA, 0x01], [0x88, 0xFA, 0x01], [15, 0]),
+    ([0x89, 0xFA, 0x01], [0x89, 0xFA, 0x01], [16, 0]),
+    ([0x8A, 0xFA, 0x01], [0x8A, 0xFA, 0x01], [17, 0]),
+    ([0x8E, 0xFA, 0x01], [0x8E, 0xFA, 0x01], [17, 0]),
+    ([0x8F, 0xFA, 0x01], [0x8F, 0xFA, 0x01], [16, 0]),
+    ([0x90, 0xFA, 0x01], [0x95, 0xFA, 0x01], [12, 0]),
+    ([0x96, 0xFA, 0x01], [0xA8, 0xFA, 0x01], [13, 0]),
+    ([0xA9, 0xFA, 0x01], [0xAC, 0xFA, 0x01], [14, 0]),
+    ([0xAD, 0xFA, 0x01], [0xAF, 0xFA, 0x01], [15, 0]),
+    ([0xB0, 0xFA, 0x01], [0xB6, 0xFA, 0x01], [13, 0]),
+    ([0xB7, 0xFA, 0x01], [0xBA, 0xFA, 0x01], [14, 0]),
+    ([0xBB, 0xFA, 0x01], [0xBD, 0xFA, 0x01], [15, 0]),
+    ([0xBE, 0xFA, 0x01], [0xBE, 0xFA, 0x01], [16, 0]),
+    ([0xBF, 0xFA, 0x01], [0xBF, 0xFA, 0x01], [15, 0]),
+    ([0xC0, 0xFA, 0x01], [0xC2, 0xFA, 0x01], [13, 0]),
+    ([0xC3, 0xFA, 0x01], [0xC5, 0xFA, 0x01], [14, 0]),
+    ([0xC6, 0xFA, 0x01], [0xC6, 0xFA, 0x01], [16, 0]),
+    ([0xC8, 0xFA, 0x01], [0xC8, 0xFA, 0x01], [17, 0]),
+    ([0xCD, 0xFA, 0x01], [0xCD, 0xFA, 0x01], [17, 0]),
+    ([0xCE, 0xFA, 0x01], [0xCF, 0xFA, 0x01], [15, 0]),
+    ([0xD0, 0xFA, 0x01], [0xD6, 0xFA, 0x01], [13, 0]),
+    ([0xD7, 0xFA, 0x01], [0xD9, 0xFA, 0x01], [14, 0]),
+    ([0xDA, 0xFA, 0x01], [0xDB, 0xFA, 0x01], [15, 0]),
+    ([0xDC, 0xFA, 0x01], [0xDC, 0xFA, 0x01], [16, 0]),
+    ([0xDF, 0xFA, 0x01], [0xDF, 0xFA, 0x01], [16, 0]),
+    ([0xE0, 0xFA, 0x01], [0xE7, 0xFA, 0x01], [14, 0]),
+    ([0xE8, 0xFA, 0x01], [0xE8, 0xFA, 0x01], [15, 0]),
+    ([0xE9, 0xFA, 0x01], [0xE9, 0xFA, 0x01], [16, 0]),
+    ([0xEA, 0xFA, 0x01], [0xEA, 0xFA, 0x01], [17, 0]),
+    ([0xEF, 0xFA, 0x01], [0xEF, 0xFA, 0x01], [17, 0]),
+    ([0xF0, 0xFA, 0x01], [0xF6, 0xFA, 0x01], [14, 0]),
+    ([0xF7, 0xFA, 0x01], [0xF8, 0xFA, 0x01], [15, 0]),
+];
+
+/// Sorted list of emoji ZWJ sequences, without variation selectors and emoji modifiers,
+/// each with the emoji versions, as `[major, minor]`, in which it was introduced
+/// without and with emoji modifiers.
+#[rustfmt::skip]
+static ZWJ_SEQUENCE_VERSIONS: [(&str, [u8; 2], [u8; 2]); 261] = [
+    ("\u{26D3}\u{200D}\u{1F4A5}", [15, 1], [15, 1]),
+    ("\u{26F9}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{26F9}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{2764}\u{200D}\u{1F525}", [13, 1], [13, 1]),
+    ("\u{2764}\u{200D}\u{1FA79}", [13, 1], [13, 1]),
+    ("\u{1F344}\u{200D}\u{1F7EB}", [15, 1], [15, 1]),
+    ("\u{1F34B}\u{200D}\u{1F7E9}", [15, 1], [15, 1]),
+    ("\u{1F3C3}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F3C3}\u{200D}\u{2640}\u{200D}\u{27A1}", [15, 1], [15, 1]),
+    ("\u{1F3C3}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F3C3}\u{200D}\u{2642}\u{200D}\u{27A1}", [15, 1], [15, 1]),
+    ("\u{1F3C3}\u{200D}\u{27A1}", [15, 1], [15, 1]),
+    ("\u{1F3C4}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F3C4}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F3CA}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F3CA}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F3CB}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F3CB}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F3CC}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F3CC}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F3F3}\u{200D}\u{26A7}", [13, 0], [13, 0]),
+    ("\u{1F3F3}\u{200D}\u{1F308}", [4, 0], [4, 0]),
+    ("\u{1F3F4}\u{200D}\u{2620}", [11, 0], [11, 0]),
+    ("\u{1F408}\u{200D}\u{2B1B}", [13, 0], [13, 0]),
+    ("\u{1F415}\u{200D}\u{1F9BA}", [12, 0], [12, 0]),
+    ("\u{1F426}\u{200D}\u{2B1B}", [15, 0], [15, 0]),
+    ("\u{1F426}\u{200D}\u{1F525}", [15, 1], [15, 1]),
+    ("\u{1F43B}\u{200D}\u{2744}", [13, 0], [13, 0]),
+    ("\u{1F441}\u{200D}\u{1F5E8}", [2, 0], [2, 0]),
+    ("\u{1F468}\u{200D}\u{2695}", [4, 0], [4, 0]),
+    ("\u{1F468}\u{200D}\u{2696}", [4, 0], [4, 0]),
+    ("\u{1F468}\u{200D}\u{2708}", [4, 0], [4, 0]),
+    ("\u{1F468}\u{200D}\u{2764}\u{200D}\u{1F468}", [2, 0], [13, 1]),
+    ("\u{1F468}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}", [2, 0], [13, 1]),
+    ("\u{1F468}\u{200D}\u{1F33E}", [4, 0], [4, 0]),
+    ("\u{1F468}\u{200D}\u{1F373}", [4, 0], [4, 0]),
+    ("\u{1F468}\u{200D}\u{1F37C}", [13, 0], [13, 0]),
+    ("\u{1F468}\u{200D}\u{1F393}", [4, 0], [4, 0]),
+    ("\u{1F468}\u{200D}\u{1F3A4}", [4, 0], [4, 0]),
+    ("\u{1F468}\u{200D}\u{1F3A8}", [4, 0], [4, 0]),
+    ("\u{1F468}\u{200D}\u{1F3EB}", [4, 0], [4, 0]),
+    ("\u{1F468}\u{200D}\u{1F3ED}", [4, 0], [4, 0]),
+    ("\u{1F468}\u{200D}\u{1F430}\u{200D}\u{1F468}", [17, 0], [17, 0]),
+    ("\u{1F468}\u{200D}\u{1F466}", [4, 0], [4, 0]),
+    ("\u{1F468}\u{200D}\u{1F466}\u{200D}\u{1F466}", [4, 0], [4, 0]),
+    ("\u{1F468}\u{200D}\u{1F467}", [4, 0], [4, 0]),
+    ("\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F466}", [4, 0], [4, 0]),
+    ("\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F467}", [4, 0], [4, 0]),
+    ("\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F466}", [2, 0], [2, 0]),
+    ("\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F466}\u{200D}\u{1F466}", [2, 0], [2, 0]),
+    ("\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F467}", [2, 0], [2, 0]),
+    ("\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F466}", [2, 0], [2, 0]),
+    ("\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F467}", [2, 0], [2, 0]),
+    ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F466}", [2, 0], [2, 0]),
+    ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F466}\u{200D}\u{1F466}", [2, 0], [2, 0]),
+    ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", [2, 0], [2, 0]),
+    ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}", [2, 0], [2, 0]),
+    ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F467}", [2, 0], [2, 0]),
+    ("\u{1F468}\u{200D}\u{1F4BB}", [4, 0], [4, 0]),
+    ("\u{1F468}\u{200D}\u{1F4BC}", [4, 0], [4, 0]),
+    ("\u{1F468}\u{200D}\u{1F527}", [4, 0], [4, 0]),
+    ("\u{1F468}\u{200D}\u{1F52C}", [4, 0], [4, 0]),
+    ("\u{1F468}\u{200D}\u{1F680}", [4, 0], [4, 0]),
+    ("\u{1F468}\u{200D}\u{1F692}", [4, 0], [4, 0]),
+    ("\u{1F468}\u{200D}\u{1F91D}\u{200D}\u{1F468}", [12, 0], [12, 0]),
+    ("\u{1F468}\u{200D}\u{1F9AF}", [12, 0], [12, 0]),
+    ("\u{1F468}\u{200D}\u{1F9AF}\u{200D}\u{27A1}", [15, 1], [15, 1]),
+    ("\u{1F468}\u{200D}\u{1F9B0}", [11, 0], [11, 0]),
+    ("\u{1F468}\u{200D}\u{1F9B1}", [11, 0], [11, 0]),
+    ("\u{1F468}\u{200D}\u{1F9B2}", [11, 0], [11, 0]),
+    ("\u{1F468}\u{200D}\u{1F9B3}", [11, 0], [11, 0]),
+    ("\u{1F468}\u{200D}\u{1F9BC}", [12, 0], [12, 0]),
+    ("\u{1F468}\u{200D}\u{1F9BC}\u{200D}\u{27A1}", [15, 1], [15, 1]),
+    ("\u{1F468}\u{200D}\u{1F9BD}", [12, 0], [12, 0]),
+    ("\u{1F468}\u{200D}\u{1F9BD}\u{200D}\u{27A1}", [15, 1], [15, 1]),
+    ("\u{1F468}\u{200D}\u{1FAEF}\u{200D}\u{1F468}", [17, 0], [17, 0]),
+    ("\u{1F469}\u{200D}\u{2695}", [4, 0], [4, 0]),
+    ("\u{1F469}\u{200D}\u{2696}", [4, 0], [4, 0]),
+    ("\u{1F469}\u{200D}\u{2708}", [4, 0], [4, 0]),
+    ("\u{1F469}\u{200D}\u{2764}\u{200D}\u{1F468}", [2, 0], [13, 1]),
+    ("\u{1F469}\u{200D}\u{2764}\u{200D}\u{1F469}", [2, 0], [13, 1]),
+    ("\u{1F469}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}", [2, 0], [13, 1]),
+    ("\u{1F469}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}", [2, 0], [13, 1]),
+    ("\u{1F469}\u{200D}\u{1F33E}", [4, 0], [4, 0]),
+    ("\u{1F469}\u{200D}\u{1F373}", [4, 0], [4, 0]),
+    ("\u{1F469}\u{200D}\u{1F37C}", [13, 0], [13, 0]),
+    ("\u{1F469}\u{200D}\u{1F393}", [4, 0], [4, 0]),
+    ("\u{1F469}\u{200D}\u{1F3A4}", [4, 0], [4, 0]),
+    ("\u{1F469}\u{200D}\u{1F3A8}", [4, 0], [4, 0]),
+    ("\u{1F469}\u{200D}\u{1F3EB}", [4, 0], [4, 0]),
+    ("\u{1F469}\u{200D}\u{1F3ED}", [4, 0], [4, 0]),
+    ("\u{1F469}\u{200D}\u{1F430}\u{200D}\u{1F469}", [17, 0], [17, 0]),
+    ("\u{1F469}\u{200D}\u{1F466}", [4, 0], [4, 0]),
+    ("\u{1F469}\u{200D}\u{1F466}\u{200D}\u{1F466}", [4, 0], [4, 0]),
+    ("\u{1F469}\u{200D}\u{1F467}", [4, 0], [4, 0]),
+    ("\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}", [4, 0], [4, 0]),
+    ("\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F467}", [4, 0], [4, 0]),
+    ("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F466}", [2, 0], [2, 0]),
+    ("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F466}\u{200D}\u{1F466}", [2, 0], [2, 0]),
+    ("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}", [2, 0], [2, 0]),
+    ("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}", [2, 0], [2, 0]),
+    ("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F467}", [2, 0], [2, 0]),
+    ("\u{1F469}\u{200D}\u{1F4BB}", [4, 0], [4, 0]),
+    ("\u{1F469}\u{200D}\u{1F4BC}", [4, 0], [4, 0]),
+    ("\u{1F469}\u{200D}\u{1F527}", [4, 0], [4, 0]),
+    ("\u{1F469}\u{200D}\u{1F52C}", [4, 0], [4, 0]),
+    ("\u{1F469}\u{200D}\u{1F680}", [4, 0], [4, 0]),
+    ("\u{1F469}\u{200D}\u{1F692}", [4, 0], [4, 0]),
+    ("\u{1F469}\u{200D}\u{1F91D}\u{200D}\u{1F468}", [12, 0], [12, 0]),
+    ("\u{1F469}\u{200D}\u{1F91D}\u{200D}\u{1F469}", [12, 0], [12, 0]),
+    ("\u{1F469}\u{200D}\u{1F9AF}", [12, 0], [12, 0]),
+    ("\u{1F469}\u{200D}\u{1F9AF}\u{200D}\u{27A1}", [15, 1], [15, 1]),
+    ("\u{1F469}\u{200D}\u{1F9B0}", [11, 0], [11, 0]),
+    ("\u{1F469}\u{200D}\u{1F9B1}", [11, 0], [11, 0]),
+    ("\u{1F469}\u{200D}\u{1F9B2}", [11, 0], [11, 0]),
+    ("\u{1F469}\u{200D}\u{1F9B3}", [11, 0], [11, 0]),
+    ("\u{1F469}\u{200D}\u{1F9BC}", [12, 0], [12, 0]),
+    ("\u{1F469}\u{200D}\u{1F9BC}\u{200D}\u{27A1}", [15, 1], [15, 1]),
+    ("\u{1F469}\u{200D}\u{1F9BD}", [12, 0], [12, 0]),
+    ("\u{1F469}\u{200D}\u{1F9BD}\u{200D}\u{27A1}", [15, 1], [15, 1]),
+    ("\u{1F469}\u{200D}\u{1FAEF}\u{200D}\u{1F469}", [17, 0], [17, 0]),
+    ("\u{1F46E}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F46E}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F46F}\u{200D}\u{2640}", [4, 0], [17, 0]),
+    ("\u{1F46F}\u{200D}\u{2642}", [4, 0], [17, 0]),
+    ("\u{1F470}\u{200D}\u{2640}", [13, 0], [13, 0]),
+    ("\u{1F470}\u{200D}\u{2642}", [13, 0], [13, 0]),
+    ("\u{1F471}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F471}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F473}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F473}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F477}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F477}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F481}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F481}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F482}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F482}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F486}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F486}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F487}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F487}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F575}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F575}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F62E}\u{200D}\u{1F4A8}", [13, 1], [13, 1]),
+    ("\u{1F635}\u{200D}\u{1F4AB}", [13, 1], [13, 1]),
+    ("\u{1F636}\u{200D}\u{1F32B}", [13, 1], [13, 1]),
+    ("\u{1F642}\u{200D}\u{2194}", [15, 1], [15, 1]),
+    ("\u{1F642}\u{200D}\u{2195}", [15, 1], [15, 1]),
+    ("\u{1F645}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F645}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F646}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F646}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F647}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F647}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F64B}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F64B}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F64D}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F64D}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F64E}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F64E}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F6A3}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F6A3}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F6B4}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F6B4}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F6B5}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F6B5}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F6B6}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F6B6}\u{200D}\u{2640}\u{200D}\u{27A1}", [15, 1], [15, 1]),
+    ("\u{1F6B6}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F6B6}\u{200D}\u{2642}\u{200D}\u{27A1}", [15, 1], [15, 1]),
+    ("\u{1F6B6}\u{200D}\u{27A1}", [15, 1], [15, 1]),
+    ("\u{1F926}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F926}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F935}\u{200D}\u{2640}", [13, 0], [13, 0]),
+    ("\u{1F935}\u{200D}\u{2642}", [13, 0], [13, 0]),
+    ("\u{1F937}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F937}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F938}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F938}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F939}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F939}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F93C}\u{200D}\u{2640}", [4, 0], [17, 0]),
+    ("\u{1F93C}\u{200D}\u{2642}", [4, 0], [17, 0]),
+    ("\u{1F93D}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F93D}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F93E}\u{200D}\u{2640}", [4, 0], [4, 0]),
+    ("\u{1F93E}\u{200D}\u{2642}", [4, 0], [4, 0]),
+    ("\u{1F9B8}\u{200D}\u{2640}", [11, 0], [11, 0]),
+    ("\u{1F9B8}\u{200D}\u{2642}", [11, 0], [11, 0]),
+    ("\u{1F9B9}\u{200D}\u{2640}", [11, 0], [11, 0]),
+    ("\u{1F9B9}\u{200D}\u{2642}", [11, 0], [11, 0]),
+    ("\u{1F9CD}\u{200D}\u{2640}", [12, 0], [12, 0]),
+    ("\u{1F9CD}\u{200D}\u{2642}", [12, 0], [12, 0]),
+    ("\u{1F9CE}\u{200D}\u{2640}", [12, 0], [12, 0]),
+    ("\u{1F9CE}\u{200D}\u{2640}\u{200D}\u{27A1}", [15, 1], [15, 1]),
+    ("\u{1F9CE}\u{200D}\u{2642}", [12, 0], [12, 0]),
+    ("\u{1F9CE}\u{200D}\u{2642}\u{200D}\u{27A1}", [15, 1], [15, 1]),
+    ("\u{1F9CE}\u{200D}\u{27A1}", [15, 1], [15, 1]),
+    ("\u{1F9CF}\u{200D}\u{2640}", [12, 0], [12, 0]),
+    ("\u{1F9CF}\u{200D}\u{2642}", [12, 0], [12, 0]),
+    ("\u{1F9D1}\u{200D}\u{2695}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{2696}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{2708}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}", [13, 1], [13, 1]),
+    ("\u{1F9D1}\u{200D}\u{2764}\u{200D}\u{1F9D1}", [13, 1], [13, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F33E}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F373}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F37C}", [13, 0], [13, 0]),
+    ("\u{1F9D1}\u{200D}\u{1F384}", [13, 0], [13, 0]),
+    ("\u{1F9D1}\u{200D}\u{1F393}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F3A4}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F3A8}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F3EB}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F3ED}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F430}\u{200D}\u{1F9D1}", [17, 0], [17, 0]),
+    ("\u{1F9D1}\u{200D}\u{1F4BB}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F4BC}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F527}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F52C}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F680}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F692}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}", [12, 0], [12, 0]),
+    ("\u{1F9D1}\u{200D}\u{1F9AF}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F9AF}\u{200D}\u{27A1}", [15, 1], [15, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F9B0}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F9B1}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F9B2}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F9B3}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F9BC}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F9BC}\u{200D}\u{27A1}", [15, 1], [15, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F9BD}", [12, 1], [12, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F9BD}\u{200D}\u{27A1}", [15, 1], [15, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F9D1}\u{200D}\u{1F9D2}", [15, 1], [15, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F9D1}\u{200D}\u{1F9D2}\u{200D}\u{1F9D2}", [15, 1], [15, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F9D2}", [15, 1], [15, 1]),
+    ("\u{1F9D1}\u{200D}\u{1F9D2}\u{200D}\u{1F9D2}", [15, 1], [15, 1]),
+    ("\u{1F9D1}\u{200D}\u{1FA70}", [17, 0], [17, 0]),
+    ("\u{1F9D1}\u{200D}\u{1FAEF}\u{200D}\u{1F9D1}", [17, 0], [17, 0]),
+    ("\u{1F9D4}\u{200D}\u{2640}", [13, 1], [13, 1]),
+    ("\u{1F9D4}\u{200D}\u{2642}", [13, 1], [13, 1]),
+    ("\u{1F9D6}\u{200D}\u{2640}", [5, 0], [5, 0]),
+    ("\u{1F9D6}\u{200D}\u{2642}", [5, 0], [5, 0]),
+    ("\u{1F9D7}\u{200D}\u{2640}", [5, 0], [5, 0]),
+    ("\u{1F9D7}\u{200D}\u{2642}", [5, 0], [5, 0]),
+    ("\u{1F9D8}\u{200D}\u{2640}", [5, 0], [5, 0]),
+    ("\u{1F9D8}\u{200D}\u{2642}", [5, 0], [5, 0]),
+    ("\u{1F9D9}\u{200D}\u{2640}", [5, 0], [5, 0]),
+    ("\u{1F9D9}\u{200D}\u{2642}", [5, 0], [5, 0]),
+    ("\u{1F9DA}\u{200D}\u{2640}", [5, 0], [5, 0]),
+    ("\u{1F9DA}\u{200D}\u{2642}", [5, 0], [5, 0]),
+    ("\u{1F9DB}\u{200D}\u{2640}", [5, 0], [5, 0]),
+    ("\u{1F9DB}\u{200D}\u{2642}", [5, 0], [5, 0]),
+    ("\u{1F9DC}\u{200D}\u{2640}", [5, 0], [5, 0]),
+    ("\u{1F9DC}\u{200D}\u{2642}", [5, 0], [5, 0]),
+    ("\u{1F9DD}\u{200D}\u{2640}", [5, 0], [5, 0]),
+    ("\u{1F9DD}\u{200D}\u{2642}", [5, 0], [5, 0]),
+    ("\u{1F9DE}\u{200D}\u{2640}", [5, 0], [5, 0]),
+    ("\u{1F9DE}\u{200D}\u{2642}", [5, 0], [5, 0]),
+    ("\u{1F9DF}\u{200D}\u{2640}", [5, 0], [5, 0]),
+    ("\u{1F9DF}\u{200D}\u{2642}", [5, 0], [5, 0]),
+    ("\u{1FAF1}\u{200D}\u{1FAF2}", [14, 0], [14, 0]),
+];
+
 /// Array of 1024-bit bitmaps. Index into the correct bitmap with the 10 LSB of your codepoint
 /// to get whether it can start an emoji presentation sequence.
 static EMOJI_PRESENTATION_LEAVES: Align128<[[u8; 128]; 7]> = Align128([
@@ -0,0 +1,158 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+
+use crate::width_tables::StateMachine;
+
+/// Where the tab stops are, for [`width_with_tabs`] and `expand_tabs`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TabStops<'a> {
+    /// A tab stop every this many columns. With `Every(0)`, tabs have no width.
+    Every(usize),
+    /// Tab stops at these columns, counting from 0, in increasing order.
+    /// Past the last one, a tab advances by a single column.
+    Explicit(&'a [usize]),
+}
+
+impl TabStops<'_> {
+    /// Returns the column that a tab at column `col` advances to.
+    fn next_stop(self, col: usize) -> usize {
+        match self {
+            TabStops::Every(0) => col,
+            TabStops::Every(n) => (col / n + 1) * n,
+            TabStops::Explicit(stops) => stops
+                .iter()
+                .copied()
+                .find(|&stop| stop > col)
+                .unwrap_or(col + 1),
+        }
+    }
+}
+
+/// Returns the displayed width of `s` in columns, with each `'\t'` advancing to the next
+/// tab stop instead of being 1 column wide.
+///
+/// The column of a tab counts the width of everything before it as measured by
+/// [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width), so wide and zero-width
+/// characters are accounted for. Columns start over after each `'\n'`, and the width
+/// of the whole string is the sum of its lines' widths, newlines included, as in
+/// [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+///
+/// Characters in the Ambiguous category are treated as 1 column wide.
+///
+/// ```rust
+/// use unicode_width::{width_with_tabs, TabStops};
+///
+/// assert_eq!(width_with_tabs("a\tb", TabStops::Every(4)), 5);
+/// assert_eq!(width_with_tabs("中\tb", TabStops::Every(4)), 5);
+/// assert_eq!(width_with_tabs("a\tb\tc", TabStops::Explicit(&[2, 10])), 11);
+/// ```
+pub fn width_with_tabs(s: &str, tab_stops: TabStops<'_>) -> usize {
+    width_with_tabs_with(StateMachine::DEFAULT, s, tab_stops)
+}
+
+/// Returns the displayed width of `s` in columns, with each `'\t'` advancing to the next
+/// tab stop instead of being 1 column wide.
+///
+/// This is [`width_with_tabs`], but with characters in the Ambiguous category
+/// treated as 2 columns wide, as in
+/// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+#[cfg(feature = "cjk")]
+pub fn width_with_tabs_cjk(s: &str, tab_stops: TabStops<'_>) -> usize {
+    width_with_tabs_with(StateMachine::CJK, s, tab_stops)
+}
+
+/// Replaces each `'\t'` in `s` with as many spaces as it takes to reach the next tab stop.
+///
+/// Tab stops are found as in [`width_with_tabs`], which returns the width of the result.
+/// `s` is returned unchanged if it has no tabs.
+///
+/// Characters in the Ambiguous category are treated as 1 column wide.
+///
+/// ```rust
+/// use unicode_width::{expand_tabs, TabStops};
+///
+/// assert_eq!(expand_tabs("a\tb\n中\tc", TabStops::Every(4)), "a   b\n中  c");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn expand_tabs<'a>(s: &'a str, tab_stops: TabStops<'_>) -> Cow<'a, str> {
+    expand_tabs_with(StateMachine::DEFAULT, s, tab_stops)
+}
+
+/// Replaces each `'\t'` in `s` with as many spaces as it takes to reach the next tab stop.
+///
+/// This is [`expand_tabs`], but with characters in the Ambiguous category
+/// treated as 2 columns wide, as in
+/// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+#[cfg(all(feature = "alloc", feature = "cjk"))]
+pub fn expand_tabs_cjk<'a>(s: &'a str, tab_stops: TabStops<'_>) -> Cow<'a, str> {
+    expand_tabs_with(StateMachine::CJK, s, tab_stops)
+}
+
+/// Calls `f` with each piece of `s` between tabs, and with the number of columns that
+/// each tab advances by.
+///
+/// A tab ends the state passed on to the character before it the same way the end of
+/// the string does, so each piece has the same width on its own as in `s`.
+fn for_each_piece<'a>(
+    machine: StateMachine<'_>,
+    s: &'a str,
+    tab_stops: TabStops<'_>,
+    mut f: impl FnMut(&'a str, usize, Option<usize>),
+) {
+    let mut col = 0;
+    let mut pieces = s.split('\t').peekable();
+    while let Some(piece) = pieces.next() {
+        let width = machine.str_width(piece);
+        col = match piece.rfind('\n') {
+            Some(i) => machine.str_width(&piece[i + 1..]),
+            None => col + width,
+        };
+        let tab = pieces.peek().map(|_| {
+            let stop = tab_stops.next_stop(col);
+            let advance = stop - col;
+            col = stop;
+            advance
+        });
+        f(piece, width, tab);
+    }
+}
+
+pub(crate) fn width_with_tabs_with(
+    machine: StateMachine<'_>,
+    s: &str,
+    tab_stops: TabStops<'_>,
+) -> usize {
+    let mut total = 0;
+    for_each_piece(machine, s, tab_stops, |_, width, tab| {
+        total += width + tab.unwrap_or(0);
+    });
+    total
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn expand_tabs_with<'a>(
+    machine: StateMachine<'_>,
+    s: &'a str,
+    tab_stops: TabStops<'_>,
+) -> Cow<'a, str> {
+    if !s.contains('\t') {
+        return Cow::Borrowed(s);
+    }
+
+    let mut expanded = String::with_capacity(s.len());
+    for_each_piece(machine, s, tab_stops, |piece, _, tab| {
+        expanded.push_str(piece);
+        expanded.extend(core::iter::repeat(' ').take(tab.unwrap_or(0)));
+    });
+    Cow::Owned(expanded)
+}
@@ -0,0 +1,193 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+use crate::split::{rsplit_at_width_with, split_at_width_with};
+use crate::width_tables::StateMachine;
+
+/// Where [`truncate_with`] cuts the string.
+#[derive(Clone, Copy)]
+pub(crate) enum Cut {
+    Start,
+    Middle,
+    End,
+}
+
+/// Shortens `s` to at most `max_width` columns by cutting off its end
+/// and putting `ellipsis` in its place.
+///
+/// `s` is returned unchanged if it already fits. Otherwise the result is the longest
+/// prefix of `s` that fits along with `ellipsis`, followed by `ellipsis`.
+/// The cut never falls inside an emoji sequence, a ligature or a wide character,
+/// so the result is never wider than `max_width`, even if that leaves a column unused.
+/// If `ellipsis` itself is wider than `max_width`, it is shortened in the same way.
+///
+/// Characters in the Ambiguous category, in `s` and in `ellipsis`, are treated as
+/// 1 column wide, as in [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+///
+/// ```rust
+/// use unicode_width::truncate_end;
+///
+/// assert_eq!(truncate_end("main", 8, "…"), "main");
+/// assert_eq!(truncate_end("feature/unicode-width", 8, "…"), "feature…");
+/// assert_eq!(truncate_end("日本語のテキスト", 8, "..."), "日本...");
+/// ```
+#[inline]
+pub fn truncate_end<'a>(s: &'a str, max_width: usize, ellipsis: &str) -> Cow<'a, str> {
+    truncate_with(StateMachine::DEFAULT, Cut::End, s, max_width, ellipsis)
+}
+
+/// Shortens `s` to at most `max_width` columns by cutting off its end
+/// and putting `ellipsis` in its place.
+///
+/// This is [`truncate_end`], but with characters in the Ambiguous category
+/// treated as 2 columns wide, as in
+/// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+#[cfg(feature = "cjk")]
+#[inline]
+pub fn truncate_end_cjk<'a>(s: &'a str, max_width: usize, ellipsis: &str) -> Cow<'a, str> {
+    truncate_with(StateMachine::CJK, Cut::End, s, max_width, ellipsis)
+}
+
+/// Shortens `s` to at most `max_width` columns by cutting off its start
+/// and putting `ellipsis` in its place.
+///
+/// The result is `ellipsis` followed by the longest suffix of `s` that fits along with it.
+/// Otherwise this works like [`truncate_end`].
+///
+/// Characters in the Ambiguous category, in `s` and in `ellipsis`, are treated as
+/// 1 column wide, as in [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+///
+/// ```rust
+/// use unicode_width::truncate_start;
+///
+/// assert_eq!(truncate_start("/home/user/src/main.rs", 12, "…"), "…src/main.rs");
+/// ```
+#[inline]
+pub fn truncate_start<'a>(s: &'a str, max_width: usize, ellipsis: &str) -> Cow<'a, str> {
+    truncate_with(StateMachine::DEFAULT, Cut::Start, s, max_width, ellipsis)
+}
+
+/// Shortens `s` to at most `max_width` columns by cutting off its start
+/// and putting `ellipsis` in its place.
+///
+/// This is [`truncate_start`], but with characters in the Ambiguous category
+/// treated as 2 columns wide, as in
+/// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+#[cfg(feature = "cjk")]
+#[inline]
+pub fn truncate_start_cjk<'a>(s: &'a str, max_width: usize, ellipsis: &str) -> Cow<'a, str> {
+    truncate_with(StateMachine::CJK, Cut::Start, s, max_width, ellipsis)
+}
+
+/// Shortens `s` to at most `max_width` columns by cutting out its middle
+/// and putting `ellipsis` in its place.
+///
+/// The result is a prefix of `s`, `ellipsis`, and a suffix of `s`.
+/// The columns left for `s` are split evenly between the two, with the prefix getting
+/// the odd one out. If the prefix can't use all of its columns, the suffix gets the rest.
+/// Otherwise this works like [`truncate_end`].
+///
+/// Characters in the Ambiguous category, in `s` and in `ellipsis`, are treated as
+/// 1 column wide, as in [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+///
+/// ```rust
+/// use unicode_width::truncate_middle;
+///
+/// assert_eq!(truncate_middle("abcdefghijkl", 7, "…"), "abc…jkl");
+/// assert_eq!(truncate_middle("日本語のテキスト", 9, "…"), "日本…スト");
+/// ```
+#[inline]
+pub fn truncate_middle<'a>(s: &'a str, max_width: usize, ellipsis: &str) -> Cow<'a, str> {
+    truncate_with(StateMachine::DEFAULT, Cut::Middle, s, max_width, ellipsis)
+}
+
+/// Shortens `s` to at most `max_width` columns by cutting out its middle
+/// and putting `ellipsis` in its place.
+///
+/// This is [`truncate_middle`], but with characters in the Ambiguous category
+/// treated as 2 columns wide, as in
+/// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+#[cfg(feature = "cjk")]
+#[inline]
+pub fn truncate_middle_cjk<'a>(s: &'a str, max_width: usize, ellipsis: &str) -> Cow<'a, str> {
+    truncate_with(StateMachine::CJK, Cut::Middle, s, max_width, ellipsis)
+}
+
+pub(crate) fn truncate_with<'a>(
+    machine: StateMachine<'_>,
+    cut: Cut,
+    s: &'a str,
+    max_width: usize,
+    ellipsis: &str,
+) -> Cow<'a, str> {
+    let width = machine.str_width(s);
+    if width <= max_width {
+        return Cow::Borrowed(s);
+    }
+
+    let (prefix_end, suffix_start, ellipsis) =
+        fit_ellipsis(machine, cut, s, width, max_width, ellipsis);
+    let (prefix, suffix) = (&s[..prefix_end], &s[suffix_start..]);
+    let mut truncated = String::with_capacity(prefix.len() + ellipsis.len() + suffix.len());
+    truncated.push_str(prefix);
+    truncated.push_str(ellipsis);
+    truncated.push_str(suffix);
+    Cow::Owned(truncated)
+}
+
+/// Picks what to keep of `s`, which is `width` columns wide, when `cut` puts
+/// `ellipsis` in the place of the rest, so that the result fits in `max_width`.
+///
+/// Returns the end of the prefix and the start of the suffix that are kept,
+/// as byte indices into `s`, along with the ellipsis, which is itself cut short
+/// if it doesn't fit on its own.
+pub(crate) fn fit_ellipsis<'e>(
+    machine: StateMachine<'_>,
+    cut: Cut,
+    s: &str,
+    width: usize,
+    max_width: usize,
+    ellipsis: &'e str,
+) -> (usize, usize, &'e str) {
+    let ellipsis_width = machine.str_width(ellipsis);
+    if ellipsis_width > max_width {
+        let (ellipsis, _, _) = split_at_width_with(machine, ellipsis_width, ellipsis, max_width);
+        return (0, s.len(), ellipsis);
+    }
+
+    // The ellipsis could join up with what's next to it,
+    // so keep shrinking the rest until the whole thing fits.
+    let mut cols = max_width - ellipsis_width;
+    let mut truncated = String::new();
+    loop {
+        let (prefix, suffix) = match cut {
+            Cut::Start => ("", rsplit_at_width_with(machine, s, cols).1),
+            Cut::Middle => {
+                let (prefix, rest, prefix_width) =
+                    split_at_width_with(machine, width, s, cols - cols / 2);
+                let (_, suffix, _) = rsplit_at_width_with(machine, rest, cols - prefix_width);
+                (prefix, suffix)
+            }
+            Cut::End => (split_at_width_with(machine, width, s, cols).0, ""),
+        };
+
+        truncated.clear();
+        truncated.push_str(prefix);
+        truncated.push_str(ellipsis);
+        truncated.push_str(suffix);
+        if cols == 0 || machine.str_width(&truncated) <= max_width {
+            return (prefix.len(), s.len() - suffix.len(), ellipsis);
+        }
+        cols -= 1;
+    }
+}
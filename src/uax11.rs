@@ -0,0 +1,115 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Character widths according to
+//! [Unicode Standard Annex #11](https://www.unicode.org/reports/tr11/) alone.
+//!
+//! Unlike [`UnicodeWidthChar::width`](crate::UnicodeWidthChar::width), this has none
+//! of the special cases in the [rules for determining width](crate#rules-for-determining-width)
+//! beyond the characters that have width 0. A character's width depends only on its
+//! [`East_Asian_Width`](EastAsianWidth) and on the [`Context`]:
+//!
+//! 1. Control characters have no width.
+//! 2. The characters listed as having width 0 in the rules for determining width,
+//!    with no exceptions, have width 0.
+//! 3. Characters with an `East_Asian_Width` of `Fullwidth` or `Wide` have width 2.
+//! 4. Characters with an `East_Asian_Width` of `Ambiguous` have width 2 in an East Asian
+//!    context, and width 1 otherwise.
+//! 5. All other characters have width 1.
+//!
+//! ```rust
+//! use unicode_width::uax11::{width, Context};
+//!
+//! assert_eq!(width('中', Context::NonEastAsian), Some(2));
+//! assert_eq!(width('\u{17D8}', Context::NonEastAsian), Some(1));
+//! assert_eq!(width('Æ', Context::EastAsian), Some(2));
+//! ```
+
+use crate::tables;
+
+/// The [`East_Asian_Width`](https://www.unicode.org/reports/tr11/#ED1) property
+/// of a character, which classifies it by how it is shown in East Asian typography.
+///
+/// ```rust
+/// use unicode_width::{east_asian_width, EastAsianWidth};
+///
+/// assert_eq!(east_asian_width('a'), EastAsianWidth::Narrow);
+/// assert_eq!(east_asian_width('ａ'), EastAsianWidth::Fullwidth);
+/// assert_eq!(east_asian_width('ｱ'), EastAsianWidth::Halfwidth);
+/// assert_eq!(east_asian_width('中'), EastAsianWidth::Wide);
+/// assert_eq!(east_asian_width('±'), EastAsianWidth::Ambiguous);
+/// assert_eq!(east_asian_width('ą'), EastAsianWidth::Neutral);
+/// assert_eq!(EastAsianWidth::Fullwidth.short_name(), "F");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EastAsianWidth {
+    /// `N`: characters that don't occur in legacy East Asian character encodings.
+    Neutral,
+    /// `Na`: narrow characters with a wide counterpart, such as ASCII.
+    Narrow,
+    /// `H`: the halfwidth forms of wide characters, such as halfwidth katakana.
+    Halfwidth,
+    /// `W`: wide characters, such as ideographs and emoji.
+    Wide,
+    /// `F`: the fullwidth forms of narrow characters, such as fullwidth ASCII.
+    Fullwidth,
+    /// `A`: characters that are wide in legacy East Asian character encodings,
+    /// and narrow elsewhere.
+    Ambiguous,
+}
+
+impl EastAsianWidth {
+    /// Returns the abbreviated name of the value, as used in `EastAsianWidth.txt`.
+    pub const fn short_name(self) -> &'static str {
+        match self {
+            Self::Neutral => "N",
+            Self::Narrow => "Na",
+            Self::Halfwidth => "H",
+            Self::Wide => "W",
+            Self::Fullwidth => "F",
+            Self::Ambiguous => "A",
+        }
+    }
+}
+
+/// Returns the `East_Asian_Width` of `c`.
+#[inline]
+pub fn east_asian_width(c: char) -> EastAsianWidth {
+    tables::east_asian_width(c)
+}
+
+/// Whether text is shown in an East Asian context, which decides the width
+/// of characters with an `East_Asian_Width` of `Ambiguous`.
+///
+/// As the annex recommends for when the context can't be established,
+/// the default is [`Context::NonEastAsian`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Context {
+    /// Ambiguous characters are narrow, with width 1.
+    #[default]
+    NonEastAsian,
+    /// Ambiguous characters are wide, with width 2, as with legacy East Asian
+    /// character encodings.
+    EastAsian,
+}
+
+/// Returns the width of `c` in columns according to UAX #11 in `context`,
+/// or `None` if `c` is a control character.
+pub fn width(c: char, context: Context) -> Option<usize> {
+    let width = tables::single_char_width(c)?;
+    Some(match (c, east_asian_width(c)) {
+        // The exceptions to the rules for characters with width 0
+        ('\u{115F}' | '\u{2D7F}', _) => 0,
+        _ if width == 0 => 0,
+        (_, EastAsianWidth::Wide | EastAsianWidth::Fullwidth) => 2,
+        (_, EastAsianWidth::Ambiguous) if context == Context::EastAsian => 2,
+        _ => 1,
+    })
+}
@@ -0,0 +1,889 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::ops::Range;
+
+use crate::ansi;
+use crate::column::{self, Rounding};
+use crate::controls::{self, ControlPolicy};
+use crate::lines::LineWidths;
+use crate::segments::WidthSegments;
+use crate::split;
+use crate::tables::{self, Builtin, WidthInfo, WidthLookup};
+use crate::tabs::{self, TabStops};
+#[cfg(feature = "alloc")]
+use crate::truncate::{self, Cut};
+
+/// The first bytes of every table file.
+const MAGIC: [u8; 4] = *b"UWTB";
+/// The version of the table file format that this crate reads and writes.
+const FORMAT_VERSION: u8 = 1;
+/// Set in the flags of a table file that has a root table for the East Asian context.
+const HAS_CJK_ROOT: u8 = 0b1;
+const HEADER_LEN: usize = 15;
+
+// The layout of the lookup tables, as described at `tables::lookup_width`.
+const ROOT_LEN: usize = 256;
+const MIDDLE_ROW_LEN: usize = 64;
+const LEAF_ROW_LEN: usize = 32;
+const EMOJI_PRESENTATION_LEAF_LEN: usize = 128;
+/// The highest index into the emoji presentation tables, which use all but the 10 LSB.
+const MAX_EMOJI_PRESENTATION_INDEX: u16 = (char::MAX as u32 >> 10) as u16;
+
+/// The width lookup tables, either the ones built into this crate or ones loaded at runtime,
+/// for picking up a newer version of Unicode without rebuilding.
+///
+/// Loaded tables replace the multi-level lookup tables of widths, and the table of
+/// characters that start emoji presentation sequences. The rest of the
+/// [rules for determining width](crate#rules-for-determining-width), such as which
+/// sequences form ligatures, are part of this crate's code and stay the same.
+///
+/// Each of the crate's width functions has a method here that uses these tables instead.
+/// They can also be used with [`WidthAccumulator::with_tables`](crate::WidthAccumulator::with_tables),
+/// [`wrap::Options::with_tables`](crate::wrap::Options::with_tables),
+/// [`DisplayWidth::with_tables`](crate::DisplayWidth::with_tables), and
+/// [`WidthConfig::tables`](crate::WidthConfig::tables), which [`Profile`](crate::Profile)s
+/// build on.
+///
+/// # Panics
+///
+/// The `_cjk` methods, and the other uses of these tables for the East Asian context,
+/// panic if the tables have no root table for it; see [`WidthTables::has_cjk_tables`].
+///
+/// # File format
+///
+/// A table file is written by [`WidthTables::to_bytes`], and is laid out as follows,
+/// with all integers little-endian:
+///
+/// | Bytes | Contents |
+/// |-------|----------|
+/// | 4     | `b"UWTB"` |
+/// | 1     | Format version, currently 1 |
+/// | 3     | Unicode version, as major, minor and update |
+/// | 1     | Flags: bit 0 is set if there is a root table for the East Asian context |
+/// | 2     | `m`, the number of middle tables |
+/// | 2     | `l`, the number of leaf tables |
+/// | 2     | `e`, the number of emoji presentation tables |
+/// | 256   | Root table, indexing middle tables by bits 13–20 of the code point |
+/// | 256   | Root table for the East Asian context, if flagged |
+/// | 64 × `m` | Middle tables, indexing leaf tables by bits 7–12 |
+/// | 32 × `l` | Leaf tables, of 2-bit widths packed four to a byte by bits 0–6, where 3 marks a character with special handling |
+/// | 2 × `e` | Bits 10–20 of the code points covered by each emoji presentation table, in ascending order |
+/// | 128 × `e` | Emoji presentation tables, of 1-bit flags by bits 0–9 |
+///
+/// ```rust,no_run
+/// use unicode_width::WidthTables;
+///
+/// let bytes = std::fs::read("widths.uwtb").unwrap();
+/// let tables = WidthTables::from_bytes(&bytes).unwrap();
+/// assert_eq!(tables.width("👩‍🔬 ok"), 5);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WidthTables<'a> {
+    data: Data<'a>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Data<'a> {
+    Builtin,
+    Loaded(Loaded<'a>),
+}
+
+/// Tables loaded from a file, with every index checked to be in bounds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Loaded<'a> {
+    unicode_version: (u8, u8, u8),
+    root: &'a [u8],
+    root_cjk: Option<&'a [u8]>,
+    middle: &'a [u8],
+    leaves: &'a [u8],
+    emoji_presentation_index: &'a [u8],
+    emoji_presentation_leaves: &'a [u8],
+}
+
+impl<'a> WidthTables<'a> {
+    /// The tables built into this crate, used by
+    /// [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width) and the rest of the crate.
+    pub const BUILTIN: Self = Self {
+        data: Data::Builtin,
+    };
+
+    /// Reads tables from a table file, checking that it is well-formed.
+    ///
+    /// The file doesn't need a root table for the East Asian context, but the `_cjk`
+    /// methods panic without one; see [`WidthTables::has_cjk_tables`].
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, TableError> {
+        if bytes.len() < HEADER_LEN || bytes[..4] != MAGIC {
+            return Err(TableError::NotATableFile);
+        }
+        if bytes[4] != FORMAT_VERSION {
+            return Err(TableError::UnsupportedFormat(bytes[4]));
+        }
+        let unicode_version = (bytes[5], bytes[6], bytes[7]);
+        let flags = bytes[8];
+        let middle_len = usize::from(u16::from_le_bytes([bytes[9], bytes[10]]));
+        let leaves_len = usize::from(u16::from_le_bytes([bytes[11], bytes[12]]));
+        let emoji_presentation_len = usize::from(u16::from_le_bytes([bytes[13], bytes[14]]));
+        if flags & !HAS_CJK_ROOT != 0 {
+            return Err(TableError::Malformed);
+        }
+
+        let mut rest = &bytes[HEADER_LEN..];
+        let mut take = |len: usize| -> Result<&'a [u8], TableError> {
+            if rest.len() < len {
+                return Err(TableError::Truncated);
+            }
+            let (taken, remaining) = rest.split_at(len);
+            rest = remaining;
+            Ok(taken)
+        };
+        let root = take(ROOT_LEN)?;
+        let root_cjk = if flags & HAS_CJK_ROOT != 0 {
+            Some(take(ROOT_LEN)?)
+        } else {
+            None
+        };
+        let middle = take(middle_len * MIDDLE_ROW_LEN)?;
+        let leaves = take(leaves_len * LEAF_ROW_LEN)?;
+        let emoji_presentation_index = take(emoji_presentation_len * 2)?;
+        let emoji_presentation_leaves = take(emoji_presentation_len * EMOJI_PRESENTATION_LEAF_LEN)?;
+        if !rest.is_empty() {
+            return Err(TableError::Malformed);
+        }
+
+        let roots_in_bounds = root
+            .iter()
+            .chain(root_cjk.unwrap_or_default())
+            .all(|&i| usize::from(i) < middle_len);
+        let middle_in_bounds = middle.iter().all(|&i| usize::from(i) < leaves_len);
+        let mut previous = None;
+        let emoji_presentation_sorted = emoji_presentation_index.chunks(2).all(|bits| {
+            let bits = u16::from_le_bytes([bits[0], bits[1]]);
+            let sorted = !matches!(previous, Some(previous) if previous >= bits);
+            previous = Some(bits);
+            sorted && bits <= MAX_EMOJI_PRESENTATION_INDEX
+        });
+        if !roots_in_bounds || !middle_in_bounds || !emoji_presentation_sorted {
+            return Err(TableError::Malformed);
+        }
+
+        let loaded = Loaded {
+            unicode_version,
+            root,
+            root_cjk,
+            middle,
+            leaves,
+            emoji_presentation_index,
+            emoji_presentation_leaves,
+        };
+        if let Some(c) = loaded.unknown_special(root, &tables::WIDTH_ROOT.0) {
+            return Err(TableError::UnknownSpecialWidth(c));
+        }
+        #[cfg(feature = "cjk")]
+        if let Some(root_cjk) = root_cjk {
+            if let Some(c) = loaded.unknown_special(root_cjk, &tables::WIDTH_ROOT_CJK.0) {
+                return Err(TableError::UnknownSpecialWidth(c));
+            }
+        }
+
+        Ok(Self {
+            data: Data::Loaded(loaded),
+        })
+    }
+
+    /// Writes the tables in the [file format](Self#file-format) that
+    /// [`WidthTables::from_bytes`] reads.
+    ///
+    /// Without the `"cjk"` feature flag, the built-in tables have no root table
+    /// for the East Asian context.
+    ///
+    /// ```rust
+    /// use unicode_width::WidthTables;
+    ///
+    /// let bytes = WidthTables::BUILTIN.to_bytes();
+    /// let tables = WidthTables::from_bytes(&bytes).unwrap();
+    /// assert_eq!(tables.unicode_version(), unicode_width::UNICODE_VERSION);
+    /// assert_eq!(tables.to_bytes(), bytes);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut middle = Vec::new();
+        let mut leaves = Vec::new();
+        let (root, root_cjk) = match self.data {
+            Data::Builtin => {
+                middle.extend(tables::WIDTH_MIDDLE.0.iter().flatten());
+                leaves.extend(tables::WIDTH_LEAVES.0.iter().flatten());
+                #[cfg(feature = "cjk")]
+                let root_cjk = Some(&tables::WIDTH_ROOT_CJK.0[..]);
+                #[cfg(not(feature = "cjk"))]
+                let root_cjk = None;
+                (&tables::WIDTH_ROOT.0[..], root_cjk)
+            }
+            Data::Loaded(loaded) => {
+                middle.extend_from_slice(loaded.middle);
+                leaves.extend_from_slice(loaded.leaves);
+                (loaded.root, loaded.root_cjk)
+            }
+        };
+        let unicode_version = self.unicode_version();
+
+        // The built-in emoji presentation tables are indexed by code, so rebuild them
+        // from the lookup instead.
+        let mut emoji_presentation_index = Vec::new();
+        let mut emoji_presentation_leaves = Vec::new();
+        for bits in 0..=MAX_EMOJI_PRESENTATION_INDEX {
+            let mut leaf = [0; EMOJI_PRESENTATION_LEAF_LEN];
+            for (i, byte) in leaf.iter_mut().enumerate() {
+                for bit in 0..8 {
+                    let cp = u32::from(bits) << 10 | (i as u32) << 3 | bit;
+                    if matches!(char::from_u32(cp), Some(c) if self.starts_emoji_presentation_seq(c))
+                    {
+                        *byte |= 1 << bit;
+                    }
+                }
+            }
+            if leaf != [0; EMOJI_PRESENTATION_LEAF_LEN] {
+                emoji_presentation_index.extend_from_slice(&bits.to_le_bytes());
+                emoji_presentation_leaves.extend_from_slice(&leaf);
+            }
+        }
+
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&MAGIC);
+        bytes.push(FORMAT_VERSION);
+        bytes.extend_from_slice(&[unicode_version.0, unicode_version.1, unicode_version.2]);
+        bytes.push(if root_cjk.is_some() { HAS_CJK_ROOT } else { 0 });
+        for len in [
+            middle.len() / MIDDLE_ROW_LEN,
+            leaves.len() / LEAF_ROW_LEN,
+            emoji_presentation_leaves.len() / EMOJI_PRESENTATION_LEAF_LEN,
+        ] {
+            bytes.extend_from_slice(&(len as u16).to_le_bytes());
+        }
+        bytes.extend_from_slice(root);
+        bytes.extend_from_slice(root_cjk.unwrap_or_default());
+        bytes.extend_from_slice(&middle);
+        bytes.extend_from_slice(&leaves);
+        bytes.extend_from_slice(&emoji_presentation_index);
+        bytes.extend_from_slice(&emoji_presentation_leaves);
+        bytes
+    }
+
+    /// Returns the version of Unicode that the tables are based on.
+    #[inline]
+    pub fn unicode_version(&self) -> (u8, u8, u8) {
+        match self.data {
+            Data::Builtin => tables::UNICODE_VERSION,
+            Data::Loaded(loaded) => loaded.unicode_version,
+        }
+    }
+
+    /// Returns whether the tables have a root table for the East Asian context, which
+    /// the `_cjk` methods need. The built-in tables have one with the `"cjk"` feature
+    /// flag enabled, and loaded tables have one if their file does.
+    #[inline]
+    pub const fn has_cjk_tables(&self) -> bool {
+        match self.data {
+            Data::Builtin => cfg!(feature = "cjk"),
+            Data::Loaded(loaded) => loaded.root_cjk.is_some(),
+        }
+    }
+
+    /// Returns the character's displayed width in columns according to these tables,
+    /// as [`UnicodeWidthChar::width`](crate::UnicodeWidthChar::width) does
+    /// according to the built-in ones.
+    #[inline]
+    pub fn char_width(&self, c: char) -> Option<usize> {
+        tables::single_char_width_with(*self, c)
+    }
+
+    /// Returns the character's displayed width in columns according to these tables,
+    /// as [`UnicodeWidthChar::width_cjk`](crate::UnicodeWidthChar::width_cjk) does
+    /// according to the built-in ones.
+    #[cfg(feature = "cjk")]
+    #[inline]
+    pub fn char_width_cjk(&self, c: char) -> Option<usize> {
+        StateMachine::new_cjk(*self).char_width(c)
+    }
+
+    /// Returns the string's displayed width in columns according to these tables,
+    /// as [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width) does
+    /// according to the built-in ones.
+    #[inline]
+    pub fn width(&self, s: &str) -> usize {
+        tables::str_width_with(*self, s)
+    }
+
+    /// Returns the string's displayed width in columns according to these tables,
+    /// as [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk) does
+    /// according to the built-in ones.
+    #[cfg(feature = "cjk")]
+    #[inline]
+    pub fn width_cjk(&self, s: &str) -> usize {
+        StateMachine::new_cjk(*self).str_width(s)
+    }
+
+    /// Returns the same as [`split_at_width`](crate::split_at_width),
+    /// but according to these tables.
+    #[inline]
+    pub fn split_at_width<'s>(&self, s: &'s str, cols: usize) -> (&'s str, &'s str, usize) {
+        let machine = StateMachine::new(*self);
+        split::split_at_width_with(machine, machine.str_width(s), s, cols)
+    }
+
+    /// Returns the same as [`split_at_width_cjk`](crate::split_at_width_cjk),
+    /// but according to these tables.
+    #[cfg(feature = "cjk")]
+    #[inline]
+    pub fn split_at_width_cjk<'s>(&self, s: &'s str, cols: usize) -> (&'s str, &'s str, usize) {
+        let machine = StateMachine::new_cjk(*self);
+        split::split_at_width_with(machine, machine.str_width(s), s, cols)
+    }
+
+    /// Returns the same as [`rsplit_at_width`](crate::rsplit_at_width),
+    /// but according to these tables.
+    #[inline]
+    pub fn rsplit_at_width<'s>(&self, s: &'s str, cols: usize) -> (&'s str, &'s str, usize) {
+        split::rsplit_at_width_with(StateMachine::new(*self), s, cols)
+    }
+
+    /// Returns the same as [`rsplit_at_width_cjk`](crate::rsplit_at_width_cjk),
+    /// but according to these tables.
+    #[cfg(feature = "cjk")]
+    #[inline]
+    pub fn rsplit_at_width_cjk<'s>(&self, s: &'s str, cols: usize) -> (&'s str, &'s str, usize) {
+        split::rsplit_at_width_with(StateMachine::new_cjk(*self), s, cols)
+    }
+
+    /// Returns the same as [`column_at_byte`](crate::column_at_byte),
+    /// but according to these tables.
+    #[inline]
+    pub fn column_at_byte(&self, s: &str, byte_idx: usize, rounding: Rounding) -> usize {
+        let machine = StateMachine::new(*self);
+        column::column_at_byte_with(machine, machine.str_width(s), s, byte_idx, rounding)
+    }
+
+    /// Returns the same as [`column_at_byte_cjk`](crate::column_at_byte_cjk),
+    /// but according to these tables.
+    #[cfg(feature = "cjk")]
+    #[inline]
+    pub fn column_at_byte_cjk(&self, s: &str, byte_idx: usize, rounding: Rounding) -> usize {
+        let machine = StateMachine::new_cjk(*self);
+        column::column_at_byte_with(machine, machine.str_width(s), s, byte_idx, rounding)
+    }
+
+    /// Returns the same as [`byte_at_column`](crate::byte_at_column),
+    /// but according to these tables.
+    #[inline]
+    pub fn byte_at_column(&self, s: &str, col: usize, rounding: Rounding) -> usize {
+        let machine = StateMachine::new(*self);
+        column::byte_at_column_with(machine, machine.str_width(s), s, col, rounding)
+    }
+
+    /// Returns the same as [`byte_at_column_cjk`](crate::byte_at_column_cjk),
+    /// but according to these tables.
+    #[cfg(feature = "cjk")]
+    #[inline]
+    pub fn byte_at_column_cjk(&self, s: &str, col: usize, rounding: Rounding) -> usize {
+        let machine = StateMachine::new_cjk(*self);
+        column::byte_at_column_with(machine, machine.str_width(s), s, col, rounding)
+    }
+
+    /// Returns the same as [`width_segments`](crate::width_segments),
+    /// but according to these tables.
+    #[inline]
+    pub fn width_segments<'s>(&self, s: &'s str) -> WidthSegments<'s>
+    where
+        'a: 's,
+    {
+        WidthSegments::new(StateMachine::new(*self), s)
+    }
+
+    /// Returns the same as [`width_segments_cjk`](crate::width_segments_cjk),
+    /// but according to these tables.
+    #[cfg(feature = "cjk")]
+    #[inline]
+    pub fn width_segments_cjk<'s>(&self, s: &'s str) -> WidthSegments<'s>
+    where
+        'a: 's,
+    {
+        WidthSegments::new(StateMachine::new_cjk(*self), s)
+    }
+
+    /// Returns the same as [`truncate_end`](crate::truncate_end),
+    /// but according to these tables.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn truncate_end<'s>(&self, s: &'s str, max_width: usize, ellipsis: &str) -> Cow<'s, str> {
+        truncate::truncate_with(StateMachine::new(*self), Cut::End, s, max_width, ellipsis)
+    }
+
+    /// Returns the same as [`truncate_end_cjk`](crate::truncate_end_cjk),
+    /// but according to these tables.
+    #[cfg(all(feature = "alloc", feature = "cjk"))]
+    #[inline]
+    pub fn truncate_end_cjk<'s>(
+        &self,
+        s: &'s str,
+        max_width: usize,
+        ellipsis: &str,
+    ) -> Cow<'s, str> {
+        truncate::truncate_with(
+            StateMachine::new_cjk(*self),
+            Cut::End,
+            s,
+            max_width,
+            ellipsis,
+        )
+    }
+
+    /// Returns the same as [`truncate_start`](crate::truncate_start),
+    /// but according to these tables.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn truncate_start<'s>(&self, s: &'s str, max_width: usize, ellipsis: &str) -> Cow<'s, str> {
+        truncate::truncate_with(StateMachine::new(*self), Cut::Start, s, max_width, ellipsis)
+    }
+
+    /// Returns the same as [`truncate_start_cjk`](crate::truncate_start_cjk),
+    /// but according to these tables.
+    #[cfg(all(feature = "alloc", feature = "cjk"))]
+    #[inline]
+    pub fn truncate_start_cjk<'s>(
+        &self,
+        s: &'s str,
+        max_width: usize,
+        ellipsis: &str,
+    ) -> Cow<'s, str> {
+        truncate::truncate_with(
+            StateMachine::new_cjk(*self),
+            Cut::Start,
+            s,
+            max_width,
+            ellipsis,
+        )
+    }
+
+    /// Returns the same as [`truncate_middle`](crate::truncate_middle),
+    /// but according to these tables.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn truncate_middle<'s>(
+        &self,
+        s: &'s str,
+        max_width: usize,
+        ellipsis: &str,
+    ) -> Cow<'s, str> {
+        truncate::truncate_with(
+            StateMachine::new(*self),
+            Cut::Middle,
+            s,
+            max_width,
+            ellipsis,
+        )
+    }
+
+    /// Returns the same as [`truncate_middle_cjk`](crate::truncate_middle_cjk),
+    /// but according to these tables.
+    #[cfg(all(feature = "alloc", feature = "cjk"))]
+    #[inline]
+    pub fn truncate_middle_cjk<'s>(
+        &self,
+        s: &'s str,
+        max_width: usize,
+        ellipsis: &str,
+    ) -> Cow<'s, str> {
+        truncate::truncate_with(
+            StateMachine::new_cjk(*self),
+            Cut::Middle,
+            s,
+            max_width,
+            ellipsis,
+        )
+    }
+
+    /// Returns the same as [`width_ansi`](crate::width_ansi),
+    /// but according to these tables.
+    #[inline]
+    pub fn width_ansi(&self, s: &str) -> usize {
+        ansi::width_ansi_with(StateMachine::new(*self), s)
+    }
+
+    /// Returns the same as [`width_ansi_cjk`](crate::width_ansi_cjk),
+    /// but according to these tables.
+    #[cfg(feature = "cjk")]
+    #[inline]
+    pub fn width_ansi_cjk(&self, s: &str) -> usize {
+        ansi::width_ansi_with(StateMachine::new_cjk(*self), s)
+    }
+
+    /// Returns the same as [`truncate_ansi`](crate::truncate_ansi),
+    /// but according to these tables.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn truncate_ansi<'s>(&self, s: &'s str, max_width: usize, ellipsis: &str) -> Cow<'s, str> {
+        ansi::truncate_ansi_with(StateMachine::new(*self), s, max_width, ellipsis)
+    }
+
+    /// Returns the same as [`truncate_ansi_cjk`](crate::truncate_ansi_cjk),
+    /// but according to these tables.
+    #[cfg(all(feature = "alloc", feature = "cjk"))]
+    #[inline]
+    pub fn truncate_ansi_cjk<'s>(
+        &self,
+        s: &'s str,
+        max_width: usize,
+        ellipsis: &str,
+    ) -> Cow<'s, str> {
+        ansi::truncate_ansi_with(StateMachine::new_cjk(*self), s, max_width, ellipsis)
+    }
+
+    /// Returns the same as [`slice_ansi`](crate::slice_ansi),
+    /// but according to these tables.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn slice_ansi(&self, s: &str, cols: Range<usize>) -> String {
+        ansi::slice_ansi_with(StateMachine::new(*self), s, cols)
+    }
+
+    /// Returns the same as [`slice_ansi_cjk`](crate::slice_ansi_cjk),
+    /// but according to these tables.
+    #[cfg(all(feature = "alloc", feature = "cjk"))]
+    #[inline]
+    pub fn slice_ansi_cjk(&self, s: &str, cols: Range<usize>) -> String {
+        ansi::slice_ansi_with(StateMachine::new_cjk(*self), s, cols)
+    }
+
+    /// Returns the same as [`width_with_tabs`](crate::width_with_tabs),
+    /// but according to these tables.
+    #[inline]
+    pub fn width_with_tabs(&self, s: &str, tab_stops: TabStops<'_>) -> usize {
+        tabs::width_with_tabs_with(StateMachine::new(*self), s, tab_stops)
+    }
+
+    /// Returns the same as [`width_with_tabs_cjk`](crate::width_with_tabs_cjk),
+    /// but according to these tables.
+    #[cfg(feature = "cjk")]
+    #[inline]
+    pub fn width_with_tabs_cjk(&self, s: &str, tab_stops: TabStops<'_>) -> usize {
+        tabs::width_with_tabs_with(StateMachine::new_cjk(*self), s, tab_stops)
+    }
+
+    /// Returns the same as [`expand_tabs`](crate::expand_tabs),
+    /// but according to these tables.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn expand_tabs<'s>(&self, s: &'s str, tab_stops: TabStops<'_>) -> Cow<'s, str> {
+        tabs::expand_tabs_with(StateMachine::new(*self), s, tab_stops)
+    }
+
+    /// Returns the same as [`expand_tabs_cjk`](crate::expand_tabs_cjk),
+    /// but according to these tables.
+    #[cfg(all(feature = "alloc", feature = "cjk"))]
+    #[inline]
+    pub fn expand_tabs_cjk<'s>(&self, s: &'s str, tab_stops: TabStops<'_>) -> Cow<'s, str> {
+        tabs::expand_tabs_with(StateMachine::new_cjk(*self), s, tab_stops)
+    }
+
+    /// Returns the same as [`line_widths`](crate::line_widths),
+    /// but according to these tables.
+    #[inline]
+    pub fn line_widths<'s>(&self, s: &'s str) -> LineWidths<'s>
+    where
+        'a: 's,
+    {
+        LineWidths::new(StateMachine::new(*self), s)
+    }
+
+    /// Returns the same as [`line_widths_cjk`](crate::line_widths_cjk),
+    /// but according to these tables.
+    #[cfg(feature = "cjk")]
+    #[inline]
+    pub fn line_widths_cjk<'s>(&self, s: &'s str) -> LineWidths<'s>
+    where
+        'a: 's,
+    {
+        LineWidths::new(StateMachine::new_cjk(*self), s)
+    }
+
+    /// Returns the same as [`max_line_width`](crate::max_line_width),
+    /// but according to these tables.
+    #[inline]
+    pub fn max_line_width(&self, s: &str) -> usize {
+        LineWidths::new(StateMachine::new(*self), s)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the same as [`max_line_width_cjk`](crate::max_line_width_cjk),
+    /// but according to these tables.
+    #[cfg(feature = "cjk")]
+    #[inline]
+    pub fn max_line_width_cjk(&self, s: &str) -> usize {
+        LineWidths::new(StateMachine::new_cjk(*self), s)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the same as [`width_with_controls`](crate::width_with_controls),
+    /// but according to these tables.
+    #[inline]
+    pub fn width_with_controls(&self, s: &str, policy: ControlPolicy) -> usize {
+        controls::width_with_controls_with(StateMachine::new(*self), s, policy)
+    }
+
+    /// Returns the same as [`width_with_controls_cjk`](crate::width_with_controls_cjk),
+    /// but according to these tables.
+    #[cfg(feature = "cjk")]
+    #[inline]
+    pub fn width_with_controls_cjk(&self, s: &str, policy: ControlPolicy) -> usize {
+        controls::width_with_controls_with(StateMachine::new_cjk(*self), s, policy)
+    }
+}
+
+/// The width state machine, looking characters up in either the built-in tables
+/// or loaded ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct StateMachine<'t> {
+    tables: WidthTables<'t>,
+    /// Whether ambiguous width characters are treated as wide.
+    cjk: bool,
+}
+
+impl<'t> StateMachine<'t> {
+    /// The state machine used by [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+    pub(crate) const DEFAULT: StateMachine<'static> = StateMachine::new(WidthTables::BUILTIN);
+
+    /// The state machine used by
+    /// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+    #[cfg(feature = "cjk")]
+    pub(crate) const CJK: StateMachine<'static> = StateMachine::new_cjk(WidthTables::BUILTIN);
+
+    /// Returns the state machine that treats ambiguous width characters as narrow.
+    #[inline]
+    pub(crate) const fn new(tables: WidthTables<'t>) -> Self {
+        Self { tables, cjk: false }
+    }
+
+    /// Returns the state machine that treats ambiguous width characters as wide.
+    ///
+    /// Panics if `tables` has no root table for the East Asian context.
+    #[cfg(feature = "cjk")]
+    #[inline]
+    pub(crate) const fn new_cjk(tables: WidthTables<'t>) -> Self {
+        assert!(
+            tables.has_cjk_tables(),
+            "width tables have no tables for the East Asian context"
+        );
+        Self { tables, cjk: true }
+    }
+
+    /// Returns the width that `c` adds to the string after it, and the state it
+    /// passes on, as `tables::width_in_str` does.
+    #[inline]
+    pub(crate) fn width_in_str(self, c: char, next_info: WidthInfo) -> (i8, WidthInfo) {
+        match (self.tables.data, self.cjk) {
+            (Data::Builtin, false) => tables::width_in_str(c, next_info),
+            #[cfg(feature = "cjk")]
+            (Data::Builtin, true) => tables::width_in_str_cjk(c, next_info),
+            #[cfg(feature = "cjk")]
+            (_, true) => tables::width_in_str_with_cjk(self.tables, c, next_info),
+            _ => tables::width_in_str_with(self.tables, c, next_info),
+        }
+    }
+
+    /// Whether `c` forms an emoji presentation sequence when followed by `'\u{FE0F}'`.
+    #[inline]
+    pub(crate) fn starts_emoji_presentation_seq(self, c: char) -> bool {
+        self.tables.starts_emoji_presentation_seq(c)
+    }
+
+    /// Returns the width of `s`, as `tables::str_width` does.
+    #[inline]
+    pub(crate) fn str_width(self, s: &str) -> usize {
+        match (self.tables.data, self.cjk) {
+            (Data::Builtin, false) => tables::str_width(s),
+            #[cfg(feature = "cjk")]
+            (Data::Builtin, true) => tables::str_width_cjk(s),
+            #[cfg(feature = "cjk")]
+            (_, true) => tables::str_width_with_cjk(self.tables, s),
+            _ => tables::str_width_with(self.tables, s),
+        }
+    }
+
+    /// Returns the width of `c` on its own, as `tables::single_char_width` does.
+    #[inline]
+    pub(crate) fn char_width(self, c: char) -> Option<usize> {
+        match (self.tables.data, self.cjk) {
+            (Data::Builtin, false) => tables::single_char_width(c),
+            #[cfg(feature = "cjk")]
+            (Data::Builtin, true) => tables::single_char_width_cjk(c),
+            #[cfg(feature = "cjk")]
+            (_, true) => tables::single_char_width_with_cjk(self.tables, c),
+            _ => tables::single_char_width_with(self.tables, c),
+        }
+    }
+}
+
+impl Default for WidthTables<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::BUILTIN
+    }
+}
+
+impl WidthLookup for WidthTables<'_> {
+    #[inline]
+    fn lookup_width(self, c: char) -> (u8, WidthInfo) {
+        match self.data {
+            Data::Builtin => Builtin.lookup_width(c),
+            Data::Loaded(loaded) => loaded.lookup_width(loaded.root, c, tables::special_width),
+        }
+    }
+
+    #[cfg(feature = "cjk")]
+    #[inline]
+    fn lookup_width_cjk(self, c: char) -> (u8, WidthInfo) {
+        match self.data {
+            Data::Builtin => Builtin.lookup_width_cjk(c),
+            Data::Loaded(loaded) => {
+                // `StateMachine::new_cjk` doesn't accept tables without it
+                let root = loaded.root_cjk.unwrap_or(loaded.root);
+                loaded.lookup_width(root, c, tables::special_width_cjk)
+            }
+        }
+    }
+
+    #[inline]
+    fn starts_emoji_presentation_seq(self, c: char) -> bool {
+        match self.data {
+            Data::Builtin => Builtin.starts_emoji_presentation_seq(c),
+            Data::Loaded(loaded) => loaded.starts_emoji_presentation_seq(c),
+        }
+    }
+}
+
+impl Loaded<'_> {
+    /// Returns the first character that `root` marks as special with a width of 3,
+    /// but that `builtin_root` doesn't, if there is one. Special widths are handled in
+    /// this crate's code, which only knows about the characters in the built-in tables.
+    fn unknown_special(&self, root: &[u8], builtin_root: &[u8; ROOT_LEN]) -> Option<char> {
+        for (i, &t1_offset) in root.iter().enumerate() {
+            let middle = &self.middle[usize::from(t1_offset) * MIDDLE_ROW_LEN..][..MIDDLE_ROW_LEN];
+            for (j, &t2_offset) in middle.iter().enumerate() {
+                let leaf = &self.leaves[usize::from(t2_offset) * LEAF_ROW_LEN..][..LEAF_ROW_LEN];
+                for (k, &packed_widths) in leaf.iter().enumerate() {
+                    // Skip the bytes without a 3 in them
+                    if packed_widths & packed_widths >> 1 & 0b0101_0101 == 0 {
+                        continue;
+                    }
+                    for l in 0..4 {
+                        let cp = i << 13 | j << 7 | k << 2 | l;
+                        let special = packed_widths >> (2 * l) & 0b11 == 3;
+                        if special && builtin_width(builtin_root, cp) != 3 {
+                            // Code points past `char::MAX` are never looked up
+                            if let Some(c) = char::from_u32(cp as u32) {
+                                return Some(c);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Looks `c` up in the same way as `tables::lookup_width`, but starting from `root`.
+    fn lookup_width(
+        &self,
+        root: &[u8],
+        c: char,
+        special_width: fn(char) -> (u8, WidthInfo),
+    ) -> (u8, WidthInfo) {
+        let cp = c as usize;
+        let t1_offset = usize::from(root[cp >> 13]);
+        let t2_offset = usize::from(self.middle[t1_offset * MIDDLE_ROW_LEN + (cp >> 7 & 0x3F)]);
+        let packed_widths = self.leaves[t2_offset * LEAF_ROW_LEN + (cp >> 2 & 0x1F)];
+        let width = packed_widths >> (2 * (cp & 0b11)) & 0b11;
+        if width < 3 {
+            (width, WidthInfo::DEFAULT)
+        } else {
+            special_width(c)
+        }
+    }
+
+    /// Looks `c` up in the same way as `tables::starts_emoji_presentation_seq`.
+    fn starts_emoji_presentation_seq(&self, c: char) -> bool {
+        let cp: u32 = c.into();
+        let top_bits = (cp >> 10) as u16;
+        let idx_of_leaf = self
+            .emoji_presentation_index
+            .chunks(2)
+            .map(|bits| u16::from_le_bytes([bits[0], bits[1]]))
+            .position(|bits| bits == top_bits);
+        let Some(idx_of_leaf) = idx_of_leaf else {
+            return false;
+        };
+        let idx_within_leaf = usize::try_from((cp >> 3) & 0x7F).unwrap();
+        let leaf_byte = self.emoji_presentation_leaves
+            [idx_of_leaf * EMOJI_PRESENTATION_LEAF_LEN + idx_within_leaf];
+        ((leaf_byte >> (cp & 7)) & 1) == 1
+    }
+}
+
+/// Looks up the width of the code point `cp` in the built-in tables, starting from
+/// `root`, without handling the special widths.
+fn builtin_width(root: &[u8; ROOT_LEN], cp: usize) -> u8 {
+    let t1_offset = usize::from(root[cp >> 13]);
+    let t2_offset = usize::from(tables::WIDTH_MIDDLE.0[t1_offset][cp >> 7 & 0x3F]);
+    let packed_widths = tables::WIDTH_LEAVES.0[t2_offset][cp >> 2 & 0x1F];
+    packed_widths >> (2 * (cp & 0b11)) & 0b11
+}
+
+/// The error returned by [`WidthTables::from_bytes`] for bytes that aren't a well-formed
+/// table file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TableError {
+    /// The bytes don't start with the header of a table file.
+    NotATableFile,
+    /// The file is in a later version of the format than this crate reads.
+    UnsupportedFormat(u8),
+    /// The file is shorter than its header says.
+    Truncated,
+    /// The file has an index out of bounds, an unknown flag, or trailing bytes.
+    Malformed,
+    /// The file marks a character for special handling, such as a ligature or
+    /// an emoji sequence, that this crate doesn't know about.
+    UnknownSpecialWidth(char),
+}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotATableFile => f.write_str("not a width table file"),
+            Self::UnsupportedFormat(version) => {
+                write!(f, "unsupported width table format version {version}")
+            }
+            Self::Truncated => f.write_str("width table file is truncated"),
+            Self::Malformed => f.write_str("malformed width table file"),
+            Self::UnknownSpecialWidth(c) => write!(
+                f,
+                "width table file has an unknown special width for U+{:04X}",
+                u32::from(*c)
+            ),
+        }
+    }
+}
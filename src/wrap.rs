@@ -0,0 +1,296 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Wrapping text into lines of a given display width.
+//!
+//! ```rust
+//! use unicode_width::wrap::{wrap, Options};
+//!
+//! let options = Options::new(14).initial_indent("- ").subsequent_indent("  ");
+//! assert_eq!(
+//!     wrap("Emoji like 👩‍🔬 are never split across lines.", &options),
+//!     ["- Emoji like", "  👩‍🔬 are never", "  split across", "  lines."],
+//! );
+//! ```
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::ansi::{Renderer, Stripped};
+use crate::segments::WidthSegments;
+use crate::width_tables::{StateMachine, WidthTables};
+
+/// How [`wrap`] lays out lines.
+#[derive(Clone, Copy)]
+pub struct Options<'a> {
+    machine: StateMachine<'a>,
+    width: usize,
+    initial_indent: &'a str,
+    subsequent_indent: &'a str,
+    break_words: bool,
+}
+
+impl<'a> Options<'a> {
+    /// Creates options for lines at most `width` columns wide, as measured by
+    /// [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width), with no indentation
+    /// and with words longer than a line left whole.
+    ///
+    /// Characters in the Ambiguous category are treated as 1 column wide.
+    #[inline]
+    pub fn new(width: usize) -> Self {
+        Self::with_state_machine(StateMachine::DEFAULT, width)
+    }
+
+    /// Creates options for lines at most `width` columns wide, as measured by
+    /// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk), with no
+    /// indentation and with words longer than a line left whole.
+    ///
+    /// Characters in the Ambiguous category are treated as 2 columns wide.
+    #[cfg(feature = "cjk")]
+    #[inline]
+    pub fn new_cjk(width: usize) -> Self {
+        Self::with_state_machine(StateMachine::CJK, width)
+    }
+
+    /// Creates options for lines at most `width` columns wide, as measured by
+    /// [`WidthTables::width`] with `tables`, with no indentation and with words
+    /// longer than a line left whole.
+    #[inline]
+    pub fn with_tables(tables: WidthTables<'a>, width: usize) -> Self {
+        Self::with_state_machine(StateMachine::new(tables), width)
+    }
+
+    /// Creates options for lines at most `width` columns wide, as measured by
+    /// [`WidthTables::width_cjk`] with `tables`, with no indentation and with words
+    /// longer than a line left whole.
+    #[cfg(feature = "cjk")]
+    #[inline]
+    pub fn with_tables_cjk(tables: WidthTables<'a>, width: usize) -> Self {
+        Self::with_state_machine(StateMachine::new_cjk(tables), width)
+    }
+
+    #[inline]
+    fn with_state_machine(machine: StateMachine<'a>, width: usize) -> Self {
+        Self {
+            machine,
+            width,
+            initial_indent: "",
+            subsequent_indent: "",
+            break_words: false,
+        }
+    }
+
+    /// Sets the string put before the first line of each paragraph.
+    /// Its width counts towards the width of the line.
+    #[inline]
+    pub fn initial_indent(self, indent: &'a str) -> Self {
+        Self {
+            initial_indent: indent,
+            ..self
+        }
+    }
+
+    /// Sets the string put before every line of a paragraph but the first,
+    /// making a hanging indent. Its width counts towards the width of the line.
+    #[inline]
+    pub fn subsequent_indent(self, indent: &'a str) -> Self {
+        Self {
+            subsequent_indent: indent,
+            ..self
+        }
+    }
+
+    /// Sets whether words that don't fit on a line of their own are broken up.
+    ///
+    /// Words are only ever broken where [`split_at_width`](crate::split_at_width)
+    /// would split them, so a line can still overflow if it can't fit even a single
+    /// wide character or emoji sequence.
+    #[inline]
+    pub fn break_words(self, break_words: bool) -> Self {
+        Self {
+            break_words,
+            ..self
+        }
+    }
+}
+
+impl core::fmt::Debug for Options<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Options")
+            .field("width", &self.width)
+            .field("initial_indent", &self.initial_indent)
+            .field("subsequent_indent", &self.subsequent_indent)
+            .field("break_words", &self.break_words)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Wraps `text` into lines that are at most as wide as `options` allows.
+///
+/// Each line of `text` is a paragraph, and is wrapped on its own. Lines are only broken
+/// at spaces (`' '`), and the spaces at a break are dropped. Spaces at the start of
+/// a paragraph are kept. A trailing `'\r'` is removed from each paragraph.
+///
+/// A word that is too wide for a line of its own gets a line to itself, unless
+/// [`Options::break_words`] is set. Either way, emoji sequences, ligatures and other
+/// sequences whose width isn't the sum of their parts are never split across lines.
+///
+/// ```rust
+/// use unicode_width::wrap::{wrap, Options};
+///
+/// assert_eq!(wrap("日本語のテキスト", &Options::new(6).break_words(true)), ["日本語", "のテキ", "スト"]);
+/// ```
+pub fn wrap<'a>(text: &'a str, options: &Options<'_>) -> Vec<Cow<'a, str>> {
+    let mut lines = Vec::new();
+    wrap_with(text, options, &mut |indent, line| {
+        lines.push(if indent.is_empty() {
+            Cow::Borrowed(line)
+        } else {
+            let mut indented = String::with_capacity(indent.len() + line.len());
+            indented.push_str(indent);
+            indented.push_str(line);
+            Cow::Owned(indented)
+        });
+    });
+    lines
+}
+
+/// Wraps `text` into lines that are at most as wide as `options` allows,
+/// leaving escape sequences intact.
+///
+/// Columns are counted as by [`width_ansi`](crate::width_ansi), and lines are broken
+/// as by [`wrap`]. Each line starts with its indentation and then the [SGR] escape
+/// sequences (such as colors) and [OSC 8] hyperlink in effect at its start, and ends with
+/// `"\x1b[0m"` if any SGR attributes are still active and `"\x1b]8;;\x1b\\"` if a
+/// hyperlink is still open. So each line can be printed on its own, and styles and
+/// hyperlinks carry over from one line to the next. Other escape sequences are
+/// dropped if they fall between two lines.
+///
+/// ```rust
+/// use unicode_width::wrap::{wrap_ansi, Options};
+///
+/// assert_eq!(
+///     wrap_ansi("\x1b[31mred text\x1b[0m plain", &Options::new(6)),
+///     ["\x1b[31mred\x1b[0m", "\x1b[31mtext\x1b[0m", "plain"],
+/// );
+/// ```
+///
+/// [SGR]: https://en.wikipedia.org/wiki/ANSI_escape_code#SGR
+/// [OSC 8]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+pub fn wrap_ansi(text: &str, options: &Options<'_>) -> Vec<String> {
+    let stripped = Stripped::new(text);
+    let mut renderer = Renderer::new(&stripped);
+    let mut lines = Vec::new();
+    wrap_with(&stripped.text, options, &mut |indent, line| {
+        let start = line.as_ptr() as usize - stripped.text.as_ptr() as usize;
+        let mut rendered = String::from(indent);
+        renderer.render(&mut rendered, start..start + line.len());
+        renderer.reset(&mut rendered);
+        lines.push(rendered);
+    });
+    lines
+}
+
+/// Calls `push` with the indentation and the contents of each line
+/// that [`wrap`] would return, in order. The contents are slices of `text`.
+pub(crate) fn wrap_with<'a>(
+    text: &'a str,
+    options: &Options<'_>,
+    push: &mut dyn FnMut(&str, &'a str),
+) {
+    for paragraph in text.split('\n') {
+        let paragraph = paragraph.strip_suffix('\r').unwrap_or(paragraph);
+        Wrapper {
+            options,
+            push: &mut *push,
+            first: true,
+        }
+        .wrap_paragraph(paragraph);
+    }
+}
+
+struct Wrapper<'o, 'p, 'a> {
+    options: &'o Options<'o>,
+    push: &'p mut dyn FnMut(&str, &'a str),
+    /// Whether no line of the paragraph has been pushed yet.
+    first: bool,
+}
+
+impl<'o, 'a> Wrapper<'o, '_, 'a> {
+    fn wrap_paragraph(&mut self, paragraph: &'a str) {
+        let machine = self.options.machine;
+
+        // The current line, as the start of its first word and the end of its last word.
+        let mut line: Option<(usize, usize)> = None;
+        // Leading spaces are part of the first word.
+        let mut word_start = 0;
+        let mut rest = paragraph.trim_start_matches(' ');
+        while !rest.is_empty() {
+            let word_end = paragraph.len() - rest.len() + rest.find(' ').unwrap_or(rest.len());
+            rest = paragraph[word_end..].trim_start_matches(' ');
+
+            if let Some((line_start, line_end)) = line {
+                if machine.str_width(&paragraph[line_start..word_end]) <= self.available() {
+                    line = Some((line_start, word_end));
+                    word_start = paragraph.len() - rest.len();
+                    continue;
+                }
+                self.push(&paragraph[line_start..line_end]);
+            }
+
+            let mut word = &paragraph[word_start..word_end];
+            if self.options.break_words && machine.str_width(word) > self.available() {
+                // Cut a chunk off before each piece that doesn't fit,
+                // unless the chunk would be empty, so that it overflows instead
+                let mut chunk_start = 0;
+                let mut chunk_width = 0;
+                for (range, width) in WidthSegments::new(machine, word) {
+                    if chunk_width + width > self.available() && range.start > chunk_start {
+                        self.push(&word[chunk_start..range.start]);
+                        chunk_start = range.start;
+                        chunk_width = 0;
+                    }
+                    chunk_width += width;
+                }
+                word = &word[chunk_start..];
+            }
+            line = Some((word_end - word.len(), word_end));
+            word_start = paragraph.len() - rest.len();
+        }
+
+        match line {
+            Some((line_start, line_end)) => self.push(&paragraph[line_start..line_end]),
+            // A blank paragraph keeps its spaces, without indentation
+            None => (self.push)("", paragraph),
+        }
+    }
+
+    fn indent(&self) -> &'o str {
+        if self.first {
+            self.options.initial_indent
+        } else {
+            self.options.subsequent_indent
+        }
+    }
+
+    /// Returns how many columns the next line has left after its indentation.
+    fn available(&self) -> usize {
+        self.options
+            .width
+            .saturating_sub(self.options.machine.str_width(self.indent()))
+    }
+
+    fn push(&mut self, line: &'a str) {
+        let indent = self.indent();
+        (self.push)(indent, line);
+        self.first = false;
+    }
+}
@@ -13,7 +13,11 @@ use std::{
     io::{BufRead, BufReader},
 };
 
-use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+use unicode_width::{
+    byte_at_column, column_at_byte, line_widths, max_line_width, rsplit_at_width, split_at_width,
+    width_ansi, width_segments, width_with_controls, width_with_tabs, ControlPolicy, DisplayWidth,
+    Rounding, TabStops, UnicodeWidthChar, UnicodeWidthStr, WidthAccumulator,
+};
 
 macro_rules! assert_width {
     ($s:expr, $nocjk:expr, $cjk:expr $(,)?) => {{
@@ -649,6 +653,1699 @@ fn test_vs1_vs2_vs3() {
     }
 }
 
+fn assert_accumulated_width(s: &str) {
+    let mut acc = WidthAccumulator::new();
+    #[cfg(feature = "cjk")]
+    let mut acc_cjk = WidthAccumulator::new_cjk();
+    for (i, c) in s.char_indices() {
+        let prefix = &s[..i + c.len_utf8()];
+        acc.push(c);
+        assert_eq!(acc.width(), prefix.width(), "{prefix:?}");
+        #[cfg(feature = "cjk")]
+        {
+            acc_cjk.push(c);
+            assert_eq!(acc_cjk.width(), prefix.width_cjk(), "{prefix:?} (CJK)");
+        }
+    }
+}
+
+#[test]
+fn test_accumulator() {
+    assert_eq!(WidthAccumulator::new().width(), 0);
+    for s in [
+        "ｈｅｌｌｏ, world\r\n",
+        "👩‍🔬👩🏽‍🔬🇮🇸🇳🇴🇮",
+        "\u{2764}\u{FE0F}\u{2764}\u{FE0E}\u{2018}\u{FE01}\u{2018}\u{FE00}",
+        "ꓹꓼ ل\u{064F}\u{200D}ا א\u{200D}ל \u{10C32}\u{200D}\u{10C03} ⵏ⵿ⴾ",
+        "\u{1A15}\u{1A17}\u{200D}\u{1A10}\u{17D2}\u{1780}\u{17D8}",
+        "<\u{0301}\u{0338}=\u{0338}\u{1011B}\u{0338}",
+    ] {
+        assert_accumulated_width(s);
+    }
+
+    // Runs longer than the accumulator's internal buffer
+    let combining = "\u{0301}".repeat(100);
+    assert_accumulated_width(&combining);
+    assert_accumulated_width(&format!("<{combining}\u{0338}"));
+    assert_accumulated_width(&format!("א{}ל", "\u{200D}".repeat(100)));
+    assert_accumulated_width(&format!("ل{}ا", "\u{064F}".repeat(100)));
+    assert_accumulated_width(&"🇮".repeat(101));
+    assert_accumulated_width(&"🇮\u{200D}".repeat(50));
+    assert_accumulated_width(&"👩\u{200D}".repeat(50));
+    assert_accumulated_width(&"\u{2764}\u{FE0F}\u{200D}".repeat(50));
+    assert_accumulated_width(&"中".repeat(100));
+    assert_accumulated_width(&"中\u{0301}".repeat(50));
+    assert_accumulated_width(&"\u{16D67}".repeat(301));
+    assert_accumulated_width(&format!("\u{16D63}{}", "\u{16D67}".repeat(300)));
+    assert_accumulated_width(&format!("🏴{}\u{E007F}", "\u{E0061}".repeat(300)));
+    assert_accumulated_width(&"\u{2764}\u{FE0F}".repeat(150));
+
+    let mut acc = WidthAccumulator::default();
+    acc.extend(["👩", "\u{200D}", "🔬"]);
+    acc.extend(['\r', '\n'].iter());
+    acc.push_str("ｈｉ");
+    assert_eq!(acc.width(), 7);
+}
+
+#[test]
+fn emoji_test_file_accumulated() {
+    let mut acc = WidthAccumulator::new();
+    #[cfg(feature = "cjk")]
+    let mut acc_cjk = WidthAccumulator::new_cjk();
+    let mut contents = String::new();
+    let norm_file = BufReader::new(
+        File::open("tests/emoji-test.txt")
+            .expect("run `unicode.py` first to download `emoji-test.txt`"),
+    );
+    for line in norm_file.lines() {
+        let line = line.unwrap() + "\n";
+        acc.push_str(&line);
+        #[cfg(feature = "cjk")]
+        acc_cjk.push_str(&line);
+        contents += &line;
+    }
+    assert_eq!(acc.width(), contents.width());
+    #[cfg(feature = "cjk")]
+    assert_eq!(acc_cjk.width(), contents.width_cjk());
+}
+
+fn assert_splits_consistently(s: &str) {
+    for cols in 0..=s.width() + 1 {
+        let (prefix, rest, width) = split_at_width(s, cols);
+        assert_eq!(format!("{prefix}{rest}"), s);
+        assert_eq!(prefix.width(), width, "{s:?} split at {cols}");
+        assert!(width <= cols, "{s:?} split at {cols}");
+        assert_eq!(width + rest.width(), s.width(), "{s:?} split at {cols}");
+
+        let (rest, suffix, width) = rsplit_at_width(s, cols);
+        assert_eq!(format!("{rest}{suffix}"), s);
+        assert_eq!(suffix.width(), width, "{s:?} rsplit at {cols}");
+        assert!(width <= cols, "{s:?} rsplit at {cols}");
+        assert_eq!(rest.width() + width, s.width(), "{s:?} rsplit at {cols}");
+    }
+}
+
+#[test]
+fn test_split_at_width() {
+    assert_eq!(split_at_width("", 3), ("", "", 0));
+    assert_eq!(split_at_width("abc", 0), ("", "abc", 0));
+    assert_eq!(split_at_width("abc", 5), ("abc", "", 3));
+    assert_eq!(split_at_width("a中b", 2), ("a", "中b", 1));
+    assert_eq!(split_at_width("a\u{0301}b", 1), ("a\u{0301}", "b", 1));
+    assert_eq!(split_at_width("a\r\nb", 1), ("a", "\r\nb", 1));
+    assert_eq!(split_at_width("a\r\nb", 2), ("a\r\n", "b", 2));
+    assert_eq!(split_at_width("👩‍🔬👩‍🔬", 3), ("👩‍🔬", "👩‍🔬", 2));
+    assert_eq!(split_at_width("👍🏽👍🏽", 3), ("👍🏽", "👍🏽", 2));
+    assert_eq!(split_at_width("🇮🇸🇳🇴🇮", 1), ("", "🇮🇸🇳🇴🇮", 0));
+    assert_eq!(split_at_width("🇮🇸🇳🇴🇮", 5), ("🇮🇸🇳🇴🇮", "", 5));
+    assert_eq!(
+        split_at_width("x\u{2764}\u{FE0F}", 2),
+        ("x", "\u{2764}\u{FE0F}", 1)
+    );
+    assert_eq!(
+        split_at_width("\u{231A}\u{FE0E}x", 1),
+        ("\u{231A}\u{FE0E}", "x", 1)
+    );
+    assert_eq!(split_at_width("ꓹꓼꓹꓼ", 1), ("ꓹꓼ", "ꓹꓼ", 1));
+    assert_eq!(split_at_width("ក\u{17D2}កក", 1), ("ក\u{17D2}ក", "ក", 1));
+    assert_eq!(split_at_width("لا", 1), ("لا", "", 1));
+    assert_eq!(split_at_width("א\u{200D}לx", 1), ("א\u{200D}ל", "x", 1));
+
+    assert_eq!(rsplit_at_width("abc", 0), ("abc", "", 0));
+    assert_eq!(rsplit_at_width("abc", 2), ("a", "bc", 2));
+    assert_eq!(rsplit_at_width("a\r\n", 1), ("a", "\r\n", 1));
+    assert_eq!(rsplit_at_width("🇮🇸🇳🇴🇮", 1), ("🇮🇸🇳🇴", "🇮", 1));
+    assert_eq!(rsplit_at_width("🇮🇸🇳🇴🇮", 2), ("🇮🇸🇳🇴", "🇮", 1));
+    assert_eq!(rsplit_at_width("🇮🇸🇳🇴🇮", 3), ("🇮🇸", "🇳🇴🇮", 3));
+    assert_eq!(rsplit_at_width("x👩‍🔬", 1), ("x👩‍🔬", "", 0));
+    assert_eq!(rsplit_at_width("x👩‍🔬", 2), ("x", "👩‍🔬", 2));
+    assert_eq!(rsplit_at_width("xꓹꓼ", 1), ("x", "ꓹꓼ", 1));
+
+    for s in [
+        "ｈｅｌｌｏ, world\r\n",
+        "👩‍🔬👩🏽‍🔬🇮🇸🇳🇴🇮",
+        "\u{2764}\u{FE0F}\u{2764}\u{FE0E}\u{2018}\u{FE01}\u{2018}\u{FE00}",
+        "ꓹꓼ ل\u{064F}\u{200D}ا א\u{200D}ל \u{10C32}\u{200D}\u{10C03} ⵏ⵿ⴾ",
+        "\u{1A15}\u{1A17}\u{200D}\u{1A10}\u{17D2}\u{1780}\u{17D8}",
+        "<\u{0301}\u{0338}=\u{0338}\u{1011B}\u{0338}",
+        "🇮\u{200D}🇮🇸\u{200D}🇳🇴🇮\u{200D}",
+        &"\u{16D67}".repeat(100),
+        &format!("\u{16D63}{}x", "\u{16D67}".repeat(99)),
+        &"\u{16D69}\u{16D67}\u{16D68}".repeat(40),
+    ] {
+        assert_splits_consistently(s);
+    }
+
+    // Kirat Rai vowel signs combine in pairs from the start of a run,
+    // so whether a split is allowed depends on everything before it
+    let kirat = "\u{16D67}".repeat(5000);
+    assert_eq!(kirat.width(), 2500);
+    assert_eq!(
+        split_at_width(&kirat, 1001),
+        (&kirat[..8008], &kirat[8008..], 1001)
+    );
+    assert_eq!(
+        rsplit_at_width(&kirat, 1001),
+        (&kirat[..11992], &kirat[11992..], 1001)
+    );
+}
+
+#[test]
+fn emoji_test_file_split() {
+    let norm_file = BufReader::new(
+        File::open("tests/emoji-test.txt")
+            .expect("run `unicode.py` first to download `emoji-test.txt`"),
+    );
+    for line in norm_file.lines() {
+        let line = line.unwrap();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (cps, status) = line.split_once(';').unwrap();
+        if status.trim().starts_with("fully-qualified") {
+            let emoji: String = cps
+                .trim()
+                .split(' ')
+                .map(|s| char::try_from(u32::from_str_radix(s, 16).unwrap()).unwrap())
+                .collect();
+            let s = format!("a{emoji}b");
+            assert_eq!(split_at_width(&s, 2), ("a", &s[1..], 1), "{emoji:?}");
+            assert_eq!(split_at_width(&s, 3), (&s[..s.len() - 1], "b", 3));
+            assert_eq!(rsplit_at_width(&s, 2), (&s[..s.len() - 1], "b", 1));
+            assert_eq!(rsplit_at_width(&s, 3), ("a", &s[1..], 3), "{emoji:?}");
+        }
+    }
+}
+
+#[cfg(feature = "cjk")]
+#[test]
+fn test_split_at_width_cjk() {
+    use unicode_width::{rsplit_at_width_cjk, split_at_width_cjk};
+
+    assert_eq!(split_at_width_cjk("“a”", 2), ("“", "a”", 2));
+    assert_eq!(split_at_width_cjk("a<\u{0338}", 2), ("a", "<\u{0338}", 1));
+    assert_eq!(rsplit_at_width_cjk("“a”", 3), ("“", "a”", 3));
+    assert_eq!(rsplit_at_width_cjk("a<\u{0338}", 1), ("a<\u{0338}", "", 0));
+}
+
+fn assert_columns_consistently(s: &str) {
+    for byte_idx in 0..=s.len() + 1 {
+        let down = column_at_byte(s, byte_idx, Rounding::Down);
+        let up = column_at_byte(s, byte_idx, Rounding::Up);
+        assert!(down <= up, "{s:?} at byte {byte_idx}");
+        if byte_idx <= s.len() && s.is_char_boundary(byte_idx) {
+            let (prefix, rest) = s.split_at(byte_idx);
+            if prefix.width() + rest.width() == s.width() {
+                assert!(down <= prefix.width(), "{s:?} at byte {byte_idx}");
+                assert!(prefix.width() <= up, "{s:?} at byte {byte_idx}");
+            }
+        }
+    }
+
+    for col in 0..=s.width() + 1 {
+        for rounding in [Rounding::Down, Rounding::Up] {
+            let byte_idx = byte_at_column(s, col, rounding);
+            let (prefix, rest) = s.split_at(byte_idx);
+            assert_eq!(
+                prefix.width() + rest.width(),
+                s.width(),
+                "{s:?} at column {col}"
+            );
+            assert_eq!(column_at_byte(s, byte_idx, Rounding::Down), prefix.width());
+            assert_eq!(column_at_byte(s, byte_idx, Rounding::Up), prefix.width());
+            match rounding {
+                Rounding::Down => assert!(prefix.width() <= col, "{s:?} at column {col}"),
+                Rounding::Up if col <= s.width() => {
+                    assert!(prefix.width() >= col, "{s:?} at column {col}")
+                }
+                Rounding::Up => assert_eq!(byte_idx, s.len()),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_column_at_byte() {
+    assert_eq!(column_at_byte("", 0, Rounding::Down), 0);
+    assert_eq!(column_at_byte("abc", 2, Rounding::Up), 2);
+    assert_eq!(column_at_byte("abc", 7, Rounding::Down), 3);
+    assert_eq!(column_at_byte("a中b", 1, Rounding::Up), 1);
+    assert_eq!(column_at_byte("a中b", 3, Rounding::Down), 1);
+    assert_eq!(column_at_byte("a中b", 3, Rounding::Up), 3);
+    assert_eq!(column_at_byte("a\r\nb", 2, Rounding::Down), 1);
+    assert_eq!(column_at_byte("a\r\nb", 2, Rounding::Up), 2);
+    assert_eq!(column_at_byte("e\u{301}x", 1, Rounding::Down), 0);
+    assert_eq!(column_at_byte("e\u{301}x", 1, Rounding::Up), 1);
+    assert_eq!(column_at_byte("x👩‍🔬y", 5, Rounding::Down), 1);
+    assert_eq!(column_at_byte("x👩‍🔬y", 5, Rounding::Up), 3);
+    assert_eq!(column_at_byte("x\u{2764}\u{FE0F}", 4, Rounding::Down), 1);
+    assert_eq!(column_at_byte("x\u{2764}\u{FE0F}", 4, Rounding::Up), 3);
+    assert_eq!(column_at_byte("🇮🇸🇳🇴", 4, Rounding::Down), 0);
+    assert_eq!(column_at_byte("🇮🇸🇳🇴", 4, Rounding::Up), 2);
+    assert_eq!(column_at_byte("ꓹꓼx", 3, Rounding::Up), 1);
+
+    assert_eq!(byte_at_column("", 1, Rounding::Down), 0);
+    assert_eq!(byte_at_column("abc", 2, Rounding::Down), 2);
+    assert_eq!(byte_at_column("abc", 7, Rounding::Down), 3);
+    assert_eq!(byte_at_column("a中b", 2, Rounding::Up), 4);
+    assert_eq!(byte_at_column("a\u{301}b", 1, Rounding::Down), 3);
+    assert_eq!(byte_at_column("\u{301}b", 0, Rounding::Up), 0);
+    assert_eq!(byte_at_column("x👩‍🔬y", 2, Rounding::Down), 1);
+    assert_eq!(byte_at_column("x👩‍🔬y", 2, Rounding::Up), 12);
+    assert_eq!(byte_at_column("🇮🇸🇳🇴", 3, Rounding::Down), 8);
+
+    for s in [
+        "ｈｅｌｌｏ, world\r\n",
+        "👩‍🔬👩🏽‍🔬🇮🇸🇳🇴🇮",
+        "\u{301}\u{2764}\u{FE0F}\u{2764}\u{FE0E}\u{2018}\u{FE01}\u{2018}\u{FE00}",
+        "ꓹꓼ ل\u{064F}\u{200D}ا א\u{200D}ל \u{10C32}\u{200D}\u{10C03} ⵏ⵿ⴾ",
+        "\u{1A15}\u{1A17}\u{200D}\u{1A10}\u{17D2}\u{1780}\u{17D8}",
+        "<\u{0301}\u{0338}=\u{0338}\u{1011B}\u{0338}",
+    ] {
+        assert_columns_consistently(s);
+    }
+}
+
+#[cfg(feature = "cjk")]
+#[test]
+fn test_column_at_byte_cjk() {
+    use unicode_width::{byte_at_column_cjk, column_at_byte_cjk};
+
+    assert_eq!(column_at_byte_cjk("“a”", 3, Rounding::Down), 2);
+    assert_eq!(column_at_byte_cjk("a<\u{0338}", 2, Rounding::Down), 1);
+    assert_eq!(column_at_byte_cjk("a<\u{0338}", 2, Rounding::Up), 3);
+    assert_eq!(byte_at_column_cjk("“a”", 1, Rounding::Down), 0);
+    assert_eq!(byte_at_column_cjk("“a”", 1, Rounding::Up), 3);
+}
+
+fn assert_segments_consistently(s: &str) {
+    let mut start = 0;
+    let mut total = 0;
+    for (range, width) in width_segments(s) {
+        assert_eq!(range.start, start, "{s:?}");
+        assert!(range.end > range.start, "{s:?}");
+        assert_eq!(s[range.clone()].width(), width, "{s:?} at {range:?}");
+        assert_eq!(s[..range.end].width() + s[range.end..].width(), s.width());
+        assert!(split_at_width(s, total + width).0.len() >= range.end);
+        start = range.end;
+        total += width;
+    }
+    assert_eq!(start, s.len(), "{s:?}");
+    assert_eq!(total, s.width(), "{s:?}");
+}
+
+#[test]
+fn test_width_segments() {
+    assert_eq!(width_segments("").next(), None);
+    assert_eq!(
+        width_segments("ab中").collect::<Vec<_>>(),
+        [(0..1, 1), (1..2, 1), (2..5, 2)]
+    );
+    assert_eq!(
+        width_segments("\u{301}a\r\n").collect::<Vec<_>>(),
+        [(0..2, 0), (2..3, 1), (3..5, 1)]
+    );
+    assert_eq!(
+        width_segments("🇮🇸🇳🇴🇮").collect::<Vec<_>>(),
+        [(0..8, 2), (8..16, 2), (16..20, 1)]
+    );
+    assert_eq!(
+        width_segments("x👩‍🔬\u{2764}\u{FE0F}").collect::<Vec<_>>(),
+        [(0..1, 1), (1..12, 2), (12..18, 2)]
+    );
+    assert_eq!(
+        width_segments("ꓹꓼក\u{17D2}ក").collect::<Vec<_>>(),
+        [(0..6, 1), (6..15, 1)]
+    );
+    assert_eq!(
+        width_segments("\u{16D63}\u{16D67}\u{16D67}x").collect::<Vec<_>>(),
+        [(0..12, 1), (12..13, 1)]
+    );
+    assert_eq!(
+        width_segments("\u{16D69}\u{16D67}\u{16D67}").collect::<Vec<_>>(),
+        [(0..4, 1), (4..12, 1)]
+    );
+
+    for s in [
+        "ｈｅｌｌｏ, world\r\n",
+        "👩‍🔬👩🏽‍🔬🇮🇸🇳🇴🇮",
+        "\u{301}\u{2764}\u{FE0F}\u{2764}\u{FE0E}\u{2018}\u{FE01}\u{2018}\u{FE00}",
+        "ꓹꓼ ل\u{064F}\u{200D}ا א\u{200D}ל \u{10C32}\u{200D}\u{10C03} ⵏ⵿ⴾ",
+        "\u{1A15}\u{1A17}\u{200D}\u{1A10}\u{17D2}\u{1780}\u{17D8}",
+        "<\u{0301}\u{0338}=\u{0338}\u{1011B}\u{0338}",
+        "🇮\u{200D}🇮🇸\u{200D}🇳🇴🇮\u{200D}",
+        "\u{16D69}\u{16D67}\u{16D67}\u{16D68}\u{16D67}\u{16D6A}\u{16D67}",
+        &"\u{16D67}".repeat(301),
+        &format!("{}x", "\u{16D69}\u{16D67}".repeat(150)),
+        &"🇮".repeat(301),
+        &"👩\u{200D}".repeat(150),
+    ] {
+        assert_segments_consistently(s);
+    }
+}
+
+#[test]
+fn emoji_test_file_segments() {
+    let norm_file = BufReader::new(
+        File::open("tests/emoji-test.txt")
+            .expect("run `unicode.py` first to download `emoji-test.txt`"),
+    );
+    for line in norm_file.lines() {
+        let line = line.unwrap();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (cps, status) = line.split_once(';').unwrap();
+        if status.trim().starts_with("fully-qualified") {
+            let emoji: String = cps
+                .trim()
+                .split(' ')
+                .map(|s| char::try_from(u32::from_str_radix(s, 16).unwrap()).unwrap())
+                .collect();
+            let s = format!("a{emoji}b");
+            let segments: Vec<_> = width_segments(&s).collect();
+            assert_eq!(
+                segments,
+                [(0..1, 1), (1..s.len() - 1, 2), (s.len() - 1..s.len(), 1)],
+                "{emoji:?}"
+            );
+        }
+    }
+}
+
+#[cfg(feature = "cjk")]
+#[test]
+fn test_width_segments_cjk() {
+    use unicode_width::width_segments_cjk;
+
+    assert_eq!(
+        width_segments_cjk("“<\u{0338}").collect::<Vec<_>>(),
+        [(0..3, 2), (3..6, 2)]
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_truncate() {
+    use unicode_width::{truncate_end, truncate_middle, truncate_start};
+
+    assert_eq!(truncate_end("abc", 3, "…"), "abc");
+    assert_eq!(truncate_end("abcd", 3, "…"), "ab…");
+    assert_eq!(truncate_end("abcd", 3, "..."), "...");
+    assert_eq!(truncate_end("abcd", 2, "..."), "..");
+    assert_eq!(truncate_end("abcd", 0, "…"), "");
+    assert_eq!(truncate_end("a中文", 3, "…"), "a…");
+    assert_eq!(truncate_end("a👩‍🔬b", 3, "…"), "a…");
+    assert_eq!(truncate_end("a👩‍🔬bc", 4, "…"), "a👩‍🔬…");
+    assert_eq!(truncate_end("ab\r\n", 2, ""), "ab");
+    assert_eq!(truncate_end("abcd", 3, "\u{301}"), "abc\u{301}");
+    assert_eq!(truncate_end("🇮🇸🇳🇴", 3, "…"), "🇮🇸…");
+
+    assert_eq!(truncate_start("abc", 3, "…"), "abc");
+    assert_eq!(truncate_start("abcd", 3, "…"), "…cd");
+    assert_eq!(truncate_start("中文a", 3, "…"), "…a");
+    assert_eq!(truncate_start("ab👩‍🔬", 3, "…"), "…👩‍🔬");
+    assert_eq!(truncate_start("abcd", 2, "…"), "…d");
+    assert_eq!(truncate_start("abcd", 1, "……"), "…");
+
+    assert_eq!(truncate_middle("abcdef", 6, "…"), "abcdef");
+    assert_eq!(truncate_middle("abcdefg", 6, "…"), "abc…fg");
+    assert_eq!(truncate_middle("abcdefg", 5, "…"), "ab…fg");
+    assert_eq!(truncate_middle("中文中文中文", 7, "…"), "中…中文");
+    assert_eq!(truncate_middle("中文中文中文", 9, "…"), "中文…中文");
+    assert_eq!(truncate_middle("ab👩‍🔬👩‍🔬cd", 7, "…"), "ab…👩‍🔬cd");
+    assert_eq!(truncate_middle("abcdef", 1, "…"), "…");
+
+    for s in [
+        "ｈｅｌｌｏ, world\r\n",
+        "👩‍🔬👩🏽‍🔬🇮🇸🇳🇴🇮",
+        "\u{2764}\u{FE0F}\u{2764}\u{FE0E}\u{2018}\u{FE01}\u{2018}\u{FE00}",
+        "ꓹꓼ ל\u{064F}\u{200D}ا א\u{200D}ל \u{10C32}\u{200D}\u{10C03} ⵏ⵿ⴾ",
+    ] {
+        for ellipsis in ["", "…", "...", "\u{200D}", "ꓼ", "\u{FE0F}"] {
+            for max_width in 0..=s.width() + 1 {
+                for truncated in [
+                    truncate_end(s, max_width, ellipsis),
+                    truncate_start(s, max_width, ellipsis),
+                    truncate_middle(s, max_width, ellipsis),
+                ] {
+                    assert!(
+                        truncated.width() <= max_width,
+                        "{s:?} truncated to {max_width} with {ellipsis:?}: {truncated:?}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "cjk"))]
+#[test]
+fn test_truncate_cjk() {
+    use unicode_width::{truncate_end_cjk, truncate_middle_cjk, truncate_start_cjk};
+
+    assert_eq!(truncate_end_cjk("“quoted”", 6, "…"), "“qu…");
+    assert_eq!(truncate_start_cjk("“quoted”", 6, "…"), "…ed”");
+    assert_eq!(truncate_middle_cjk("“quoted”", 7, "…"), "“q…”");
+    assert_eq!(truncate_end_cjk("abc", 1, "…"), "");
+}
+
+#[test]
+fn test_display_width() {
+    assert_eq!(format!("{}", DisplayWidth::new("中文")), "中文");
+    assert_eq!(format!("{:3}", DisplayWidth::new("中文")), "中文");
+    assert_eq!(format!("[{:6}]", DisplayWidth::new("中文")), "[中文  ]");
+    assert_eq!(
+        format!("[{:>6}]", DisplayWidth::new("e\u{301}")),
+        "[     e\u{301}]"
+    );
+    assert_eq!(format!("[{:^5}]", DisplayWidth::new("中文")), "[中文 ]");
+    assert_eq!(format!("[{:^6}]", DisplayWidth::new("中")), "[  中  ]");
+    assert_eq!(format!("[{:*^7}]", DisplayWidth::new("👩‍🔬")), "[**👩‍🔬***]");
+    assert_eq!(format!("[{:.3}]", DisplayWidth::new("中文")), "[中]");
+    assert_eq!(format!("[{:5.3}]", DisplayWidth::new("中文")), "[中   ]");
+    assert_eq!(format!("[{:.1}]", DisplayWidth::new("👩‍🔬")), "[]");
+    assert_eq!(format!("[{:\u{301}>3}]", DisplayWidth::new("a")), "[  a]");
+    assert_eq!(format!("[{:\u{7}>3}]", DisplayWidth::new("a")), "[  a]");
+
+    // Wide fill characters leave spaces next to the string
+    assert_eq!(
+        format!("[{:\u{3000}<6}]", DisplayWidth::new("abc")),
+        "[abc \u{3000}]"
+    );
+    assert_eq!(
+        format!("[{:\u{3000}>6}]", DisplayWidth::new("abc")),
+        "[\u{3000} abc]"
+    );
+    assert_eq!(
+        format!("[{:\u{3000}^9}]", DisplayWidth::new("abc")),
+        "[\u{3000} abc \u{3000}]"
+    );
+
+    for (s, width) in [("ｈｅｌｌｏ", 12), ("🇮🇸🇳🇴🇮", 8), ("a\r\n", 3)] {
+        let formatted = format!("{:^width$}", DisplayWidth::new(s));
+        assert_eq!(formatted.width(), width, "{formatted:?}");
+    }
+
+    #[cfg(feature = "cjk")]
+    assert_eq!(format!("[{:4}]", DisplayWidth::new_cjk("“")), "[“  ]");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_wrap() {
+    use unicode_width::wrap::{wrap, Options};
+
+    assert_eq!(wrap("", &Options::new(5)), [""]);
+    assert_eq!(wrap("a b c", &Options::new(5)), ["a b c"]);
+    assert_eq!(wrap("a b c", &Options::new(3)), ["a b", "c"]);
+    assert_eq!(wrap("aa   bb  ", &Options::new(4)), ["aa", "bb"]);
+    assert_eq!(wrap("  aa bb", &Options::new(5)), ["  aa", "bb"]);
+    assert_eq!(wrap("ab\r\n\ncd", &Options::new(5)), ["ab", "", "cd"]);
+    assert_eq!(wrap("   ", &Options::new(5)), ["   "]);
+    assert_eq!(
+        wrap("  \nab", &Options::new(5).initial_indent("* ")),
+        ["  ", "* ab"]
+    );
+    assert_eq!(wrap("中文 中文", &Options::new(4)), ["中文", "中文"]);
+    assert_eq!(wrap("abcdef gh", &Options::new(4)), ["abcdef", "gh"]);
+    assert_eq!(wrap("x 👩‍🔬", &Options::new(3)), ["x", "👩‍🔬"]);
+    assert_eq!(wrap("e\u{301} f", &Options::new(3)), ["e\u{301} f"]);
+
+    let options = Options::new(6).initial_indent("* ").subsequent_indent("  ");
+    assert_eq!(
+        wrap("one two three\nfour", &options),
+        ["* one", "  two", "  three", "* four"]
+    );
+
+    let options = Options::new(4).break_words(true);
+    assert_eq!(wrap("abcdefghij", &options), ["abcd", "efgh", "ij"]);
+    assert_eq!(wrap("ab cdefgh i", &options), ["ab", "cdef", "gh i"]);
+    assert_eq!(wrap("中文中文中", &options), ["中文", "中文", "中"]);
+    assert_eq!(wrap("a中文", &options), ["a中", "文"]);
+    let long = "中".repeat(4000);
+    let lines = wrap(&long, &Options::new(80).break_words(true));
+    assert_eq!(lines.len(), 100);
+    assert!(lines.iter().all(|line| *line == "中".repeat(40)));
+    assert_eq!(wrap("👩‍🔬👩‍🔬👩‍🔬", &options), ["👩‍🔬👩‍🔬", "👩‍🔬"]);
+    assert_eq!(
+        wrap("🇮🇸🇳🇴🇮", &Options::new(3).break_words(true)),
+        ["🇮🇸", "🇳🇴🇮"]
+    );
+    assert_eq!(
+        wrap("中文", &Options::new(1).break_words(true)),
+        ["中", "文"]
+    );
+    assert_eq!(
+        wrap(
+            "ab中",
+            &Options::new(3).initial_indent(">").break_words(true)
+        ),
+        [">ab", "中"]
+    );
+
+    for text in [
+        "ｈｅｌｌｏ, world and 👩‍🔬👩🏽‍🔬🇮🇸🇳🇴🇮 some words",
+        "\u{2764}\u{FE0F}\u{2764}\u{FE0E} \u{2018}\u{FE01}\u{2018}\u{FE00}",
+        "ꓹꓼ ל\u{064F}\u{200D}ا א\u{200D}ל \u{10C32}\u{200D}\u{10C03} ⵏ⵿ⴾ",
+    ] {
+        for width in 3..12 {
+            let options = Options::new(width).subsequent_indent(" ").break_words(true);
+            for line in wrap(text, &options) {
+                assert!(
+                    line.width() <= width,
+                    "{text:?} wrapped to {width}: {line:?}"
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "cjk")]
+    assert_eq!(wrap("“a” “b”", &Options::new_cjk(6)), ["“a”", "“b”"]);
+}
+
+#[test]
+fn test_width_with_tabs() {
+    let every_4 = TabStops::Every(4);
+    assert_eq!(width_with_tabs("", every_4), 0);
+    assert_eq!(width_with_tabs("\t", every_4), 4);
+    assert_eq!(width_with_tabs("abc\t", every_4), 4);
+    assert_eq!(width_with_tabs("abcd\t", every_4), 8);
+    assert_eq!(width_with_tabs("\t\tx", every_4), 9);
+    assert_eq!(width_with_tabs("中\u{301}\tx", every_4), 5);
+    assert_eq!(width_with_tabs("e\u{301}\u{301}\tx", every_4), 5);
+    assert_eq!(width_with_tabs("👩‍🔬a\tx", every_4), 5);
+    assert_eq!(width_with_tabs("ab\n\tx", every_4), 8);
+    assert_eq!(width_with_tabs("ab\r\n\tx", every_4), 8);
+    assert_eq!(width_with_tabs("a\tb", TabStops::Every(1)), 3);
+    assert_eq!(width_with_tabs("a\tb", TabStops::Every(0)), 2);
+
+    let stops = TabStops::Explicit(&[3, 5]);
+    assert_eq!(width_with_tabs("\t", stops), 3);
+    assert_eq!(width_with_tabs("abc\t", stops), 5);
+    assert_eq!(width_with_tabs("\t\t\t\t", stops), 7);
+    assert_eq!(width_with_tabs("中文中\t", stops), 7);
+    assert_eq!(width_with_tabs("\t", TabStops::Explicit(&[])), 1);
+
+    for s in [
+        "ｈｅｌｌｏ,\tworld\r\n",
+        "a\u{2764}\u{FE0F}\tꓹꓼ\t\t",
+        "\n\t\r\t\u{200D}\t",
+    ] {
+        assert_eq!(width_with_tabs(s, TabStops::Every(1)), s.width(), "{s:?}");
+    }
+
+    #[cfg(feature = "cjk")]
+    {
+        use unicode_width::width_with_tabs_cjk;
+        assert_eq!(width_with_tabs_cjk("“\tx", every_4), 5);
+        assert_eq!(width_with_tabs_cjk("“”\tx", every_4), 9);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_expand_tabs() {
+    use std::borrow::Cow;
+    use unicode_width::expand_tabs;
+
+    let every_4 = TabStops::Every(4);
+    assert!(matches!(expand_tabs("abc", every_4), Cow::Borrowed("abc")));
+    assert_eq!(expand_tabs("\tx", every_4), "    x");
+    assert_eq!(expand_tabs("中\u{301}\tx", every_4), "中\u{301}  x");
+    assert_eq!(expand_tabs("ab\ncd\te", every_4), "ab\ncd  e");
+    assert_eq!(
+        expand_tabs("a\tb\tc\td", TabStops::Explicit(&[2, 6])),
+        "a b   c d"
+    );
+    assert_eq!(expand_tabs("a\tb", TabStops::Every(0)), "ab");
+
+    for s in [
+        "ｈｅｌｌｏ,\tworld\r\n\t!",
+        "a\u{2764}\u{FE0F}\tꓹꓼ\t\t",
+        "\n\t\r\t\u{200D}\t",
+    ] {
+        for tab_stops in [every_4, TabStops::Every(3), TabStops::Explicit(&[1, 4, 9])] {
+            assert_eq!(
+                expand_tabs(s, tab_stops).width(),
+                width_with_tabs(s, tab_stops),
+                "{s:?}"
+            );
+        }
+    }
+
+    #[cfg(feature = "cjk")]
+    assert_eq!(unicode_width::expand_tabs_cjk("“\tx", every_4), "“  x");
+}
+
+#[test]
+fn test_width_ansi() {
+    assert_eq!(width_ansi(""), 0);
+    assert_eq!(width_ansi("plain 中文"), 10);
+    assert_eq!(width_ansi("\x1b[m"), 0);
+    assert_eq!(width_ansi("\x1b[38;2;255;0;0mred\x1b[0m"), 3);
+    assert_eq!(width_ansi("\x1b[?25l\x1b[2 qx"), 1);
+    assert_eq!(width_ansi("\u{9B}1mbold\u{9B}m"), 4);
+
+    // Other escape sequences
+    assert_eq!(width_ansi("\x1b(Bx\x1b7\x1b8\x1bc"), 1);
+    assert_eq!(width_ansi("\x1b#8x"), 1);
+
+    // Strings
+    assert_eq!(width_ansi("\x1b]0;title\x07x"), 1);
+    assert_eq!(width_ansi("\x1b]0;title\x1b\\x"), 1);
+    assert_eq!(width_ansi("\x1b]0;title\u{9C}x"), 1);
+    assert_eq!(width_ansi("\u{9D}0;title\x07x"), 1);
+    assert_eq!(width_ansi("\x1bPq#0;2;0;0;0\x07 still in DCS\x1b\\x"), 1);
+    assert_eq!(width_ansi("\x1b_apc\u{9C}\u{98}sos\x1b\\x"), 1);
+    assert_eq!(
+        width_ansi("\x1b]8;;https://example.com/中文\x1b\\中文\x1b]8;;\x1b\\"),
+        4
+    );
+
+    // C1 controls
+    assert_eq!(width_ansi("a\u{85}b\u{8D}c"), 3);
+
+    // Cut off or interrupted sequences
+    assert_eq!(width_ansi("ab\x1b"), 2);
+    assert_eq!(width_ansi("ab\x1b[1;3"), 2);
+    assert_eq!(width_ansi("ab\x1b]0;title"), 2);
+    assert_eq!(width_ansi("\x1b[1中"), 2);
+    assert_eq!(width_ansi("\x1b]0;title\x1b[31mx"), 1);
+    assert_eq!(width_ansi("\x1b中"), 2);
+    assert_eq!(width_ansi("\x1b\x1b[1mx"), 1);
+
+    // Plain C0 controls are measured as usual
+    assert_eq!(width_ansi("a\x07b\r\n"), "a\x07b\r\n".width());
+
+    // Escape sequences don't break up what's around them
+    assert_eq!(width_ansi("👩\x1b[31m\u{200D}🔬"), 2);
+    assert_eq!(width_ansi("\u{2764}\x1b[0m\u{FE0F}"), 2);
+    assert_eq!(width_ansi("a\r\x1b[K\n"), 2);
+
+    #[cfg(feature = "cjk")]
+    {
+        use unicode_width::width_ansi_cjk;
+        assert_eq!(width_ansi_cjk("\x1b[1m“quoted”\x1b[m"), 10);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_truncate_ansi() {
+    use unicode_width::truncate_ansi;
+
+    assert_eq!(
+        truncate_ansi("\x1b[31mred\x1b[0m", 3, "…"),
+        "\x1b[31mred\x1b[0m"
+    );
+    assert_eq!(
+        truncate_ansi("\x1b[31mred\x1b[0m", 2, "…"),
+        "\x1b[31mr…\x1b[0m"
+    );
+    assert_eq!(truncate_ansi("ab\x1b[1mcd", 3, "…"), "ab…");
+    assert_eq!(truncate_ansi("a\x1b[1mbcd", 3, "…"), "a\x1b[1mb…\x1b[0m");
+    assert_eq!(
+        truncate_ansi("\x1b[1ma\x1b[mbcd", 3, "…"),
+        "\x1b[1ma\x1b[mb…"
+    );
+    assert_eq!(truncate_ansi("\x1b[1mabc", 1, "..."), "\x1b[1m.\x1b[0m");
+    assert_eq!(truncate_ansi("\x1b[1mabc", 0, "…"), "\x1b[1m\x1b[0m");
+    assert_eq!(truncate_ansi("\x1b[1m中文", 3, "…"), "\x1b[1m中…\x1b[0m");
+    assert_eq!(truncate_ansi("👩\x1b[31m\u{200D}🔬x", 2, "…"), "…");
+    assert_eq!(
+        truncate_ansi("\x1b]8;;http://a\x1b\\link\x1b]8;;\x1b\\", 3, "…"),
+        "\x1b]8;;http://a\x1b\\li…\x1b]8;;\x1b\\"
+    );
+    assert_eq!(
+        truncate_ansi(
+            "\x1b[1m\x1b]8;id=1;http://a\x07link\x1b]8;;\x07 text",
+            3,
+            "…"
+        ),
+        "\x1b[1m\x1b]8;id=1;http://a\x07li…\x1b[0m\x1b]8;;\x1b\\"
+    );
+    assert_eq!(
+        truncate_ansi("\x1b]8;;http://a\x1b\\ab\x1b]8;;\x1b\\cd", 3, "…"),
+        "\x1b]8;;http://a\x1b\\ab…\x1b]8;;\x1b\\"
+    );
+
+    for s in [
+        "\x1b[1;31merror\x1b[0m: \x1b[1mmismatched types\x1b[0m",
+        "\x1b[32m👩‍🔬\x1b[0m👩🏽\x1b[1m\u{200D}🔬🇮🇸🇳🇴🇮",
+    ] {
+        for max_width in 0..=width_ansi(s) + 1 {
+            let truncated = truncate_ansi(s, max_width, "…");
+            assert!(width_ansi(&truncated) <= max_width, "{truncated:?}");
+        }
+    }
+
+    #[cfg(feature = "cjk")]
+    assert_eq!(
+        unicode_width::truncate_ansi_cjk("\x1b[1m“a”", 4, "…"),
+        "\x1b[1m“…\x1b[0m"
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_slice_ansi() {
+    use unicode_width::slice_ansi;
+
+    assert_eq!(slice_ansi("abc", 0..0), "");
+    assert_eq!(slice_ansi("abc", 1..2), "b");
+    assert_eq!(slice_ansi("abc", 2..7), "c");
+    assert_eq!(slice_ansi("a中b", 2..3), "");
+    assert_eq!(slice_ansi("a中b", 2..4), "b");
+    assert_eq!(slice_ansi("a中b", 0..2), "a");
+    assert_eq!(
+        slice_ansi("\x1b[1ma\x1b[31mbc", 1..2),
+        "\x1b[1m\x1b[31mb\x1b[0m"
+    );
+    assert_eq!(
+        slice_ansi("\x1b[1ma\x1b[0;31mbc", 2..3),
+        "\x1b[0;31mc\x1b[0m"
+    );
+    assert_eq!(slice_ansi("\x1b[1ma\x1b[31;0mbc", 2..3), "c");
+    assert_eq!(
+        slice_ansi("\x1b[38;5;0ma\x1b[mbc", 0..1),
+        "\x1b[38;5;0ma\x1b[0m"
+    );
+    assert_eq!(
+        slice_ansi("\x1b[38;2;0;0;0ma\x1b[1mb", 1..2),
+        "\x1b[38;2;0;0;0m\x1b[1mb\x1b[0m"
+    );
+    assert_eq!(slice_ansi("\x1b[48;5;0;0ma", 0..1), "a");
+    assert_eq!(
+        slice_ansi("\u{9B}4ma\x1b[?25lb", 1..2),
+        "\u{9B}4m\x1b[?25lb\x1b[0m"
+    );
+    assert_eq!(slice_ansi("\x1b]0;title\x07ab", 1..2), "b");
+    assert_eq!(
+        slice_ansi("a\x1b]8;;http://a\x1b\\bcd\x1b]8;;\x1b\\e", 2..3),
+        "\x1b]8;;http://a\x1b\\c\x1b]8;;\x1b\\"
+    );
+    assert_eq!(
+        slice_ansi("a\x1b]8;;http://a\x1b\\bcd\x1b]8;;\x1b\\e", 3..5),
+        "\x1b]8;;http://a\x1b\\d\x1b]8;;\x1b\\e"
+    );
+    assert_eq!(slice_ansi("a\x1b[1mb\x1b[0mc", 0..3), "a\x1b[1mb\x1b[0mc");
+    assert_eq!(
+        slice_ansi("👩\x1b[31m\u{200D}🔬x", 0..2),
+        "👩\x1b[31m\u{200D}🔬\x1b[0m"
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_wrap_ansi() {
+    use unicode_width::wrap::{wrap_ansi, Options};
+
+    assert_eq!(wrap_ansi("", &Options::new(4)), [""]);
+    assert_eq!(wrap_ansi("ab cd", &Options::new(4)), ["ab", "cd"]);
+    assert_eq!(
+        wrap_ansi("\x1b[1mab \x1b[31mcd\x1b[0m ef", &Options::new(3)),
+        ["\x1b[1mab\x1b[0m", "\x1b[1m\x1b[31mcd\x1b[0m", "ef"]
+    );
+    assert_eq!(
+        wrap_ansi("\x1b[1mab\ncd", &Options::new(3).initial_indent(">")),
+        [">\x1b[1mab\x1b[0m", ">\x1b[1mcd\x1b[0m"]
+    );
+    assert_eq!(
+        wrap_ansi(
+            "\x1b[32m👩‍🔬👩‍🔬\x1b[0m👩‍🔬",
+            &Options::new(4).subsequent_indent(" ").break_words(true)
+        ),
+        ["\x1b[32m👩‍🔬👩‍🔬\x1b[0m", " 👩‍🔬"]
+    );
+
+    let text =
+        "\x1b[1;31merror[E0308]\x1b[0m\x1b[1m: mismatched types\x1b[0m \x1b[32m中文中文中文\x1b[0m";
+    for width in 2..20 {
+        let lines = wrap_ansi(text, &Options::new(width).break_words(true));
+        for line in &lines {
+            assert!(width_ansi(line) <= width, "{line:?}");
+        }
+    }
+}
+
+#[test]
+fn test_width_with_controls() {
+    use ControlPolicy::*;
+
+    for s in ["", "abc", "中文", "👩‍🔬"] {
+        for policy in [Standard, Caret, Hex, CodePoint, Pictures] {
+            assert_eq!(width_with_controls(s, policy), s.width());
+        }
+    }
+
+    assert_eq!(width_with_controls("a\r\n", Standard), 2);
+    assert_eq!(width_with_controls("a\r\n", Caret), 5);
+    assert_eq!(width_with_controls("\0\x1F\x7F", Caret), 6);
+    assert_eq!(width_with_controls("\u{80}\u{9F}", Caret), 8);
+    assert_eq!(width_with_controls("\t\u{9F}", Hex), 8);
+    assert_eq!(width_with_controls("\t\u{9F}", CodePoint), 16);
+    assert_eq!(width_with_controls("\t\x7F\u{9F}", Pictures), 10);
+    assert_eq!(
+        width_with_controls("\u{A0}\u{AD}", Caret),
+        "\u{A0}\u{AD}".width()
+    );
+
+    // What comes before a control character is measured the same way as before what replaces it
+    assert_eq!(width_with_controls("\u{2764}\x01\u{FE0F}", Caret), 3);
+    assert_eq!(width_with_controls("👩\x01\u{200D}🔬", Hex), 8);
+
+    #[cfg(feature = "cjk")]
+    {
+        use unicode_width::width_with_controls_cjk;
+        assert_eq!(width_with_controls_cjk("“\x01", Caret), 4);
+        assert_eq!(width_with_controls_cjk("\x01", Pictures), 1);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_escape_controls() {
+    use std::borrow::Cow;
+    use unicode_width::escape_controls;
+    use ControlPolicy::*;
+
+    assert!(matches!(
+        escape_controls("a\x01", Standard),
+        Cow::Borrowed(_)
+    ));
+    assert!(matches!(escape_controls("abc", Caret), Cow::Borrowed(_)));
+    assert_eq!(escape_controls("a\tb\n", Caret), "a^Ib^J");
+    assert_eq!(
+        escape_controls("\0\x1B\x1F\x7F\u{80}\u{9F}", Caret),
+        "^@^[^_^?M-^@M-^_"
+    );
+    assert_eq!(
+        escape_controls("\0\n\x7F\u{9B}", Hex),
+        "\\x00\\x0A\\x7F\\x9B"
+    );
+    assert_eq!(
+        escape_controls("\0\n\x7F\u{9B}", CodePoint),
+        "<U+0000><U+000A><U+007F><U+009B>"
+    );
+    assert_eq!(escape_controls("\0\n\x7F\u{9B}", Pictures), "␀␊␡<U+009B>");
+
+    for s in [
+        "a\r\n\tb",
+        "\u{2764}\x01\u{FE0F}",
+        "\x1b[31m中\u{85}",
+        "👩\x01\u{200D}🔬",
+    ] {
+        for policy in [Standard, Caret, Hex, CodePoint, Pictures] {
+            assert_eq!(
+                escape_controls(s, policy).width(),
+                width_with_controls(s, policy),
+                "{s:?} {policy:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_line_widths() {
+    fn widths(s: &str) -> Vec<usize> {
+        line_widths(s).collect()
+    }
+
+    assert_eq!(widths(""), []);
+    assert_eq!(widths("abc"), [3]);
+    assert_eq!(widths("\n"), [0]);
+    assert_eq!(widths("\n\n"), [0, 0]);
+    assert_eq!(widths("a\nbc\n"), [1, 2]);
+    assert_eq!(widths("a\r\nbc\rdef"), [1, 2, 3]);
+    assert_eq!(widths("a\r\rb"), [1, 0, 1]);
+    assert_eq!(widths("a\n\rb"), [1, 0, 1]);
+    assert_eq!(widths("中文\u{2028}ab\u{2029}👩‍🔬"), [4, 2, 2]);
+    assert_eq!(widths("\u{2764}\n\u{FE0F}"), [1, 0]);
+    assert_eq!(widths("a\tb\u{85}c"), [5]);
+
+    assert_eq!(max_line_width(""), 0);
+    assert_eq!(max_line_width("\n\n"), 0);
+    assert_eq!(max_line_width("ab\r\n中文中\nc"), 6);
+
+    let s = "first line\n\n  中文\r\n👩‍🔬\rlast";
+    assert_eq!(
+        widths(s),
+        s.lines()
+            .flat_map(|l| l.split('\r'))
+            .map(|l| l.width())
+            .collect::<Vec<_>>()
+    );
+
+    #[cfg(feature = "cjk")]
+    {
+        use unicode_width::{line_widths_cjk, max_line_width_cjk};
+        assert_eq!(line_widths_cjk("“a”\nb").collect::<Vec<_>>(), [5, 1]);
+        assert_eq!(max_line_width_cjk("“a”\nb"), 5);
+    }
+}
+
+#[test]
+fn test_width_config() {
+    use unicode_width::WidthConfig;
+
+    let default = WidthConfig::DEFAULT;
+    assert_eq!(WidthConfig::default(), default);
+    for s in [
+        "ｈｅｌｌｏ, world\r\n",
+        "👩‍🔬👩🏽‍🔬🇮🇸🇳🇴🇮",
+        "\u{2764}\u{FE0F}\u{2764}\u{FE0E}\u{2018}\u{FE01}\u{2018}\u{FE00}",
+        "ꓹꓼ ל\u{064F}\u{200D}ا א\u{200D}ל \u{10C32}\u{200D}\u{10C03} ⵏ⵿ⴾ",
+    ] {
+        assert_eq!(default.width(s), s.width());
+        #[cfg(feature = "cjk")]
+        assert_eq!(WidthConfig::CJK.width(s), s.width_cjk());
+    }
+    for c in ['a', '\0', '中', '\u{2081}', '\u{1F1EE}', '\u{FE0F}'] {
+        assert_eq!(default.char_width(c), c.width());
+        #[cfg(feature = "cjk")]
+        assert_eq!(WidthConfig::CJK.char_width(c), c.width_cjk());
+    }
+
+    let no_zwj = default.join_zwj_sequences(false);
+    assert_eq!(no_zwj.width("👩‍🔬"), 4);
+    assert_eq!(no_zwj.width("👩🏽‍🔬"), 4);
+    assert_eq!(no_zwj.width("❤\u{FE0F}\u{200D}🔥"), 4);
+    assert_eq!(no_zwj.width("👨‍👩‍👧"), 6);
+    assert_eq!(no_zwj.width("א\u{200D}ל"), 1);
+    assert_eq!(no_zwj.width("\u{10C32}\u{200D}\u{10C03}"), 1);
+    assert_eq!(no_zwj.width("a\u{200D}"), 1);
+
+    let no_vs16 = default.widen_emoji_presentation(false);
+    assert_eq!(no_vs16.width("\u{2764}\u{FE0F}"), 1);
+    assert_eq!(no_vs16.width("\u{231A}\u{FE0F}"), 2);
+    assert_eq!(no_vs16.width("❤\u{FE0F}\u{200D}🔥"), 2);
+    assert_eq!(no_vs16.width("1\u{FE0F}\u{20E3}"), 1);
+
+    let no_vs15 = default.narrow_text_presentation(false);
+    assert_eq!(no_vs15.width("\u{231A}\u{FE0E}"), 2);
+    assert_eq!(no_vs15.width("\u{2764}\u{FE0E}"), 1);
+    assert_eq!(no_vs15.width("\u{2018}\u{FE00}"), 1);
+
+    let wide_ri = default.unpaired_regional_indicator_width(2);
+    assert_eq!(wide_ri.width("🇮"), 2);
+    assert_eq!(wide_ri.width("🇮🇸"), 2);
+    assert_eq!(wide_ri.width("🇮🇸🇳a🇮🇸🇳🇴"), 9);
+    assert_eq!(wide_ri.char_width('\u{1F1EE}'), Some(2));
+    assert_eq!(default.unpaired_regional_indicator_width(0).width("🇮🇸🇳"), 2);
+
+    let no_breaks = default.count_line_breaks(false);
+    assert_eq!(no_breaks.width("a\nb\r\nc\rd\n\r"), 4);
+    assert_eq!(no_breaks.width("\r\r\n\n"), 0);
+
+    #[cfg(feature = "cjk")]
+    {
+        assert_eq!(WidthConfig::CJK, default.ambiguous_wide(true));
+        let cjk = WidthConfig::CJK.join_zwj_sequences(false);
+        assert_eq!(cjk.width("“👩‍🔬”"), 8);
+        assert_eq!(cjk.char_width('“'), Some(2));
+    }
+}
+
+#[test]
+fn test_profiles() {
+    use unicode_width::{Profile, WidthConfig};
+
+    let s = "👩‍🔬 \u{2764}\u{FE0F} \u{231A}\u{FE0E}";
+    for (profile, width) in [
+        (Profile::Xterm, 9),
+        (Profile::Vte, 9),
+        (Profile::Kitty, 7),
+        (Profile::WezTerm, 7),
+        (Profile::Foot, 7),
+        (Profile::Alacritty, 9),
+        (Profile::WindowsTerminal, 8),
+        (Profile::Conhost, 9),
+        (Profile::AppleTerminal, 7),
+        (Profile::Tmux, 10),
+        (Profile::Screen, 9),
+    ] {
+        assert_eq!(profile.width(s), width, "{profile:?}");
+        assert_eq!(WidthConfig::from(profile), profile.config());
+        assert_eq!(profile.width("ｈｅｌｌｏ, world"), 17);
+    }
+}
+
+#[test]
+fn test_glibc_compat() {
+    use unicode_width::compat::glibc::{wcswidth, wcwidth, UNICODE_VERSION};
+
+    assert_eq!(UNICODE_VERSION, (14, 0, 0));
+
+    // The ranges of characters that glibc considers printable,
+    // but to which it gives another width than `UnicodeWidthChar::width`
+    let mut differences: Vec<(u32, u32, i32, Option<usize>)> = Vec::new();
+    for c in '\0'..=char::MAX {
+        let (glibc, ours) = (wcwidth(c), c.width());
+        if ours.is_none() {
+            // Control characters
+            assert!(glibc == -1 || c == '\0', "{c:?}");
+        }
+        if glibc == -1 || ours == Some(glibc as usize) {
+            continue;
+        }
+        match differences.last_mut() {
+            Some((_, hi, g, o)) if *hi + 1 == c as u32 && (*g, *o) == (glibc, ours) => {
+                *hi = c as u32
+            }
+            _ => differences.push((c as u32, c as u32, glibc, ours)),
+        }
+    }
+
+    let mut expected = [
+        (0x0000, 0x0000, 0, None),
+        // Format characters, and characters that combine with those around them,
+        // which glibc shows on their own
+        (0x00AD, 0x00AD, 1, Some(0)),
+        (0x0605, 0x0605, 1, Some(0)),
+        (0x070F, 0x070F, 1, Some(0)),
+        (0x0890, 0x0891, 1, Some(0)),
+        (0x08E2, 0x08E2, 1, Some(0)),
+        (0x09BE, 0x09BE, 1, Some(0)),
+        (0x09D7, 0x09D7, 1, Some(0)),
+        (0x0B3E, 0x0B3E, 1, Some(0)),
+        (0x0B57, 0x0B57, 1, Some(0)),
+        (0x0BBE, 0x0BBE, 1, Some(0)),
+        (0x0BD7, 0x0BD7, 1, Some(0)),
+        (0x0CC0, 0x0CC0, 1, Some(0)),
+        (0x0CC2, 0x0CC2, 1, Some(0)),
+        (0x0CC7, 0x0CC8, 1, Some(0)),
+        (0x0CCA, 0x0CCB, 1, Some(0)),
+        (0x0CD5, 0x0CD6, 1, Some(0)),
+        (0x0D3E, 0x0D3E, 1, Some(0)),
+        (0x0D4E, 0x0D4E, 1, Some(0)),
+        (0x0D57, 0x0D57, 1, Some(0)),
+        (0x0DCF, 0x0DCF, 1, Some(0)),
+        (0x0DDF, 0x0DDF, 1, Some(0)),
+        (0x1715, 0x1715, 1, Some(0)),
+        (0x1734, 0x1734, 1, Some(0)),
+        (0x1B35, 0x1B35, 1, Some(0)),
+        (0x1B3B, 0x1B3B, 1, Some(0)),
+        (0x1B3D, 0x1B3D, 1, Some(0)),
+        (0x1B43, 0x1B44, 1, Some(0)),
+        (0x1BAA, 0x1BAA, 1, Some(0)),
+        (0x1BF2, 0x1BF3, 1, Some(0)),
+        (0xA8FA, 0xA8FA, 1, Some(0)),
+        (0xA953, 0xA953, 1, Some(0)),
+        (0xA9C0, 0xA9C0, 1, Some(0)),
+        (0xFF9E, 0xFFA0, 1, Some(0)),
+        (0x111C0, 0x111C0, 1, Some(0)),
+        (0x111C2, 0x111C3, 1, Some(0)),
+        (0x11235, 0x11235, 1, Some(0)),
+        (0x1133E, 0x1133E, 1, Some(0)),
+        (0x1134D, 0x1134D, 1, Some(0)),
+        (0x11357, 0x11357, 1, Some(0)),
+        (0x114B0, 0x114B0, 1, Some(0)),
+        (0x114BD, 0x114BD, 1, Some(0)),
+        (0x115AF, 0x115AF, 1, Some(0)),
+        (0x116B6, 0x116B6, 1, Some(0)),
+        (0x11930, 0x11930, 1, Some(0)),
+        (0x1193D, 0x1193D, 1, Some(0)),
+        (0x1193F, 0x1193F, 1, Some(0)),
+        (0x11941, 0x11941, 1, Some(0)),
+        (0x11A84, 0x11A89, 1, Some(0)),
+        (0x11D46, 0x11D46, 1, Some(0)),
+        (0x1D165, 0x1D166, 1, Some(0)),
+        (0x1D16D, 0x1D172, 1, Some(0)),
+        // Khmer ligatures
+        (0x17A4, 0x17A4, 1, Some(2)),
+        (0x17D8, 0x17D8, 1, Some(3)),
+        // Made wide after Unicode 14
+        (0x2630, 0x2637, 1, Some(2)),
+        (0x268A, 0x268F, 1, Some(2)),
+        (0x1D300, 0x1D356, 1, Some(2)),
+        (0x1D360, 0x1D376, 1, Some(2)),
+        // Format characters that are visible after Unicode 14, or that this crate shows
+        (0x2D7F, 0x2D7F, 0, Some(1)),
+        (0xFFF9, 0xFFFB, 0, Some(1)),
+        (0x1171E, 0x1171E, 0, Some(1)),
+        (0x13430, 0x13438, 0, Some(1)),
+        // Wide in glibc, but combining or fillers here
+        (0x302E, 0x302F, 2, Some(0)),
+        (0x3164, 0x3164, 2, Some(0)),
+        (0x16FF0, 0x16FF1, 2, Some(0)),
+        // Ambiguous, but wide in glibc
+        (0x3248, 0x324F, 2, Some(1)),
+    ];
+    expected.sort();
+    assert_eq!(differences, expected);
+
+    // Unassigned in Unicode 14
+    assert_eq!(wcwidth('\u{1FAE8}'), -1);
+    assert_eq!(wcwidth('\u{FFFF}'), -1);
+    assert_eq!(wcwidth('\u{2028}'), -1);
+    assert_eq!(wcwidth('\u{E000}'), 1);
+
+    assert_eq!(wcswidth(""), 0);
+    assert_eq!(wcswidth("ｈｅｌｌｏ, world"), 17);
+    assert_eq!(wcswidth("\u{2764}\u{FE0F}"), 1);
+    assert_eq!(wcswidth("🇮🇸"), 2);
+    assert_eq!(wcswidth("a\r\n"), -1);
+    assert_eq!(wcswidth("ab\0\x1b"), 2);
+}
+
+#[test]
+fn test_python_wcwidth_compat() {
+    use unicode_width::compat::python_wcwidth::{wcswidth, wcwidth};
+    #[cfg(feature = "cjk")]
+    use unicode_width::compat::python_wcwidth::{wcswidth_cjk, wcwidth_cjk};
+
+    fn parse_widths<'a>(fields: impl Iterator<Item = &'a str>) -> (i32, i32) {
+        let widths: Vec<i32> = fields.map(|w| w.trim().parse().unwrap()).collect();
+        (widths[0], widths[1])
+    }
+
+    let chars_file = BufReader::new(File::open("tests/python-wcwidth-chars.txt").unwrap());
+    for line in chars_file.lines() {
+        let line = line.unwrap();
+        if line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.split(';');
+        let (lo, hi) = fields.next().unwrap().split_once("..").unwrap();
+        let (width, _width_cjk) = parse_widths(fields);
+        let lo = u32::from_str_radix(lo, 16).unwrap();
+        let hi = u32::from_str_radix(hi, 16).unwrap();
+        for c in (lo..=hi).filter_map(char::from_u32) {
+            assert_eq!(wcwidth(c), width, "{c:?}");
+            #[cfg(feature = "cjk")]
+            assert_eq!(wcwidth_cjk(c), _width_cjk, "{c:?}");
+        }
+    }
+
+    let strings_file = BufReader::new(File::open("tests/python-wcwidth-strings.txt").unwrap());
+    for line in strings_file.lines() {
+        let line = line.unwrap();
+        if line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.split(';');
+        let s: String = fields
+            .next()
+            .unwrap()
+            .split_whitespace()
+            .map(|cp| char::from_u32(u32::from_str_radix(cp, 16).unwrap()).unwrap())
+            .collect();
+        let (width, _width_cjk) = parse_widths(fields);
+        assert_eq!(wcswidth(&s), width, "{s:?}");
+        #[cfg(feature = "cjk")]
+        assert_eq!(wcswidth_cjk(&s), _width_cjk, "{s:?}");
+    }
+}
+
+#[test]
+fn test_unicode_version() {
+    use unicode_width::WidthConfig;
+
+    let config = WidthConfig::DEFAULT.unicode_version((12, 1, 0));
+    assert_eq!(config.char_width('\u{1FAE9}'), Some(1));
+    assert_eq!(config.char_width('\u{1F97A}'), Some(2));
+    assert_eq!(config.char_width('\u{32FF}'), Some(2));
+    assert_eq!(config.char_width('\u{0378}'), Some(1));
+    assert_eq!(config.char_width('\x1b'), None);
+    assert_eq!(
+        config.unicode_version((12, 0, 0)).char_width('\u{32FF}'),
+        Some(1)
+    );
+    assert_eq!(config.newer_char_width(2).char_width('\u{1FAE9}'), Some(2));
+
+    assert_eq!(config.width("\u{1FAE9}\u{FE0F}"), 1);
+    assert_eq!(WidthConfig::DEFAULT.width("a\u{1ACF}"), 1);
+    assert_eq!(config.unicode_version((16, 0, 0)).width("a\u{1ACF}"), 2);
+    assert_eq!(config.width("🧑🏻\u{200D}\u{1FAEF}\u{200D}🧑🏼"), 5);
+    assert_eq!(config.width("👩\u{200D}🔬"), 2);
+    assert_eq!(config.count_line_breaks(false).width("\u{1FAE9}\r\n"), 1);
+
+    for version in [(17, 0, 0), (17, 0, 1), (255, 0, 0)] {
+        let config = WidthConfig::DEFAULT.unicode_version(version);
+        assert_eq!(config.width("🧑🏻\u{200D}\u{1FAEF}\u{200D}🧑🏼"), 2);
+        assert_eq!(config.char_width('\u{1FAE9}'), Some(2));
+    }
+}
+
+#[test]
+fn test_emoji_version() {
+    use unicode_width::{EmojiVersion, WidthConfig};
+
+    let config = WidthConfig::DEFAULT.emoji_version(EmojiVersion::new(12, 0));
+    assert_eq!(config.char_width('\u{1FAE9}'), Some(1));
+    assert_eq!(config.char_width('\u{1F97A}'), Some(2));
+    assert_eq!(config.char_width('\u{0378}'), Some(1));
+    assert_eq!(config.width("\u{1FAE9}\u{FE0F}"), 1);
+    assert_eq!(config.width("\u{1FAE9}\u{1F3FB}"), 3);
+
+    let couple = "👩\u{200D}❤\u{FE0F}\u{200D}👨";
+    let toned_couple = "👩🏻\u{200D}❤\u{FE0F}\u{200D}👨🏼";
+    assert_eq!(config.width(couple), 2);
+    assert_eq!(config.width(toned_couple), 6);
+    assert_eq!(
+        config
+            .emoji_version(EmojiVersion::new(13, 1))
+            .width(toned_couple),
+        2
+    );
+    assert_eq!(config.width("🐦\u{200D}🔥"), 4);
+    assert_eq!(config.width("🧑🏻\u{200D}\u{1FAEF}\u{200D}🧑🏼"), 5);
+    assert_eq!(config.join_zwj_sequences(false).width(couple), 6);
+
+    assert_eq!(EmojiVersion::new(16, 0).major(), 16);
+    assert!(EmojiVersion::new(5, 0) < EmojiVersion::new(12, 1));
+    for version in [EmojiVersion::LATEST, EmojiVersion::new(255, 0)] {
+        let config = WidthConfig::DEFAULT.emoji_version(version);
+        assert_eq!(config.width("🧑🏻\u{200D}\u{1FAEF}\u{200D}🧑🏼"), 2);
+        assert_eq!(config.width(toned_couple), 2);
+        assert_eq!(config.char_width('\u{1FAE9}'), Some(2));
+    }
+}
+
+#[test]
+fn test_width_overrides() {
+    use unicode_width::{WidthConfig, WidthOverrides};
+
+    const OVERRIDES: WidthOverrides<'static> = WidthOverrides::new(&[
+        ('\x1b'..='\x1b', 0),
+        ('\u{2192}'..='\u{2193}', 2),
+        ('\u{E000}'..='\u{F8FF}', 2),
+        ('\u{1F52C}'..='\u{1F52C}', 1),
+    ]);
+    let config = WidthConfig::DEFAULT.overrides(OVERRIDES);
+    assert_eq!(OVERRIDES.get('\u{2193}'), Some(2));
+    assert_eq!(OVERRIDES.get('\u{2194}'), None);
+    assert_eq!(config.char_width('\u{E0B0}'), Some(2));
+    assert_eq!(config.char_width('\x1b'), Some(0));
+    assert_eq!(config.char_width('a'), Some(1));
+    #[cfg(feature = "cjk")]
+    assert_eq!(config.ambiguous_wide(true).char_width('\u{2192}'), Some(2));
+
+    assert_eq!(config.width("a\u{2192}b"), 4);
+    assert_eq!(config.width("\u{2192}\u{FE0E}\u{0301}"), 2);
+    assert_eq!(config.width("👩\u{200D}🔬"), 3);
+    assert_eq!(config.width("👩\u{200D}🚀"), 2);
+    assert_eq!(config.width("\u{05D0}\u{200D}\u{05DC}"), 1);
+    assert_eq!(
+        WidthConfig::DEFAULT
+            .overrides(WidthOverrides::EMPTY)
+            .width("👩\u{200D}🔬"),
+        2
+    );
+    for s in ["\u{E0A0}\u{E0B0}x", "a\u{2192}\u{1F52C}b", "\x1b[m"] {
+        let sum: usize = s.chars().map(|c| config.char_width(c).unwrap_or(0)).sum();
+        assert_eq!(config.width(s), sum, "{s:?}");
+    }
+
+    let no_breaks = WidthConfig::DEFAULT
+        .overrides(WidthOverrides::new(&[('\n'..='\n', 0)]))
+        .count_line_breaks(false);
+    assert_eq!(no_breaks.width("\n"), 0);
+    assert_eq!(no_breaks.width("a\nb"), 2);
+    assert_eq!(no_breaks.width("a\r\nb\r"), 2);
+    let wide_breaks = WidthConfig::DEFAULT
+        .overrides(WidthOverrides::new(&[('\n'..='\n', 2)]))
+        .count_line_breaks(false);
+    assert_eq!(wide_breaks.width("a\r\nb"), 4);
+
+    let no_ri = WidthConfig::DEFAULT
+        .overrides(WidthOverrides::new(&[('\u{1F1EE}'..='\u{1F1EE}', 0)]))
+        .unpaired_regional_indicator_width(2);
+    assert_eq!(no_ri.width("🇮"), 0);
+    assert_eq!(no_ri.width("🇮🇸🇳"), 2);
+    assert_eq!(no_ri.width("🇸🇮🇳🇴"), 4);
+
+    #[cfg(feature = "alloc")]
+    {
+        use unicode_width::parse_width_overrides;
+
+        let text = "# icons\nF8FF ; 1\n\nE0A0..E0A2;2 # Powerline\n";
+        let ranges = parse_width_overrides(text).unwrap();
+        assert_eq!(
+            ranges,
+            [('\u{E0A0}'..='\u{E0A2}', 2), ('\u{F8FF}'..='\u{F8FF}', 1)]
+        );
+        let config = WidthConfig::DEFAULT.overrides(WidthOverrides::new(&ranges));
+        assert_eq!(config.width("\u{E0A1}\u{F8FF}\u{E0A3}"), 4);
+
+        for (text, line) in [
+            ("E0A0\n", 1),
+            ("\nE0A0 ; x\n", 2),
+            ("E0A0..E09F ; 1", 1),
+            ("D800 ; 1", 1),
+            ("E0A0..E0A2 ; 1\nE0A2 ; 2", 2),
+        ] {
+            assert_eq!(
+                parse_width_overrides(text).unwrap_err().line(),
+                line,
+                "{text:?}"
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_width_overrides_unsorted() {
+    unicode_width::WidthOverrides::new(&[('b'..='b', 2), ('a'..='a', 2)]);
+}
+
+#[test]
+fn test_nerd_fonts() {
+    use unicode_width::{presets, WidthConfig};
+
+    let config = WidthConfig::DEFAULT.overrides(presets::nerd_fonts());
+    assert_eq!(config.char_width('\u{E0A0}'), Some(1));
+    assert_eq!(config.char_width('\u{E0B6}'), Some(1));
+    assert_eq!(config.char_width('\u{E0C9}'), Some(1));
+    assert_eq!(config.char_width('\u{E5FA}'), Some(2));
+    assert_eq!(config.char_width('\u{F09B}'), Some(2));
+    assert_eq!(config.char_width('\u{F0001}'), Some(2));
+    assert_eq!(config.char_width('\u{2500}'), Some(1));
+    assert_eq!(config.width("\u{E0B6}\u{F07B} ~/src\u{E0B4}"), 10);
+    #[cfg(feature = "cjk")]
+    {
+        let config = config.ambiguous_wide(true);
+        assert_eq!(config.char_width('\u{E0B0}'), Some(1));
+        assert_eq!(config.char_width('\u{E0C9}'), Some(2));
+        assert_eq!(config.width("\u{E0A0} main \u{E0B0}"), 8);
+    }
+}
+
+#[test]
+fn test_width_tables() {
+    use unicode_width::WidthTables;
+
+    let tables = WidthTables::BUILTIN;
+    assert_eq!(tables.unicode_version(), unicode_width::UNICODE_VERSION);
+    assert_eq!(tables.char_width('\u{1F600}'), Some(2));
+    assert_eq!(tables.width("👩\u{200D}🔬 \u{2764}\u{FE0F}"), 5);
+    #[cfg(feature = "cjk")]
+    assert_eq!(tables.width_cjk("\u{00A1}"), 2);
+
+    #[cfg(feature = "alloc")]
+    {
+        use unicode_width::TableError;
+
+        let bytes = tables.to_bytes();
+        let loaded = WidthTables::from_bytes(&bytes).unwrap();
+        for c in '\0'..=char::MAX {
+            assert_eq!(loaded.char_width(c), c.width(), "{c:?}");
+            #[cfg(feature = "cjk")]
+            assert_eq!(loaded.char_width_cjk(c), c.width_cjk(), "{c:?}");
+        }
+        for s in [
+            "👩\u{200D}🔬",
+            "\u{2764}\u{FE0F}\u{FE0E}",
+            "🇮🇸🇳🇴\u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}",
+            "\u{0627}\u{0644}\r\n\u{17D2}",
+        ] {
+            assert_eq!(loaded.width(s), s.width(), "{s:?}");
+            #[cfg(feature = "cjk")]
+            assert_eq!(loaded.width_cjk(s), s.width_cjk(), "{s:?}");
+        }
+
+        // Widen 'é' by editing its entry in the leaf tables
+        let root_len = if cfg!(feature = "cjk") { 512 } else { 256 };
+        let middle_len = usize::from(u16::from_le_bytes([bytes[9], bytes[10]]));
+        let middle = 15 + root_len;
+        let leaves = middle + 64 * middle_len;
+        let leaf = usize::from(bytes[middle + 64 * usize::from(bytes[15]) + 1]);
+        let mut edited = bytes.clone();
+        let cp = 'é' as usize;
+        edited[leaves + 32 * leaf + (cp >> 2 & 0x1F)] ^= 0b11 << (2 * (cp & 0b11));
+        let edited: &'static [u8] = edited.leak();
+        let edited = WidthTables::from_bytes(edited).unwrap();
+        assert_eq!(edited.char_width('é'), Some(2));
+        assert_eq!(edited.width("café"), 5);
+        assert_eq!(edited.char_width('è'), Some(1));
+        assert_eq!(edited.split_at_width("café!", 4), ("caf", "é!", 3));
+        assert_eq!(edited.rsplit_at_width("café!", 2), ("café", "!", 1));
+        assert_eq!(edited.column_at_byte("café!", 5, Rounding::Down), 5);
+        assert_eq!(edited.byte_at_column("café!", 4, Rounding::Up), 5);
+        let segments: Vec<_> = edited.width_segments("cé").collect();
+        assert_eq!(segments, [(0..1, 1), (1..3, 2)]);
+        assert_eq!(edited.truncate_end("café!", 5, "…"), "caf…");
+        assert_eq!(edited.truncate_start("café!", 4, "…"), "…é!");
+        assert_eq!(edited.truncate_middle("éaé", 4, "…"), "é…");
+        assert_eq!(edited.width_ansi("\x1b[1mcafé\x1b[0m"), 5);
+        assert_eq!(
+            edited.truncate_ansi("\x1b[1mcafé\x1b[0m", 4, "…"),
+            "\x1b[1mcaf…\x1b[0m"
+        );
+        assert_eq!(
+            edited.slice_ansi("\x1b[1mcafé\x1b[0m", 3..5),
+            "\x1b[1mé\x1b[0m"
+        );
+        let every_4 = TabStops::Every(4);
+        assert_eq!(edited.width_with_tabs("é\té", every_4), 6);
+        #[cfg(feature = "alloc")]
+        assert_eq!(edited.expand_tabs("é\té", every_4), "é  é");
+        let lines: Vec<_> = edited.line_widths("café\né").collect();
+        assert_eq!(lines, [5, 2]);
+        assert_eq!(edited.max_line_width("é\ncafé"), 5);
+        assert_eq!(edited.width_with_controls("é\x01", ControlPolicy::Caret), 4);
+        assert_eq!(
+            format!("[{:4}]", DisplayWidth::with_tables("é", edited)),
+            "[é  ]"
+        );
+        let mut acc = WidthAccumulator::with_tables(edited);
+        acc.push_str("café");
+        assert_eq!(acc.width(), 5);
+        let options = unicode_width::wrap::Options::with_tables(edited, 4);
+        assert_eq!(unicode_width::wrap::wrap("ab é c", &options), ["ab", "é c"]);
+        let config = unicode_width::WidthConfig::DEFAULT.tables(edited);
+        assert_eq!(config.width("café"), 5);
+        assert_eq!(config.char_width('é'), Some(2));
+        assert_eq!(
+            unicode_width::Profile::Xterm
+                .config()
+                .tables(edited)
+                .width("é"),
+            2
+        );
+
+        let borrowed = WidthTables::from_bytes(&bytes).unwrap();
+        let config = unicode_width::WidthConfig::DEFAULT.tables(borrowed);
+        assert_eq!(config.width("café"), 4);
+
+        // Mark 'a' for special handling, which the crate doesn't know how to do
+        let mut unknown_special = bytes.clone();
+        let leaf = usize::from(bytes[middle + 64 * usize::from(bytes[15])]);
+        let cp = 'a' as usize;
+        unknown_special[leaves + 32 * leaf + (cp >> 2 & 0x1F)] |= 0b11 << (2 * (cp & 0b11));
+        assert_eq!(
+            WidthTables::from_bytes(&unknown_special),
+            Err(TableError::UnknownSpecialWidth('a'))
+        );
+
+        let mut truncated = bytes.clone();
+        truncated.pop();
+        assert_eq!(
+            WidthTables::from_bytes(&truncated),
+            Err(TableError::Truncated)
+        );
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(
+            WidthTables::from_bytes(&trailing),
+            Err(TableError::Malformed)
+        );
+        let mut out_of_bounds = bytes.clone();
+        out_of_bounds[15] = 0xFF;
+        assert_eq!(
+            WidthTables::from_bytes(&out_of_bounds),
+            Err(TableError::Malformed)
+        );
+        let mut version = bytes.clone();
+        version[4] = 2;
+        assert_eq!(
+            WidthTables::from_bytes(&version),
+            Err(TableError::UnsupportedFormat(2))
+        );
+        assert_eq!(
+            WidthTables::from_bytes(b"not a table file"),
+            Err(TableError::NotATableFile)
+        );
+        #[cfg(feature = "cjk")]
+        {
+            let mut no_cjk = bytes.clone();
+            no_cjk[8] = 0;
+            no_cjk.drain(15 + 256..15 + 512);
+            let no_cjk = WidthTables::from_bytes(&no_cjk).unwrap();
+            assert!(tables.has_cjk_tables());
+            assert!(!no_cjk.has_cjk_tables());
+            assert_eq!(no_cjk.width("\u{00A1}👩\u{200D}🔬"), 3);
+            let result = std::panic::catch_unwind(|| no_cjk.width_cjk("\u{00A1}"));
+            assert!(result.is_err());
+        }
+    }
+}
+
+#[test]
+fn test_east_asian_width() {
+    use unicode_width::{char_width_class, east_asian_width, CharWidthClass, EastAsianWidth};
+
+    assert_eq!(east_asian_width('a'), EastAsianWidth::Narrow);
+    assert_eq!(east_asian_width('中'), EastAsianWidth::Wide);
+    assert_eq!(east_asian_width('Ａ'), EastAsianWidth::Fullwidth);
+    assert_eq!(east_asian_width('ｱ'), EastAsianWidth::Halfwidth);
+    assert_eq!(east_asian_width('¡'), EastAsianWidth::Ambiguous);
+    assert_eq!(east_asian_width('\u{E000}'), EastAsianWidth::Ambiguous);
+    assert_eq!(east_asian_width('\u{0400}'), EastAsianWidth::Neutral);
+    assert_eq!(east_asian_width('\u{10FFFD}'), EastAsianWidth::Ambiguous);
+
+    assert_eq!(char_width_class('\x1b'), CharWidthClass::Control);
+    assert_eq!(char_width_class('\u{300}'), CharWidthClass::ZeroWidth);
+    assert_eq!(char_width_class('\u{200B}'), CharWidthClass::ZeroWidth);
+    assert_eq!(char_width_class('a'), CharWidthClass::Narrow);
+    assert_eq!(char_width_class('ｱ'), CharWidthClass::Narrow);
+    assert_eq!(char_width_class('中'), CharWidthClass::Wide);
+    assert_eq!(char_width_class('👩'), CharWidthClass::Wide);
+    assert_eq!(char_width_class('¡'), CharWidthClass::Ambiguous);
+    assert_eq!(char_width_class('\u{115F}'), CharWidthClass::Special);
+    assert_eq!(char_width_class('\u{17D8}'), CharWidthClass::Special);
+    #[cfg(feature = "cjk")]
+    assert_eq!(char_width_class('é'), CharWidthClass::Special);
+}
+
+#[test]
+fn test_emoji_properties() {
+    use unicode_width::emoji;
+
+    for c in ['😀', '👋', '\u{1F1E6}', '\u{1FAE9}'] {
+        assert!(emoji::is_emoji(c), "{c:?}");
+        assert!(emoji::is_emoji_presentation(c), "{c:?}");
+        assert!(
+            emoji::is_extended_pictographic(c) != emoji::is_emoji_component(c),
+            "{c:?}"
+        );
+    }
+    for c in ['#', '0', '\u{A9}', '\u{2764}', '\u{231A}'] {
+        assert!(emoji::is_emoji(c), "{c:?}");
+        assert!(emoji::starts_emoji_presentation_seq(c), "{c:?}");
+    }
+    assert!(!emoji::is_emoji_presentation('\u{2764}'));
+    assert!(emoji::is_emoji_presentation('\u{231A}'));
+    assert!(emoji::starts_non_ideographic_text_presentation_seq(
+        '\u{231A}'
+    ));
+    assert!(!emoji::starts_non_ideographic_text_presentation_seq(
+        '\u{1F201}'
+    ));
+
+    assert!(emoji::is_emoji_component('\u{1F3FB}'));
+    assert!(emoji::is_emoji_component('\u{200D}'));
+    assert!(emoji::is_emoji_component('\u{FE0F}'));
+    assert!(emoji::is_emoji_component('\u{E0061}'));
+    assert!(emoji::is_emoji_modifier_base('👋'));
+    assert!(!emoji::is_emoji_modifier_base('😀'));
+    assert!(emoji::is_emoji_modifier('\u{1F3FB}'));
+    assert!(!emoji::is_emoji_modifier('👋'));
+
+    // Reserved for future emoji
+    assert!(emoji::is_extended_pictographic('\u{1FFFD}'));
+    assert!(!emoji::is_emoji('\u{1FFFD}'));
+
+    for c in ['a', '中', '\u{0}', '\u{E000}', '\u{10FFFF}'] {
+        assert!(!emoji::is_emoji(c), "{c:?}");
+        assert!(!emoji::is_emoji_presentation(c), "{c:?}");
+        assert!(!emoji::is_emoji_component(c), "{c:?}");
+        assert!(!emoji::is_extended_pictographic(c), "{c:?}");
+    }
+}
+
+#[test]
+fn test_legacy_0_1() {
+    use unicode_width::compat::legacy_0_1::{char_width, str_width};
+    #[cfg(feature = "cjk")]
+    use unicode_width::compat::legacy_0_1::{char_width_cjk, str_width_cjk};
+
+    assert_eq!(char_width('\0'), Some(0));
+    assert_eq!(char_width('\n'), None);
+    assert_eq!(char_width('\u{AD}'), Some(1));
+    assert_eq!(char_width('\u{115F}'), Some(2));
+    assert_eq!(char_width('\u{17A4}'), Some(1));
+    assert_eq!(char_width('\u{17D8}'), Some(1));
+    assert_eq!(char_width('\u{2D7F}'), Some(0));
+    assert_eq!(char_width('\u{0605}'), Some(1));
+    assert_eq!(char_width('\u{0D4E}'), Some(1));
+    assert_eq!(char_width('\u{A8FA}'), Some(1));
+    assert_eq!(char_width('\u{2070}'), Some(1));
+
+    assert_eq!(str_width("a\r\nb"), 2);
+    assert_eq!(str_width("\x1b[31mred"), 7);
+    assert_eq!(str_width("\u{2764}\u{FE0F}"), 2);
+    assert_eq!(str_width("\u{231A}\u{FE0E}"), 2);
+    assert_eq!(str_width("👩\u{200D}🔬"), 4);
+    assert_eq!(str_width("🇮🇸"), 2);
+    assert_eq!(str_width("ꓸꓹꓼ"), 3);
+    assert_eq!(str_width("لا"), 2);
+    assert_eq!(str_width("\u{0D4E}\u{0D15}"), 2);
+
+    #[cfg(feature = "cjk")]
+    {
+        assert_eq!(char_width_cjk('\u{AD}'), Some(1));
+        assert_eq!(char_width_cjk('\u{C6}'), Some(2));
+        assert_eq!(char_width_cjk('\u{2C7}'), Some(2));
+        assert_eq!(char_width_cjk('\u{2018}'), Some(2));
+        assert_eq!(char_width_cjk('\u{0387}'), Some(1));
+        assert_eq!(str_width_cjk("<\u{338}"), 1);
+        assert_eq!(str_width_cjk("\u{2018}\u{FE00}"), 2);
+    }
+
+    // Other characters have the same width as they do now
+    for c in '\0'..=char::MAX {
+        if !matches!(c, '\0' | '\u{AD}' | '\u{17A4}' | '\u{17D8}' | '\u{2D7F}')
+            && char_width(c) == Some(0)
+        {
+            assert_eq!(c.width(), Some(0), "{c:?}");
+        }
+    }
+}
+
+#[test]
+fn test_uax11() {
+    use unicode_width::uax11::{width, Context};
+
+    let ne = Context::NonEastAsian;
+    assert_eq!(Context::default(), ne);
+    assert_eq!(width('a', ne), Some(1));
+    assert_eq!(width('中', ne), Some(2));
+    assert_eq!(width('\u{FF21}', ne), Some(2));
+    assert_eq!(width('\u{17A4}', ne), Some(1));
+    assert_eq!(width('\u{17D8}', ne), Some(1));
+    assert_eq!(width('\u{115F}', ne), Some(0));
+    assert_eq!(width('\u{2D7F}', ne), Some(0));
+    assert_eq!(width('\u{301}', ne), Some(0));
+    assert_eq!(width('\u{1F1EE}', ne), Some(1));
+    assert_eq!(width('\u{C6}', ne), Some(1));
+    assert_eq!(width('\u{E000}', ne), Some(1));
+    assert_eq!(width('\0', ne), None);
+    assert_eq!(width('\x1b', ne), None);
+
+    // Only the exceptions differ from the default widths of single characters
+    for c in '\0'..=char::MAX {
+        if !matches!(c, '\u{115F}' | '\u{2D7F}' | '\u{17A4}' | '\u{17D8}') {
+            assert_eq!(width(c, ne), c.width(), "{c:?}");
+        }
+    }
+
+    let ea = Context::EastAsian;
+    assert_eq!(width('a', ea), Some(1));
+    assert_eq!(width('中', ea), Some(2));
+    assert_eq!(width('\u{B1}', ea), Some(2));
+    assert_eq!(width('\u{C6}', ea), Some(2));
+    assert_eq!(width('\u{2018}', ea), Some(2));
+    assert_eq!(width('\u{E000}', ea), Some(2));
+    assert_eq!(width('\u{301}', ea), Some(0));
+    assert_eq!(width('\u{17D8}', ea), Some(1));
+    assert_eq!(width('\x1b', ea), None);
+}
+
 // Test traits are unsealed
 
 #[cfg(feature = "cjk")]